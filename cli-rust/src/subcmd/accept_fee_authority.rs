@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{Pool, FEE_AUTHORITY_NOMINEE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    accept_fee_authority_ix, AcceptFeeAuthorityIxArgs, AcceptFeeAuthorityKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Accepts the nomination as an unstake liquidity pool's fee authority")]
+pub struct AcceptFeeAuthorityArgs {
+    #[arg(help = "Pubkey of the pool to take over the fee authority of")]
+    pool_account: String,
+    #[arg(
+        help = "Path to keypair that is the pool's nominated fee authority. Defaults to config wallet"
+    )]
+    new_fee_authority: Option<String>,
+}
+
+impl SubcmdExec for AcceptFeeAuthorityArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_account).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut new_fee_authority = payer_pk;
+        if let Some(auth) = self.new_fee_authority.as_ref() {
+            let new_fee_authority_keypair = read_keypair_file(auth).unwrap();
+            new_fee_authority = new_fee_authority_keypair.pubkey();
+            signers.push(Box::new(new_fee_authority_keypair));
+        }
+
+        let fee_authority_nominee_account = Pubkey::find_program_address(
+            &[&pool_account.to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+            &ID,
+        )
+        .0;
+
+        let ix = accept_fee_authority_ix(
+            AcceptFeeAuthorityKeys {
+                new_fee_authority,
+                fee_authority: pool.fee_authority,
+                pool_account,
+                fee_authority_nominee_account,
+            },
+            AcceptFeeAuthorityIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} fee authority updated from {} to {}",
+            pool_account, pool.fee_authority, new_fee_authority
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
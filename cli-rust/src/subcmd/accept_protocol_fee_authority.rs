@@ -0,0 +1,66 @@
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{ProtocolFee, PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED, PROTOCOL_FEE_SEED},
+    ID,
+};
+use unstake_interface::{
+    accept_protocol_fee_authority_ix, AcceptProtocolFeeAuthorityIxArgs,
+    AcceptProtocolFeeAuthorityKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Accepts the nomination as the program's protocol fee authority")]
+pub struct AcceptProtocolFeeAuthorityArgs {
+    #[arg(
+        help = "Path to keypair that is the nominated protocol fee authority. Defaults to config wallet"
+    )]
+    new_authority: Option<String>,
+}
+
+impl SubcmdExec for AcceptProtocolFeeAuthorityArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let protocol_fee_account = Pubkey::find_program_address(&[PROTOCOL_FEE_SEED], &ID).0;
+        let protocol_fee = client.get_account_data(&protocol_fee_account).unwrap();
+        let protocol_fee = ProtocolFee::try_deserialize(&mut protocol_fee.as_slice()).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut new_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.new_authority.as_ref() {
+            let new_authority_keypair = read_keypair_file(auth).unwrap();
+            new_authority = new_authority_keypair.pubkey();
+            signers.push(Box::new(new_authority_keypair));
+        }
+
+        let ix = accept_protocol_fee_authority_ix(
+            AcceptProtocolFeeAuthorityKeys {
+                new_authority,
+                authority: protocol_fee.authority,
+                protocol_fee_account,
+                nominee_account: Pubkey::find_program_address(
+                    &[PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED],
+                    &ID,
+                )
+                .0,
+            },
+            AcceptProtocolFeeAuthorityIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!("Protocol fee authority set to {}", new_authority);
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
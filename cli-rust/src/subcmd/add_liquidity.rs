@@ -11,9 +11,12 @@ use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Tran
 use spl_associated_token_account::{
     get_associated_token_address, instruction::create_associated_token_account,
 };
-use unstake::state::FLASH_ACCOUNT_SEED_SUFFIX;
+use unstake::state::{
+    ALLOWED_LP_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+    POOL_PAUSE_SEED_SUFFIX, PROTOCOL_PAUSE_SEED, WITHDRAW_QUEUE_SEED_SUFFIX,
+};
 use unstake::{state::Pool, ID};
-use unstake_interface::{add_liquidity_ix, AddLiquidityIxArgs, AddLiquidityKeys};
+use unstake_interface::{add_liquidity_v2_ix, AddLiquidityV2IxArgs, AddLiquidityV2Keys};
 
 use crate::tx_utils::send_or_sim_tx;
 
@@ -30,6 +33,11 @@ pub struct AddLiquidityArgs {
     from: Option<String>,
     #[arg(help = "LP token account to mint LP tokens to. Defaults to ATA of `from`")]
     mint_lp_tokens_to: Option<String>,
+    #[arg(
+        long,
+        help = "Minimum amount of LP tokens to receive. Fails if fewer would be minted"
+    )]
+    min_lp_out: Option<f64>,
 }
 
 impl SubcmdExec for AddLiquidityArgs {
@@ -65,8 +73,8 @@ impl SubcmdExec for AddLiquidityArgs {
         let flash_account =
             Pubkey::find_program_address(&[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX], &ID);
 
-        let ix = add_liquidity_ix(
-            AddLiquidityKeys {
+        let ix = add_liquidity_v2_ix(
+            AddLiquidityV2Keys {
                 from,
                 pool_account: pool_key,
                 mint_lp_tokens_to,
@@ -75,9 +83,35 @@ impl SubcmdExec for AddLiquidityArgs {
                 token_program: spl_token::id(),
                 pool_sol_reserves: pool_sol_reserves.0,
                 flash_account: flash_account.0,
+                pool_pause_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                protocol_pause_account: Pubkey::find_program_address(&[PROTOCOL_PAUSE_SEED], &ID).0,
+                pool_config_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                allowed_lp_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &from.to_bytes(),
+                        ALLOWED_LP_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
             },
-            AddLiquidityIxArgs {
+            AddLiquidityV2IxArgs {
                 amount: amount_lamports,
+                min_lp_tokens_out: self.min_lp_out.map_or(0, sol_to_lamports),
             },
         )
         .unwrap();
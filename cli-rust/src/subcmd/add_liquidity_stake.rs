@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::instruction::Instruction;
+use solana_program::{message::Message, pubkey::Pubkey, stake, system_program, sysvar};
+
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use unstake::state::{
+    ALLOWED_LP_SEED_SUFFIX, FEE_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+    POOL_PAUSE_SEED_SUFFIX, PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED, STAKE_DEPOSIT_FEE_SEED_SUFFIX,
+    VALIDATOR_FEE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+};
+use unstake::{
+    state::{Pool, ProtocolFee},
+    ID,
+};
+use unstake_interface::{add_liquidity_stake_ix, AddLiquidityStakeIxArgs, AddLiquidityStakeKeys};
+
+use crate::{tx_utils::send_or_sim_tx, utils::stake_account_voter};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Adds liquidity to a liquidity pool by depositing a stake account")]
+pub struct AddLiquidityStakeArgs {
+    #[arg(help = "Pubkey of the liquidity pool to add liquidity to")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the stake account to deposit")]
+    stake_account: String,
+    #[arg(
+        help = "Path to the keypair that is the stake account's withdraw authority. Defaults to wallet in config"
+    )]
+    depositor: Option<String>,
+    #[arg(help = "LP token account to mint LP tokens to. Defaults to ATA of `depositor`")]
+    mint_lp_tokens_to: Option<String>,
+}
+
+impl SubcmdExec for AddLiquidityStakeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_key).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+        let stake_account = Pubkey::from_str(&self.stake_account).unwrap();
+        let voter = stake_account_voter(&client.get_account_data(&stake_account).unwrap());
+        let protocol_fee_account = Pubkey::find_program_address(&[PROTOCOL_FEE_SEED], &ID).0;
+        let protocol_fee_data = &mut &client.get_account_data(&protocol_fee_account).unwrap()[..];
+        let protocol_fee = ProtocolFee::try_deserialize(protocol_fee_data).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut depositor = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(depositor_path) = self.depositor.as_ref() {
+            let depositor_keypair = read_keypair_file(depositor_path).unwrap();
+            depositor = depositor_keypair.pubkey();
+            signers.push(Box::new(depositor_keypair));
+        }
+
+        let depositor_ata = get_associated_token_address(&depositor, &pool.lp_mint);
+        let mint_lp_tokens_to = self
+            .mint_lp_tokens_to
+            .as_ref()
+            .map_or(depositor_ata, |s| Pubkey::from_str(s).unwrap());
+
+        let ix = add_liquidity_stake_ix(
+            AddLiquidityStakeKeys {
+                depositor,
+                stake_account,
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                lp_mint: pool.lp_mint,
+                mint_lp_tokens_to,
+                stake_account_record_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), &stake_account.to_bytes()],
+                    &ID,
+                )
+                .0,
+                stake_deposit_fee_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), STAKE_DEPOSIT_FEE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                fee_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FEE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                validator_fee_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &voter.to_bytes(),
+                        VALIDATOR_FEE_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                flash_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                pool_pause_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                protocol_pause_account: Pubkey::find_program_address(&[PROTOCOL_PAUSE_SEED], &ID).0,
+                pool_config_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                allowed_lp_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &depositor.to_bytes(),
+                        ALLOWED_LP_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                protocol_fee_account,
+                protocol_fee_destination: protocol_fee.destination,
+                clock: sysvar::clock::id(),
+                stake_history: sysvar::stake_history::id(),
+                stake_program: stake::program::id(),
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+            },
+            AddLiquidityStakeIxArgs {},
+        )
+        .unwrap();
+
+        let mut instructions: Vec<Instruction> = vec![ix];
+
+        if client.get_account(&mint_lp_tokens_to).is_err() {
+            if !mint_lp_tokens_to.eq(&depositor_ata) {
+                panic!("LP token account {} does not exist", mint_lp_tokens_to);
+            }
+
+            println!(
+                "LP token account {} does not exist, creating...",
+                mint_lp_tokens_to
+            );
+
+            instructions.insert(
+                0,
+                create_associated_token_account(
+                    &payer_pk,
+                    &depositor,
+                    &pool.lp_mint,
+                    &spl_token::id(),
+                ),
+            )
+        }
+
+        let msg = Message::new(&instructions, Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        send_or_sim_tx(args, &client, &tx);
+        println!(
+            "Stake account {} added as liquidity to pool at {}",
+            stake_account, pool_key
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::instruction::Instruction;
+use solana_program::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, system_program,
+};
+
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use unstake::state::{
+    ALLOWED_LP_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+    POOL_PAUSE_SEED_SUFFIX, PROTOCOL_PAUSE_SEED, WITHDRAW_QUEUE_SEED_SUFFIX,
+    WSOL_STAGING_SEED_SUFFIX,
+};
+use unstake::{state::Pool, ID};
+use unstake_interface::{add_liquidity_wsol_ix, AddLiquidityWsolIxArgs, AddLiquidityWsolKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Adds wSOL liquidity from a wSOL token account to a liquidity pool")]
+pub struct AddLiquidityWsolArgs {
+    #[arg(help = "Pubkey of the liquidity pool to add liquidity to")]
+    pool_account: String,
+    #[arg(help = "Amount in SOL to add as liquidity")]
+    amount_sol: f64,
+    #[arg(
+        help = "Path to the keypair authority over the wSOL token account. Defaults to wallet in config"
+    )]
+    authority: Option<String>,
+    #[arg(help = "wSOL token account to add liquidity from. Defaults to wSOL ATA of authority")]
+    from: Option<String>,
+    #[arg(help = "LP token account to mint LP tokens to. Defaults to ATA of authority")]
+    mint_lp_tokens_to: Option<String>,
+    #[arg(
+        long,
+        help = "Minimum amount of LP tokens to receive. Fails if fewer would be minted"
+    )]
+    min_lp_out: Option<f64>,
+}
+
+impl SubcmdExec for AddLiquidityWsolArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_key).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+        let amount_sol = self.amount_sol;
+        let amount_lamports = sol_to_lamports(amount_sol);
+
+        let payer_pk = payer.pubkey();
+        let mut authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let from = self.from.as_ref().map_or_else(
+            || get_associated_token_address(&authority, &spl_token::native_mint::id()),
+            |s| Pubkey::from_str(s).unwrap(),
+        );
+        let authority_ata = get_associated_token_address(&authority, &pool.lp_mint);
+        let mint_lp_tokens_to = self
+            .mint_lp_tokens_to
+            .as_ref()
+            .map_or(authority_ata, |s| Pubkey::from_str(s).unwrap());
+
+        let ix = add_liquidity_wsol_ix(
+            AddLiquidityWsolKeys {
+                from_authority: authority,
+                payer: payer_pk,
+                from,
+                wsol_staging_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WSOL_STAGING_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                native_mint: spl_token::native_mint::id(),
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                lp_mint: pool.lp_mint,
+                mint_lp_tokens_to,
+                flash_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                pool_pause_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                protocol_pause_account: Pubkey::find_program_address(&[PROTOCOL_PAUSE_SEED], &ID).0,
+                pool_config_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                allowed_lp_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &authority.to_bytes(),
+                        ALLOWED_LP_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+            },
+            AddLiquidityWsolIxArgs {
+                amount: amount_lamports,
+                min_lp_tokens_out: self.min_lp_out.map_or(0, sol_to_lamports),
+            },
+        )
+        .unwrap();
+
+        let mut instructions: Vec<Instruction> = vec![ix];
+
+        if client.get_account(&mint_lp_tokens_to).is_err() {
+            if !mint_lp_tokens_to.eq(&authority_ata) {
+                panic!("LP token account {} does not exist", mint_lp_tokens_to);
+            }
+
+            println!(
+                "LP token account {} does not exist, creating...",
+                mint_lp_tokens_to
+            );
+
+            instructions.insert(
+                0,
+                create_associated_token_account(
+                    &payer_pk,
+                    &authority,
+                    &pool.lp_mint,
+                    &spl_token::id(),
+                ),
+            )
+        }
+
+        let msg = Message::new(&instructions, Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        send_or_sim_tx(args, &client, &tx);
+        println!(
+            "{} wSOL liquidity added to pool at {}",
+            amount_sol, pool_key
+        );
+    }
+}
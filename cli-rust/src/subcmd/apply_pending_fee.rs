@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, sysvar};
+use solana_sdk::transaction::Transaction;
+use unstake::{
+    state::{PendingFee, Pool, FEE_SEED_SUFFIX, PENDING_FEE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{apply_pending_fee_ix, ApplyPendingFeeIxArgs, ApplyPendingFeeKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Apply an unstake liquidity pool's queued fee change once its delay has passed"
+)]
+pub struct ApplyPendingFeeArgs {
+    #[arg(help = "Pubkey of the pool to apply the pending fee of")]
+    pool_account: String,
+}
+
+impl SubcmdExec for ApplyPendingFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let pool = client.get_account(&pool_account).unwrap();
+        let pool = Pool::try_deserialize(&mut pool.data.as_slice()).unwrap();
+
+        let fee_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_SEED_SUFFIX], &ID).0;
+        let pending_fee_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), PENDING_FEE_SEED_SUFFIX], &ID)
+                .0;
+        let pending_fee = client.get_account(&pending_fee_account).unwrap();
+        let pending_fee = PendingFee::try_deserialize(&mut pending_fee.data.as_slice()).unwrap();
+
+        let ix = apply_pending_fee_ix(
+            ApplyPendingFeeKeys {
+                fee_authority: pool.fee_authority,
+                pool_account,
+                fee_account,
+                pending_fee_account,
+                clock: sysvar::clock::id(),
+            },
+            ApplyPendingFeeIxArgs {},
+        )
+        .unwrap();
+
+        let payer_pk = payer.pubkey();
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&vec![payer], msg, blockhash);
+        println!(
+            "Liquidity pool at {} fees updated to {:?}",
+            pool_account, pending_fee.fee
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, sysvar};
+use solana_sdk::transaction::Transaction;
+use unstake::{
+    state::{PendingLockupFee, Pool, LOCKUP_FEE_SEED_SUFFIX, PENDING_LOCKUP_FEE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    apply_pending_lockup_fee_ix, ApplyPendingLockupFeeIxArgs, ApplyPendingLockupFeeKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Apply an unstake liquidity pool's queued lockup fee change once its delay has passed"
+)]
+pub struct ApplyPendingLockupFeeArgs {
+    #[arg(help = "Pubkey of the pool to apply the pending lockup fee of")]
+    pool_account: String,
+}
+
+impl SubcmdExec for ApplyPendingLockupFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let pool = client.get_account(&pool_account).unwrap();
+        let pool = Pool::try_deserialize(&mut pool.data.as_slice()).unwrap();
+
+        let lockup_fee_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), LOCKUP_FEE_SEED_SUFFIX], &ID)
+                .0;
+        let pending_lockup_fee_account = Pubkey::find_program_address(
+            &[&pool_account.to_bytes(), PENDING_LOCKUP_FEE_SEED_SUFFIX],
+            &ID,
+        )
+        .0;
+        let pending_lockup_fee = client.get_account(&pending_lockup_fee_account).unwrap();
+        let pending_lockup_fee =
+            PendingLockupFee::try_deserialize(&mut pending_lockup_fee.data.as_slice()).unwrap();
+
+        let ix = apply_pending_lockup_fee_ix(
+            ApplyPendingLockupFeeKeys {
+                fee_authority: pool.fee_authority,
+                pool_account,
+                lockup_fee_account,
+                pending_lockup_fee_account,
+                clock: sysvar::clock::id(),
+            },
+            ApplyPendingLockupFeeIxArgs {},
+        )
+        .unwrap();
+
+        let payer_pk = payer.pubkey();
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&vec![payer], msg, blockhash);
+        println!(
+            "Liquidity pool at {} lockup fee updated to {}",
+            pool_account, pending_lockup_fee.lockup_fee.fee_ratio
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{pubkey::Pubkey, sysvar};
+use solana_sdk::transaction::Transaction;
+use unstake::{
+    state::{PendingProtocolFee, ProtocolFee, PENDING_PROTOCOL_FEE_SEED, PROTOCOL_FEE_SEED},
+    ID,
+};
+use unstake_interface::{
+    apply_pending_protocol_fee_ix, ApplyPendingProtocolFeeIxArgs, ApplyPendingProtocolFeeKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Applies the proposed protocol fee ratios once their delay has passed")]
+pub struct ApplyPendingProtocolFeeArgs {}
+
+impl SubcmdExec for ApplyPendingProtocolFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let protocol_fee_account = Pubkey::find_program_address(&[PROTOCOL_FEE_SEED], &ID).0;
+        let protocol_fee = client.get_account_data(&protocol_fee_account).unwrap();
+        let protocol_fee = ProtocolFee::try_deserialize(&mut protocol_fee.as_slice()).unwrap();
+
+        let pending_protocol_fee_account =
+            Pubkey::find_program_address(&[PENDING_PROTOCOL_FEE_SEED], &ID).0;
+        let pending_protocol_fee = client
+            .get_account_data(&pending_protocol_fee_account)
+            .unwrap();
+        let pending_protocol_fee =
+            PendingProtocolFee::try_deserialize(&mut pending_protocol_fee.as_slice()).unwrap();
+
+        let ix = apply_pending_protocol_fee_ix(
+            ApplyPendingProtocolFeeKeys {
+                authority: protocol_fee.authority,
+                protocol_fee_account,
+                pending_protocol_fee_account,
+                clock: sysvar::clock::id(),
+            },
+            ApplyPendingProtocolFeeIxArgs {},
+        )
+        .unwrap();
+
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&payer.pubkey()),
+            &[payer.as_ref()],
+            blockhash,
+        );
+        println!("Protocol fee updated to {:?}", pending_protocol_fee);
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
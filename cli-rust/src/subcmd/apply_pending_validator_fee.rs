@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::transaction::Transaction;
+use unstake::{
+    state::{
+        PendingValidatorFee, Pool, PENDING_VALIDATOR_FEE_SEED_SUFFIX, VALIDATOR_FEE_SEED_SUFFIX,
+    },
+    ID,
+};
+use unstake_interface::{
+    apply_pending_validator_fee_ix, ApplyPendingValidatorFeeIxArgs, ApplyPendingValidatorFeeKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Apply an unstake liquidity pool's queued validator fee change or removal once its delay has passed"
+)]
+pub struct ApplyPendingValidatorFeeArgs {
+    #[arg(help = "Pubkey of the pool to apply the pending validator fee of")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the validator's vote account")]
+    vote_account: String,
+}
+
+impl SubcmdExec for ApplyPendingValidatorFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let vote_account = Pubkey::from_str(&self.vote_account).unwrap();
+
+        let pool = client.get_account(&pool_account).unwrap();
+        let pool = Pool::try_deserialize(&mut pool.data.as_slice()).unwrap();
+
+        let [validator_fee_account, pending_validator_fee_account] =
+            [VALIDATOR_FEE_SEED_SUFFIX, PENDING_VALIDATOR_FEE_SEED_SUFFIX].map(|suffix| {
+                Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), &vote_account.to_bytes(), suffix],
+                    &ID,
+                )
+                .0
+            });
+        let pending_validator_fee = client.get_account(&pending_validator_fee_account).unwrap();
+        let pending_validator_fee =
+            PendingValidatorFee::try_deserialize(&mut pending_validator_fee.data.as_slice())
+                .unwrap();
+
+        let payer_pk = payer.pubkey();
+        let ix = apply_pending_validator_fee_ix(
+            ApplyPendingValidatorFeeKeys {
+                payer: payer_pk,
+                fee_authority: pool.fee_authority,
+                pool_account,
+                vote_account,
+                validator_fee_account,
+                pending_validator_fee_account,
+                system_program: system_program::id(),
+                clock: sysvar::clock::id(),
+            },
+            ApplyPendingValidatorFeeIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&vec![payer], msg, blockhash);
+        match pending_validator_fee.validator_fee {
+            Some(fee) => println!(
+                "Liquidity pool at {} validator fee for {} updated to {:?}",
+                pool_account, vote_account, fee
+            ),
+            None => println!(
+                "Liquidity pool at {} validator fee for {} removed",
+                pool_account, vote_account
+            ),
+        }
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
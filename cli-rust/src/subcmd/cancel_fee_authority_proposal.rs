@@ -3,32 +3,33 @@ use std::str::FromStr;
 use clap::Args;
 use solana_program::{message::Message, pubkey::Pubkey};
 use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
-use unstake_interface::{set_fee_authority_ix, SetFeeAuthorityIxArgs, SetFeeAuthorityKeys};
+use unstake::{state::FEE_AUTHORITY_NOMINEE_SEED_SUFFIX, ID};
+use unstake_interface::{
+    cancel_fee_authority_proposal_ix, CancelFeeAuthorityProposalIxArgs,
+    CancelFeeAuthorityProposalKeys,
+};
 
 use crate::tx_utils::send_or_sim_tx;
 
 use super::SubcmdExec;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Sets the fee authority for an unstake liquidity pool")]
-pub struct SetFeeAuthorityArgs {
-    #[arg(help = "Pubkey of the pool to set the fee authority of")]
+#[command(long_about = "Cancels the fee authority nomination of an unstake liquidity pool")]
+pub struct CancelFeeAuthorityProposalArgs {
+    #[arg(help = "Pubkey of the pool to cancel the fee authority nomination of")]
     pool_account: String,
-    #[arg(help = "Pubkey that is to be the pool's new fee authority")]
-    new_fee_authority: String,
     #[arg(
         help = "Path to keypair that is the pool's current fee authority. Defaults to config wallet"
     )]
     fee_authority: Option<String>,
 }
 
-impl SubcmdExec for SetFeeAuthorityArgs {
+impl SubcmdExec for CancelFeeAuthorityProposalArgs {
     fn process_cmd(&self, args: &crate::Args) {
         let payer = args.config.signer();
         let client = args.config.rpc_client();
 
         let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
-        let new_fee_authority = Pubkey::from_str(&self.new_fee_authority).unwrap();
 
         let payer_pk = payer.pubkey();
         let mut signers = vec![payer];
@@ -39,13 +40,17 @@ impl SubcmdExec for SetFeeAuthorityArgs {
             signers.push(Box::new(fee_authority_keypair));
         }
 
-        let ix = set_fee_authority_ix(
-            SetFeeAuthorityKeys {
-                pool_account,
+        let ix = cancel_fee_authority_proposal_ix(
+            CancelFeeAuthorityProposalKeys {
                 fee_authority,
-                new_fee_authority,
+                pool_account,
+                fee_authority_nominee_account: Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
             },
-            SetFeeAuthorityIxArgs {},
+            CancelFeeAuthorityProposalIxArgs {},
         )
         .unwrap();
 
@@ -53,8 +58,8 @@ impl SubcmdExec for SetFeeAuthorityArgs {
         let blockhash = client.get_latest_blockhash().unwrap();
         let tx = Transaction::new(&signers, msg, blockhash);
         println!(
-            "Liquidity pool at {} fee authority updated from {} to {}",
-            pool_account, fee_authority, new_fee_authority
+            "Liquidity pool at {} fee authority nomination cancelled",
+            pool_account
         );
         send_or_sim_tx(args, &client, &tx);
     }
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{WITHDRAW_QUEUE_SEED_SUFFIX, WITHDRAW_TICKET_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    claim_withdraw_ticket_ix, ClaimWithdrawTicketIxArgs, ClaimWithdrawTicketKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Claims the SOL of a withdraw ticket created by request-withdrawal, refunding its rent to the owner"
+)]
+pub struct ClaimWithdrawTicketArgs {
+    #[arg(help = "Pubkey of the liquidity pool the withdraw ticket is for")]
+    pool_account: String,
+    #[arg(help = "Path to the keypair that owns the withdraw ticket. Defaults to config wallet.")]
+    owner: Option<String>,
+    #[arg(help = "SOL account to send the claimed SOL to. Defaults to config wallet.")]
+    to: Option<String>,
+}
+
+impl SubcmdExec for ClaimWithdrawTicketArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut owner = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(owner_path) = self.owner.as_ref() {
+            let owner_keypair = read_keypair_file(owner_path).unwrap();
+            owner = owner_keypair.pubkey();
+            signers.push(Box::new(owner_keypair));
+        }
+
+        let mut to = payer_pk;
+        if let Some(to_pk) = self.to.as_ref() {
+            to = Pubkey::from_str(to_pk).unwrap();
+        }
+
+        let ix = claim_withdraw_ticket_ix(
+            ClaimWithdrawTicketKeys {
+                owner,
+                to,
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                withdraw_ticket_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &owner.to_bytes(),
+                        WITHDRAW_TICKET_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                system_program: system_program::id(),
+            },
+            ClaimWithdrawTicketIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Withdraw ticket of {} for pool at {} claimed to {}",
+            owner, pool_key, to
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{instruction::AccountMeta, message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::state::{
+    ALLOWED_LP_SEED_SUFFIX, FEE_AUTHORITY_NOMINEE_SEED_SUFFIX, FEE_DELAY_SEED_SUFFIX,
+    FEE_SEED_SUFFIX, FLASH_LOAN_FEE_SEED_SUFFIX, LOCKED_LP_SEED_SUFFIX, LOCKUP_FEE_SEED_SUFFIX,
+    PENDING_FEE_SEED_SUFFIX, PENDING_LOCKUP_FEE_SEED_SUFFIX, PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+    POOL_CONFIG_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX, STAKE_DEPOSIT_FEE_SEED_SUFFIX,
+    VALIDATOR_FEE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+};
+use unstake::{state::Pool, ID};
+use unstake_interface::{close_pool_ix, ClosePoolIxArgs, ClosePoolKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Closes an empty unstake liquidity pool, burning its locked LP tokens, sweeping its leftover SOL and refunding rent to the destination"
+)]
+pub struct ClosePoolArgs {
+    #[arg(help = "Pubkey of the pool to close")]
+    pool_account: String,
+    #[arg(help = "Path to keypair that is the pool's fee authority. Defaults to config wallet")]
+    fee_authority: Option<String>,
+    #[arg(
+        long,
+        help = "Pubkey to sweep the pool's leftover SOL and refund rent to. Defaults to config wallet"
+    )]
+    destination: Option<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Comma-separated vote account pubkeys whose ValidatorFee and PendingValidatorFee accounts to close. ValidatorFee accounts not listed are left open"
+    )]
+    validator_fee_vote_accounts: Vec<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Comma-separated LP pubkeys whose AllowedLp accounts to close. AllowedLp accounts not listed are left open"
+    )]
+    allowed_lps: Vec<String>,
+}
+
+impl SubcmdExec for ClosePoolArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_account).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut fee_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+        let destination = self
+            .destination
+            .as_ref()
+            .map_or(payer_pk, |s| Pubkey::from_str(s).unwrap());
+
+        let pool_pda = |suffix: &[u8]| {
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), suffix], &ID).0
+        };
+
+        let mut ix = close_pool_ix(
+            ClosePoolKeys {
+                fee_authority,
+                destination,
+                pool_account,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_account.to_bytes()], &ID).0,
+                lp_mint: pool.lp_mint,
+                locked_lp_token_account: pool_pda(LOCKED_LP_SEED_SUFFIX),
+                fee_account: pool_pda(FEE_SEED_SUFFIX),
+                flash_loan_fee_account: pool_pda(FLASH_LOAN_FEE_SEED_SUFFIX),
+                fee_delay_account: pool_pda(FEE_DELAY_SEED_SUFFIX),
+                pending_fee_account: pool_pda(PENDING_FEE_SEED_SUFFIX),
+                fee_authority_nominee_account: pool_pda(FEE_AUTHORITY_NOMINEE_SEED_SUFFIX),
+                pool_config_account: pool_pda(POOL_CONFIG_SEED_SUFFIX),
+                pool_pause_account: pool_pda(POOL_PAUSE_SEED_SUFFIX),
+                stake_deposit_fee_account: pool_pda(STAKE_DEPOSIT_FEE_SEED_SUFFIX),
+                lockup_fee_account: pool_pda(LOCKUP_FEE_SEED_SUFFIX),
+                pending_lockup_fee_account: pool_pda(PENDING_LOCKUP_FEE_SEED_SUFFIX),
+                withdraw_queue_account: pool_pda(WITHDRAW_QUEUE_SEED_SUFFIX),
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+            },
+            ClosePoolIxArgs {},
+        )
+        .unwrap();
+        // (pubkey, pda) pairs of the ValidatorFee, PendingValidatorFee and AllowedLp accounts to close
+        for (keys, suffix) in [
+            (&self.validator_fee_vote_accounts, VALIDATOR_FEE_SEED_SUFFIX),
+            (
+                &self.validator_fee_vote_accounts,
+                PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+            ),
+            (&self.allowed_lps, ALLOWED_LP_SEED_SUFFIX),
+        ] {
+            for key in keys.iter().map(|s| Pubkey::from_str(s).unwrap()) {
+                let pda = Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), &key.to_bytes(), suffix],
+                    &ID,
+                )
+                .0;
+                ix.accounts.push(AccountMeta::new_readonly(key, false));
+                ix.accounts.push(AccountMeta::new(pda, false));
+            }
+        }
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} closed, SOL and rent sent to {}",
+            pool_account, destination
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
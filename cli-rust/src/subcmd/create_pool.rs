@@ -22,7 +22,12 @@ pub struct CreatePoolArgs {
     #[arg(
         help = "Path to JSON file defining liquidity pool's fee settings. Example contents:\n\
         '{ \"liquidity_linear\": { \"max_liq_remaining\": 0.003, \"zero_liq_remaining\": 0.03 }}\n\
-        '{ \"flat\": 0.01 }'"
+        '{ \"flat\": 0.01 }'\n\
+        '{ \"piecewise_linear\": [{ \"utilization\": 0, \"fee_ratio\": 0.001 }, { \"utilization\": 0.8, \"fee_ratio\": 0.005 }, { \"utilization\": 1, \"fee_ratio\": 0.05 }]}'\n\
+        '{ \"epoch_progress\": { \"base_ratio\": 0.01, \"epoch_end_multiplier\": 0.5 }}'\n\
+        '{ \"delegation_state\": { \"active_ratio\": 0.01, \"deactivating_ratio\": 0.005, \"inactive_ratio\": 0.001 }}'\n\
+        Optionally bound the fee charged per unstake in lamports with \"min_fee_lamports\" and/or \"max_fee_lamports\":\n\
+        '{ \"flat\": 0.01, \"min_fee_lamports\": 10000000, \"max_fee_lamports\": 100000000000 }'"
     )]
     fee_path: String,
     #[arg(
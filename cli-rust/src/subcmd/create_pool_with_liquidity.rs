@@ -0,0 +1,153 @@
+use clap::Args;
+
+use solana_program::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, system_program, sysvar,
+};
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use unstake::{
+    state::{FEE_SEED_SUFFIX, LOCKED_LP_SEED_SUFFIX, PROTOCOL_PAUSE_SEED},
+    ID,
+};
+use unstake_interface::{
+    create_pool_with_liquidity_ix, CreatePoolWithLiquidityIxArgs, CreatePoolWithLiquidityKeys,
+};
+
+use crate::{
+    tx_utils::{send_or_sim_tx, unique_signers},
+    utils::convert_fee,
+};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Create a new unstake liquidity pool and add its initial SOL liquidity in the same instruction"
+)]
+pub struct CreatePoolWithLiquidityArgs {
+    #[arg(
+        help = "Path to JSON file defining liquidity pool's fee settings. See `create-pool` for examples"
+    )]
+    fee_path: String,
+    #[arg(help = "Amount in SOL of initial liquidity, paid by the wallet in config")]
+    initial_liquidity_sol: f64,
+    #[arg(
+        help = "Path to keypair actings as the pool's fee authority. Defaults to wallet in config."
+    )]
+    fee_authority: Option<String>,
+    #[arg(
+        help = "Path to keypair that will be the pool's address. Defaults to randomly generated keypair."
+    )]
+    pool_account: Option<String>,
+    #[arg(
+        help = "Path to keypair that will be the pool's LP mint address. Defaults to randomly generated keypair."
+    )]
+    lp_mint: Option<String>,
+    #[arg(
+        long,
+        help = "Path to keypair that will be the address of the LP token account the initial LP tokens are minted to. Defaults to randomly generated keypair. Ignored if --lock-initial-lp is set"
+    )]
+    lp_token_account: Option<String>,
+    #[arg(
+        long,
+        help = "Permanently lock the initial LP tokens in the pool's locked LP token account instead of minting them to an LP token account owned by the wallet in config"
+    )]
+    lock_initial_lp: bool,
+}
+
+impl SubcmdExec for CreatePoolWithLiquidityArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let fee = convert_fee(&self.fee_path);
+        let initial_lamports = sol_to_lamports(self.initial_liquidity_sol);
+
+        println!("Fee: {:?}", fee);
+
+        let [pool_kp, lp_mint_kp, lp_token_account_kp] =
+            [&self.pool_account, &self.lp_mint, &self.lp_token_account].map(|opt| {
+                opt.as_ref()
+                    .map_or_else(Keypair::new, |path| read_keypair_file(path).unwrap())
+            });
+        let pool_sol_reserves = Pubkey::find_program_address(&[&pool_kp.pubkey().to_bytes()], &ID);
+        let fee_account =
+            Pubkey::find_program_address(&[&pool_kp.pubkey().to_bytes(), FEE_SEED_SUFFIX], &ID);
+        let payer_pk = payer.pubkey();
+        let locked_lp_token_account = Pubkey::find_program_address(
+            &[&pool_kp.pubkey().to_bytes(), LOCKED_LP_SEED_SUFFIX],
+            &ID,
+        );
+        // unused optional account is set to the program ID
+        let (mint_lp_tokens_to, locked_lp_token_account, lp_tokens_destination) =
+            if self.lock_initial_lp {
+                (ID, locked_lp_token_account.0, locked_lp_token_account.0)
+            } else {
+                let lp_token_account = lp_token_account_kp.pubkey();
+                (lp_token_account, ID, lp_token_account)
+            };
+
+        let mut accounts = CreatePoolWithLiquidityKeys {
+            pool_account: pool_kp.pubkey(),
+            lp_mint: lp_mint_kp.pubkey(),
+            fee_authority: payer.pubkey(),
+            payer: payer_pk,
+            fee_account: fee_account.0,
+            pool_sol_reserves: pool_sol_reserves.0,
+            lp_token_account_owner: payer_pk,
+            mint_lp_tokens_to,
+            locked_lp_token_account,
+            protocol_pause_account: Pubkey::find_program_address(&[PROTOCOL_PAUSE_SEED], &ID).0,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent: sysvar::rent::id(),
+        };
+        let mut signers = vec![payer, Box::new(pool_kp), Box::new(lp_mint_kp)];
+        if !self.lock_initial_lp {
+            signers.push(Box::new(lp_token_account_kp));
+        }
+        if let Some(path) = self.fee_authority.as_ref() {
+            let new_fee_auth = read_keypair_file(path).unwrap();
+            accounts.fee_authority = new_fee_auth.pubkey();
+            signers.push(Box::new(new_fee_auth));
+        }
+        unique_signers(&mut signers);
+
+        let mut ix = create_pool_with_liquidity_ix(
+            accounts,
+            CreatePoolWithLiquidityIxArgs {
+                fee,
+                initial_lamports,
+            },
+        )
+        .unwrap();
+        // the LP token account to be created must sign if provided
+        ix.accounts
+            .iter_mut()
+            .filter(|meta| !self.lock_initial_lp && meta.pubkey == mint_lp_tokens_to)
+            .for_each(|meta| meta.is_signer = true);
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Initializing Liquidity pool at {} with {} SOL\n\
+          LP mint: {}\n\
+          Fee authority: {}\n\
+          Initial LP tokens minted to: {}{}",
+            accounts.pool_account,
+            self.initial_liquidity_sol,
+            accounts.lp_mint,
+            accounts.fee_authority,
+            lp_tokens_destination,
+            if self.lock_initial_lp {
+                " (locked)"
+            } else {
+                ""
+            },
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
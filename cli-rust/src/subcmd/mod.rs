@@ -1,52 +1,133 @@
 use clap::Subcommand;
 
+mod accept_fee_authority;
+mod accept_protocol_fee_authority;
+mod add_allowed_lp;
 mod add_liquidity;
+mod add_liquidity_stake;
+mod add_liquidity_wsol;
+mod apply_pending_fee;
+mod apply_pending_lockup_fee;
+mod apply_pending_protocol_fee;
+mod apply_pending_validator_fee;
+mod cancel_fee_authority_proposal;
+mod claim_withdraw_ticket;
+mod close_pool;
 mod create_pool;
+mod create_pool_with_liquidity;
 mod deactivate_all;
 mod deactivate_stake_account;
 mod fetch_protocol_fee;
 mod init_protocol_fee;
+mod propose_fee_authority;
 mod reclaim_all;
 mod reclaim_stake_account;
+mod remove_allowed_lp;
 mod remove_liquidity;
+mod remove_liquidity_in_kind;
+mod remove_liquidity_wsol;
+mod remove_validator_fee;
+mod request_withdrawal;
 mod set_fee;
-mod set_fee_authority;
+mod set_fee_delay;
 mod set_flash_loan_fee;
+mod set_lockup_fee;
 mod set_lp_token_metadata;
+mod set_pause_guardian;
+mod set_pool_config;
+mod set_pool_pause;
+mod set_protocol_fee;
+mod set_protocol_pause;
+mod set_stake_deposit_fee;
+mod set_validator_fee;
 // mod unstakes;
 mod view_pool;
 
+pub use accept_fee_authority::*;
+pub use accept_protocol_fee_authority::*;
+pub use add_allowed_lp::*;
 pub use add_liquidity::*;
+pub use add_liquidity_stake::*;
+pub use add_liquidity_wsol::*;
+pub use apply_pending_fee::*;
+pub use apply_pending_lockup_fee::*;
+pub use apply_pending_protocol_fee::*;
+pub use apply_pending_validator_fee::*;
+pub use cancel_fee_authority_proposal::*;
+pub use claim_withdraw_ticket::*;
+pub use close_pool::*;
 pub use create_pool::*;
+pub use create_pool_with_liquidity::*;
 pub use deactivate_all::*;
 pub use deactivate_stake_account::*;
 pub use fetch_protocol_fee::*;
 pub use init_protocol_fee::*;
+pub use propose_fee_authority::*;
 pub use reclaim_all::*;
 pub use reclaim_stake_account::*;
+pub use remove_allowed_lp::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_in_kind::*;
+pub use remove_liquidity_wsol::*;
+pub use remove_validator_fee::*;
+pub use request_withdrawal::*;
 pub use set_fee::*;
-pub use set_fee_authority::*;
+pub use set_fee_delay::*;
 pub use set_flash_loan_fee::*;
+pub use set_lockup_fee::*;
 pub use set_lp_token_metadata::*;
+pub use set_pause_guardian::*;
+pub use set_pool_config::*;
+pub use set_pool_pause::*;
+pub use set_protocol_fee::*;
+pub use set_protocol_pause::*;
+pub use set_stake_deposit_fee::*;
+pub use set_validator_fee::*;
 // pub use unstakes::*;
 pub use view_pool::*;
 
 #[derive(Debug, Subcommand)]
 pub enum Subcmd {
+    AcceptFeeAuthority(AcceptFeeAuthorityArgs),
+    AcceptProtocolFeeAuthority(AcceptProtocolFeeAuthorityArgs),
+    AddAllowedLp(AddAllowedLpArgs),
     AddLiquidity(AddLiquidityArgs),
+    AddLiquidityStake(AddLiquidityStakeArgs),
+    AddLiquidityWsol(AddLiquidityWsolArgs),
+    ApplyPendingFee(ApplyPendingFeeArgs),
+    ApplyPendingLockupFee(ApplyPendingLockupFeeArgs),
+    ApplyPendingProtocolFee(ApplyPendingProtocolFeeArgs),
+    ApplyPendingValidatorFee(ApplyPendingValidatorFeeArgs),
+    CancelFeeAuthorityProposal(CancelFeeAuthorityProposalArgs),
+    ClaimWithdrawTicket(ClaimWithdrawTicketArgs),
+    ClosePool(ClosePoolArgs),
     CreatePool(CreatePoolArgs),
+    CreatePoolWithLiquidity(CreatePoolWithLiquidityArgs),
     DeactivateAll(DeactivateAllArgs),
     DeactivateStakeAccount(DeactivateStakeAccountArgs),
     FetchProtocolFee(FetchProtocolFeeArgs),
     InitProtocolFee(InitProtocolFeeArgs),
+    ProposeFeeAuthority(ProposeFeeAuthorityArgs),
     ReclaimAll(ReclaimAllArgs),
     ReclaimStakeAccount(ReclaimStakeAccountArgs),
+    RemoveAllowedLp(RemoveAllowedLpArgs),
     RemoveLiquidity(RemoveLiquidityArgs),
+    RemoveLiquidityInKind(RemoveLiquidityInKindArgs),
+    RemoveLiquidityWsol(RemoveLiquidityWsolArgs),
+    RemoveValidatorFee(RemoveValidatorFeeArgs),
+    RequestWithdrawal(RequestWithdrawalArgs),
     SetFlashLoanFee(SetFlashLoanFeeArgs),
     SetFee(SetFeeArgs),
-    SetFeeAuthority(SetFeeAuthorityArgs),
+    SetFeeDelay(SetFeeDelayArgs),
+    SetLockupFee(SetLockupFeeArgs),
     SetLpTokenMetadata(SetLpTokenMetadataArgs),
+    SetPauseGuardian(SetPauseGuardianArgs),
+    SetPoolConfig(SetPoolConfigArgs),
+    SetPoolPause(SetPoolPauseArgs),
+    SetProtocolFee(SetProtocolFeeArgs),
+    SetProtocolPause(SetProtocolPauseArgs),
+    SetStakeDepositFee(SetStakeDepositFeeArgs),
+    SetValidatorFee(SetValidatorFeeArgs),
     // Unstakes(UnstakesArgs),
     ViewPool(ViewPoolArgs),
 }
@@ -58,19 +139,46 @@ pub trait SubcmdExec {
 impl SubcmdExec for Subcmd {
     fn process_cmd(&self, args: &crate::Args) {
         match self {
+            Self::AcceptFeeAuthority(a) => a.process_cmd(args),
+            Self::AcceptProtocolFeeAuthority(a) => a.process_cmd(args),
+            Self::AddAllowedLp(a) => a.process_cmd(args),
             Self::AddLiquidity(a) => a.process_cmd(args),
+            Self::AddLiquidityStake(a) => a.process_cmd(args),
+            Self::AddLiquidityWsol(a) => a.process_cmd(args),
+            Self::ApplyPendingFee(a) => a.process_cmd(args),
+            Self::ApplyPendingLockupFee(a) => a.process_cmd(args),
+            Self::ApplyPendingProtocolFee(a) => a.process_cmd(args),
+            Self::ApplyPendingValidatorFee(a) => a.process_cmd(args),
+            Self::CancelFeeAuthorityProposal(a) => a.process_cmd(args),
+            Self::ClaimWithdrawTicket(a) => a.process_cmd(args),
+            Self::ClosePool(a) => a.process_cmd(args),
             Self::CreatePool(a) => a.process_cmd(args),
+            Self::CreatePoolWithLiquidity(a) => a.process_cmd(args),
             Self::DeactivateAll(a) => a.process_cmd(args),
             Self::DeactivateStakeAccount(a) => a.process_cmd(args),
             Self::FetchProtocolFee(a) => a.process_cmd(args),
             Self::InitProtocolFee(a) => a.process_cmd(args),
+            Self::ProposeFeeAuthority(a) => a.process_cmd(args),
             Self::ReclaimAll(a) => a.process_cmd(args),
             Self::ReclaimStakeAccount(a) => a.process_cmd(args),
+            Self::RemoveAllowedLp(a) => a.process_cmd(args),
             Self::RemoveLiquidity(a) => a.process_cmd(args),
+            Self::RemoveLiquidityInKind(a) => a.process_cmd(args),
+            Self::RemoveLiquidityWsol(a) => a.process_cmd(args),
+            Self::RemoveValidatorFee(a) => a.process_cmd(args),
+            Self::RequestWithdrawal(a) => a.process_cmd(args),
             Self::SetFlashLoanFee(a) => a.process_cmd(args),
             Self::SetFee(a) => a.process_cmd(args),
-            Self::SetFeeAuthority(a) => a.process_cmd(args),
+            Self::SetFeeDelay(a) => a.process_cmd(args),
+            Self::SetLockupFee(a) => a.process_cmd(args),
             Self::SetLpTokenMetadata(a) => a.process_cmd(args),
+            Self::SetPauseGuardian(a) => a.process_cmd(args),
+            Self::SetPoolConfig(a) => a.process_cmd(args),
+            Self::SetPoolPause(a) => a.process_cmd(args),
+            Self::SetProtocolFee(a) => a.process_cmd(args),
+            Self::SetProtocolPause(a) => a.process_cmd(args),
+            Self::SetStakeDepositFee(a) => a.process_cmd(args),
+            Self::SetValidatorFee(a) => a.process_cmd(args),
             // Self::Unstakes(a) => a.process_cmd(args),
             Self::ViewPool(a) => a.process_cmd(args),
         }
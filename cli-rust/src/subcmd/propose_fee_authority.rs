@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::FEE_AUTHORITY_NOMINEE_SEED_SUFFIX, ID};
+use unstake_interface::{
+    propose_fee_authority_ix, ProposeFeeAuthorityIxArgs, ProposeFeeAuthorityKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Nominates a new fee authority for an unstake liquidity pool. \
+    The nominee only takes over once it signs accept-fee-authority"
+)]
+pub struct ProposeFeeAuthorityArgs {
+    #[arg(help = "Pubkey of the pool to nominate a new fee authority for")]
+    pool_account: String,
+    #[arg(help = "Pubkey to nominate as the pool's new fee authority")]
+    new_fee_authority: String,
+    #[arg(
+        help = "Path to keypair that is the pool's current fee authority. Defaults to config wallet"
+    )]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for ProposeFeeAuthorityArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let new_fee_authority = Pubkey::from_str(&self.new_fee_authority).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut fee_authority = payer_pk;
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let fee_authority_nominee_account = Pubkey::find_program_address(
+            &[&pool_account.to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+            &ID,
+        )
+        .0;
+
+        let ix = propose_fee_authority_ix(
+            ProposeFeeAuthorityKeys {
+                fee_authority,
+                pool_account,
+                new_fee_authority,
+                fee_authority_nominee_account,
+                system_program: system_program::id(),
+            },
+            ProposeFeeAuthorityIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "{} nominated as fee authority of liquidity pool at {}, must accept with accept-fee-authority",
+            new_fee_authority, pool_account
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
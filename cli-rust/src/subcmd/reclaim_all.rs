@@ -39,11 +39,16 @@ impl SubcmdExec for ReclaimAllArgs {
             fetch_liquidity_pool_stake_accounts(&client, &pool_account, &pool_sol_reserves.0);
         println!("{:#?}", liquidity_pool_stake_accounts);
         let deactivating_stake_accounts = liquidity_pool_stake_accounts.deactivating;
+        let locked_stake_accounts = liquidity_pool_stake_accounts.locked;
         let stake_accounts_to_reclaim = liquidity_pool_stake_accounts.inactive;
         println!(
             "Found {} deactivating stake accounts",
             deactivating_stake_accounts.len()
         );
+        println!(
+            "Found {} inactive stake accounts still locked up, skipping",
+            locked_stake_accounts.len()
+        );
         println!(
             "Found {} inactive stake accounts to reclaim",
             stake_accounts_to_reclaim.len()
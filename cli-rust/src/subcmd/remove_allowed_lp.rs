@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::ALLOWED_LP_SEED_SUFFIX, ID};
+use unstake_interface::{remove_allowed_lp_ix, RemoveAllowedLpIxArgs, RemoveAllowedLpKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Removes a depositor from an unstake liquidity pool's LP allowlist, refunding rent to the config wallet"
+)]
+pub struct RemoveAllowedLpArgs {
+    #[arg(help = "Pubkey of the pool to remove the depositor from")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the depositor to remove")]
+    lp: String,
+    #[arg(help = "Path to keypair that is the pool's fee authority. Defaults to config wallet")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for RemoveAllowedLpArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let lp = Pubkey::from_str(&self.lp).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut fee_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let allowed_lp_account = Pubkey::find_program_address(
+            &[
+                &pool_account.to_bytes(),
+                &lp.to_bytes(),
+                ALLOWED_LP_SEED_SUFFIX,
+            ],
+            &ID,
+        )
+        .0;
+
+        let ix = remove_allowed_lp_ix(
+            RemoveAllowedLpKeys {
+                fee_authority,
+                pool_account,
+                lp,
+                allowed_lp_account,
+                refund_rent_to: payer_pk,
+            },
+            RemoveAllowedLpIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} removed allowed LP {}",
+            pool_account, lp
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
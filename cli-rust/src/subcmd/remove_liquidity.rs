@@ -8,17 +8,19 @@ use solana_program::{
 use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
 use spl_associated_token_account::get_associated_token_address;
 use unstake::{
-    state::{Pool, FLASH_ACCOUNT_SEED_SUFFIX},
+    state::{Pool, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX},
     ID,
 };
-use unstake_interface::{remove_liquidity_ix, RemoveLiquidityIxArgs, RemoveLiquidityKeys};
+use unstake_interface::{remove_liquidity_v2_ix, RemoveLiquidityV2IxArgs, RemoveLiquidityV2Keys};
 
 use crate::tx_utils::send_or_sim_tx;
 
 use super::SubcmdExec;
 
 #[derive(Args, Debug)]
-#[command(long_about = "Remove SOL liquidity to a liquidity pool")]
+#[command(
+    long_about = "Remove SOL liquidity to a liquidity pool. Use request-withdrawal instead if the pool's SOL reserves are insufficient"
+)]
 pub struct RemoveLiquidityArgs {
     #[arg(help = "Pubkey of the liquidity pool to remove liquidity from")]
     pool_account: String,
@@ -32,6 +34,11 @@ pub struct RemoveLiquidityArgs {
     burn_from: Option<String>,
     #[arg(help = "SOL account to return removed SOL liquidity to. Defaults to config wallet.")]
     to: Option<String>,
+    #[arg(
+        long,
+        help = "Minimum amount of SOL to receive. Fails if less would be returned"
+    )]
+    min_sol_out: Option<f64>,
 }
 
 impl SubcmdExec for RemoveLiquidityArgs {
@@ -68,8 +75,8 @@ impl SubcmdExec for RemoveLiquidityArgs {
             |s| Pubkey::from_str(s).unwrap(),
         );
 
-        let ix = remove_liquidity_ix(
-            RemoveLiquidityKeys {
+        let ix = remove_liquidity_v2_ix(
+            RemoveLiquidityV2Keys {
                 burn_lp_tokens_from_authority: authority,
                 pool_account: pool_key,
                 burn_lp_tokens_from,
@@ -79,9 +86,15 @@ impl SubcmdExec for RemoveLiquidityArgs {
                 flash_account: flash_account.0,
                 system_program: system_program::id(),
                 token_program: spl_token::id(),
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
             },
-            RemoveLiquidityIxArgs {
+            RemoveLiquidityV2IxArgs {
                 amount_lp: amount_lp_atomics,
+                min_lamports_out: self.min_sol_out.map_or(0, sol_to_lamports),
             },
         )
         .unwrap();
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{
+    message::Message,
+    native_token::{lamports_to_sol, sol_to_lamports},
+    pubkey::Pubkey,
+    stake, system_program, sysvar,
+};
+use solana_sdk::{
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use unstake::{
+    state::{Pool, StakeAccountRecord, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    remove_liquidity_in_kind_ix, RemoveLiquidityInKindIxArgs, RemoveLiquidityInKindKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Remove liquidity from a liquidity pool as one of its stake accounts, splitting it into a new stake account if only part of it is redeemed"
+)]
+pub struct RemoveLiquidityInKindArgs {
+    #[arg(help = "Pubkey of the liquidity pool to remove liquidity from")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the pool's stake account to redeem")]
+    stake_account: String,
+    #[arg(
+        long,
+        help = "Amount of SOL of the stake account to redeem. Defaults to all of it"
+    )]
+    amount_sol: Option<f64>,
+    #[arg(
+        help = "Path to the keypair authority over the LP token account. Defaults to config wallet."
+    )]
+    authority: Option<String>,
+    #[arg(help = "LP token account to burn LP tokens from. Defaults to ATA of authority.")]
+    burn_from: Option<String>,
+    #[arg(help = "New authority of the redeemed stake account. Defaults to config wallet.")]
+    new_stake_authority: Option<String>,
+    #[arg(
+        long,
+        help = "Path to the keypair of the stake account's lockup custodian. Required if its lockup is in force."
+    )]
+    custodian: Option<String>,
+}
+
+impl SubcmdExec for RemoveLiquidityInKindArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_key).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+        let stake_account = Pubkey::from_str(&self.stake_account).unwrap();
+        let stake_account_record_account =
+            Pubkey::find_program_address(&[&pool_key.to_bytes(), &stake_account.to_bytes()], &ID).0;
+        let record_data = &mut &client
+            .get_account_data(&stake_account_record_account)
+            .unwrap()[..];
+        let record = StakeAccountRecord::try_deserialize(record_data).unwrap();
+        let lamports = self
+            .amount_sol
+            .map_or(record.lamports_at_creation, sol_to_lamports);
+
+        let payer_pk = payer.pubkey();
+        let mut authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+        // only used if the stake account is partially redeemed
+        let split_stake_account = Keypair::new();
+        let split_stake_account_pk = split_stake_account.pubkey();
+        signers.push(Box::new(split_stake_account));
+
+        let custodian = match self.custodian.as_ref() {
+            Some(path) => {
+                let custodian_keypair = read_keypair_file(path).unwrap();
+                let custodian = custodian_keypair.pubkey();
+                signers.push(Box::new(custodian_keypair));
+                custodian
+            }
+            None => ID,
+        };
+
+        let new_stake_authority = self
+            .new_stake_authority
+            .as_ref()
+            .map_or(payer_pk, |s| Pubkey::from_str(s).unwrap());
+
+        let burn_lp_tokens_from = self.burn_from.as_ref().map_or_else(
+            || get_associated_token_address(&authority, &pool.lp_mint),
+            |s| Pubkey::from_str(s).unwrap(),
+        );
+
+        let ix = remove_liquidity_in_kind_ix(
+            RemoveLiquidityInKindKeys {
+                burn_lp_tokens_from_authority: authority,
+                new_stake_authority,
+                stake_account,
+                split_stake_account: split_stake_account_pk,
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                stake_account_record_account,
+                lp_mint: pool.lp_mint,
+                burn_lp_tokens_from,
+                flash_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                clock: sysvar::clock::id(),
+                stake_program: stake::program::id(),
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+                custodian,
+            },
+            RemoveLiquidityInKindIxArgs { lamports },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        match lamports == record.lamports_at_creation {
+            true => println!(
+                "Stake account {} redeemed from pool at {}",
+                stake_account, pool_key
+            ),
+            false => println!(
+                "{} SOL of stake account {} redeemed from pool at {} to new stake account {}",
+                lamports_to_sol(lamports),
+                stake_account,
+                pool_key,
+                split_stake_account_pk,
+            ),
+        }
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::instruction::Instruction;
+use solana_program::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, system_program,
+};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use unstake::{
+    state::{Pool, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    remove_liquidity_wsol_ix, RemoveLiquidityWsolIxArgs, RemoveLiquidityWsolKeys,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Remove SOL liquidity from a liquidity pool as wSOL. Use request-withdrawal instead if the pool's SOL reserves are insufficient"
+)]
+pub struct RemoveLiquidityWsolArgs {
+    #[arg(help = "Pubkey of the liquidity pool to remove liquidity from")]
+    pool_account: String,
+    #[arg(help = "Amount in LP tokens to remove as liquidity")]
+    amount_lp: f64,
+    #[arg(
+        help = "Path to the keypair authority over the LP token account. Defaults to config wallet."
+    )]
+    authority: Option<String>,
+    #[arg(help = "LP token account to burn LP tokens from. Defaults to ATA of authority.")]
+    burn_from: Option<String>,
+    #[arg(
+        help = "wSOL token account to return removed SOL liquidity to. Defaults to wSOL ATA of config wallet."
+    )]
+    to: Option<String>,
+    #[arg(
+        long,
+        help = "Minimum amount of SOL to receive. Fails if less would be returned"
+    )]
+    min_sol_out: Option<f64>,
+}
+
+impl SubcmdExec for RemoveLiquidityWsolArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_key).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+        let amount_lp = self.amount_lp;
+        let amount_lp_atomics = sol_to_lamports(amount_lp);
+
+        let payer_pk = payer.pubkey();
+        let mut authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let payer_wsol_ata = get_associated_token_address(&payer_pk, &spl_token::native_mint::id());
+        let to = self
+            .to
+            .as_ref()
+            .map_or(payer_wsol_ata, |s| Pubkey::from_str(s).unwrap());
+
+        let burn_lp_tokens_from = self.burn_from.as_ref().map_or_else(
+            || get_associated_token_address(&authority, &pool.lp_mint),
+            |s| Pubkey::from_str(s).unwrap(),
+        );
+
+        let ix = remove_liquidity_wsol_ix(
+            RemoveLiquidityWsolKeys {
+                burn_lp_tokens_from_authority: authority,
+                to,
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                lp_mint: pool.lp_mint,
+                burn_lp_tokens_from,
+                flash_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+            },
+            RemoveLiquidityWsolIxArgs {
+                amount_lp: amount_lp_atomics,
+                min_lamports_out: self.min_sol_out.map_or(0, sol_to_lamports),
+            },
+        )
+        .unwrap();
+
+        if client.get_account(&burn_lp_tokens_from).is_err() {
+            panic!("LP token account {} does not exist", burn_lp_tokens_from);
+        }
+
+        let mut instructions: Vec<Instruction> = vec![ix];
+
+        if client.get_account(&to).is_err() {
+            if !to.eq(&payer_wsol_ata) {
+                panic!("wSOL token account {} does not exist", to);
+            }
+
+            println!("wSOL token account {} does not exist, creating...", to);
+
+            instructions.insert(
+                0,
+                create_associated_token_account(
+                    &payer_pk,
+                    &payer_pk,
+                    &spl_token::native_mint::id(),
+                    &spl_token::id(),
+                ),
+            )
+        }
+
+        let msg = Message::new(&instructions, Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "{} LP tokens liquidity removed from pool at {} as wSOL",
+            amount_lp, pool_key,
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{
+        ValidatorFee, ValidatorFeeEnum, FEE_DELAY_SEED_SUFFIX, PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        VALIDATOR_FEE_SEED_SUFFIX,
+    },
+    ID,
+};
+use unstake_interface::{
+    remove_validator_fee_ix, RemoveValidatorFeeIxArgs, RemoveValidatorFeeKeys,
+};
+
+use crate::{tx_utils::send_or_sim_tx, utils::fetch_min_delay_seconds};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Removes a validator's fee override or denial from an unstake liquidity pool, refunding rent to the config wallet. Removing an override is queued if the pool has a fee delay"
+)]
+pub struct RemoveValidatorFeeArgs {
+    #[arg(help = "Pubkey of the pool to remove the validator fee of")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the validator's vote account")]
+    vote_account: String,
+    #[arg(help = "Path to keypair that is the pool's fee authority. Defaults to config wallet")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for RemoveValidatorFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let vote_account = Pubkey::from_str(&self.vote_account).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut fee_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let validator_fee_account = Pubkey::find_program_address(
+            &[
+                &pool_account.to_bytes(),
+                &vote_account.to_bytes(),
+                VALIDATOR_FEE_SEED_SUFFIX,
+            ],
+            &ID,
+        )
+        .0;
+        let fee_delay_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID).0;
+        let validator_fee = client.get_account(&validator_fee_account).unwrap();
+        let validator_fee =
+            ValidatorFee::try_deserialize(&mut validator_fee.data.as_slice()).unwrap();
+        let min_delay_seconds = fetch_min_delay_seconds(&client, &fee_delay_account);
+        // removing overrides of pools with a fee delay is queued in the pending validator fee
+        // account, set to the program ID to remove the validator fee immediately otherwise
+        let is_queued =
+            min_delay_seconds > 0 && !matches!(validator_fee.fee, ValidatorFeeEnum::Deny);
+        let pending_validator_fee_account = match is_queued {
+            true => {
+                Pubkey::find_program_address(
+                    &[
+                        &pool_account.to_bytes(),
+                        &vote_account.to_bytes(),
+                        PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0
+            }
+            false => ID,
+        };
+
+        let ix = remove_validator_fee_ix(
+            RemoveValidatorFeeKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                vote_account,
+                validator_fee_account,
+                refund_rent_to: payer_pk,
+                fee_delay_account,
+                system_program: system_program::id(),
+                clock: sysvar::clock::id(),
+                pending_validator_fee_account,
+            },
+            RemoveValidatorFeeIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        match is_queued {
+            false => println!(
+                "Liquidity pool at {} validator fee for {} removed",
+                pool_account, vote_account
+            ),
+            true => println!(
+                "Liquidity pool at {} validator fee for {} removal queued, can be applied with apply-pending-validator-fee in {} seconds",
+                pool_account, vote_account, min_delay_seconds
+            ),
+        }
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
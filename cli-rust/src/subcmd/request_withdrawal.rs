@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, system_program,
+};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+use unstake::{
+    state::{
+        Pool, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX, WITHDRAW_TICKET_SEED_SUFFIX,
+    },
+    ID,
+};
+use unstake_interface::{request_withdrawal_ix, RequestWithdrawalIxArgs, RequestWithdrawalKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Burns LP tokens for a withdraw ticket that locks in their SOL value, to be claimed with claim-withdraw-ticket once the pool's SOL reserves can cover it"
+)]
+pub struct RequestWithdrawalArgs {
+    #[arg(help = "Pubkey of the liquidity pool to withdraw liquidity from")]
+    pool_account: String,
+    #[arg(help = "Amount in LP tokens to withdraw")]
+    amount_lp: f64,
+    #[arg(
+        help = "Path to the keypair authority over the LP token account, which owns the withdraw ticket. Defaults to config wallet."
+    )]
+    authority: Option<String>,
+    #[arg(help = "LP token account to burn LP tokens from. Defaults to ATA of authority.")]
+    burn_from: Option<String>,
+}
+
+impl SubcmdExec for RequestWithdrawalArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_key = Pubkey::from_str(&self.pool_account).unwrap();
+        let pool_data = &mut &client.get_account_data(&pool_key).unwrap()[..];
+        let pool = Pool::try_deserialize(pool_data).unwrap();
+        let amount_lp = self.amount_lp;
+        let amount_lp_atomics = sol_to_lamports(amount_lp);
+
+        let payer_pk = payer.pubkey();
+        let mut authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let burn_lp_tokens_from = self.burn_from.as_ref().map_or_else(
+            || get_associated_token_address(&authority, &pool.lp_mint),
+            |s| Pubkey::from_str(s).unwrap(),
+        );
+
+        let ix = request_withdrawal_ix(
+            RequestWithdrawalKeys {
+                payer: payer_pk,
+                burn_lp_tokens_from_authority: authority,
+                pool_account: pool_key,
+                pool_sol_reserves: Pubkey::find_program_address(&[&pool_key.to_bytes()], &ID).0,
+                lp_mint: pool.lp_mint,
+                burn_lp_tokens_from,
+                flash_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                withdraw_queue_account: Pubkey::find_program_address(
+                    &[&pool_key.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                withdraw_ticket_account: Pubkey::find_program_address(
+                    &[
+                        &pool_key.to_bytes(),
+                        &authority.to_bytes(),
+                        WITHDRAW_TICKET_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+                token_program: spl_token::id(),
+                system_program: system_program::id(),
+            },
+            RequestWithdrawalIxArgs {
+                amount_lp: amount_lp_atomics,
+            },
+        )
+        .unwrap();
+
+        if client.get_account(&burn_lp_tokens_from).is_err() {
+            panic!("LP token account {} does not exist", burn_lp_tokens_from);
+        }
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "{} LP tokens liquidity withdrawal requested from pool at {} for {}",
+            amount_lp, pool_key, authority,
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
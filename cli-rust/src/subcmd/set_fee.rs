@@ -3,10 +3,16 @@ use std::str::FromStr;
 use clap::Args;
 use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
 use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
-use unstake::{state::FEE_SEED_SUFFIX, ID};
+use unstake::{
+    state::{FEE_DELAY_SEED_SUFFIX, FEE_SEED_SUFFIX, PENDING_FEE_SEED_SUFFIX},
+    ID,
+};
 use unstake_interface::{set_fee_ix, SetFeeIxArgs, SetFeeKeys};
 
-use crate::{tx_utils::send_or_sim_tx, utils::convert_fee};
+use crate::{
+    tx_utils::send_or_sim_tx,
+    utils::{convert_fee, fetch_min_delay_seconds},
+};
 
 use super::SubcmdExec;
 
@@ -18,7 +24,12 @@ pub struct SetFeeArgs {
     #[arg(
         help = "Path to JSON file defining liquidity pool's fee settings. Example contents:\n\
       '{ \"liquidity_linear\": { \"max_liq_remaining\": 0.003, \"zero_liq_remaining\": 0.03 }}\n\
-      '{ \"flat\": 0.01 }'"
+      '{ \"flat\": 0.01 }'\n\
+      '{ \"piecewise_linear\": [{ \"utilization\": 0, \"fee_ratio\": 0.001 }, { \"utilization\": 0.8, \"fee_ratio\": 0.005 }, { \"utilization\": 1, \"fee_ratio\": 0.05 }]}'\n\
+      '{ \"epoch_progress\": { \"base_ratio\": 0.01, \"epoch_end_multiplier\": 0.5 }}'\n\
+      '{ \"delegation_state\": { \"active_ratio\": 0.01, \"deactivating_ratio\": 0.005, \"inactive_ratio\": 0.001 }}'\n\
+      Optionally bound the fee charged per unstake in lamports with \"min_fee_lamports\" and/or \"max_fee_lamports\":\n\
+      '{ \"flat\": 0.01, \"min_fee_lamports\": 10000000, \"max_fee_lamports\": 100000000000 }'"
     )]
     fee_path: String,
     #[arg(help = "Path to keypair that is the pool's fee authority. Defaults to config wallet")]
@@ -46,6 +57,22 @@ impl SubcmdExec for SetFeeArgs {
         let fee_account =
             Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_SEED_SUFFIX], &ID);
 
+        let fee_delay_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID);
+        let min_delay_seconds = fetch_min_delay_seconds(&client, &fee_delay_account.0);
+        // fee changes of pools with a fee delay are queued in the pending fee account,
+        // set to the program ID to set the fee immediately otherwise
+        let pending_fee_account = match min_delay_seconds {
+            0 => ID,
+            _ => {
+                Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), PENDING_FEE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0
+            }
+        };
+
         let ix = set_fee_ix(
             SetFeeKeys {
                 pool_account,
@@ -53,6 +80,9 @@ impl SubcmdExec for SetFeeArgs {
                 system_program: system_program::id(),
                 fee_account: fee_account.0,
                 rent: sysvar::rent::id(),
+                fee_delay_account: fee_delay_account.0,
+                clock: sysvar::clock::id(),
+                pending_fee_account,
             },
             SetFeeIxArgs { fee: fee.clone() },
         )
@@ -61,10 +91,16 @@ impl SubcmdExec for SetFeeArgs {
         let msg = Message::new(&[ix], Some(&payer_pk));
         let blockhash = client.get_latest_blockhash().unwrap();
         let tx = Transaction::new(&signers, msg, blockhash);
-        println!(
-            "Liquidity pool at {} fees updated to {:?}",
-            pool_account, fee
-        );
+        match min_delay_seconds {
+            0 => println!(
+                "Liquidity pool at {} fees updated to {:?}",
+                pool_account, fee
+            ),
+            _ => println!(
+                "Liquidity pool at {} fees update to {:?} queued, can be applied with apply-pending-fee in {} seconds",
+                pool_account, fee, min_delay_seconds
+            ),
+        }
         send_or_sim_tx(args, &client, &tx);
     }
 }
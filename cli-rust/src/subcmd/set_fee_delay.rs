@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::FEE_DELAY_SEED_SUFFIX, ID};
+use unstake_interface::{set_fee_delay_ix, SetFeeDelayIxArgs, SetFeeDelayKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Set the minimum delay between queueing and applying fee changes for an unstake liquidity pool. Can only be increased"
+)]
+pub struct SetFeeDelayArgs {
+    #[arg(help = "Pubkey of the pool to set the fee delay of")]
+    pool_account: String,
+
+    #[arg(help = "Minimum delay in seconds, at most 2592000 (30 days)")]
+    min_delay_seconds: u64,
+
+    #[arg(help = "Path to keypair that is the pool's current fee authority")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetFeeDelayArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut fee_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let fee_delay_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID).0;
+
+        let ix = set_fee_delay_ix(
+            SetFeeDelayKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                fee_delay_account,
+                system_program: system_program::id(),
+            },
+            SetFeeDelayIxArgs {
+                min_delay_seconds: self.min_delay_seconds,
+            },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} fee delay set to {} seconds",
+            pool_account, self.min_delay_seconds
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
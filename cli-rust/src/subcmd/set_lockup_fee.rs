@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{FEE_DELAY_SEED_SUFFIX, LOCKUP_FEE_SEED_SUFFIX, PENDING_LOCKUP_FEE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    set_lockup_fee_ix, LockupFee, Rational, SetLockupFeeIxArgs, SetLockupFeeKeys,
+};
+
+use crate::{tx_utils::send_or_sim_tx, utils::fetch_min_delay_seconds};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Set the fee charged on top of the pool's fee for unstaking locked up stake accounts. Queued if the pool has a fee delay and the lockup fee is already set"
+)]
+pub struct SetLockupFeeArgs {
+    #[arg(help = "Pubkey of the pool to set the lockup fee of")]
+    pool_account: String,
+
+    #[arg(help = "Numerator of the lockup fee ratio")]
+    fee_ratio_num: u64,
+
+    #[arg(help = "Denominator of the lockup fee ratio")]
+    fee_ratio_denom: u64,
+
+    #[arg(help = "Path to keypair that is the pool's current fee authority")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetLockupFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let lockup_fee = LockupFee {
+            fee_ratio: Rational {
+                num: self.fee_ratio_num,
+                denom: self.fee_ratio_denom,
+            },
+        };
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+
+        let lockup_fee_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), LOCKUP_FEE_SEED_SUFFIX], &ID)
+                .0;
+        let fee_delay_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID).0;
+        // changes to an existing lockup fee of pools with a fee delay are queued in the
+        // pending lockup fee account, set to the program ID to set the lockup fee immediately otherwise
+        let min_delay_seconds = fetch_min_delay_seconds(&client, &fee_delay_account);
+        let is_queued = min_delay_seconds > 0 && client.get_account(&lockup_fee_account).is_ok();
+        let pending_lockup_fee_account = match is_queued {
+            true => {
+                Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), PENDING_LOCKUP_FEE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0
+            }
+            false => ID,
+        };
+
+        let mut fee_authority = payer_pk;
+        if let Some(fee_auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(fee_auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let ix = set_lockup_fee_ix(
+            SetLockupFeeKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                lockup_fee_account,
+                system_program: system_program::id(),
+                fee_delay_account,
+                clock: sysvar::clock::id(),
+                pending_lockup_fee_account,
+            },
+            SetLockupFeeIxArgs {
+                lockup_fee: lockup_fee.clone(),
+            },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        match is_queued {
+            false => println!(
+                "Liquidity pool at {} lockup fee set to {:?}",
+                pool_account, lockup_fee
+            ),
+            true => println!(
+                "Liquidity pool at {} lockup fee update to {:?} queued, can be applied with apply-pending-lockup-fee in {} seconds",
+                pool_account, lockup_fee, min_delay_seconds
+            ),
+        }
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
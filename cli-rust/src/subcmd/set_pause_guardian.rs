@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::POOL_PAUSE_SEED_SUFFIX, ID};
+use unstake_interface::{set_pause_guardian_ix, SetPauseGuardianIxArgs, SetPauseGuardianKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the guardian that can pause and unpause an unstake liquidity pool in addition to its fee authority"
+)]
+pub struct SetPauseGuardianArgs {
+    #[arg(help = "Pubkey of the pool to set the pause guardian of")]
+    pool_account: String,
+    #[arg(help = "Pubkey that is to be the pool's new pause guardian")]
+    new_guardian: String,
+    #[arg(
+        help = "Path to keypair that is the pool's current fee authority. Defaults to config wallet"
+    )]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetPauseGuardianArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let new_guardian = Pubkey::from_str(&self.new_guardian).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut fee_authority = payer_pk;
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let ix = set_pause_guardian_ix(
+            SetPauseGuardianKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                new_guardian,
+                pool_pause_account: Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                system_program: system_program::id(),
+            },
+            SetPauseGuardianIxArgs {},
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} pause guardian set to {}",
+            pool_account, new_guardian
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,102 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, system_program,
+};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::POOL_CONFIG_SEED_SUFFIX, ID};
+use unstake_interface::{set_pool_config_ix, SetPoolConfigIxArgs, SetPoolConfigKeys};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Sets the unstake and deposit limits of an unstake liquidity pool. Limits that are not provided are removed"
+)]
+pub struct SetPoolConfigArgs {
+    #[arg(help = "Pubkey of the pool to set the config of")]
+    pool_account: String,
+    #[arg(
+        long,
+        help = "Maximum amount of SOL that can be unstaked in a single instruction"
+    )]
+    max_unstake_sol: Option<f64>,
+    #[arg(
+        long,
+        help = "Maximum amount of SOL of stake the pool can take in per epoch. Stake that can be withdrawn immediately is not counted"
+    )]
+    max_epoch_incoming_stake_sol: Option<f64>,
+    #[arg(
+        long,
+        help = "Maximum amount of SOL the pool can own after adding liquidity"
+    )]
+    max_liquidity_sol: Option<f64>,
+    #[arg(
+        long,
+        help = "Only allow depositors added with add-allowed-lp to add liquidity"
+    )]
+    lp_allowlist: bool,
+    #[arg(
+        help = "Path to keypair that is the pool's current fee authority. Defaults to config wallet"
+    )]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetPoolConfigArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let max_unstake_lamports = self.max_unstake_sol.map(sol_to_lamports);
+        let max_epoch_incoming_stake_lamports =
+            self.max_epoch_incoming_stake_sol.map(sol_to_lamports);
+        let max_pool_owned_lamports = self.max_liquidity_sol.map(sol_to_lamports);
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut fee_authority = payer_pk;
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let ix = set_pool_config_ix(
+            SetPoolConfigKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                pool_config_account: Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                system_program: system_program::id(),
+            },
+            SetPoolConfigIxArgs {
+                max_unstake_lamports,
+                max_epoch_incoming_stake_lamports,
+                max_pool_owned_lamports,
+                lp_allowlist_enabled: self.lp_allowlist,
+            },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} config set to max unstake {:?} lamports, max incoming stake per epoch {:?} lamports, max liquidity {:?} lamports, LP allowlist enabled: {}",
+            pool_account,
+            max_unstake_lamports,
+            max_epoch_incoming_stake_lamports,
+            max_pool_owned_lamports,
+            self.lp_allowlist
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
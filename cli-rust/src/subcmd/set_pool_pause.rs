@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::POOL_PAUSE_SEED_SUFFIX, ID};
+use unstake_interface::{set_pool_pause_ix, SetPoolPauseIxArgs, SetPoolPauseKeys};
+
+use crate::{tx_utils::send_or_sim_tx, utils::PauseArgs};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Pauses or unpauses instruction classes of an unstake liquidity pool. \
+    Classes not specified are unpaused. remove-liquidity can never be paused"
+)]
+pub struct SetPoolPauseArgs {
+    #[arg(help = "Pubkey of the pool to pause")]
+    pool_account: String,
+
+    #[command(flatten)]
+    pause: PauseArgs,
+
+    #[arg(
+        long,
+        help = "Path to keypair that is the pool's fee authority or pause guardian. Defaults to config wallet"
+    )]
+    authority: Option<String>,
+}
+
+impl SubcmdExec for SetPoolPauseArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut authority = payer_pk;
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let paused = self.pause.paused_instructions();
+        let ix = set_pool_pause_ix(
+            SetPoolPauseKeys {
+                payer: payer_pk,
+                authority,
+                pool_account,
+                pool_pause_account: Pubkey::find_program_address(
+                    &[&pool_account.to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+                    &ID,
+                )
+                .0,
+                system_program: system_program::id(),
+            },
+            SetPoolPauseIxArgs { paused },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!(
+            "Liquidity pool at {} paused instructions set to {:?}",
+            pool_account, paused
+        );
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{
+        ProtocolFee, PENDING_PROTOCOL_FEE_SEED, PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED,
+        PROTOCOL_FEE_DELAY_SECONDS, PROTOCOL_FEE_SEED,
+    },
+    ID,
+};
+use unstake_interface::{
+    propose_protocol_fee_authority_ix, propose_protocol_fee_ix, set_protocol_fee_ix,
+    ProposeProtocolFeeAuthorityIxArgs, ProposeProtocolFeeAuthorityKeys, ProposeProtocolFeeIxArgs,
+    ProposeProtocolFeeKeys, Rational, SetProtocolFeeIxArgs, SetProtocolFeeKeys,
+};
+
+use crate::{tx_utils::send_or_sim_tx, utils::number_to_positive_rational_checked};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(long_about = "Updates the program's protocol fee. \
+    New fee ratios are proposed and can only be applied with apply-pending-protocol-fee after a delay. \
+    A new authority is nominated and only takes over once it signs accept-protocol-fee-authority")]
+pub struct SetProtocolFeeArgs {
+    #[arg(
+        long,
+        help = "New proportion of unstake fees that go to the protocol, e.g. 0.1. Defaults to the current ratio if only referrer_fee_ratio is set"
+    )]
+    fee_ratio: Option<f64>,
+
+    #[arg(
+        long,
+        help = "New proportion of protocol fees that go to the referrer, e.g. 0.5. Defaults to the current ratio if only fee_ratio is set"
+    )]
+    referrer_fee_ratio: Option<f64>,
+
+    #[arg(long, help = "New protocol fee destination. Takes effect immediately")]
+    destination: Option<String>,
+
+    #[arg(long, help = "Pubkey to nominate as the new protocol fee authority")]
+    new_authority: Option<String>,
+
+    #[arg(
+        long,
+        help = "Path to keypair that is the current protocol fee authority. Defaults to config wallet"
+    )]
+    authority: Option<String>,
+}
+
+impl SubcmdExec for SetProtocolFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let protocol_fee_account = Pubkey::find_program_address(&[PROTOCOL_FEE_SEED], &ID).0;
+        let protocol_fee = client.get_account_data(&protocol_fee_account).unwrap();
+        let protocol_fee = ProtocolFee::try_deserialize(&mut protocol_fee.as_slice()).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(destination) = self.destination.as_ref() {
+            let destination = Pubkey::from_str(destination).unwrap();
+            ixs.push(
+                set_protocol_fee_ix(
+                    SetProtocolFeeKeys {
+                        authority,
+                        protocol_fee_account,
+                    },
+                    SetProtocolFeeIxArgs {
+                        protocol_fee: unstake_interface::ProtocolFee {
+                            destination,
+                            authority: protocol_fee.authority,
+                            fee_ratio: to_interface_rational(&protocol_fee.fee_ratio),
+                            referrer_fee_ratio: to_interface_rational(
+                                &protocol_fee.referrer_fee_ratio,
+                            ),
+                        },
+                    },
+                )
+                .unwrap(),
+            );
+            println!("Protocol fee destination set to {}", destination);
+        }
+
+        if self.fee_ratio.is_some() || self.referrer_fee_ratio.is_some() {
+            let fee_ratio = self.fee_ratio.map_or_else(
+                || to_interface_rational(&protocol_fee.fee_ratio),
+                number_to_positive_rational_checked,
+            );
+            let referrer_fee_ratio = self.referrer_fee_ratio.map_or_else(
+                || to_interface_rational(&protocol_fee.referrer_fee_ratio),
+                number_to_positive_rational_checked,
+            );
+            println!(
+                "Protocol fee ratios {:?}, {:?} proposed, can be applied with apply-pending-protocol-fee in {} seconds",
+                fee_ratio, referrer_fee_ratio, PROTOCOL_FEE_DELAY_SECONDS
+            );
+            ixs.push(
+                propose_protocol_fee_ix(
+                    ProposeProtocolFeeKeys {
+                        authority,
+                        protocol_fee_account,
+                        pending_protocol_fee_account: Pubkey::find_program_address(
+                            &[PENDING_PROTOCOL_FEE_SEED],
+                            &ID,
+                        )
+                        .0,
+                        clock: sysvar::clock::id(),
+                        system_program: system_program::id(),
+                    },
+                    ProposeProtocolFeeIxArgs {
+                        fee_ratio,
+                        referrer_fee_ratio,
+                    },
+                )
+                .unwrap(),
+            );
+        }
+
+        if let Some(new_authority) = self.new_authority.as_ref() {
+            let new_authority = Pubkey::from_str(new_authority).unwrap();
+            ixs.push(
+                propose_protocol_fee_authority_ix(
+                    ProposeProtocolFeeAuthorityKeys {
+                        authority,
+                        protocol_fee_account,
+                        new_authority,
+                        nominee_account: Pubkey::find_program_address(
+                            &[PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED],
+                            &ID,
+                        )
+                        .0,
+                        system_program: system_program::id(),
+                    },
+                    ProposeProtocolFeeAuthorityIxArgs {},
+                )
+                .unwrap(),
+            );
+            println!(
+                "{} nominated as protocol fee authority, must accept with accept-protocol-fee-authority",
+                new_authority
+            );
+        }
+
+        if ixs.is_empty() {
+            panic!("Nothing to update");
+        }
+
+        let msg = Message::new(&ixs, Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
+
+fn to_interface_rational(rational: &unstake::rational::Rational) -> Rational {
+    Rational {
+        num: rational.num,
+        denom: rational.denom,
+    }
+}
@@ -0,0 +1,62 @@
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED},
+    ID,
+};
+use unstake_interface::{set_protocol_pause_ix, SetProtocolPauseIxArgs, SetProtocolPauseKeys};
+
+use crate::{tx_utils::send_or_sim_tx, utils::PauseArgs};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Pauses or unpauses instruction classes across all unstake liquidity pools. \
+    Classes not specified are unpaused. remove-liquidity can never be paused"
+)]
+pub struct SetProtocolPauseArgs {
+    #[command(flatten)]
+    pause: PauseArgs,
+
+    #[arg(
+        long,
+        help = "Path to keypair that is the protocol fee authority. Defaults to config wallet"
+    )]
+    authority: Option<String>,
+}
+
+impl SubcmdExec for SetProtocolPauseArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+        let mut authority = payer_pk;
+        if let Some(auth) = self.authority.as_ref() {
+            let authority_keypair = read_keypair_file(auth).unwrap();
+            authority = authority_keypair.pubkey();
+            signers.push(Box::new(authority_keypair));
+        }
+
+        let paused = self.pause.paused_instructions();
+        let ix = set_protocol_pause_ix(
+            SetProtocolPauseKeys {
+                authority,
+                protocol_fee_account: Pubkey::find_program_address(&[PROTOCOL_FEE_SEED], &ID).0,
+                protocol_pause_account: Pubkey::find_program_address(&[PROTOCOL_PAUSE_SEED], &ID).0,
+                system_program: system_program::id(),
+            },
+            SetProtocolPauseIxArgs { paused },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!("Protocol-wide paused instructions set to {:?}", paused);
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{state::STAKE_DEPOSIT_FEE_SEED_SUFFIX, ID};
+use unstake_interface::{
+    set_stake_deposit_fee_ix, Rational, SetStakeDepositFeeIxArgs, SetStakeDepositFeeKeys,
+    StakeDepositFee,
+};
+
+use crate::tx_utils::send_or_sim_tx;
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Set the discount applied to stake accounts deposited as liquidity with add-liquidity-stake"
+)]
+pub struct SetStakeDepositFeeArgs {
+    #[arg(help = "Pubkey of the pool to set the stake deposit fee of")]
+    pool_account: String,
+
+    #[arg(help = "Numerator of the stake deposit fee ratio")]
+    fee_ratio_num: u64,
+
+    #[arg(help = "Denominator of the stake deposit fee ratio")]
+    fee_ratio_denom: u64,
+
+    #[arg(help = "Path to keypair that is the pool's current fee authority")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetStakeDepositFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+
+        let stake_deposit_fee = StakeDepositFee {
+            fee_ratio: Rational {
+                num: self.fee_ratio_num,
+                denom: self.fee_ratio_denom,
+            },
+        };
+
+        let payer_pk = payer.pubkey();
+        let mut signers = vec![payer];
+
+        let stake_deposit_fee_account = Pubkey::find_program_address(
+            &[&pool_account.to_bytes(), STAKE_DEPOSIT_FEE_SEED_SUFFIX],
+            &ID,
+        )
+        .0;
+
+        let mut fee_authority = payer_pk;
+        if let Some(fee_auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(fee_auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let ix = set_stake_deposit_fee_ix(
+            SetStakeDepositFeeKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                stake_deposit_fee_account,
+                system_program: system_program::id(),
+            },
+            SetStakeDepositFeeIxArgs { stake_deposit_fee },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        println!("Stake deposit fee set to {}", stake_deposit_fee_account);
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
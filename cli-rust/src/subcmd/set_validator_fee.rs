@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_program::{message::Message, pubkey::Pubkey, system_program, sysvar};
+use solana_sdk::{signature::read_keypair_file, signer::Signer, transaction::Transaction};
+use unstake::{
+    state::{FEE_DELAY_SEED_SUFFIX, PENDING_VALIDATOR_FEE_SEED_SUFFIX, VALIDATOR_FEE_SEED_SUFFIX},
+    ID,
+};
+use unstake_interface::{
+    set_validator_fee_ix, SetValidatorFeeIxArgs, SetValidatorFeeKeys, ValidatorFee,
+    ValidatorFeeEnum,
+};
+
+use crate::{
+    tx_utils::send_or_sim_tx,
+    utils::{convert_fee, fetch_min_delay_seconds},
+};
+
+use super::SubcmdExec;
+
+#[derive(Args, Debug)]
+#[command(
+    long_about = "Overrides the fee charged for, or denies, stake delegated to a validator for an unstake liquidity pool"
+)]
+pub struct SetValidatorFeeArgs {
+    #[arg(help = "Pubkey of the pool to set the validator fee of")]
+    pool_account: String,
+    #[arg(help = "Pubkey of the validator's vote account")]
+    vote_account: String,
+    #[arg(
+        long,
+        help = "Path to JSON file defining the fee to charge instead of the pool's fee. Same format as set-fee",
+        conflicts_with = "deny",
+        required_unless_present = "deny"
+    )]
+    fee_path: Option<String>,
+    #[arg(long, help = "Deny unstaking stake delegated to the validator")]
+    deny: bool,
+    #[arg(help = "Path to keypair that is the pool's fee authority. Defaults to config wallet")]
+    fee_authority: Option<String>,
+}
+
+impl SubcmdExec for SetValidatorFeeArgs {
+    fn process_cmd(&self, args: &crate::Args) {
+        let payer = args.config.signer();
+        let client = args.config.rpc_client();
+
+        let validator_fee = ValidatorFee {
+            fee: match self.fee_path.as_ref() {
+                Some(fee_path) => ValidatorFeeEnum::Override {
+                    fee: convert_fee(fee_path).fee,
+                },
+                None => ValidatorFeeEnum::Deny,
+            },
+        };
+
+        let pool_account = Pubkey::from_str(&self.pool_account).unwrap();
+        let vote_account = Pubkey::from_str(&self.vote_account).unwrap();
+
+        let payer_pk = payer.pubkey();
+        let mut fee_authority = payer_pk;
+        let mut signers = vec![payer];
+        if let Some(auth) = self.fee_authority.as_ref() {
+            let fee_authority_keypair = read_keypair_file(auth).unwrap();
+            fee_authority = fee_authority_keypair.pubkey();
+            signers.push(Box::new(fee_authority_keypair));
+        }
+
+        let validator_fee_account = Pubkey::find_program_address(
+            &[
+                &pool_account.to_bytes(),
+                &vote_account.to_bytes(),
+                VALIDATOR_FEE_SEED_SUFFIX,
+            ],
+            &ID,
+        )
+        .0;
+        let fee_delay_account =
+            Pubkey::find_program_address(&[&pool_account.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID).0;
+        let min_delay_seconds = fetch_min_delay_seconds(&client, &fee_delay_account);
+        // overrides for pools with a fee delay are queued in the pending validator fee account,
+        // set to the program ID to set the validator fee immediately otherwise
+        let is_queued =
+            min_delay_seconds > 0 && !matches!(validator_fee.fee, ValidatorFeeEnum::Deny);
+        let (validator_fee_account, pending_validator_fee_account) = match is_queued {
+            true => (
+                ID,
+                Pubkey::find_program_address(
+                    &[
+                        &pool_account.to_bytes(),
+                        &vote_account.to_bytes(),
+                        PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+                    ],
+                    &ID,
+                )
+                .0,
+            ),
+            false => (validator_fee_account, ID),
+        };
+
+        let ix = set_validator_fee_ix(
+            SetValidatorFeeKeys {
+                payer: payer_pk,
+                fee_authority,
+                pool_account,
+                vote_account,
+                validator_fee_account,
+                system_program: system_program::id(),
+                fee_delay_account,
+                clock: sysvar::clock::id(),
+                pending_validator_fee_account,
+            },
+            SetValidatorFeeIxArgs {
+                validator_fee: validator_fee.clone(),
+            },
+        )
+        .unwrap();
+
+        let msg = Message::new(&[ix], Some(&payer_pk));
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx = Transaction::new(&signers, msg, blockhash);
+        match is_queued {
+            false => println!(
+                "Liquidity pool at {} validator fee for {} set to {:?}",
+                pool_account, vote_account, validator_fee
+            ),
+            true => println!(
+                "Liquidity pool at {} validator fee for {} update to {:?} queued, can be applied with apply-pending-validator-fee in {} seconds",
+                pool_account, vote_account, validator_fee, min_delay_seconds
+            ),
+        }
+        send_or_sim_tx(args, &client, &tx);
+    }
+}
@@ -4,7 +4,11 @@ use anchor_lang::AccountDeserialize;
 use clap::Args;
 use solana_program::{native_token::lamports_to_sol, pubkey::Pubkey};
 use unstake::{
-    state::{Fee, Pool, FEE_SEED_SUFFIX},
+    state::{
+        Fee, FeeDelay, PendingFee, Pool, PoolConfig, PoolPause, WithdrawQueue,
+        FEE_DELAY_SEED_SUFFIX, FEE_SEED_SUFFIX, PENDING_FEE_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+        POOL_PAUSE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
     ID,
 };
 
@@ -30,6 +34,45 @@ impl SubcmdExec for ViewPoolArgs {
         let fee_account = client.get_account(&fee_account_pk.0).unwrap();
         let fee = Fee::try_deserialize(&mut fee_account.data.as_slice()).unwrap();
 
+        let fee_delay_account_pk =
+            Pubkey::find_program_address(&[&pool_pk.to_bytes(), FEE_DELAY_SEED_SUFFIX], &ID);
+        let fee_delay = client
+            .get_account(&fee_delay_account_pk.0)
+            .ok()
+            .map(|account| FeeDelay::try_deserialize(&mut account.data.as_slice()).unwrap());
+
+        let pending_fee_account_pk =
+            Pubkey::find_program_address(&[&pool_pk.to_bytes(), PENDING_FEE_SEED_SUFFIX], &ID);
+        let pending_fee = client
+            .get_account(&pending_fee_account_pk.0)
+            .ok()
+            .map(|account| PendingFee::try_deserialize(&mut account.data.as_slice()).unwrap());
+
+        let pool_pause_account_pk =
+            Pubkey::find_program_address(&[&pool_pk.to_bytes(), POOL_PAUSE_SEED_SUFFIX], &ID);
+        let pool_pause = client
+            .get_account(&pool_pause_account_pk.0)
+            .ok()
+            .map(|account| PoolPause::try_deserialize(&mut account.data.as_slice()).unwrap());
+
+        let pool_config_account_pk =
+            Pubkey::find_program_address(&[&pool_pk.to_bytes(), POOL_CONFIG_SEED_SUFFIX], &ID);
+        let pool_config = client
+            .get_account(&pool_config_account_pk.0)
+            .ok()
+            .map(|account| PoolConfig::try_deserialize(&mut account.data.as_slice()).unwrap());
+
+        let withdraw_queue_account_pk =
+            Pubkey::find_program_address(&[&pool_pk.to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX], &ID);
+        let earmarked_lamports = client
+            .get_account(&withdraw_queue_account_pk.0)
+            .ok()
+            .map_or(0, |account| {
+                WithdrawQueue::try_deserialize(&mut account.data.as_slice())
+                    .unwrap()
+                    .earmarked_lamports
+            });
+
         let pool_sol_reserves = Pubkey::find_program_address(&[&pool_pk.to_bytes()], &ID);
         let liq_lamports = client.get_balance(&pool_sol_reserves.0).unwrap();
 
@@ -37,7 +80,26 @@ impl SubcmdExec for ViewPoolArgs {
 
         println!("Pool:\n{:#?}", pool);
         println!("Fee:\n{:#?}", fee);
+        if let Some(fee_delay) = fee_delay {
+            println!("Fee delay: {} seconds", fee_delay.min_delay_seconds);
+        }
+        if let Some(pending_fee) = pending_fee {
+            println!(
+                "Pending fee, can be applied from unix timestamp {}:\n{:#?}",
+                pending_fee.activation_timestamp, pending_fee.fee
+            );
+        }
+        if let Some(pool_pause) = pool_pause {
+            println!("Pause:\n{:#?}", pool_pause);
+        }
+        if let Some(pool_config) = pool_config {
+            println!("Config:\n{:#?}", pool_config);
+        }
         println!("Liquidity: {} SOL", lamports_to_sol(liq_lamports));
+        println!(
+            "Earmarked for withdraw tickets: {} SOL",
+            lamports_to_sol(earmarked_lamports)
+        );
         println!(
             "Utilization: {} / {} ({:.4}%)",
             lamports_to_sol(pool.incoming_stake),
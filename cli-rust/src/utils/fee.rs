@@ -12,15 +12,62 @@ struct LiquidityLinearFeeArg(LiquidityLinearFeeParams);
 #[derive(Debug, Deserialize)]
 struct FlatFeeArg(f64);
 
+#[derive(Debug, Deserialize)]
+struct PiecewiseLinearFeePoint {
+    pub utilization: f64,
+    pub fee_ratio: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct PiecewiseLinearFeeArg(Vec<PiecewiseLinearFeePoint>);
+
+#[derive(Debug, Deserialize)]
+struct EpochProgressFeeParams {
+    pub base_ratio: f64,
+    pub epoch_end_multiplier: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EpochProgressFeeArg(EpochProgressFeeParams);
+
+#[derive(Debug, Deserialize)]
+struct DelegationStateFeeParams {
+    pub active_ratio: f64,
+    pub deactivating_ratio: f64,
+    pub inactive_ratio: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DelegationStateFeeArg(DelegationStateFeeParams);
+
 #[derive(Debug, Deserialize)]
 enum FeeArg {
     #[serde(rename = "liquidity_linear")]
     LiquidityLinear(LiquidityLinearFeeArg),
     #[serde(rename = "flat")]
     Flat(FlatFeeArg),
+    #[serde(rename = "piecewise_linear")]
+    PiecewiseLinear(PiecewiseLinearFeeArg),
+    #[serde(rename = "epoch_progress")]
+    EpochProgress(EpochProgressFeeArg),
+    #[serde(rename = "delegation_state")]
+    DelegationState(DelegationStateFeeArg),
 }
 
-fn number_to_positive_rational_checked(n: f64) -> unstake_interface::Rational {
+#[derive(Debug, Deserialize)]
+struct FeeFileArg {
+    #[serde(flatten)]
+    fee: FeeArg,
+    #[serde(default)]
+    min_fee_lamports: Option<u64>,
+    #[serde(default)]
+    max_fee_lamports: Option<u64>,
+}
+
+// must match PIECEWISE_LINEAR_MAX_POINTS in the program
+const PIECEWISE_LINEAR_MAX_POINTS: usize = 5;
+
+pub fn number_to_positive_rational_checked(n: f64) -> unstake_interface::Rational {
     if n < 0.0 {
         panic!("Only positive numbers allowed, {} given", n);
     }
@@ -44,16 +91,19 @@ fn number_to_positive_rational_checked(n: f64) -> unstake_interface::Rational {
     }
 }
 
-fn to_fee_checked(fee_arg: &FeeArg) -> unstake_interface::Fee {
+fn to_fee_enum_checked(fee_arg: &FeeArg) -> unstake_interface::FeeEnum {
     match fee_arg {
         FeeArg::LiquidityLinear(fee) => to_liquidity_linear_fee_checked(fee),
         FeeArg::Flat(fee) => to_flat_fee_checked(fee),
+        FeeArg::PiecewiseLinear(fee) => to_piecewise_linear_fee_checked(fee),
+        FeeArg::EpochProgress(fee) => to_epoch_progress_fee_checked(fee),
+        FeeArg::DelegationState(fee) => to_delegation_state_fee_checked(fee),
     }
 }
 
 fn to_liquidity_linear_fee_checked(
     liquidity_linear: &LiquidityLinearFeeArg,
-) -> unstake_interface::Fee {
+) -> unstake_interface::FeeEnum {
     if liquidity_linear.0.max_liq_remaining > liquidity_linear.0.zero_liq_remaining {
         panic!("maxLiqRemaining should be <= zeroLiqRemaining");
     }
@@ -61,27 +111,103 @@ fn to_liquidity_linear_fee_checked(
         number_to_positive_rational_checked(liquidity_linear.0.max_liq_remaining);
     let zero_liq_remaining =
         number_to_positive_rational_checked(liquidity_linear.0.zero_liq_remaining);
-    unstake_interface::Fee {
-        fee: unstake_interface::FeeEnum::LiquidityLinear {
-            params: unstake_interface::LiquidityLinearParams {
-                max_liq_remaining,
-                zero_liq_remaining,
-            },
+    unstake_interface::FeeEnum::LiquidityLinear {
+        params: unstake_interface::LiquidityLinearParams {
+            max_liq_remaining,
+            zero_liq_remaining,
         },
     }
 }
 
-fn to_flat_fee_checked(flat: &FlatFeeArg) -> unstake_interface::Fee {
-    unstake_interface::Fee {
-        fee: unstake_interface::FeeEnum::Flat {
-            ratio: number_to_positive_rational_checked(flat.0),
+fn to_flat_fee_checked(flat: &FlatFeeArg) -> unstake_interface::FeeEnum {
+    unstake_interface::FeeEnum::Flat {
+        ratio: number_to_positive_rational_checked(flat.0),
+    }
+}
+
+fn to_piecewise_linear_fee_checked(
+    piecewise_linear: &PiecewiseLinearFeeArg,
+) -> unstake_interface::FeeEnum {
+    let points_arg = &piecewise_linear.0;
+    if points_arg.len() < 2 || points_arg.len() > PIECEWISE_LINEAR_MAX_POINTS {
+        panic!(
+            "piecewise_linear should have between 2 and {} points",
+            PIECEWISE_LINEAR_MAX_POINTS
+        );
+    }
+    if points_arg[0].utilization != 0.0 || points_arg[points_arg.len() - 1].utilization != 1.0 {
+        panic!("piecewise_linear should start at utilization 0 and end at utilization 1");
+    }
+    for pair in points_arg.windows(2) {
+        if pair[0].utilization >= pair[1].utilization {
+            panic!("piecewise_linear utilizations should be strictly increasing");
+        }
+        if pair[0].fee_ratio > pair[1].fee_ratio {
+            panic!("piecewise_linear fee_ratios should be non-decreasing");
+        }
+    }
+    let mut points: [unstake_interface::PiecewiseLinearPoint; PIECEWISE_LINEAR_MAX_POINTS] =
+        Default::default();
+    for (point, arg) in points.iter_mut().zip(points_arg) {
+        *point = unstake_interface::PiecewiseLinearPoint {
+            utilization: number_to_positive_rational_checked(arg.utilization),
+            fee_ratio: number_to_positive_rational_checked(arg.fee_ratio),
+        };
+    }
+    unstake_interface::FeeEnum::PiecewiseLinear {
+        params: unstake_interface::PiecewiseLinearParams {
+            points,
+            points_len: points_arg.len() as u8,
         },
     }
 }
 
+fn to_epoch_progress_fee_checked(
+    epoch_progress: &EpochProgressFeeArg,
+) -> unstake_interface::FeeEnum {
+    if epoch_progress.0.epoch_end_multiplier > 1.0 {
+        panic!("epoch_end_multiplier should be <= 1");
+    }
+    unstake_interface::FeeEnum::EpochProgress {
+        params: unstake_interface::EpochProgressParams {
+            base_ratio: number_to_positive_rational_checked(epoch_progress.0.base_ratio),
+            epoch_end_multiplier: number_to_positive_rational_checked(
+                epoch_progress.0.epoch_end_multiplier,
+            ),
+        },
+    }
+}
+
+fn to_delegation_state_fee_checked(
+    delegation_state: &DelegationStateFeeArg,
+) -> unstake_interface::FeeEnum {
+    unstake_interface::FeeEnum::DelegationState {
+        params: unstake_interface::DelegationStateParams {
+            active_ratio: number_to_positive_rational_checked(delegation_state.0.active_ratio),
+            deactivating_ratio: number_to_positive_rational_checked(
+                delegation_state.0.deactivating_ratio,
+            ),
+            inactive_ratio: number_to_positive_rational_checked(delegation_state.0.inactive_ratio),
+        },
+    }
+}
+
+fn to_fee_checked(fee_file_arg: &FeeFileArg) -> unstake_interface::Fee {
+    if let (Some(min), Some(max)) = (fee_file_arg.min_fee_lamports, fee_file_arg.max_fee_lamports) {
+        if min > max {
+            panic!("min_fee_lamports should be <= max_fee_lamports");
+        }
+    }
+    unstake_interface::Fee {
+        fee: to_fee_enum_checked(&fee_file_arg.fee),
+        min_fee_lamports: fee_file_arg.min_fee_lamports,
+        max_fee_lamports: fee_file_arg.max_fee_lamports,
+    }
+}
+
 pub fn convert_fee(fee_path: &str) -> unstake_interface::Fee {
     let fee_string = std::fs::read_to_string(fee_path).unwrap();
-    let fee: FeeArg = serde_json::from_str(&fee_string).unwrap();
+    let fee: FeeFileArg = serde_json::from_str(&fee_string).unwrap();
 
     to_fee_checked(&fee)
 }
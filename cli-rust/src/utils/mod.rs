@@ -1,4 +1,6 @@
 mod fee;
+mod pause;
 mod state;
 pub use fee::*;
+pub use pause::*;
 pub use state::*;
@@ -0,0 +1,25 @@
+use clap::Args;
+use unstake_interface::PausedInstructions;
+
+/// Instruction classes to pause. Classes not specified are unpaused
+#[derive(Args, Debug)]
+pub struct PauseArgs {
+    #[arg(long, help = "Pause unstake, unstake-wsol and their variants")]
+    unstake: bool,
+
+    #[arg(long, help = "Pause take-flash-loan")]
+    take_flash_loan: bool,
+
+    #[arg(long, help = "Pause add-liquidity")]
+    add_liquidity: bool,
+}
+
+impl PauseArgs {
+    pub fn paused_instructions(&self) -> PausedInstructions {
+        PausedInstructions {
+            unstake: self.unstake,
+            take_flash_loan: self.take_flash_loan,
+            add_liquidity: self.add_liquidity,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use anchor_lang::AccountDeserialize;
 use solana_account_decoder::parse_stake::{parse_stake, StakeAccountType};
 use solana_account_decoder::UiAccountEncoding;
 use solana_client::rpc_client::RpcClient;
@@ -5,12 +8,12 @@ use solana_client::{
     rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     rpc_filter::{Memcmp, RpcFilterType},
 };
-use solana_program::pubkey::Pubkey;
+use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
 use solana_sdk::{
-    account::Account,
+    account::{from_account, Account},
     commitment_config::{CommitmentConfig, CommitmentLevel},
 };
-use unstake::ID;
+use unstake::{state::FeeDelay, ID};
 
 pub enum StakeState {
     Inactive,
@@ -56,12 +59,43 @@ pub fn stake_account_state(
     }
 }
 
+/// Inactive stake accounts whose lockup is still in force
+/// cannot be reclaimed until the lockup ends
+pub fn is_lockup_in_force(stake_acc_with_record: &(Pubkey, Account), clock: &Clock) -> bool {
+    let state = parse_stake(&stake_acc_with_record.1.data).unwrap();
+
+    match state {
+        StakeAccountType::Initialized(stake_account)
+        | StakeAccountType::Delegated(stake_account) => {
+            let lockup = stake_account.meta.lockup;
+            lockup.unix_timestamp > clock.unix_timestamp || lockup.epoch > clock.epoch
+        }
+        StakeAccountType::Uninitialized | StakeAccountType::RewardsPool => false,
+    }
+}
+
+/// The vote account the stake account is delegated to,
+/// or the default pubkey if it was never delegated
+pub fn stake_account_voter(stake_account_data: &[u8]) -> Pubkey {
+    match parse_stake(stake_account_data).unwrap() {
+        StakeAccountType::Delegated(stake_account) => {
+            stake_account.stake.map_or_else(Pubkey::default, |stake| {
+                Pubkey::from_str(&stake.delegation.voter).unwrap()
+            })
+        }
+        _ => Pubkey::default(),
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct LiquidityPoolStakeAccounts {
     pub active: Vec<Pubkey>,
+    /// inactive stake accounts that are not locked up
     pub inactive: Vec<Pubkey>,
     pub activating: Vec<Pubkey>,
     pub deactivating: Vec<Pubkey>,
+    /// inactive stake accounts whose lockup is still in force
+    pub locked: Vec<Pubkey>,
 }
 
 const META_AUTHORIZED_WITHDRAWER_OFFSET: usize = 44;
@@ -119,6 +153,7 @@ pub fn fetch_liquidity_pool_stake_accounts(
         })
         .unwrap()
         .epoch;
+    let clock: Clock = from_account(&client.get_account(&sysvar::clock::id()).unwrap()).unwrap();
 
     stake_accs_with_record.into_iter().fold(
         LiquidityPoolStakeAccounts {
@@ -126,11 +161,15 @@ pub fn fetch_liquidity_pool_stake_accounts(
             inactive: Vec::new(),
             activating: Vec::new(),
             deactivating: Vec::new(),
+            locked: Vec::new(),
         },
         |mut res, ksa| {
             let state = stake_account_state(&ksa, &current_epoch);
             match state {
-                StakeState::Inactive => res.inactive.push(ksa.0),
+                StakeState::Inactive => match is_lockup_in_force(&ksa, &clock) {
+                    true => res.locked.push(ksa.0),
+                    false => res.inactive.push(ksa.0),
+                },
                 StakeState::Activating => res.activating.push(ksa.0),
                 StakeState::Active => res.active.push(ksa.0),
                 StakeState::Deactivating => res.deactivating.push(ksa.0),
@@ -139,3 +178,15 @@ pub fn fetch_liquidity_pool_stake_accounts(
         },
     )
 }
+
+/// The pool's fee delay, 0 if its fee delay account is not initialized
+pub fn fetch_min_delay_seconds(client: &RpcClient, fee_delay_account: &Pubkey) -> u64 {
+    client
+        .get_account(fee_delay_account)
+        .ok()
+        .map_or(0, |fee_delay_account| {
+            FeeDelay::try_deserialize(&mut fee_delay_account.data.as_slice())
+                .unwrap()
+                .min_delay_seconds
+        })
+}
@@ -18,7 +18,7 @@ import {
   createPoolTx,
   removeLiquidityTx,
   setFeeTx,
-  setFeeAuthorityTx,
+  proposeFeeAuthorityTx,
   findPoolFeeAccount,
   findPoolSolReserves,
   findProtocolFeeAccount,
@@ -405,17 +405,18 @@ yargs(hideBin(process.argv))
     }
   )
   .command(
-    "set_fee_authority <pool_account> <new_fee_authority>",
-    "sets the fee authority for an unstake liquidity pool",
+    "propose_fee_authority <pool_account> <new_fee_authority>",
+    "nominates a new fee authority for an unstake liquidity pool, which must accept to take over",
     (y) =>
       y
         .positional("pool_account", {
           type: "string",
-          description: "Pubkey of the pool to set the fee of",
+          description:
+            "Pubkey of the pool to nominate a new fee authority for",
         })
         .positional("new_fee_authority", {
           type: "string",
-          description: "Pubkey that is to be the pool's new fee authority",
+          description: "Pubkey nominated to be the pool's new fee authority",
         })
         .option("fee_authority", {
           type: "string",
@@ -444,7 +445,7 @@ yargs(hideBin(process.argv))
         feeAuthority = kp.publicKey;
       }
 
-      const tx = await setFeeAuthorityTx(program, {
+      const tx = await proposeFeeAuthorityTx(program, {
         feeAuthority,
         poolAccount,
         newFeeAuthority,
@@ -453,9 +454,7 @@ yargs(hideBin(process.argv))
       console.log(
         "Liquidity pool at",
         poolAccount.toString(),
-        "fee authority updated from",
-        feeAuthority.toString(),
-        "to",
+        "fee authority nominee set to",
         newFeeAuthority.toString()
       );
       console.log("TX:", sig);
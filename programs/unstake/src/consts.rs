@@ -1 +1,7 @@
 pub const SOL_DECIMALS: u8 = 9;
+
+/// Virtual lamports and LP tokens added to the pool's owned lamports and LP token supply
+/// when converting between the two, so that donating lamports to the pool's SOL reserves
+/// cannot inflate the LP token's price enough for rounding to cost depositors more than
+/// `donation / VIRTUAL_LP_OFFSET + 2` lamports
+pub const VIRTUAL_LP_OFFSET: u64 = 1_000_000;
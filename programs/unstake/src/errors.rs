@@ -47,4 +47,114 @@ pub enum UnstakeError {
 
     #[msg("No succeeding repay flash loan instruction found")]
     NoSucceedingRepayFlashLoan, // 0x177e
+
+    #[msg("Lamports to be received is below the specified minimum")]
+    LamportsOutBelowMinimum, // 0x177f
+
+    #[msg("No stake accounts to unstake were provided")]
+    NoStakeAccountsToUnstake, // 0x1780
+
+    #[msg("The provided stake account record account is not the PDA of the stake account")]
+    InvalidStakeAccountRecordAccount, // 0x1781
+
+    #[msg("The pool's lockup fee account must be provided to unstake a stake account that is locked up")]
+    LockupFeeAccountRequired, // 0x1782
+
+    #[msg("The pool does not accept stake delegated to this validator")]
+    ValidatorDenied, // 0x1783
+
+    #[msg("Stake accounts unstaked together must be charged the same fee")]
+    StakeAccountFeesMismatch, // 0x1784
+
+    #[msg("The provided validator fee account is not the PDA of the stake account's validator")]
+    InvalidValidatorFeeAccount, // 0x1785
+
+    #[msg(
+        "The pool's pending fee account must be provided to set the fee of a pool with a fee delay"
+    )]
+    PendingFeeAccountRequired, // 0x1786
+
+    #[msg("The pending fee cannot be applied yet")]
+    PendingFeeNotActive, // 0x1787
+
+    #[msg("The pool's fee delay cannot be decreased")]
+    FeeDelayDecreaseNotAllowed, // 0x1788
+
+    #[msg("The signer is not the nominated authority")]
+    InvalidAuthorityNominee, // 0x1789
+
+    #[msg("The protocol fee authority and fee ratios can only be changed by proposing new values")]
+    ProtocolFeeChangeNotAllowed, // 0x178a
+
+    #[msg("This instruction is paused for the pool")]
+    PoolPaused, // 0x178b
+
+    #[msg("This instruction is paused for all pools")]
+    ProtocolPaused, // 0x178c
+
+    #[msg("The signer is neither the pool's fee authority nor its pause guardian")]
+    InvalidPauseAuthority, // 0x178d
+
+    #[msg("The amount to unstake exceeds the pool's maximum unstake size")]
+    UnstakeAboveMaximum, // 0x178e
+
+    #[msg("The amount to unstake exceeds the pool's remaining incoming stake cap for the current epoch")]
+    EpochIncomingStakeCapExceeded, // 0x178f
+
+    #[msg("Adding this amount of liquidity exceeds the pool's deposit cap")]
+    DepositCapExceeded, // 0x1790
+
+    #[msg("The depositor is not on the pool's LP allowlist")]
+    LpNotAllowed, // 0x1791
+
+    #[msg("Not enough SOL in the pool reserves to service this withdrawal")]
+    InsufficientReservesForWithdrawal, // 0x1792
+
+    #[msg("The amount to redeem in kind exceeds the stake account's recorded lamports")]
+    InKindRedemptionExceedsStakeAccount, // 0x1793
+
+    #[msg("A new stake account to split into is required to redeem part of a stake account")]
+    SplitStakeAccountRequired, // 0x1794
+
+    #[msg("LP tokens to be minted is below the specified minimum")]
+    LpTokensOutBelowMinimum, // 0x1795
+
+    #[msg("Lamports of liquidity to be removed is below the specified minimum")]
+    LiquidityOutBelowMinimum, // 0x1796
+
+    #[msg("Source token account is not a wrapped SOL account")]
+    SourceNotWSol, // 0x1797
+
+    #[msg("The pool still has LP tokens, incoming stake or SOL owed to withdraw tickets")]
+    PoolNotEmpty, // 0x1798
+
+    #[msg(
+        "This change could raise the pool's fees and is not allowed while the pool has a fee delay"
+    )]
+    FeeChangeRequiresDelay, // 0x1799
+
+    #[msg("The nominee was proposed by a previous authority")]
+    StaleAuthorityNominee, // 0x179a
+
+    #[msg("Exactly one of mint_lp_tokens_to and locked_lp_token_account must be provided")]
+    InvalidLpTokenDestination, // 0x179b
+
+    #[msg(
+        "Account is not the pool's ValidatorFee, PendingValidatorFee or AllowedLp account for the provided pubkey"
+    )]
+    InvalidAccountToClose, // 0x179c
+
+    #[msg("This instruction is deprecated, use its V2 version instead")]
+    DeprecatedInstruction, // 0x179d
+
+    #[msg("A stake account was provided more than once")]
+    DuplicateStakeAccount, // 0x179e
+
+    #[msg("The fee delay exceeds the maximum fee delay")]
+    FeeDelayTooLong, // 0x179f
+
+    #[msg(
+        "Exactly one of validator_fee_account and pending_validator_fee_account must be provided"
+    )]
+    InvalidValidatorFeeDestination, // 0x17a0
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{FeeAuthorityNominee, Pool, FEE_AUTHORITY_NOMINEE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct AcceptFeeAuthority<'info> {
+    /// the nominated fee_authority
+    pub new_fee_authority: Signer<'info>,
+
+    /// pool's current fee_authority, receives fee_authority_nominee_account's rent lamports
+    #[account(mut)]
+    pub fee_authority: SystemAccount<'info>,
+
+    /// pool account to be modified
+    #[account(
+        mut,
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// fee authority nominee account to close
+    #[account(
+        mut,
+        close = fee_authority,
+        constraint = fee_authority_nominee_account.nominee == new_fee_authority.key() @ UnstakeError::InvalidAuthorityNominee,
+        constraint = fee_authority_nominee_account.proposer == fee_authority.key() @ UnstakeError::StaleAuthorityNominee,
+        seeds = [&pool_account.key().to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_authority_nominee_account: Account<'info, FeeAuthorityNominee>,
+}
+
+impl<'info> AcceptFeeAuthority<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.pool_account.fee_authority = ctx.accounts.new_fee_authority.key();
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//! An instruction for the nominated protocol fee authority to take over as protocol fee authority
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        ProtocolFee, ProtocolFeeAuthorityNominee, PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED,
+        PROTOCOL_FEE_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AcceptProtocolFeeAuthority<'info> {
+    /// the nominated protocol fee authority
+    pub new_authority: Signer<'info>,
+
+    /// current protocol fee authority, receives nominee_account's rent lamports
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+
+    /// protocol fee account to update
+    #[account(
+        mut,
+        has_one = authority @ UnstakeError::InvalidProtocolFeeAuthority,
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// nominee account to close
+    #[account(
+        mut,
+        close = authority,
+        constraint = nominee_account.nominee == new_authority.key() @ UnstakeError::InvalidAuthorityNominee,
+        seeds = [PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED],
+        bump,
+    )]
+    pub nominee_account: Account<'info, ProtocolFeeAuthorityNominee>,
+}
+
+impl<'info> AcceptProtocolFeeAuthority<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.protocol_fee_account.authority = ctx.accounts.new_authority.key();
+        Ok(())
+    }
+}
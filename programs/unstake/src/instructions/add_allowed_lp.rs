@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{AllowedLp, Pool, ALLOWED_LP_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct AddAllowedLp<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the allowed lp account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// depositor to allow to add liquidity to the pool
+    /// CHECK: any pubkey
+    pub lp: UncheckedAccount<'info>,
+
+    /// allowed lp account to initialize
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AllowedLp::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &lp.key().to_bytes(),
+            ALLOWED_LP_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub allowed_lp_account: Account<'info, AllowedLp>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAllowedLp<'info> {
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
@@ -3,13 +3,18 @@ use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 use std::convert::TryFrom;
 
 use crate::{
+    consts::VIRTUAL_LP_OFFSET,
     errors::UnstakeError,
-    state::{Pool, FLASH_ACCOUNT_SEED_SUFFIX},
+    state::{
+        AllowedLp, PausableInstruction, Pool, PoolConfig, PoolPause, ProtocolPause, WithdrawQueue,
+        ALLOWED_LP_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+        POOL_PAUSE_SEED_SUFFIX, PROTOCOL_PAUSE_SEED, WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
     utils::calc_pool_owned_lamports,
 };
 
 #[derive(Accounts)]
-pub struct AddLiquidity<'info> {
+pub struct AddLiquidityV2<'info> {
     /// SOL SystemAccount that's adding liquidity
     #[account(mut)]
     pub from: Signer<'info>,
@@ -51,11 +56,66 @@ pub struct AddLiquidity<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no deposit cap or LP allowlist if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// from's allowed lp account.
+    /// Only required to be initialized if the pool's LP allowlist is enabled
+    /// CHECK: PDA checks address
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &from.key().to_bytes(),
+            ALLOWED_LP_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub allowed_lp_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
 }
 
-impl<'info> AddLiquidity<'info> {
+impl<'info> AddLiquidityV2<'info> {
+    /// Fails with LpTokensOutBelowMinimum if the LP tokens minted
+    /// is below `min_lp_tokens_out`
     #[inline(always)]
-    pub fn run(ctx: Context<Self>, amount: u64) -> Result<()> {
+    pub fn run(ctx: Context<Self>, amount: u64, min_lp_tokens_out: u64) -> Result<()> {
         let from = &ctx.accounts.from;
         let pool_account = &mut ctx.accounts.pool_account;
         let pool_sol_reserves = &ctx.accounts.pool_sol_reserves;
@@ -66,9 +126,25 @@ impl<'info> AddLiquidity<'info> {
         let system_program = &ctx.accounts.system_program;
 
         // order matters, must calculate first before mutation
-        let pool_owned_lamports =
-            calc_pool_owned_lamports(pool_sol_reserves, pool_account, flash_account)?;
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            pool_sol_reserves,
+            pool_account,
+            flash_account,
+            WithdrawQueue::earmarked_lamports(&ctx.accounts.withdraw_queue_account)?,
+        )?;
+        if ctx.accounts.pool_config_account.owner == &crate::ID {
+            let pool_config: Account<PoolConfig> =
+                Account::try_from(&ctx.accounts.pool_config_account)?;
+            pool_config.validate_add_liquidity(
+                pool_owned_lamports,
+                amount,
+                AllowedLp::is_allowed(&ctx.accounts.allowed_lp_account),
+            )?;
+        }
         let to_mint = calc_lp_tokens_to_mint(pool_owned_lamports, lp_mint.supply, amount)?;
+        if to_mint < min_lp_tokens_out {
+            return Err(UnstakeError::LpTokensOutBelowMinimum.into());
+        }
 
         // transfer SOL
         let transfer_cpi_accs = system_program::Transfer {
@@ -100,7 +176,7 @@ impl<'info> AddLiquidity<'info> {
     }
 }
 
-fn calc_lp_tokens_to_mint(
+pub(crate) fn calc_lp_tokens_to_mint(
     pool_owned_lamports: u64,
     lp_mint_supply: u64,
     amount_to_add: u64,
@@ -115,10 +191,12 @@ fn calc_lp_tokens_to_mint(
             .and_then(|v| v.checked_sub(lp_mint_supply))
             .ok_or(UnstakeError::InternalError)?,
 
-        // mint = amount * supply BEFORE TRANSFER / owned_lamports BEFORE TRANSFER
+        // mint = amount * (supply BEFORE TRANSFER + offset) / (owned_lamports BEFORE TRANSFER + offset)
         false => u128::from(amount_to_add)
-            .checked_mul(u128::from(lp_mint_supply))
-            .and_then(|v| v.checked_div(u128::from(pool_owned_lamports)))
+            .checked_mul(u128::from(lp_mint_supply) + u128::from(VIRTUAL_LP_OFFSET))
+            .and_then(|v| {
+                v.checked_div(u128::from(pool_owned_lamports) + u128::from(VIRTUAL_LP_OFFSET))
+            })
             .and_then(|v| u64::try_from(v).ok())
             .ok_or(UnstakeError::InternalError)?,
     };
@@ -134,6 +212,8 @@ mod tests {
     use spl_math::uint::U256;
     use std::cmp::{max, min};
 
+    use crate::utils::calc_lamports_to_return;
+
     use super::*;
 
     prop_compose! {
@@ -199,12 +279,14 @@ mod tests {
         fn test_normal_cases_proportional(
             (pool_owned_lamports, lp_mint_supply, amount_to_add) in normal_cases()
         ) {
-            // to_mint / (lp_mint_supply + to_mint) <= amount_to_add / (pool_owned_lamports + amount_to_add) ->
-            // to_mint * (pool_owned_lamports + amount_to_add) <= amount_to_add * (lp_mint_supply + to_mint)
+            // proportional to the totals including the virtual offset:
+            // to_mint / (lp_mint_supply + offset + to_mint) <= amount_to_add / (pool_owned_lamports + offset + amount_to_add) ->
+            // to_mint * (pool_owned_lamports + offset + amount_to_add) <= amount_to_add * (lp_mint_supply + offset + to_mint)
 
             let to_mint = calc_lp_tokens_to_mint(pool_owned_lamports, lp_mint_supply, amount_to_add).unwrap();
-            let lhs = (U256::from(to_mint)) * (U256::from(pool_owned_lamports) + U256::from(amount_to_add));
-            let rhs = (U256::from(amount_to_add)) * (U256::from(lp_mint_supply) + U256::from(to_mint));
+            let offset = U256::from(VIRTUAL_LP_OFFSET);
+            let lhs = (U256::from(to_mint)) * (U256::from(pool_owned_lamports) + offset + U256::from(amount_to_add));
+            let rhs = (U256::from(amount_to_add)) * (U256::from(lp_mint_supply) + offset + U256::from(to_mint));
             // TODO: there should be an error bound on the ineq, not sure what it is
             prop_assert!(lhs <= rhs);
         }
@@ -220,4 +302,50 @@ mod tests {
             prop_assert!(err == UnstakeError::LiquidityToAddTooLittle || err == UnstakeError::InternalError);
         }
     }
+
+    /// First depositor deposits `first_amount` into an empty pool,
+    /// then donates `donation` lamports directly to the pool's SOL reserves
+    fn pool_after_donation(first_amount: u64, donation: u64) -> (u64, u64) {
+        let first_minted = calc_lp_tokens_to_mint(0, 0, first_amount).unwrap();
+        (first_amount + donation, first_minted)
+    }
+
+    proptest! {
+        #[test]
+        fn test_donation_bounds_subsequent_depositor_loss(
+            // worst case: first depositor mints fewer LP tokens than the offset
+            first_amount in 1..=VIRTUAL_LP_OFFSET,
+            donation in 0..=u64::MAX / 4,
+            amount_to_add in 1..=u64::MAX / 4,
+        ) {
+            let (pool_owned_lamports, lp_mint_supply) = pool_after_donation(first_amount, donation);
+            // a deposit too small to mint any LP tokens fails instead of losing lamports
+            if let Ok(to_mint) = calc_lp_tokens_to_mint(pool_owned_lamports, lp_mint_supply, amount_to_add) {
+                let returned = calc_lamports_to_return(
+                    pool_owned_lamports + amount_to_add,
+                    lp_mint_supply + to_mint,
+                    to_mint,
+                ).unwrap();
+                let loss = amount_to_add.saturating_sub(returned);
+                prop_assert!(loss <= donation / VIRTUAL_LP_OFFSET + 2);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_donation_to_fail_subsequent_deposit_is_large(
+            // worst case: first depositor mints fewer LP tokens than the offset
+            first_amount in 1..=VIRTUAL_LP_OFFSET,
+            donation in 0..=VIRTUAL_LP_OFFSET * VIRTUAL_LP_OFFSET,
+            amount_to_add in 1..=VIRTUAL_LP_OFFSET,
+        ) {
+            let (pool_owned_lamports, lp_mint_supply) = pool_after_donation(first_amount, donation);
+            if calc_lp_tokens_to_mint(pool_owned_lamports, lp_mint_supply, amount_to_add).is_err() {
+                prop_assert!(
+                    u128::from(donation) > u128::from(amount_to_add - 1) * u128::from(VIRTUAL_LP_OFFSET)
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,343 @@
+use anchor_lang::{prelude::*, solana_program::stake::state::StakeAuthorize};
+use anchor_spl::{
+    stake::{self, Authorize, Stake, StakeAccount},
+    token::{self, Mint, MintTo, Token, TokenAccount},
+};
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        AllowedLp, ApplyFeeArgs, Fee, PausableInstruction, Pool, PoolConfig, PoolPause,
+        ProtocolFee, ProtocolPause, StakeAccountRecord, StakeDepositFee, WithdrawQueue,
+        ALLOWED_LP_SEED_SUFFIX, FEE_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX,
+        POOL_CONFIG_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX, PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED,
+        STAKE_DEPOSIT_FEE_SEED_SUFFIX, VALIDATOR_FEE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::{
+        calc_pool_owned_lamports, epoch_progress, resolve_fee, stake_account_state,
+        stake_account_voter,
+    },
+};
+
+use super::{add_liquidity::calc_lp_tokens_to_mint, pay_out_protocol_fee, PayOutProtocolFeeArgs};
+
+/// The referrer to pay referrer fees to can optionally be passed
+/// as the first remaining account, same as Unstake
+#[derive(Accounts)]
+pub struct AddLiquidityStake<'info> {
+    /// withdraw authority of stake_account.
+    /// Pays for stake_account_record_account's rent
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// stake account to deposit
+    /// rely on stake program CPI call to ensure owned by depositor
+    #[account(
+        mut,
+        // see Unstake
+        constraint = !stake_account.lockup()
+            .ok_or(UnstakeError::StakeAccountLockupNotRetrievable)?
+            .is_in_force(&clock, None)
+            @ UnstakeError::StakeAccountLockupInForce,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// pool that the stake account is being deposited to
+    #[account(
+        mut,
+        has_one = lp_mint
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves.
+    /// Pays the protocol fee
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's LP mint
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// lp token account to mint lp tokens to
+    #[account(
+        mut,
+        constraint = mint_lp_tokens_to.mint == lp_mint.key() @ UnstakeError::InvalidLpTokenAccount
+    )]
+    pub mint_lp_tokens_to: Account<'info, TokenAccount>,
+
+    /// stake account record to be created
+    #[account(
+        init,
+        payer = depositor,
+        space = StakeAccountRecord::LEN,
+        seeds = [&pool_account.key().to_bytes(), &stake_account.key().to_bytes()],
+        bump,
+    )]
+    pub stake_account_record_account: Account<'info, StakeAccountRecord>,
+
+    /// pool's stake deposit fee account.
+    /// Only the unstake fee is charged if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), STAKE_DEPOSIT_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub stake_deposit_fee_account: UncheckedAccount<'info>,
+
+    /// pool's fee account.
+    /// Depositing a stake account costs at least as much as unstaking it
+    /// since it can be followed by RemoveLiquidity
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    /// pool's validator fee account for the validator stake_account is delegated to.
+    /// The pool's fee is charged if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &stake_account_voter(&stake_account).to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: UncheckedAccount<'info>,
+
+    /// amount taken for all active flash loans of the pool
+    /// CHECK: PDA checked
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_account: UncheckedAccount<'info>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized.
+    /// Paused if either adding liquidity or unstaking is paused
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::PoolPaused,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized.
+    /// Paused if either adding liquidity or unstaking is paused
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::ProtocolPaused,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no deposit cap, LP allowlist or unstake limits if it is not initialized
+    /// CHECK: PDA checks address. Deserialized and updated in run() if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// depositor's allowed lp account.
+    /// Only required to be initialized if the pool's LP allowlist is enabled
+    /// CHECK: PDA checks address
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &depositor.key().to_bytes(),
+            ALLOWED_LP_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub allowed_lp_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// CHECK: address-check checks that its the correct
+    /// destination specified in `protocol_fee_account`
+    #[account(
+        mut,
+        address = protocol_fee_account.destination @ UnstakeError::WrongProtocolFeeDestination,
+    )]
+    pub protocol_fee_destination: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_history: Sysvar<'info, StakeHistory>,
+    pub stake_program: Program<'info, Stake>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddLiquidityStake<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
+        let accounts = ctx.accounts;
+        let stake_account_lamports = accounts.stake_account.to_account_info().lamports();
+        let earmarked_lamports =
+            WithdrawQueue::earmarked_lamports(&accounts.withdraw_queue_account)?;
+
+        // order matters, must calculate first before mutation
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            &accounts.pool_sol_reserves,
+            &accounts.pool_account,
+            &accounts.flash_account,
+            earmarked_lamports,
+        )?;
+        if accounts.pool_config_account.owner == &crate::ID {
+            let pool_config: Account<PoolConfig> =
+                Account::try_from(&accounts.pool_config_account)?;
+            pool_config.validate_add_liquidity(
+                pool_owned_lamports,
+                stake_account_lamports,
+                AllowedLp::is_allowed(&accounts.allowed_lp_account),
+            )?;
+        }
+        // the deposited stake account is always recorded and added to incoming_stake,
+        // even if it is inactive
+        PoolConfig::record_unstake_if_initialized(
+            &accounts.pool_config_account,
+            accounts.clock.epoch,
+            stake_account_lamports,
+            stake_account_lamports,
+        )?;
+
+        // charge at least what unstaking the stake account would,
+        // since the LP tokens can be redeemed for SOL immediately
+        let unstake_fee_lamports =
+            resolve_fee(&accounts.fee_account, &accounts.validator_fee_account)?
+                .apply(&ApplyFeeArgs {
+                    pool_incoming_stake: accounts.pool_account.incoming_stake,
+                    sol_reserves_lamports: accounts
+                        .pool_sol_reserves
+                        .lamports()
+                        .saturating_sub(earmarked_lamports),
+                    stake_account_lamports,
+                    epoch_progress: epoch_progress(&accounts.clock)?,
+                    stake_account_state: stake_account_state(
+                        &accounts.stake_account,
+                        &accounts.clock,
+                        &accounts.stake_history,
+                    ),
+                })
+                .ok_or(UnstakeError::InternalError)?;
+        let fee_lamports = StakeDepositFee::fee_lamports(
+            &accounts.stake_deposit_fee_account,
+            stake_account_lamports,
+        )?
+        .max(unstake_fee_lamports);
+        // the protocol takes its share of the fee like it does for Unstake,
+        // paid from the pool reserves since the stake account is not liquid
+        let protocol_fee_lamports = accounts
+            .protocol_fee_account
+            .apply(fee_lamports)
+            .ok_or(UnstakeError::InternalError)?;
+        let available_lamports = accounts
+            .pool_sol_reserves
+            .lamports()
+            .saturating_sub(earmarked_lamports);
+        if protocol_fee_lamports > available_lamports {
+            return Err(UnstakeError::NotEnoughLiquidity.into());
+        }
+        let to_mint = calc_lp_tokens_to_mint(
+            pool_owned_lamports,
+            accounts.lp_mint.supply,
+            stake_account_lamports
+                .checked_sub(fee_lamports)
+                .ok_or(UnstakeError::InternalError)?,
+        )?;
+
+        // authorize pool_sol_reserves as staker and withdrawer of the stake_account.
+        // depositor only needs to be the withdrawer, see Unstake
+        for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+            stake::authorize(
+                CpiContext::new(
+                    accounts.stake_program.to_account_info(),
+                    Authorize {
+                        stake: accounts.stake_account.to_account_info(),
+                        authorized: accounts.depositor.to_account_info(),
+                        new_authorized: accounts.pool_sol_reserves.to_account_info(),
+                        clock: accounts.clock.to_account_info(),
+                    },
+                ),
+                stake_authorize,
+                None,
+            )?;
+        }
+
+        // record the stake account, to be deactivated and reclaimed like unstaked ones
+        accounts
+            .stake_account_record_account
+            .set_inner(StakeAccountRecord {
+                lamports_at_creation: stake_account_lamports,
+            });
+        accounts.pool_account.incoming_stake = accounts
+            .pool_account
+            .incoming_stake
+            .checked_add(stake_account_lamports)
+            .ok_or(UnstakeError::InternalError)?;
+
+        let seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+        pay_out_protocol_fee(PayOutProtocolFeeArgs {
+            pool_sol_reserves: accounts.pool_sol_reserves.to_account_info(),
+            pool_sol_reserves_seeds: seeds,
+            protocol_fee: &accounts.protocol_fee_account,
+            protocol_fee_lamports,
+            protocol_fee_destination: accounts.protocol_fee_destination.to_account_info(),
+            referrer: ctx.remaining_accounts.first().map(|a| a.to_account_info()),
+            system_program: accounts.system_program.to_account_info(),
+        })?;
+
+        // mint LP tokens
+        let mint_cpi_accs = MintTo {
+            mint: accounts.lp_mint.to_account_info(),
+            to: accounts.mint_lp_tokens_to.to_account_info(),
+            authority: accounts.pool_sol_reserves.to_account_info(),
+        };
+        token::mint_to(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                mint_cpi_accs,
+                &[seeds],
+            ),
+            to_mint,
+        )
+    }
+}
@@ -0,0 +1,229 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{
+    self, spl_token, CloseAccount, Mint, MintTo, Token, TokenAccount, Transfer,
+};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        AllowedLp, PausableInstruction, Pool, PoolConfig, PoolPause, ProtocolPause, WithdrawQueue,
+        ALLOWED_LP_SEED_SUFFIX, FLASH_ACCOUNT_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+        POOL_PAUSE_SEED_SUFFIX, PROTOCOL_PAUSE_SEED, WITHDRAW_QUEUE_SEED_SUFFIX,
+        WSOL_STAGING_SEED_SUFFIX,
+    },
+    utils::calc_pool_owned_lamports,
+};
+
+use super::add_liquidity::calc_lp_tokens_to_mint;
+
+#[derive(Accounts)]
+pub struct AddLiquidityWsol<'info> {
+    /// signer that has authority over the from wSOL token account
+    pub from_authority: Signer<'info>,
+
+    /// pays for wsol_staging_account's rent, refunded within the instruction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// wSOL token account that's adding liquidity
+    #[account(
+        mut,
+        constraint = from.mint == spl_token::native_mint::id() @ UnstakeError::SourceNotWSol
+    )]
+    pub from: Account<'info, TokenAccount>,
+
+    /// transient wSOL token account to unwrap the added wSOL into pool_sol_reserves.
+    /// Closed at the end of the instruction
+    #[account(
+        init,
+        payer = payer,
+        seeds = [&pool_account.key().to_bytes(), WSOL_STAGING_SEED_SUFFIX],
+        bump,
+        token::mint = native_mint,
+        token::authority = pool_sol_reserves,
+    )]
+    pub wsol_staging_account: Account<'info, TokenAccount>,
+
+    /// wSOL mint
+    #[account(address = spl_token::native_mint::id())]
+    pub native_mint: Account<'info, Mint>,
+
+    /// pool that SOL liquidity is being added to
+    #[account(
+        mut,
+        has_one = lp_mint
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's LP mint
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// lp token account to mint lp tokens to
+    #[account(
+        mut,
+        constraint = mint_lp_tokens_to.mint == lp_mint.key() @ UnstakeError::InvalidLpTokenAccount
+    )]
+    pub mint_lp_tokens_to: Account<'info, TokenAccount>,
+
+    /// amount taken for all active flash loans of the pool
+    /// CHECK: PDA checked
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_account: UncheckedAccount<'info>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no deposit cap or LP allowlist if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// from_authority's allowed lp account.
+    /// Only required to be initialized if the pool's LP allowlist is enabled
+    /// CHECK: PDA checks address
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &from_authority.key().to_bytes(),
+            ALLOWED_LP_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub allowed_lp_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddLiquidityWsol<'info> {
+    /// Fails with LpTokensOutBelowMinimum if the LP tokens minted
+    /// is below `min_lp_tokens_out`
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, amount: u64, min_lp_tokens_out: u64) -> Result<()> {
+        let accounts = ctx.accounts;
+
+        // order matters, must calculate first before mutation
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            &accounts.pool_sol_reserves,
+            &accounts.pool_account,
+            &accounts.flash_account,
+            WithdrawQueue::earmarked_lamports(&accounts.withdraw_queue_account)?,
+        )?;
+        if accounts.pool_config_account.owner == &crate::ID {
+            let pool_config: Account<PoolConfig> =
+                Account::try_from(&accounts.pool_config_account)?;
+            pool_config.validate_add_liquidity(
+                pool_owned_lamports,
+                amount,
+                AllowedLp::is_allowed(&accounts.allowed_lp_account),
+            )?;
+        }
+        let to_mint = calc_lp_tokens_to_mint(pool_owned_lamports, accounts.lp_mint.supply, amount)?;
+        if to_mint < min_lp_tokens_out {
+            return Err(UnstakeError::LpTokensOutBelowMinimum.into());
+        }
+
+        let seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+
+        // unwrap wSOL: transfer to wsol_staging_account then close it to pool_sol_reserves
+        let staging_rent_lamports = accounts.wsol_staging_account.to_account_info().lamports();
+        token::transfer(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.from.to_account_info(),
+                    to: accounts.wsol_staging_account.to_account_info(),
+                    authority: accounts.from_authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: accounts.wsol_staging_account.to_account_info(),
+                destination: accounts.pool_sol_reserves.to_account_info(),
+                authority: accounts.pool_sol_reserves.to_account_info(),
+            },
+            &[seeds],
+        ))?;
+
+        // refund wsol_staging_account's rent
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: accounts.pool_sol_reserves.to_account_info(),
+                    to: accounts.payer.to_account_info(),
+                },
+                &[seeds],
+            ),
+            staging_rent_lamports,
+        )?;
+
+        // mint LP tokens
+        let mint_cpi_accs = MintTo {
+            mint: accounts.lp_mint.to_account_info(),
+            to: accounts.mint_lp_tokens_to.to_account_info(),
+            authority: accounts.pool_sol_reserves.to_account_info(),
+        };
+        token::mint_to(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                mint_cpi_accs,
+                &[seeds],
+            ),
+            to_mint,
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{Fee, PendingFee, Pool, FEE_SEED_SUFFIX, PENDING_FEE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct ApplyPendingFee<'info> {
+    /// pool's fee_authority, receives pending_fee_account's rent lamports
+    #[account(mut)]
+    pub fee_authority: SystemAccount<'info>,
+
+    /// pool account for the fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// fee account to be modified.
+    /// Already reallocated to fit the new fee by SetFee
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    /// pending fee account to apply and close
+    #[account(
+        mut,
+        close = fee_authority,
+        seeds = [&pool_account.key().to_bytes(), PENDING_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_fee_account: Account<'info, PendingFee>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ApplyPendingFee<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let pending_fee = &ctx.accounts.pending_fee_account;
+        if ctx.accounts.clock.unix_timestamp < pending_fee.activation_timestamp {
+            return Err(UnstakeError::PendingFeeNotActive.into());
+        }
+
+        ctx.accounts.fee_account.set_inner(pending_fee.fee.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        LockupFee, PendingLockupFee, Pool, LOCKUP_FEE_SEED_SUFFIX, PENDING_LOCKUP_FEE_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ApplyPendingLockupFee<'info> {
+    /// pool's fee_authority, receives pending_lockup_fee_account's rent lamports
+    #[account(mut)]
+    pub fee_authority: SystemAccount<'info>,
+
+    /// pool account for the lockup fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// lockup fee account to be modified.
+    /// Already initialized by SetLockupFee
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub lockup_fee_account: Account<'info, LockupFee>,
+
+    /// pending lockup fee account to apply and close
+    #[account(
+        mut,
+        close = fee_authority,
+        seeds = [&pool_account.key().to_bytes(), PENDING_LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_lockup_fee_account: Account<'info, PendingLockupFee>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ApplyPendingLockupFee<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let pending_lockup_fee = &ctx.accounts.pending_lockup_fee_account;
+        if ctx.accounts.clock.unix_timestamp < pending_lockup_fee.activation_timestamp {
+            return Err(UnstakeError::PendingFeeNotActive.into());
+        }
+
+        ctx.accounts.lockup_fee_account.set_inner(LockupFee {
+            fee_ratio: pending_lockup_fee.lockup_fee.fee_ratio,
+        });
+        Ok(())
+    }
+}
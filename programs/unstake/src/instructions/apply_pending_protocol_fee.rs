@@ -0,0 +1,50 @@
+//! A permissionless crank for applying proposed protocol fee ratios once their delay has passed
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{PendingProtocolFee, ProtocolFee, PENDING_PROTOCOL_FEE_SEED, PROTOCOL_FEE_SEED},
+};
+
+#[derive(Accounts)]
+pub struct ApplyPendingProtocolFee<'info> {
+    /// protocol fee authority, receives pending_protocol_fee_account's rent lamports
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+
+    /// protocol fee account to update
+    #[account(
+        mut,
+        has_one = authority @ UnstakeError::InvalidProtocolFeeAuthority,
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// pending protocol fee account to apply and close
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PENDING_PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub pending_protocol_fee_account: Account<'info, PendingProtocolFee>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ApplyPendingProtocolFee<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let pending_protocol_fee = &ctx.accounts.pending_protocol_fee_account;
+        if ctx.accounts.clock.unix_timestamp < pending_protocol_fee.activation_timestamp {
+            return Err(UnstakeError::PendingFeeNotActive.into());
+        }
+
+        let protocol_fee_account = &mut ctx.accounts.protocol_fee_account;
+        protocol_fee_account.fee_ratio = pending_protocol_fee.fee_ratio;
+        protocol_fee_account.referrer_fee_ratio = pending_protocol_fee.referrer_fee_ratio;
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::{prelude::*, AccountsClose};
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        PendingValidatorFee, Pool, ValidatorFee, PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        VALIDATOR_FEE_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ApplyPendingValidatorFee<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority, receives pending_validator_fee_account's rent lamports
+    /// and validator_fee_account's if it is removed
+    #[account(mut)]
+    pub fee_authority: SystemAccount<'info>,
+
+    /// pool account for the validator fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// vote account of the validator to apply the pending validator fee of
+    /// CHECK: any pubkey
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator fee account to initialize, modify or close
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ValidatorFee::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: Account<'info, ValidatorFee>,
+
+    /// pending validator fee account to apply and close
+    #[account(
+        mut,
+        close = fee_authority,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub pending_validator_fee_account: Account<'info, PendingValidatorFee>,
+
+    pub system_program: Program<'info, System>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ApplyPendingValidatorFee<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let accounts = ctx.accounts;
+        let pending_validator_fee = &accounts.pending_validator_fee_account;
+        if accounts.clock.unix_timestamp < pending_validator_fee.activation_timestamp {
+            return Err(UnstakeError::PendingFeeNotActive.into());
+        }
+
+        match pending_validator_fee.validator_fee {
+            Some(fee) => accounts
+                .validator_fee_account
+                .set_inner(ValidatorFee { fee }),
+            None => {
+                // a validator fee account that was removed after the removal was queued
+                // was just initialized with an invalid zeroed fee, refund its rent to payer
+                let refund_rent_to = match accounts.validator_fee_account.validate() {
+                    Ok(()) => accounts.fee_authority.to_account_info(),
+                    Err(_) => accounts.payer.to_account_info(),
+                };
+                accounts.validator_fee_account.close(refund_rent_to)?;
+            }
+        }
+        Ok(())
+    }
+}
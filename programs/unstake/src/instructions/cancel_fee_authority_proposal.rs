@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{FeeAuthorityNominee, Pool, FEE_AUTHORITY_NOMINEE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct CancelFeeAuthorityProposal<'info> {
+    /// pool's fee_authority, receives fee_authority_nominee_account's rent lamports
+    #[account(mut)]
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the fee authority nominee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// fee authority nominee account to close
+    #[account(
+        mut,
+        close = fee_authority,
+        seeds = [&pool_account.key().to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_authority_nominee_account: Account<'info, FeeAuthorityNominee>,
+}
+
+impl<'info> CancelFeeAuthorityProposal<'info> {
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        Pool, WithdrawQueue, WithdrawTicket, WITHDRAW_QUEUE_SEED_SUFFIX,
+        WITHDRAW_TICKET_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimWithdrawTicket<'info> {
+    /// owner of the withdraw ticket.
+    /// Refunded withdraw_ticket_account's rent lamports
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// SOL account to send the ticket's lamports to
+    #[account(mut)]
+    pub to: SystemAccount<'info>,
+
+    /// pool that SOL liquidity is being withdrawn from
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves. Ticket's lamports deducted from here
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's withdraw queue account
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: Account<'info, WithdrawQueue>,
+
+    /// withdraw ticket to claim and close
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &owner.key().to_bytes(),
+            WITHDRAW_TICKET_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub withdraw_ticket_account: Account<'info, WithdrawTicket>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimWithdrawTicket<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let to = &ctx.accounts.to;
+        let pool_account = &ctx.accounts.pool_account;
+        let pool_sol_reserves = &ctx.accounts.pool_sol_reserves;
+        let withdraw_queue_account = &mut ctx.accounts.withdraw_queue_account;
+        let lamports = ctx.accounts.withdraw_ticket_account.lamports;
+        let system_program = &ctx.accounts.system_program;
+
+        if lamports > pool_sol_reserves.lamports() {
+            return Err(UnstakeError::InsufficientReservesForWithdrawal.into());
+        }
+        withdraw_queue_account.earmarked_lamports = withdraw_queue_account
+            .earmarked_lamports
+            .checked_sub(lamports)
+            .ok_or(UnstakeError::InternalError)?;
+
+        // transfer SOL
+        let transfer_cpi_accs = system_program::Transfer {
+            from: pool_sol_reserves.to_account_info(),
+            to: to.to_account_info(),
+        };
+        let seeds: &[&[u8]] = &[
+            &pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                transfer_cpi_accs,
+                &[seeds],
+            ),
+            lamports,
+        )
+    }
+}
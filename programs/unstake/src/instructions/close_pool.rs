@@ -0,0 +1,297 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{
+    self, spl_token::instruction::AuthorityType, Burn, CloseAccount, Mint, SetAuthority, Token,
+};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        Fee, LockedLp, Pool, WithdrawQueue, ALLOWED_LP_SEED_SUFFIX,
+        FEE_AUTHORITY_NOMINEE_SEED_SUFFIX, FEE_DELAY_SEED_SUFFIX, FEE_SEED_SUFFIX,
+        FLASH_LOAN_FEE_SEED_SUFFIX, LOCKED_LP_SEED_SUFFIX, LOCKUP_FEE_SEED_SUFFIX,
+        PENDING_FEE_SEED_SUFFIX, PENDING_LOCKUP_FEE_SEED_SUFFIX, PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        POOL_CONFIG_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX, STAKE_DEPOSIT_FEE_SEED_SUFFIX,
+        VALIDATOR_FEE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::close_pda_if_initialized,
+};
+
+/// The pool's ValidatorFee, PendingValidatorFee and AllowedLp accounts to close are passed via
+/// remaining_accounts as consecutive (pubkey, pda) pairs, where pubkey is the vote account or LP
+/// the PDA is for.
+/// Any of them that are not passed are left open and apply again if the pool is re-created
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// account to sweep pool_sol_reserves' SOL and refund the closed accounts' rent to
+    /// CHECK: any account
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// pool account to close
+    #[account(
+        mut,
+        close = destination,
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority,
+        has_one = lp_mint,
+        constraint = pool_account.incoming_stake == 0 @ UnstakeError::PoolNotEmpty,
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves. All its SOL is swept to destination
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's LP mint. Its mint authority is revoked.
+    /// The only LP tokens left must be the ones locked in locked_lp_token_account
+    #[account(
+        mut,
+        constraint = lp_mint.supply == LockedLp::amount(&locked_lp_token_account)?
+            @ UnstakeError::PoolNotEmpty,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// pool's locked lp token account.
+    /// Its LP tokens are burnt and it is closed in run() if initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), LOCKED_LP_SEED_SUFFIX],
+        bump,
+    )]
+    pub locked_lp_token_account: UncheckedAccount<'info>,
+
+    /// pool's fee account to close
+    #[account(
+        mut,
+        close = destination,
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    /// pool's flash loan fee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), FLASH_LOAN_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_loan_fee_account: UncheckedAccount<'info>,
+
+    /// pool's fee delay account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: UncheckedAccount<'info>,
+
+    /// pool's pending fee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), PENDING_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_fee_account: UncheckedAccount<'info>,
+
+    /// pool's fee authority nominee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_authority_nominee_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// pool's pause account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// pool's stake deposit fee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), STAKE_DEPOSIT_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub stake_deposit_fee_account: UncheckedAccount<'info>,
+
+    /// pool's lockup fee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub lockup_fee_account: UncheckedAccount<'info>,
+
+    /// pool's pending lockup fee account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), PENDING_LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_lockup_fee_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// Closed in run() if initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+        constraint = WithdrawQueue::earmarked_lamports(&withdraw_queue_account)? == 0
+            @ UnstakeError::PoolNotEmpty,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClosePool<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let accounts = ctx.accounts;
+        let destination = accounts.destination.to_account_info();
+
+        // pool and fee accounts are closed by anchor on exit
+        for pda in [
+            &accounts.flash_loan_fee_account,
+            &accounts.fee_delay_account,
+            &accounts.pending_fee_account,
+            &accounts.fee_authority_nominee_account,
+            &accounts.pool_config_account,
+            &accounts.pool_pause_account,
+            &accounts.stake_deposit_fee_account,
+            &accounts.lockup_fee_account,
+            &accounts.pending_lockup_fee_account,
+            &accounts.withdraw_queue_account,
+        ] {
+            close_pda_if_initialized(pda, &destination)?;
+        }
+        if ctx.remaining_accounts.len() % 2 != 0 {
+            return Err(ErrorCode::AccountNotEnoughKeys.into());
+        }
+        let pool_account_key = accounts.pool_account.key();
+        for pair in ctx.remaining_accounts.chunks_exact(2) {
+            let (key, pda) = (&pair[0], &pair[1]);
+            let is_pool_pda = [
+                VALIDATOR_FEE_SEED_SUFFIX,
+                PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+                ALLOWED_LP_SEED_SUFFIX,
+            ]
+            .iter()
+            .any(|suffix| {
+                let (expected, _bump) = Pubkey::find_program_address(
+                    &[&pool_account_key.to_bytes(), &key.key().to_bytes(), suffix],
+                    &crate::ID,
+                );
+                pda.key() == expected
+            });
+            if !is_pool_pda {
+                return Err(UnstakeError::InvalidAccountToClose.into());
+            }
+            close_pda_if_initialized(pda, &destination)?;
+        }
+
+        let seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+
+        // burn the locked LP tokens, leaving the LP mint with no supply, and close their account
+        if accounts.locked_lp_token_account.owner == &token::ID {
+            let locked_lp_amount = LockedLp::amount(&accounts.locked_lp_token_account)?;
+            token::burn(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: accounts.lp_mint.to_account_info(),
+                        from: accounts.locked_lp_token_account.to_account_info(),
+                        authority: accounts.pool_sol_reserves.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                locked_lp_amount,
+            )?;
+            token::close_account(CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: accounts.locked_lp_token_account.to_account_info(),
+                    destination: destination.clone(),
+                    authority: accounts.pool_sol_reserves.to_account_info(),
+                },
+                &[seeds],
+            ))?;
+        }
+
+        // revoke LP mint's mint authority so that LP tokens can never be minted again
+        token::set_authority(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: accounts.pool_sol_reserves.to_account_info(),
+                    account_or_mint: accounts.lp_mint.to_account_info(),
+                },
+                &[seeds],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        // sweep leftover SOL
+        let dust_lamports = accounts.pool_sol_reserves.lamports();
+        if dust_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: accounts.pool_sol_reserves.to_account_info(),
+                        to: destination,
+                    },
+                    &[seeds],
+                ),
+                dust_lamports,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::{
+    anchor_len::AnchorLen,
+    consts::SOL_DECIMALS,
+    errors::UnstakeError,
+    state::{
+        Fee, PausableInstruction, Pool, ProtocolPause, FEE_SEED_SUFFIX, LOCKED_LP_SEED_SUFFIX,
+        PROTOCOL_PAUSE_SEED,
+    },
+};
+
+use super::add_liquidity::calc_lp_tokens_to_mint;
+
+#[derive(Accounts)]
+pub struct CreatePoolWithLiquidity<'info> {
+    /// pubkey paying for new accounts' rent and the initial liquidity
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account to be created
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::LEN,
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool SOL reserves and authority
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// fee account to be created
+    #[account(
+        init,
+        payer = payer,
+        space = Fee::LEN,
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    /// the LP token mint to be created
+    #[account(
+        init,
+        payer = payer,
+        mint::authority = pool_sol_reserves,
+        mint::decimals = SOL_DECIMALS,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// owner of mint_lp_tokens_to, usually the pool's creator.
+    /// Ignored if mint_lp_tokens_to is not provided
+    /// CHECK: any pubkey can own a token account
+    pub lp_token_account_owner: UncheckedAccount<'info>,
+
+    /// the lp token account to be created to mint the initial lp tokens to.
+    /// Must sign if provided.
+    /// If not provided, the initial lp tokens are locked in locked_lp_token_account
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = lp_token_account_owner,
+    )]
+    pub mint_lp_tokens_to: Option<Account<'info, TokenAccount>>,
+
+    /// the pool's lp token account to be created to permanently lock the initial lp tokens in.
+    /// Must be provided if and only if mint_lp_tokens_to is not
+    #[account(
+        init,
+        payer = payer,
+        seeds = [&pool_account.key().to_bytes(), LOCKED_LP_SEED_SUFFIX],
+        bump,
+        token::mint = lp_mint,
+        token::authority = pool_sol_reserves,
+    )]
+    pub locked_lp_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::AddLiquidity)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreatePoolWithLiquidity<'info> {
+    #[inline(always)]
+    pub fn validate(fee: &Fee) -> Result<()> {
+        fee.validate()
+    }
+
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, fee: Fee, initial_lamports: u64) -> Result<()> {
+        let accounts = ctx.accounts;
+        let mint_lp_tokens_to = match (
+            accounts.mint_lp_tokens_to.as_ref(),
+            accounts.locked_lp_token_account.as_ref(),
+        ) {
+            (Some(token_account), None) | (None, Some(token_account)) => {
+                token_account.to_account_info()
+            }
+            _ => return Err(UnstakeError::InvalidLpTokenDestination.into()),
+        };
+
+        accounts.fee_account.set_inner(fee);
+        accounts.pool_account.set_inner(Pool {
+            fee_authority: accounts.fee_authority.key(),
+            lp_mint: accounts.lp_mint.key(),
+            incoming_stake: 0,
+        });
+
+        // see AddLiquidity, pool_sol_reserves may have been sent lamports before creation
+        let to_mint =
+            calc_lp_tokens_to_mint(accounts.pool_sol_reserves.lamports(), 0, initial_lamports)?;
+
+        // transfer SOL
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: accounts.payer.to_account_info(),
+                    to: accounts.pool_sol_reserves.to_account_info(),
+                },
+            ),
+            initial_lamports,
+        )?;
+
+        // mint LP tokens
+        let mint_cpi_accs = MintTo {
+            mint: accounts.lp_mint.to_account_info(),
+            to: mint_lp_tokens_to,
+            authority: accounts.pool_sol_reserves.to_account_info(),
+        };
+        let seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                mint_cpi_accs,
+                &[seeds],
+            ),
+            to_mint,
+        )
+    }
+}
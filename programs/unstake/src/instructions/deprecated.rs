@@ -0,0 +1,161 @@
+//! v1 instructions superseded by their V2 versions.
+//!
+//! Their account layouts are kept exactly as they were so that existing clients
+//! fail with an explicit DeprecatedInstruction error instead of having their
+//! accounts misread. The accounts are never used and therefore left unchecked.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::UnstakeError;
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub unstaker: Signer<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_sol_reserves: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub fee_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub stake_account_record_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub protocol_fee_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub protocol_fee_destination: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub stake_program: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub system_program: UncheckedAccount<'info>,
+}
+
+impl<'info> Unstake<'info> {
+    /// Always fails, use UnstakeV2
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Err(UnstakeError::DeprecatedInstruction.into())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UnstakeWsol<'info> {
+    pub unstaker: Signer<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_sol_reserves: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub fee_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub stake_account_record_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub protocol_fee_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub protocol_fee_destination: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub clock: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub stake_program: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub system_program: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub token_program: UncheckedAccount<'info>,
+}
+
+impl<'info> UnstakeWsol<'info> {
+    /// Always fails, use UnstakeWsolV2
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Err(UnstakeError::DeprecatedInstruction.into())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub from: Signer<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_sol_reserves: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub mint_lp_tokens_to: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub flash_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub token_program: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub system_program: UncheckedAccount<'info>,
+}
+
+impl<'info> AddLiquidity<'info> {
+    /// Always fails, use AddLiquidityV2
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Err(UnstakeError::DeprecatedInstruction.into())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    pub burn_lp_tokens_from_authority: Signer<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub to: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub pool_sol_reserves: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub burn_lp_tokens_from: UncheckedAccount<'info>,
+    /// CHECK: unused
+    #[account(mut)]
+    pub flash_account: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub token_program: UncheckedAccount<'info>,
+    /// CHECK: unused
+    pub system_program: UncheckedAccount<'info>,
+}
+
+impl<'info> RemoveLiquidity<'info> {
+    /// Always fails, use RemoveLiquidityV2
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Err(UnstakeError::DeprecatedInstruction.into())
+    }
+}
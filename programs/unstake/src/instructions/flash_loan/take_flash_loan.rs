@@ -16,7 +16,10 @@ use anchor_lang::{
 use crate::{
     errors::UnstakeError,
     instruction::RepayFlashLoan,
-    state::{FlashAccount, Pool, FLASH_ACCOUNT_SEED_SUFFIX},
+    state::{
+        FlashAccount, PausableInstruction, Pool, PoolPause, ProtocolPause,
+        FLASH_ACCOUNT_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX, PROTOCOL_PAUSE_SEED,
+    },
     utils::{allocate_assign_pda, AllocateAssignPdaArgs},
 };
 
@@ -54,6 +57,28 @@ pub struct TakeFlashLoan<'info> {
     /// CHECK: Checked using address
     #[account(address = sysvar::instructions::ID @ UnstakeError::InvalidInstructionsSysvar)]
     pub instructions: UncheckedAccount<'info>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::TakeFlashLoan)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::TakeFlashLoan)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
 }
 
 impl<'info> TakeFlashLoan<'info> {
@@ -1,25 +1,83 @@
+mod accept_fee_authority;
+mod accept_protocol_fee_authority;
+mod add_allowed_lp;
 mod add_liquidity;
+mod add_liquidity_stake;
+mod add_liquidity_wsol;
+mod apply_pending_fee;
+mod apply_pending_lockup_fee;
+mod apply_pending_protocol_fee;
+mod apply_pending_validator_fee;
+mod cancel_fee_authority_proposal;
+mod claim_withdraw_ticket;
+mod close_pool;
 mod create_pool;
+mod create_pool_with_liquidity;
 mod deactivate_stake_account;
+mod deprecated;
 mod flash_loan;
 mod init_protocol_fee;
+mod propose_fee_authority;
+mod propose_protocol_fee;
+mod propose_protocol_fee_authority;
 mod reclaim_stake_account;
+mod remove_allowed_lp;
 mod remove_liquidity;
+mod remove_liquidity_in_kind;
+mod remove_liquidity_wsol;
+mod remove_validator_fee;
+mod request_withdrawal;
 mod set_fee;
-mod set_fee_authority;
+mod set_fee_delay;
+mod set_lockup_fee;
 mod set_lp_token_metadata;
+mod set_pause_guardian;
+mod set_pool_config;
+mod set_pool_pause;
 mod set_protocol_fee;
+mod set_protocol_pause;
+mod set_stake_deposit_fee;
+mod set_validator_fee;
 mod unstake_instructions;
 
+pub use accept_fee_authority::*;
+pub use accept_protocol_fee_authority::*;
+pub use add_allowed_lp::*;
 pub use add_liquidity::*;
+pub use add_liquidity_stake::*;
+pub use add_liquidity_wsol::*;
+pub use apply_pending_fee::*;
+pub use apply_pending_lockup_fee::*;
+pub use apply_pending_protocol_fee::*;
+pub use apply_pending_validator_fee::*;
+pub use cancel_fee_authority_proposal::*;
+pub use claim_withdraw_ticket::*;
+pub use close_pool::*;
 pub use create_pool::*;
+pub use create_pool_with_liquidity::*;
 pub use deactivate_stake_account::*;
+pub use deprecated::*;
 pub use flash_loan::*;
 pub use init_protocol_fee::*;
+pub use propose_fee_authority::*;
+pub use propose_protocol_fee::*;
+pub use propose_protocol_fee_authority::*;
 pub use reclaim_stake_account::*;
+pub use remove_allowed_lp::*;
 pub use remove_liquidity::*;
+pub use remove_liquidity_in_kind::*;
+pub use remove_liquidity_wsol::*;
+pub use remove_validator_fee::*;
+pub use request_withdrawal::*;
 pub use set_fee::*;
-pub use set_fee_authority::*;
+pub use set_fee_delay::*;
+pub use set_lockup_fee::*;
 pub use set_lp_token_metadata::*;
+pub use set_pause_guardian::*;
+pub use set_pool_config::*;
+pub use set_pool_pause::*;
 pub use set_protocol_fee::*;
+pub use set_protocol_pause::*;
+pub use set_stake_deposit_fee::*;
+pub use set_validator_fee::*;
 pub use unstake_instructions::*;
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{FeeAuthorityNominee, Pool, FEE_AUTHORITY_NOMINEE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct ProposeFeeAuthority<'info> {
+    /// pool's current fee_authority.
+    /// Pays for fee_authority_nominee_account's rent if it does not exist yet
+    #[account(mut)]
+    pub fee_authority: Signer<'info>,
+
+    /// pool account to nominate a new fee_authority for
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// CHECK: the new fee_authority only needs to be a valid pubkey,
+    /// it must sign AcceptFeeAuthority to take over
+    pub new_fee_authority: UncheckedAccount<'info>,
+
+    /// fee authority nominee account to record new_fee_authority in,
+    /// replacing any pubkey already nominated
+    #[account(
+        init_if_needed,
+        payer = fee_authority,
+        space = FeeAuthorityNominee::LEN,
+        seeds = [&pool_account.key().to_bytes(), FEE_AUTHORITY_NOMINEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_authority_nominee_account: Account<'info, FeeAuthorityNominee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeFeeAuthority<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let fee_authority_nominee_account = &mut ctx.accounts.fee_authority_nominee_account;
+        fee_authority_nominee_account.nominee = ctx.accounts.new_fee_authority.key();
+        fee_authority_nominee_account.proposer = ctx.accounts.fee_authority.key();
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! A protocol owner facing instruction for proposing new protocol fee ratios,
+//! which can only be applied after PROTOCOL_FEE_DELAY_SECONDS
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    rational::Rational,
+    state::{
+        PendingProtocolFee, ProtocolFee, PENDING_PROTOCOL_FEE_SEED, PROTOCOL_FEE_DELAY_SECONDS,
+        PROTOCOL_FEE_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ProposeProtocolFee<'info> {
+    /// protocol fee authority.
+    /// Pays for pending_protocol_fee_account's rent if it does not exist yet
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// protocol fee account to propose new ratios for
+    #[account(
+        has_one = authority @ UnstakeError::InvalidProtocolFeeAuthority,
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// pending protocol fee account to queue the new ratios in,
+    /// replacing any ratios already queued
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PendingProtocolFee::LEN,
+        seeds = [PENDING_PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub pending_protocol_fee_account: Account<'info, PendingProtocolFee>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeProtocolFee<'info> {
+    #[inline(always)]
+    pub fn run(
+        ctx: Context<Self>,
+        fee_ratio: Rational,
+        referrer_fee_ratio: Rational,
+    ) -> Result<()> {
+        let activation_timestamp = ctx
+            .accounts
+            .clock
+            .unix_timestamp
+            .checked_add(PROTOCOL_FEE_DELAY_SECONDS)
+            .ok_or(UnstakeError::InternalError)?;
+        let pending_protocol_fee = PendingProtocolFee {
+            fee_ratio,
+            referrer_fee_ratio,
+            activation_timestamp,
+        };
+        pending_protocol_fee.validate()?;
+
+        ctx.accounts
+            .pending_protocol_fee_account
+            .set_inner(pending_protocol_fee);
+        Ok(())
+    }
+}
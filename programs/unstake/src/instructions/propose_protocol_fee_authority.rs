@@ -0,0 +1,54 @@
+//! A protocol owner facing instruction for nominating a new protocol fee authority,
+//! which only takes over once it accepts
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        ProtocolFee, ProtocolFeeAuthorityNominee, PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED,
+        PROTOCOL_FEE_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ProposeProtocolFeeAuthority<'info> {
+    /// protocol fee authority.
+    /// Pays for nominee_account's rent if it does not exist yet
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// protocol fee account to nominate a new authority for
+    #[account(
+        has_one = authority @ UnstakeError::InvalidProtocolFeeAuthority,
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// CHECK: the new authority only needs to be a valid pubkey,
+    /// it must sign AcceptProtocolFeeAuthority to take over
+    pub new_authority: UncheckedAccount<'info>,
+
+    /// nominee account to record new_authority in,
+    /// replacing any pubkey already nominated
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ProtocolFeeAuthorityNominee::LEN,
+        seeds = [PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED],
+        bump,
+    )]
+    pub nominee_account: Account<'info, ProtocolFeeAuthorityNominee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeProtocolFeeAuthority<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.nominee_account.nominee = ctx.accounts.new_authority.key();
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::UnstakeError,
+    state::{AllowedLp, Pool, ALLOWED_LP_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct RemoveAllowedLp<'info> {
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the allowed lp account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// depositor to disallow from adding liquidity to the pool
+    /// CHECK: any pubkey
+    pub lp: UncheckedAccount<'info>,
+
+    /// allowed lp account to close.
+    /// The depositor can still remove the liquidity it has already added
+    #[account(
+        mut,
+        close = refund_rent_to,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &lp.key().to_bytes(),
+            ALLOWED_LP_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub allowed_lp_account: Account<'info, AllowedLp>,
+
+    /// account to refund allowed_lp_account's rent lamports to
+    /// CHECK: any account
+    #[account(mut)]
+    pub refund_rent_to: UncheckedAccount<'info>,
+}
+
+impl<'info> RemoveAllowedLp<'info> {
+    #[inline(always)]
+    pub fn run(_ctx: Context<Self>) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,15 +1,14 @@
 use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
-use std::convert::TryFrom;
 
 use crate::{
     errors::UnstakeError,
-    state::{Pool, FLASH_ACCOUNT_SEED_SUFFIX},
-    utils::calc_pool_owned_lamports,
+    state::{Pool, WithdrawQueue, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX},
+    utils::{calc_lamports_to_return, calc_pool_owned_lamports},
 };
 
 #[derive(Accounts)]
-pub struct RemoveLiquidity<'info> {
+pub struct RemoveLiquidityV2<'info> {
     /// signer thas has authority over burn_lp_tokens_from token account
     pub burn_lp_tokens_from_authority: Signer<'info>,
 
@@ -54,11 +53,22 @@ pub struct RemoveLiquidity<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
 }
 
-impl<'info> RemoveLiquidity<'info> {
+impl<'info> RemoveLiquidityV2<'info> {
+    /// Fails with LiquidityOutBelowMinimum if the lamports returned
+    /// is below `min_lamports_out`
     #[inline(always)]
-    pub fn run(ctx: Context<Self>, amount_lp: u64) -> Result<()> {
+    pub fn run(ctx: Context<Self>, amount_lp: u64, min_lamports_out: u64) -> Result<()> {
         let burn_lp_tokens_from_authority = &ctx.accounts.burn_lp_tokens_from_authority;
         let to = &ctx.accounts.to;
         let pool_account = &mut ctx.accounts.pool_account;
@@ -69,10 +79,30 @@ impl<'info> RemoveLiquidity<'info> {
         let token_program = &ctx.accounts.token_program;
         let system_program = &ctx.accounts.system_program;
 
+        let earmarked_lamports =
+            WithdrawQueue::earmarked_lamports(&ctx.accounts.withdraw_queue_account)?;
+
         // order matters, must calculate first before mutation
-        let pool_owned_lamports =
-            calc_pool_owned_lamports(pool_sol_reserves, pool_account, flash_account)?;
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            pool_sol_reserves,
+            pool_account,
+            flash_account,
+            earmarked_lamports,
+        )?;
         let to_return = calc_lamports_to_return(pool_owned_lamports, lp_mint.supply, amount_lp)?;
+        if to_return < min_lamports_out {
+            return Err(UnstakeError::LiquidityOutBelowMinimum.into());
+        }
+
+        // SOL earmarked for withdraw tickets cannot be removed,
+        // use RequestWithdrawal instead if the reserves are insufficient
+        if to_return
+            > pool_sol_reserves
+                .lamports()
+                .saturating_sub(earmarked_lamports)
+        {
+            return Err(UnstakeError::InsufficientReservesForWithdrawal.into());
+        }
 
         // transfer SOL
         let transfer_cpi_accs = system_program::Transfer {
@@ -107,20 +137,3 @@ impl<'info> RemoveLiquidity<'info> {
         )
     }
 }
-
-fn calc_lamports_to_return(
-    pool_owned_lamports: u64,
-    lp_mint_supply: u64,
-    amount_lp_to_burn: u64,
-) -> std::result::Result<u64, UnstakeError> {
-    // 0 edge-cases: return 0
-    if pool_owned_lamports == 0 || lp_mint_supply == 0 {
-        return Ok(0);
-    }
-    // return = amount_lp_to_burn * owned_lamports BEFORE BURN / lp_mint.supply BEFORE BURN
-    u128::from(amount_lp_to_burn)
-        .checked_mul(u128::from(pool_owned_lamports))
-        .and_then(|v| v.checked_div(u128::from(lp_mint_supply)))
-        .and_then(|v| u64::try_from(v).ok())
-        .ok_or(UnstakeError::InternalError)
-}
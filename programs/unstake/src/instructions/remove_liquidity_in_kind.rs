@@ -0,0 +1,220 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::stake::state::{StakeAuthorize, StakeState},
+};
+use anchor_spl::{
+    stake::{self, Authorize, Stake, StakeAccount},
+    token::{self, Burn, Mint, Token, TokenAccount},
+};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        Pool, StakeAccountRecord, WithdrawQueue, FLASH_ACCOUNT_SEED_SUFFIX,
+        WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::{
+        allocate_assign_pda, calc_lp_tokens_to_burn, calc_pool_owned_lamports, signing_custodian,
+        split_stake_account, AllocateAssignPdaArgs, SplitStakeAccountArgs,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RemoveLiquidityInKind<'info> {
+    /// signer thas has authority over burn_lp_tokens_from token account
+    pub burn_lp_tokens_from_authority: Signer<'info>,
+
+    /// new staker and withdraw authority of the redeemed stake account
+    /// CHECK: any pubkey can be a stake account's authority
+    pub new_stake_authority: UncheckedAccount<'info>,
+
+    /// pool-owned stake account to redeem.
+    /// Should have authority set to pool_sol_reserves, rely on stake program CPI call's check to verify
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// new stake account to split the redeemed lamports into.
+    /// Required if not redeeming all of stake_account's recorded lamports
+    #[account(mut)]
+    pub split_stake_account: Option<Signer<'info>>,
+
+    /// pool that owns stake_account
+    #[account(
+        mut,
+        has_one = lp_mint
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves.
+    /// Receives stake_account_record_account's rent if it is closed
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// stake_account's stake account record
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), &stake_account.key().to_bytes()],
+        bump,
+    )]
+    pub stake_account_record_account: Account<'info, StakeAccountRecord>,
+
+    /// pool's LP mint
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// lp token account to redeem/burn lp tokens from
+    #[account(
+        mut,
+        constraint = burn_lp_tokens_from.mint == lp_mint.key() @ UnstakeError::InvalidLpTokenAccount
+    )]
+    pub burn_lp_tokens_from: Account<'info, TokenAccount>,
+
+    /// amount taken for all active flash loans of the pool
+    /// CHECK: PDA checked
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_program: Program<'info, Stake>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+
+    /// Optional. The stake account's lockup custodian.
+    /// Only used if it signed the transaction,
+    /// required to redeem a stake account whose lockup is in force
+    /// CHECK: stake program CPI checks that this is the lockup custodian
+    pub custodian: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> RemoveLiquidityInKind<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, lamports: u64) -> Result<()> {
+        let accounts = ctx.accounts;
+
+        let recorded_lamports = accounts.stake_account_record_account.lamports_at_creation;
+        if lamports > recorded_lamports {
+            return Err(UnstakeError::InKindRedemptionExceedsStakeAccount.into());
+        }
+        let is_full_redemption = lamports == recorded_lamports;
+
+        // the entire stake account, including any rewards accrued since it was recorded,
+        // is handed over on full redemption so LP tokens must be burnt for all of its lamports
+        let redeemed_lamports = match is_full_redemption {
+            true => accounts.stake_account.to_account_info().lamports(),
+            false => lamports,
+        };
+
+        // order matters, must calculate first before mutation
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            &accounts.pool_sol_reserves,
+            &accounts.pool_account,
+            &accounts.flash_account,
+            WithdrawQueue::earmarked_lamports(&accounts.withdraw_queue_account)?,
+        )?;
+        let amount_lp = calc_lp_tokens_to_burn(
+            pool_owned_lamports,
+            accounts.lp_mint.supply,
+            redeemed_lamports,
+        )?;
+
+        let pool_sol_reserves_seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+
+        // redeem the entire stake account or split the redeemed lamports off it
+        let redeemed_stake_account = match is_full_redemption {
+            true => {
+                accounts
+                    .stake_account_record_account
+                    .close(accounts.pool_sol_reserves.to_account_info())?;
+                accounts.stake_account.to_account_info()
+            }
+            false => {
+                let split = accounts
+                    .split_stake_account
+                    .as_ref()
+                    .ok_or(UnstakeError::SplitStakeAccountRequired)?;
+                allocate_assign_pda(AllocateAssignPdaArgs {
+                    pda_account: split.as_ref(),
+                    system_program: accounts.system_program.as_ref(),
+                    pda_account_len: StakeState::size_of() as u64,
+                    pda_account_owner_program: &accounts.stake_program.key(),
+                    pda_account_signer_seeds: &[],
+                })?;
+                split_stake_account(SplitStakeAccountArgs {
+                    stake_program: accounts.stake_program.as_ref(),
+                    stake_account: accounts.stake_account.as_ref(),
+                    split_stake_account: split.as_ref(),
+                    staker: accounts.pool_sol_reserves.as_ref(),
+                    lamports,
+                    staker_signer_seeds: &[pool_sol_reserves_seeds],
+                })?;
+                accounts.stake_account_record_account.lamports_at_creation = recorded_lamports
+                    .checked_sub(lamports)
+                    .ok_or(UnstakeError::InternalError)?;
+                split.to_account_info()
+            }
+        };
+
+        // hand the stake account over to the LP
+        let custodian = accounts
+            .custodian
+            .as_ref()
+            .filter(|c| signing_custodian(Some(c)).is_some())
+            .map(|c| c.to_account_info());
+        for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+            stake::authorize(
+                CpiContext::new_with_signer(
+                    accounts.stake_program.to_account_info(),
+                    Authorize {
+                        stake: redeemed_stake_account.clone(),
+                        authorized: accounts.pool_sol_reserves.to_account_info(),
+                        new_authorized: accounts.new_stake_authority.to_account_info(),
+                        clock: accounts.clock.to_account_info(),
+                    },
+                    &[pool_sol_reserves_seeds],
+                ),
+                stake_authorize,
+                custodian.clone(),
+            )?;
+        }
+
+        // Update incoming_stake, which only tracks the recorded lamports
+        accounts.pool_account.incoming_stake = accounts
+            .pool_account
+            .incoming_stake
+            .checked_sub(lamports)
+            .ok_or(UnstakeError::InternalError)?;
+
+        // burn LP tokens
+        let burn_cpi_accs = Burn {
+            mint: accounts.lp_mint.to_account_info(),
+            from: accounts.burn_lp_tokens_from.to_account_info(),
+            authority: accounts.burn_lp_tokens_from_authority.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(accounts.token_program.to_account_info(), burn_cpi_accs),
+            amount_lp,
+        )
+    }
+}
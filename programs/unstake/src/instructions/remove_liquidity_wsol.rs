@@ -0,0 +1,139 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, spl_token, Burn, Mint, SyncNative, Token, TokenAccount};
+
+use crate::{
+    errors::UnstakeError,
+    state::{Pool, WithdrawQueue, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX},
+    utils::{calc_lamports_to_return, calc_pool_owned_lamports},
+};
+
+#[derive(Accounts)]
+pub struct RemoveLiquidityWsol<'info> {
+    /// signer thas has authority over burn_lp_tokens_from token account
+    pub burn_lp_tokens_from_authority: Signer<'info>,
+
+    /// wSOL token account to remove SOL liquidity to
+    #[account(
+        mut,
+        constraint = to.mint == spl_token::native_mint::id() @ UnstakeError::DestinationNotWSol
+    )]
+    pub to: Account<'info, TokenAccount>,
+
+    /// pool that SOL liquidity is being removed from
+    #[account(
+        mut,
+        has_one = lp_mint
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves. SOL liquidity deducted from here
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's LP mint
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// lp token account to redeem/burn lp tokens from
+    #[account(
+        mut,
+        constraint = burn_lp_tokens_from.mint == lp_mint.key() @ UnstakeError::InvalidLpTokenAccount
+    )]
+    pub burn_lp_tokens_from: Account<'info, TokenAccount>,
+
+    /// amount taken for all active flash loans of the pool
+    /// CHECK: PDA checked
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveLiquidityWsol<'info> {
+    /// Fails with LiquidityOutBelowMinimum if the lamports returned
+    /// is below `min_lamports_out`
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, amount_lp: u64, min_lamports_out: u64) -> Result<()> {
+        let accounts = ctx.accounts;
+
+        let earmarked_lamports =
+            WithdrawQueue::earmarked_lamports(&accounts.withdraw_queue_account)?;
+
+        // order matters, must calculate first before mutation
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            &accounts.pool_sol_reserves,
+            &accounts.pool_account,
+            &accounts.flash_account,
+            earmarked_lamports,
+        )?;
+        let to_return =
+            calc_lamports_to_return(pool_owned_lamports, accounts.lp_mint.supply, amount_lp)?;
+        if to_return < min_lamports_out {
+            return Err(UnstakeError::LiquidityOutBelowMinimum.into());
+        }
+
+        // see RemoveLiquidity
+        if to_return
+            > accounts
+                .pool_sol_reserves
+                .lamports()
+                .saturating_sub(earmarked_lamports)
+        {
+            return Err(UnstakeError::InsufficientReservesForWithdrawal.into());
+        }
+
+        // transfer SOL and wrap it
+        let seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &[*ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: accounts.pool_sol_reserves.to_account_info(),
+                    to: accounts.to.to_account_info(),
+                },
+                &[seeds],
+            ),
+            to_return,
+        )?;
+        token::sync_native(CpiContext::new(
+            accounts.token_program.to_account_info(),
+            SyncNative {
+                account: accounts.to.to_account_info(),
+            },
+        ))?;
+
+        // burn LP tokens
+        let burn_cpi_accs = Burn {
+            mint: accounts.lp_mint.to_account_info(),
+            from: accounts.burn_lp_tokens_from.to_account_info(),
+            authority: accounts.burn_lp_tokens_from_authority.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(accounts.token_program.to_account_info(), burn_cpi_accs),
+            amount_lp,
+        )
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::{prelude::*, AccountsClose};
+
+use crate::anchor_len::AnchorLen;
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        FeeDelay, PendingValidatorFee, Pool, ValidatorFee, ValidatorFeeEnum, FEE_DELAY_SEED_SUFFIX,
+        PENDING_VALIDATOR_FEE_SEED_SUFFIX, VALIDATOR_FEE_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RemoveValidatorFee<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the validator fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// vote account of the validator to remove the validator fee of
+    /// CHECK: any pubkey
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator fee account to close.
+    /// The pool's fee is charged for the validator's stake again after this.
+    /// Closed in run() unless the removal is queued
+    #[account(
+        mut,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: Account<'info, ValidatorFee>,
+
+    /// account to refund validator_fee_account's rent lamports to.
+    /// Unused if the removal is queued, ApplyPendingValidatorFee refunds it to the fee authority
+    /// CHECK: any account
+    #[account(mut)]
+    pub refund_rent_to: UncheckedAccount<'info>,
+
+    /// pool's fee delay account.
+    /// Must always be provided so that the fee delay cannot be bypassed,
+    /// but may not be initialized if the pool has no fee delay.
+    /// CHECK: PDA checked, deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// pending validator fee account to queue the removal in.
+    /// Required to remove an Override if the pool has a fee delay.
+    /// Set to the program ID to remove the validator fee immediately otherwise
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingValidatorFee::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub pending_validator_fee_account: Option<Account<'info, PendingValidatorFee>>,
+}
+
+impl<'info> RemoveValidatorFee<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        let accounts = ctx.accounts;
+        let min_delay_seconds = FeeDelay::min_delay_seconds(&accounts.fee_delay_account)?;
+
+        if let Some(pending_validator_fee_account) = accounts.pending_validator_fee_account.as_mut()
+        {
+            pending_validator_fee_account.set_inner(PendingValidatorFee {
+                validator_fee: None,
+                activation_timestamp: FeeDelay::activation_timestamp(
+                    min_delay_seconds,
+                    &accounts.clock,
+                )?,
+            });
+            return Ok(());
+        }
+
+        // the pool's fee can be higher than an Override's,
+        // so only Deny can be removed immediately for pools with a fee delay
+        if min_delay_seconds > 0
+            && !matches!(accounts.validator_fee_account.fee, ValidatorFeeEnum::Deny)
+        {
+            return Err(UnstakeError::FeeChangeRequiresDelay.into());
+        }
+        accounts
+            .validator_fee_account
+            .close(accounts.refund_rent_to.to_account_info())
+    }
+}
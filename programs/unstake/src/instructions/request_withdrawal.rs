@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        Pool, WithdrawQueue, WithdrawTicket, FLASH_ACCOUNT_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+        WITHDRAW_TICKET_SEED_SUFFIX,
+    },
+    utils::{calc_lamports_to_return, calc_pool_owned_lamports},
+};
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// signer thas has authority over burn_lp_tokens_from token account.
+    /// Owner of the withdraw ticket
+    pub burn_lp_tokens_from_authority: Signer<'info>,
+
+    /// pool that SOL liquidity is being withdrawn from
+    #[account(
+        has_one = lp_mint
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves
+    #[account(
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's LP mint
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// lp token account to redeem/burn lp tokens from
+    #[account(
+        mut,
+        constraint = burn_lp_tokens_from.mint == lp_mint.key() @ UnstakeError::InvalidLpTokenAccount
+    )]
+    pub burn_lp_tokens_from: Account<'info, TokenAccount>,
+
+    /// amount taken for all active flash loans of the pool
+    /// CHECK: PDA checked
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FLASH_ACCOUNT_SEED_SUFFIX],
+        bump,
+    )]
+    pub flash_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawQueue::LEN,
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: Account<'info, WithdrawQueue>,
+
+    /// burn_lp_tokens_from_authority's withdraw ticket for the pool.
+    /// The redemption value is added to the ticket if it already exists
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawTicket::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &burn_lp_tokens_from_authority.key().to_bytes(),
+            WITHDRAW_TICKET_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub withdraw_ticket_account: Account<'info, WithdrawTicket>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestWithdrawal<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, amount_lp: u64) -> Result<()> {
+        let burn_lp_tokens_from_authority = &ctx.accounts.burn_lp_tokens_from_authority;
+        let pool_account = &ctx.accounts.pool_account;
+        let pool_sol_reserves = &ctx.accounts.pool_sol_reserves;
+        let lp_mint = &ctx.accounts.lp_mint;
+        let burn_lp_tokens_from = &ctx.accounts.burn_lp_tokens_from;
+        let flash_account = &ctx.accounts.flash_account;
+        let withdraw_queue_account = &mut ctx.accounts.withdraw_queue_account;
+        let withdraw_ticket_account = &mut ctx.accounts.withdraw_ticket_account;
+        let token_program = &ctx.accounts.token_program;
+
+        // order matters, must calculate first before mutation
+        let pool_owned_lamports = calc_pool_owned_lamports(
+            pool_sol_reserves,
+            pool_account,
+            flash_account,
+            withdraw_queue_account.earmarked_lamports,
+        )?;
+        let to_return = calc_lamports_to_return(pool_owned_lamports, lp_mint.supply, amount_lp)?;
+
+        // lock in redemption value
+        withdraw_ticket_account.lamports = withdraw_ticket_account
+            .lamports
+            .checked_add(to_return)
+            .ok_or(UnstakeError::InternalError)?;
+        withdraw_queue_account.earmarked_lamports = withdraw_queue_account
+            .earmarked_lamports
+            .checked_add(to_return)
+            .ok_or(UnstakeError::InternalError)?;
+
+        // burn LP tokens
+        let burn_cpi_accs = Burn {
+            mint: lp_mint.to_account_info(),
+            from: burn_lp_tokens_from.to_account_info(),
+            authority: burn_lp_tokens_from_authority.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(token_program.to_account_info(), burn_cpi_accs),
+            amount_lp,
+        )
+    }
+}
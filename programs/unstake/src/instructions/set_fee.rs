@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    anchor_len::AnchorLen,
     errors::UnstakeError,
-    state::{Fee, Pool, FEE_SEED_SUFFIX},
+    state::{
+        Fee, FeeDelay, PendingFee, Pool, FEE_DELAY_SEED_SUFFIX, FEE_SEED_SUFFIX,
+        PENDING_FEE_SEED_SUFFIX,
+    },
 };
 
 #[derive(Accounts)]
 pub struct SetFee<'info> {
-    /// pool's fee_authority
+    /// pool's fee_authority.
+    /// Pays for fee_account reallocation if the new fee requires more space
+    /// and for pending_fee_account's rent if it does not exist yet
+    #[account(mut)]
     pub fee_authority: Signer<'info>,
 
     /// pool account for the fee account
@@ -21,11 +28,38 @@ pub struct SetFee<'info> {
         mut,
         seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
         bump,
+        realloc = Fee::LEN,
+        realloc::payer = fee_authority,
+        realloc::zero = false,
     )]
     pub fee_account: Account<'info, Fee>,
 
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// pool's fee delay account.
+    /// Must always be provided so that the fee delay cannot be bypassed,
+    /// but may not be initialized if the pool has no fee delay.
+    /// CHECK: PDA checked, deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// pool's pending fee account to queue the new fee in.
+    /// Required if the pool has a fee delay.
+    /// If provided, the new fee is queued instead of being set immediately
+    #[account(
+        init_if_needed,
+        payer = fee_authority,
+        space = PendingFee::LEN,
+        seeds = [&pool_account.key().to_bytes(), PENDING_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_fee_account: Option<Account<'info, PendingFee>>,
 }
 
 impl<'info> SetFee<'info> {
@@ -36,9 +70,25 @@ impl<'info> SetFee<'info> {
 
     #[inline(always)]
     pub fn run(ctx: Context<Self>, fee: Fee) -> Result<()> {
-        let fee_account = &mut ctx.accounts.fee_account;
+        let min_delay_seconds = FeeDelay::min_delay_seconds(&ctx.accounts.fee_delay_account)?;
 
-        fee_account.set_inner(fee);
+        match ctx.accounts.pending_fee_account.as_mut() {
+            Some(pending_fee_account) => {
+                pending_fee_account.set_inner(PendingFee {
+                    fee,
+                    activation_timestamp: FeeDelay::activation_timestamp(
+                        min_delay_seconds,
+                        &ctx.accounts.clock,
+                    )?,
+                });
+            }
+            None => {
+                if min_delay_seconds > 0 {
+                    return Err(UnstakeError::PendingFeeAccountRequired.into());
+                }
+                ctx.accounts.fee_account.set_inner(fee);
+            }
+        }
         Ok(())
     }
 }
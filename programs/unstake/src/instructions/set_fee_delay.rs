@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{FeeDelay, Pool, FEE_DELAY_SEED_SUFFIX, MAX_FEE_DELAY_SECONDS},
+};
+
+#[derive(Accounts)]
+pub struct SetFeeDelay<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the fee delay account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// fee delay account to initialize
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FeeDelay::LEN,
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: Account<'info, FeeDelay>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFeeDelay<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, min_delay_seconds: u64) -> Result<()> {
+        let fee_delay_account = &mut ctx.accounts.fee_delay_account;
+        if min_delay_seconds < fee_delay_account.min_delay_seconds {
+            return Err(UnstakeError::FeeDelayDecreaseNotAllowed.into());
+        }
+        if min_delay_seconds > MAX_FEE_DELAY_SECONDS {
+            return Err(UnstakeError::FeeDelayTooLong.into());
+        }
+
+        fee_delay_account.min_delay_seconds = min_delay_seconds;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        FeeDelay, LockupFee, PendingLockupFee, Pool, FEE_DELAY_SEED_SUFFIX, LOCKUP_FEE_SEED_SUFFIX,
+        PENDING_LOCKUP_FEE_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetLockupFee<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the lockup fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// lockup fee account to initialize
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LockupFee::LEN,
+        seeds = [&pool_account.key().to_bytes(), LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub lockup_fee_account: Account<'info, LockupFee>,
+
+    pub system_program: Program<'info, System>,
+
+    /// pool's fee delay account.
+    /// Must always be provided so that the fee delay cannot be bypassed,
+    /// but may not be initialized if the pool has no fee delay.
+    /// CHECK: PDA checked, deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// pending lockup fee account to queue the new lockup fee in.
+    /// Required for changes that could raise fees if the pool has a fee delay.
+    /// Set to the program ID to set the lockup fee immediately otherwise
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingLockupFee::LEN,
+        seeds = [&pool_account.key().to_bytes(), PENDING_LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pending_lockup_fee_account: Option<Account<'info, PendingLockupFee>>,
+}
+
+impl<'info> SetLockupFee<'info> {
+    #[inline(always)]
+    pub fn validate(lockup_fee: &LockupFee) -> Result<()> {
+        lockup_fee.validate()
+    }
+
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, lockup_fee: LockupFee) -> Result<()> {
+        let accounts = ctx.accounts;
+        let min_delay_seconds = FeeDelay::min_delay_seconds(&accounts.fee_delay_account)?;
+
+        if let Some(pending_lockup_fee_account) = accounts.pending_lockup_fee_account.as_mut() {
+            pending_lockup_fee_account.set_inner(PendingLockupFee {
+                lockup_fee,
+                activation_timestamp: FeeDelay::activation_timestamp(
+                    min_delay_seconds,
+                    &accounts.clock,
+                )?,
+            });
+            return Ok(());
+        }
+
+        let lockup_fee_account = &mut accounts.lockup_fee_account;
+
+        // a newly initialized lockup fee account has an invalid zeroed fee_ratio.
+        // Locked up stake accounts cannot be unstaked before it is initialized,
+        // so only initializing or lowering it can take effect immediately
+        // for pools with a fee delay
+        let is_initialized = lockup_fee_account.fee_ratio.validate();
+        if min_delay_seconds > 0
+            && is_initialized
+            && !lockup_fee.fee_ratio.is_lte(&lockup_fee_account.fee_ratio)
+        {
+            return Err(UnstakeError::FeeChangeRequiresDelay.into());
+        }
+
+        lockup_fee_account.set_inner(lockup_fee);
+        Ok(())
+    }
+}
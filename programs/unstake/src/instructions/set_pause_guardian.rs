@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{Pool, PoolPause, POOL_PAUSE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct SetPauseGuardian<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the pool pause account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// new guardian to replace the current guardian with
+    /// CHECK: any pubkey
+    pub new_guardian: UncheckedAccount<'info>,
+
+    /// pool pause account to initialize or modify
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolPause::LEN,
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_pause_account: Account<'info, PoolPause>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPauseGuardian<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.pool_pause_account.guardian = ctx.accounts.new_guardian.key();
+        Ok(())
+    }
+}
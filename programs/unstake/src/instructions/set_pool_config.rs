@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{Pool, PoolConfig, POOL_CONFIG_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct SetPoolConfig<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the pool config account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool config account to initialize or modify
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolConfig::LEN,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: Account<'info, PoolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPoolConfig<'info> {
+    /// Only updates the limits, the current epoch's incoming stake count is kept
+    #[inline(always)]
+    pub fn run(
+        ctx: Context<Self>,
+        max_unstake_lamports: Option<u64>,
+        max_epoch_incoming_stake_lamports: Option<u64>,
+        max_pool_owned_lamports: Option<u64>,
+        lp_allowlist_enabled: bool,
+    ) -> Result<()> {
+        let pool_config_account = &mut ctx.accounts.pool_config_account;
+
+        pool_config_account.max_unstake_lamports = max_unstake_lamports;
+        pool_config_account.max_epoch_incoming_stake_lamports = max_epoch_incoming_stake_lamports;
+        pool_config_account.max_pool_owned_lamports = max_pool_owned_lamports;
+        pool_config_account.lp_allowlist_enabled = lp_allowlist_enabled;
+        Ok(())
+    }
+}
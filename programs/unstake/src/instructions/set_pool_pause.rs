@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{PausedInstructions, Pool, PoolPause, POOL_PAUSE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct SetPoolPause<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority or the pool pause account's guardian
+    pub authority: Signer<'info>,
+
+    /// pool account for the pool pause account
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool pause account to initialize or modify
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PoolPause::LEN,
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = authority.key() == pool_account.fee_authority
+            || authority.key() == pool_pause_account.guardian
+            @ UnstakeError::InvalidPauseAuthority,
+    )]
+    pub pool_pause_account: Account<'info, PoolPause>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPoolPause<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, paused: PausedInstructions) -> Result<()> {
+        ctx.accounts.pool_pause_account.paused = paused;
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
-//! A protocol owner facing instruction for updating the global protocol fee account
+//! A protocol owner facing instruction for updating the global protocol fee account's destination.
+//! The authority and fee ratios must be changed via
+//! ProposeProtocolFeeAuthority and ProposeProtocolFee instead
 
 use anchor_lang::prelude::*;
 
@@ -28,8 +30,21 @@ impl<'info> SetProtocolFee<'info> {
         protocol_fee.validate()
     }
 
+    #[inline(always)]
+    pub fn validate_unchanged(&self, protocol_fee: &ProtocolFee) -> Result<()> {
+        let current = &self.protocol_fee_account;
+        if protocol_fee.authority != current.authority
+            || protocol_fee.fee_ratio != current.fee_ratio
+            || protocol_fee.referrer_fee_ratio != current.referrer_fee_ratio
+        {
+            return Err(UnstakeError::ProtocolFeeChangeNotAllowed.into());
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     pub fn run(ctx: Context<Self>, protocol_fee: ProtocolFee) -> Result<()> {
+        ctx.accounts.validate_unchanged(&protocol_fee)?;
         let protocol_fee_account = &mut ctx.accounts.protocol_fee_account;
 
         protocol_fee_account.set_inner(protocol_fee);
@@ -0,0 +1,46 @@
+//! A protocol owner facing instruction for pausing instruction classes across all pools
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        PausedInstructions, ProtocolFee, ProtocolPause, PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetProtocolPause<'info> {
+    /// protocol fee authority.
+    /// Pays for protocol_pause_account's rent if it does not exist yet
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority @ UnstakeError::InvalidProtocolFeeAuthority,
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// protocol pause account to initialize or modify
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ProtocolPause::LEN,
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+    )]
+    pub protocol_pause_account: Account<'info, ProtocolPause>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetProtocolPause<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, paused: PausedInstructions) -> Result<()> {
+        ctx.accounts.protocol_pause_account.paused = paused;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{Pool, StakeDepositFee, STAKE_DEPOSIT_FEE_SEED_SUFFIX},
+};
+
+#[derive(Accounts)]
+pub struct SetStakeDepositFee<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the stake deposit fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// stake deposit fee account to initialize
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = StakeDepositFee::LEN,
+        seeds = [&pool_account.key().to_bytes(), STAKE_DEPOSIT_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub stake_deposit_fee_account: Account<'info, StakeDepositFee>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetStakeDepositFee<'info> {
+    #[inline(always)]
+    pub fn validate(stake_deposit_fee: &StakeDepositFee) -> Result<()> {
+        stake_deposit_fee.validate()
+    }
+
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, stake_deposit_fee: StakeDepositFee) -> Result<()> {
+        let stake_deposit_fee_account = &mut ctx.accounts.stake_deposit_fee_account;
+
+        stake_deposit_fee_account.set_inner(stake_deposit_fee);
+        Ok(())
+    }
+}
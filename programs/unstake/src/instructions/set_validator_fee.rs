@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    anchor_len::AnchorLen,
+    errors::UnstakeError,
+    state::{
+        FeeDelay, PendingValidatorFee, Pool, ValidatorFee, ValidatorFeeEnum, FEE_DELAY_SEED_SUFFIX,
+        PENDING_VALIDATOR_FEE_SEED_SUFFIX, VALIDATOR_FEE_SEED_SUFFIX,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetValidatorFee<'info> {
+    /// pubkey paying for new accounts' rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// pool's fee_authority
+    pub fee_authority: Signer<'info>,
+
+    /// pool account for the validator fee account
+    #[account(
+        has_one = fee_authority @ UnstakeError::InvalidFeeAuthority
+    )]
+    pub pool_account: Account<'info, Pool>,
+
+    /// vote account of the validator to set the validator fee of
+    /// CHECK: any pubkey, Pubkey::default() for stake accounts that have never been delegated
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator fee account to initialize or modify.
+    /// Set to the program ID to queue the new validator fee in pending_validator_fee_account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ValidatorFee::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: Option<Account<'info, ValidatorFee>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// pool's fee delay account.
+    /// Must always be provided so that the fee delay cannot be bypassed,
+    /// but may not be initialized if the pool has no fee delay.
+    /// CHECK: PDA checked, deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_DELAY_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_delay_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// pending validator fee account to queue the new validator fee in.
+    /// Required for changes that could raise fees if the pool has a fee delay.
+    /// Set to the program ID to set the validator fee immediately otherwise
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingValidatorFee::LEN,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &vote_account.key().to_bytes(),
+            PENDING_VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub pending_validator_fee_account: Option<Account<'info, PendingValidatorFee>>,
+}
+
+impl<'info> SetValidatorFee<'info> {
+    #[inline(always)]
+    pub fn validate(validator_fee: &ValidatorFee) -> Result<()> {
+        validator_fee.validate()
+    }
+
+    #[inline(always)]
+    pub fn run(ctx: Context<Self>, validator_fee: ValidatorFee) -> Result<()> {
+        let accounts = ctx.accounts;
+        let min_delay_seconds = FeeDelay::min_delay_seconds(&accounts.fee_delay_account)?;
+
+        match (
+            accounts.validator_fee_account.as_mut(),
+            accounts.pending_validator_fee_account.as_mut(),
+        ) {
+            (Some(validator_fee_account), None) => {
+                // an Override can charge more than the fee it replaces,
+                // so only Deny can take effect immediately for pools with a fee delay
+                if min_delay_seconds > 0 && !matches!(validator_fee.fee, ValidatorFeeEnum::Deny) {
+                    return Err(UnstakeError::FeeChangeRequiresDelay.into());
+                }
+                validator_fee_account.set_inner(validator_fee);
+            }
+            (None, Some(pending_validator_fee_account)) => {
+                pending_validator_fee_account.set_inner(PendingValidatorFee {
+                    validator_fee: Some(validator_fee.fee),
+                    activation_timestamp: FeeDelay::activation_timestamp(
+                        min_delay_seconds,
+                        &accounts.clock,
+                    )?,
+                });
+            }
+            _ => return Err(UnstakeError::InvalidValidatorFeeDestination.into()),
+        }
+        Ok(())
+    }
+}
@@ -2,7 +2,12 @@
 mod unstake_accounts;
 
 mod unstake;
+mod unstake_multi;
+mod unstake_partial;
 mod unstake_wsol;
 
 pub use self::unstake::*;
+pub use unstake_accounts::{pay_out_protocol_fee, PayOutProtocolFeeArgs};
+pub use unstake_multi::*;
+pub use unstake_partial::*;
 pub use unstake_wsol::*;
@@ -1,22 +1,33 @@
 use std::convert::TryInto;
 
-use anchor_lang::{prelude::*, solana_program::stake::state::StakeAuthorize, system_program};
-use anchor_spl::stake::{self, Authorize, Stake, StakeAccount};
+use anchor_lang::{
+    prelude::*,
+    solana_program::stake::state::{Delegation, StakeAuthorize},
+    system_program,
+};
+use anchor_spl::stake::{self, Authorize, Stake, StakeAccount, Withdraw};
 
 use crate::{
     anchor_len::AnchorLen,
     errors::UnstakeError,
-    state::{Fee, Pool, ProtocolFee, StakeAccountRecord},
+    state::{
+        ApplyFeeArgs, Fee, LockupFee, Pool, PoolConfig, ProtocolFee, StakeAccountRecord,
+        StakeAccountState, WithdrawQueue,
+    },
     utils::{
-        allocate_assign_pda, make_rent_exempt_with_pda_payer, AllocateAssignPdaArgs,
+        allocate_assign_pda, epoch_progress, make_rent_exempt_with_pda_payer, resolve_fee,
+        signing_custodian, stake_account_state, AllocateAssignPdaArgs,
         MakeRentExemptWithPdaPayerArgs,
     },
 };
 
 pub struct UnstakeResult {
+    /// the fee charged, either the pool's fee or the validator's override
+    pub fee: Fee,
     pub stake_account_lamports: u64,
     pub lamports_to_unstaker: u64,
     pub fee_lamports: u64,
+    pub stake_account_state: StakeAccountState,
 }
 
 pub trait UnstakeAccounts<'info>
@@ -37,12 +48,14 @@ where
 
     fn fee_account(&self) -> &Account<'info, Fee>;
 
-    fn stake_account_record_account_immut(&self) -> &UncheckedAccount<'info>;
+    fn validator_fee_account(&self) -> &UncheckedAccount<'info>;
 
-    fn stake_account_record_account(&mut self) -> &mut UncheckedAccount<'info>;
+    fn stake_account_record_account(&self) -> &UncheckedAccount<'info>;
 
     fn clock(&self) -> &Sysvar<'info, Clock>;
 
+    fn stake_history(&self) -> &Sysvar<'info, StakeHistory>;
+
     fn stake_program(&self) -> &Program<'info, Stake>;
 
     fn system_program(&self) -> &Program<'info, System>;
@@ -51,217 +64,649 @@ where
 
     fn protocol_fee_destination(&self) -> &AccountInfo<'info>;
 
+    fn custodian(&self) -> Option<&UncheckedAccount<'info>>;
+
+    fn lockup_fee_account(&self) -> Option<&Account<'info, LockupFee>>;
+
+    fn pool_config_account(&self) -> &UncheckedAccount<'info>;
+
+    fn withdraw_queue_account(&self) -> &UncheckedAccount<'info>;
+
     /// referrer account to pay referrer fees to
     fn referrer(ctx: &mut Context<'_, '_, '_, 'info, Self>) -> Option<AccountInfo<'info>> {
         ctx.remaining_accounts.first().map(|a| a.to_account_info())
     }
 
-    fn run_unstake(ctx: &mut Context<'_, '_, '_, 'info, Self>) -> Result<UnstakeResult> {
-        // initialize stake_account_record_account
-        let stake_account_record_account_seeds: &[&[u8]] = &[
-            &ctx.accounts.pool_account().key().to_bytes(),
-            &ctx.accounts.stake_account().key().to_bytes(),
-            &[*ctx
-                .bumps
-                .get("stake_account_record_account")
-                .ok_or(UnstakeError::PdaBumpNotCached)?],
-        ];
-        allocate_assign_pda(AllocateAssignPdaArgs {
-            pda_account: ctx.accounts.stake_account_record_account_immut().as_ref(),
-            system_program: ctx.accounts.system_program(),
-            pda_account_len: StakeAccountRecord::LEN.try_into().unwrap(),
-            pda_account_owner_program: &crate::ID,
-            pda_account_signer_seeds: &[stake_account_record_account_seeds],
-        })?;
-        let pool_sol_reserves_seeds: &[&[u8]] = &[
-            &ctx.accounts.pool_account().key().to_bytes(),
-            &[*ctx
-                .bumps
-                .get("pool_sol_reserves")
-                .ok_or(UnstakeError::PdaBumpNotCached)?],
-        ];
-        make_rent_exempt_with_pda_payer(MakeRentExemptWithPdaPayerArgs {
-            account: ctx.accounts.stake_account_record_account_immut().as_ref(),
-            system_program: ctx.accounts.system_program(),
-            pda_payer: ctx.accounts.pool_sol_reserves(),
-            pda_payer_signer_seeds: &[pool_sol_reserves_seeds],
-        })?;
-
-        let stake_account_lamports = ctx.accounts.stake_account().to_account_info().lamports();
-        let pool_sol_reserves_lamports = ctx.accounts.pool_sol_reserves().lamports();
-
-        // authorize pool_sol_reserves as staker and withdrawer of the stake_account
-        stake::authorize(
-            CpiContext::new(
-                ctx.accounts.stake_program().to_account_info(),
-                Authorize {
-                    stake: ctx.accounts.stake_account().to_account_info(),
-                    authorized: ctx.accounts.unstaker().to_account_info(),
-                    new_authorized: ctx.accounts.pool_sol_reserves().to_account_info(),
-                    clock: ctx.accounts.clock().to_account_info(),
-                },
+    /// Fails with LamportsOutBelowMinimum if the lamports paid out
+    /// to the unstaker is below `min_lamports_out`
+    fn run_unstake(
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
+        min_lamports_out: u64,
+    ) -> Result<UnstakeResult> {
+        let stake_account_state = stake_account_state(
+            ctx.accounts.stake_account(),
+            ctx.accounts.clock(),
+            ctx.accounts.stake_history(),
+        );
+        // lockup can only be in force here if the custodian signed.
+        // No lockup fee for inactive stake accounts since they're withdrawn from immediately
+        let is_lockup_fee_charged = stake_account_state != StakeAccountState::Inactive
+            && ctx
+                .accounts
+                .stake_account()
+                .lockup()
+                .ok_or(UnstakeError::StakeAccountLockupNotRetrievable)?
+                .is_in_force(ctx.accounts.clock(), None);
+        let lockup_fee = match is_lockup_fee_charged {
+            true => Some(
+                (**ctx
+                    .accounts
+                    .lockup_fee_account()
+                    .ok_or(UnstakeError::LockupFeeAccountRequired)?)
+                .clone(),
             ),
-            StakeAuthorize::Staker,
-            None, // custodian
+            false => None,
+        };
+        let custodian = ctx
+            .accounts
+            .custodian()
+            .filter(|c| signing_custodian(Some(c)).is_some())
+            .map(|c| c.to_account_info());
+        let referrer = Self::referrer(ctx);
+        let pool_sol_reserves_bump = *ctx
+            .bumps
+            .get("pool_sol_reserves")
+            .ok_or(UnstakeError::PdaBumpNotCached)?;
+        let stake_account_record_account_bump = *ctx
+            .bumps
+            .get("stake_account_record_account")
+            .ok_or(UnstakeError::PdaBumpNotCached)?;
+        let fee = resolve_fee(
+            ctx.accounts.fee_account(),
+            ctx.accounts.validator_fee_account(),
         )?;
-        stake::authorize(
-            CpiContext::new(
-                ctx.accounts.stake_program().to_account_info(),
-                Authorize {
-                    stake: ctx.accounts.stake_account().to_account_info(),
-                    authorized: ctx.accounts.unstaker().to_account_info(),
-                    new_authorized: ctx.accounts.pool_sol_reserves().to_account_info(),
-                    clock: ctx.accounts.clock().to_account_info(),
-                },
-            ),
-            StakeAuthorize::Withdrawer,
-            None, // custodian
+        let protocol_fee = (**ctx.accounts.protocol_fee_account()).clone();
+        let unstaker = ctx.accounts.unstaker().to_account_info();
+        let stake_account = ctx.accounts.stake_account().to_account_info();
+        let destination = ctx.accounts.destination_account_info();
+        let pool_sol_reserves = ctx.accounts.pool_sol_reserves().to_account_info();
+        let stake_account_record_account = ctx
+            .accounts
+            .stake_account_record_account()
+            .to_account_info();
+        let protocol_fee_destination = ctx.accounts.protocol_fee_destination().to_account_info();
+        let clock = ctx.accounts.clock().to_account_info();
+        let stake_history = ctx.accounts.stake_history().to_account_info();
+        let stake_program = ctx.accounts.stake_program().to_account_info();
+        let system_program = ctx.accounts.system_program().to_account_info();
+        let earmarked_lamports =
+            WithdrawQueue::earmarked_lamports(ctx.accounts.withdraw_queue_account())?;
+
+        let unstake_result = process_unstake(ProcessUnstakeArgs {
+            unstaker,
+            stake_account,
+            stake_account_state,
+            destination,
+            pool_account: ctx.accounts.pool_account(),
+            pool_sol_reserves,
+            pool_sol_reserves_bump,
+            fee: &fee,
+            stake_account_record_account,
+            stake_account_record_account_bump,
+            protocol_fee: &protocol_fee,
+            protocol_fee_destination,
+            referrer,
+            clock,
+            stake_history,
+            stake_program,
+            system_program,
+            min_lamports_out,
+            custodian,
+            lockup_fee: lockup_fee.as_ref(),
+            earmarked_lamports,
+        })?;
+
+        record_unstake_in_pool_config(
+            ctx.accounts.pool_config_account(),
+            ctx.accounts.clock(),
+            &unstake_result,
         )?;
 
-        let incoming_stake = ctx.accounts.pool_account().incoming_stake;
-        let fee_lamports = ctx
-            .accounts
-            .fee_account()
-            .apply(
-                incoming_stake,
-                pool_sol_reserves_lamports,
-                stake_account_lamports,
-            )
-            .ok_or(UnstakeError::InternalError)?;
-        let lamports_to_unstaker = stake_account_lamports
-            .checked_sub(fee_lamports)
-            .ok_or(UnstakeError::InternalError)?;
-        let protocol_fee_lamports = ctx
-            .accounts
-            .protocol_fee_account()
-            .apply(fee_lamports)
-            .ok_or(UnstakeError::InternalError)?;
-        let lamports_to_transfer = lamports_to_unstaker
-            .checked_add(protocol_fee_lamports)
-            .ok_or(UnstakeError::InternalError)?;
+        Ok(unstake_result)
+    }
 
-        if lamports_to_transfer > pool_sol_reserves_lamports {
-            return Err(UnstakeError::NotEnoughLiquidity.into());
-        }
+    fn log_successful_unstake(ctx: &Context<Self>, unstake_result: UnstakeResult) {
+        log_unstake(LogUnstakeArgs {
+            log_ix_tag: Self::LOG_IX_TAG,
+            unstaker: &ctx.accounts.unstaker().key(),
+            stake_account: &ctx.accounts.stake_account().key(),
+            delegation: ctx.accounts.stake_account().delegation(),
+            unstake_result: &unstake_result,
+        });
+    }
+}
 
-        // pay the unstaker from the pool reserves
-        let user_transfer_cpi_accs = system_program::Transfer {
-            from: ctx.accounts.pool_sol_reserves().to_account_info(),
-            to: ctx.accounts.destination_account_info(),
-        };
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program().to_account_info(),
-                user_transfer_cpi_accs,
-                &[pool_sol_reserves_seeds],
-            ),
-            lamports_to_unstaker,
-        )?;
+/// Enforces the pool's unstake limits, if the pool has a config.
+///
+/// Only stake accounts that are not withdrawn immediately
+/// count towards the pool's incoming stake for the epoch
+pub fn record_unstake_in_pool_config(
+    pool_config_account: &AccountInfo,
+    clock: &Clock,
+    unstake_result: &UnstakeResult,
+) -> Result<()> {
+    let incoming_stake_lamports = match unstake_result.stake_account_state {
+        StakeAccountState::Inactive => 0,
+        _ => unstake_result.stake_account_lamports,
+    };
+    PoolConfig::record_unstake_if_initialized(
+        pool_config_account,
+        clock.epoch,
+        unstake_result.stake_account_lamports,
+        incoming_stake_lamports,
+    )
+}
 
-        // further separate referrer fees and protocol fees
-        let lamports_to_protocol = match Self::referrer(ctx) {
-            None => protocol_fee_lamports,
-            Some(referrer) => {
-                let lamports_to_referrer = ctx
-                    .accounts
-                    .protocol_fee_account()
-                    .apply_referrer_fee(protocol_fee_lamports)
-                    .ok_or(UnstakeError::InternalError)?;
-                let lamports_to_protocol = protocol_fee_lamports
-                    .checked_sub(lamports_to_referrer)
-                    .ok_or(UnstakeError::InternalError)?;
-
-                // pay the referrer fees from the pool reserves
-                let referrer_fee_transfer_cpi_accs = system_program::Transfer {
-                    from: ctx.accounts.pool_sol_reserves().to_account_info(),
-                    to: referrer,
-                };
-                system_program::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.system_program().to_account_info(),
-                        referrer_fee_transfer_cpi_accs,
-                        &[pool_sol_reserves_seeds],
-                    ),
-                    lamports_to_referrer,
-                )?;
-
-                lamports_to_protocol
-            }
-        };
+pub struct ProcessUnstakeArgs<'a, 'info> {
+    /// withdrawer of stake_account
+    pub unstaker: AccountInfo<'info>,
+    pub stake_account: AccountInfo<'info>,
+    pub stake_account_state: StakeAccountState,
+    pub destination: AccountInfo<'info>,
+    pub pool_account: &'a mut Account<'info, Pool>,
+    pub pool_sol_reserves: AccountInfo<'info>,
+    pub pool_sol_reserves_bump: u8,
+    pub fee: &'a Fee,
+    /// PDA [pool_account, stake_account]
+    pub stake_account_record_account: AccountInfo<'info>,
+    pub stake_account_record_account_bump: u8,
+    pub protocol_fee: &'a ProtocolFee,
+    pub protocol_fee_destination: AccountInfo<'info>,
+    pub referrer: Option<AccountInfo<'info>>,
+    pub clock: AccountInfo<'info>,
+    pub stake_history: AccountInfo<'info>,
+    pub stake_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub min_lamports_out: u64,
+    /// lockup custodian of stake_account, if it signed
+    pub custodian: Option<AccountInfo<'info>>,
+    /// additional fee to charge, if stake_account's lockup is in force
+    pub lockup_fee: Option<&'a LockupFee>,
+    /// lamports in pool_sol_reserves owed to withdraw tickets,
+    /// which cannot be used to service the unstake
+    pub earmarked_lamports: u64,
+}
 
-        // pay the protocol fees from the pool reserves
-        let protocol_fee_transfer_cpi_accs = system_program::Transfer {
-            from: ctx.accounts.pool_sol_reserves().to_account_info(),
-            to: ctx.accounts.protocol_fee_destination().to_account_info(),
-        };
-        system_program::transfer(
+/// Transfers ownership of stake_account to the pool
+/// and pays out its lamports, excluding fees, to destination.
+///
+/// Fails with LamportsOutBelowMinimum if the lamports paid out
+/// to the unstaker is below `min_lamports_out`
+pub fn process_unstake(args: ProcessUnstakeArgs) -> Result<UnstakeResult> {
+    let ProcessUnstakeArgs {
+        unstaker,
+        stake_account,
+        stake_account_state,
+        destination,
+        pool_account,
+        pool_sol_reserves,
+        pool_sol_reserves_bump,
+        fee,
+        stake_account_record_account,
+        stake_account_record_account_bump,
+        protocol_fee,
+        protocol_fee_destination,
+        referrer,
+        clock,
+        stake_history,
+        stake_program,
+        system_program,
+        min_lamports_out,
+        custodian,
+        lockup_fee,
+        earmarked_lamports,
+    } = args;
+
+    // initialized or fully deactivated stake accounts are withdrawn from immediately,
+    // no stake_account_record_account required
+    let is_withdrawn_immediately = stake_account_state == StakeAccountState::Inactive;
+
+    let pool_account_key = pool_account.key();
+    let pool_sol_reserves_seeds: &[&[u8]] =
+        &[&pool_account_key.to_bytes(), &[pool_sol_reserves_bump]];
+
+    if !is_withdrawn_immediately {
+        init_stake_account_record(InitStakeAccountRecordArgs {
+            pool_account: &pool_account_key,
+            stake_account: &stake_account,
+            stake_account_record_account: &stake_account_record_account,
+            stake_account_record_account_bump,
+            pool_sol_reserves: &pool_sol_reserves,
+            pool_sol_reserves_bump,
+            system_program: &system_program,
+        })?;
+    }
+
+    let stake_account_lamports = stake_account.lamports();
+    let pool_sol_reserves_lamports = pool_sol_reserves
+        .lamports()
+        .saturating_sub(earmarked_lamports);
+
+    authorize_pool_sol_reserves(AuthorizePoolSolReservesArgs {
+        unstaker: &unstaker,
+        stake_account: &stake_account,
+        pool_sol_reserves: &pool_sol_reserves,
+        clock: &clock,
+        stake_program: &stake_program,
+        custodian: custodian.as_ref(),
+    })?;
+
+    let UnstakeLamports {
+        fee_lamports,
+        lamports_to_unstaker,
+        protocol_fee_lamports,
+    } = calc_unstake_lamports(CalcUnstakeLamportsArgs {
+        fee,
+        protocol_fee,
+        apply_fee_args: &ApplyFeeArgs {
+            pool_incoming_stake: pool_account.incoming_stake,
+            sol_reserves_lamports: pool_sol_reserves_lamports,
+            stake_account_lamports,
+            epoch_progress: epoch_progress(&Clock::from_account_info(&clock)?)?,
+            stake_account_state,
+        },
+        lockup_fee,
+        min_lamports_out,
+    })?;
+
+    if is_withdrawn_immediately {
+        stake::withdraw(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program().to_account_info(),
-                protocol_fee_transfer_cpi_accs,
+                stake_program,
+                Withdraw {
+                    stake: stake_account.clone(),
+                    withdrawer: pool_sol_reserves.clone(),
+                    to: pool_sol_reserves.clone(),
+                    clock,
+                    stake_history,
+                },
                 &[pool_sol_reserves_seeds],
             ),
-            lamports_to_protocol,
+            stake_account_lamports,
+            custodian,
         )?;
+    }
 
-        // populate and save the stake_account_record
-        // NB: need to manually serialize here since we're using UncheckedAccount
-        // since we need to manually initialize from PDA
-        let new_record = StakeAccountRecord {
-            lamports_at_creation: stake_account_lamports,
-        };
-        new_record.try_serialize(
-            &mut *ctx
-                .accounts
-                .stake_account_record_account()
-                .try_borrow_mut_data()?,
-        )?;
+    pay_out_unstake(PayOutUnstakeArgs {
+        pool_account: &pool_account_key,
+        pool_sol_reserves,
+        pool_sol_reserves_bump,
+        destination,
+        lamports_to_unstaker,
+        protocol_fee,
+        protocol_fee_lamports,
+        protocol_fee_destination,
+        referrer,
+        system_program,
+        earmarked_lamports,
+    })?;
+
+    if !is_withdrawn_immediately {
+        write_stake_account_record(&stake_account_record_account, stake_account_lamports)?;
 
         // update pool_account incoming_stake
-        ctx.accounts.pool_account().incoming_stake = ctx
-            .accounts
-            .pool_account()
+        pool_account.incoming_stake = pool_account
             .incoming_stake
             .checked_add(stake_account_lamports)
             .ok_or(UnstakeError::InternalError)?;
-
-        Ok(UnstakeResult {
-            stake_account_lamports,
-            lamports_to_unstaker,
-            fee_lamports,
-        })
     }
 
-    fn log_successful_unstake(ctx: &Context<Self>, unstake_result: UnstakeResult) {
-        // emit analytics log
-        let (voter_pubkey, activation_epoch) =
-            ctx.accounts.stake_account().delegation().map_or_else(
-                || (String::from(""), String::from("")),
-                |delegation| {
-                    (
-                        delegation.voter_pubkey.to_string(),
-                        delegation.activation_epoch.to_string(),
-                    )
+    Ok(UnstakeResult {
+        fee: fee.clone(),
+        stake_account_lamports,
+        lamports_to_unstaker,
+        fee_lamports,
+        stake_account_state,
+    })
+}
+
+pub struct InitStakeAccountRecordArgs<'a, 'info> {
+    pub pool_account: &'a Pubkey,
+    pub stake_account: &'a AccountInfo<'info>,
+    /// PDA [pool_account, stake_account]
+    pub stake_account_record_account: &'a AccountInfo<'info>,
+    pub stake_account_record_account_bump: u8,
+    pub pool_sol_reserves: &'a AccountInfo<'info>,
+    pub pool_sol_reserves_bump: u8,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Allocates and assigns stake_account_record_account,
+/// paying for its rent from the pool reserves
+pub fn init_stake_account_record(args: InitStakeAccountRecordArgs) -> Result<()> {
+    let InitStakeAccountRecordArgs {
+        pool_account,
+        stake_account,
+        stake_account_record_account,
+        stake_account_record_account_bump,
+        pool_sol_reserves,
+        pool_sol_reserves_bump,
+        system_program,
+    } = args;
+
+    let pool_sol_reserves_seeds: &[&[u8]] = &[&pool_account.to_bytes(), &[pool_sol_reserves_bump]];
+    let stake_account_record_account_seeds: &[&[u8]] = &[
+        &pool_account.to_bytes(),
+        &stake_account.key().to_bytes(),
+        &[stake_account_record_account_bump],
+    ];
+    allocate_assign_pda(AllocateAssignPdaArgs {
+        pda_account: stake_account_record_account,
+        system_program,
+        pda_account_len: StakeAccountRecord::LEN.try_into().unwrap(),
+        pda_account_owner_program: &crate::ID,
+        pda_account_signer_seeds: &[stake_account_record_account_seeds],
+    })?;
+    make_rent_exempt_with_pda_payer(MakeRentExemptWithPdaPayerArgs {
+        account: stake_account_record_account,
+        system_program,
+        pda_payer: pool_sol_reserves,
+        pda_payer_signer_seeds: &[pool_sol_reserves_seeds],
+    })
+}
+
+/// populate and save the stake_account_record
+/// NB: need to manually serialize here since we're using UncheckedAccount
+/// since we need to manually initialize from PDA
+pub fn write_stake_account_record(
+    stake_account_record_account: &AccountInfo,
+    lamports_at_creation: u64,
+) -> Result<()> {
+    let new_record = StakeAccountRecord {
+        lamports_at_creation,
+    };
+    new_record.try_serialize(&mut *stake_account_record_account.try_borrow_mut_data()?)
+}
+
+pub struct AuthorizePoolSolReservesArgs<'a, 'info> {
+    pub unstaker: &'a AccountInfo<'info>,
+    pub stake_account: &'a AccountInfo<'info>,
+    pub pool_sol_reserves: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub stake_program: &'a AccountInfo<'info>,
+    /// lockup custodian of stake_account, if it signed
+    pub custodian: Option<&'a AccountInfo<'info>>,
+}
+
+/// authorize pool_sol_reserves as staker and withdrawer of the stake_account.
+/// `unstaker` only needs to be the withdrawer since the stake program allows
+/// the withdrawer to reassign the staker
+pub fn authorize_pool_sol_reserves(args: AuthorizePoolSolReservesArgs) -> Result<()> {
+    let AuthorizePoolSolReservesArgs {
+        unstaker,
+        stake_account,
+        pool_sol_reserves,
+        clock,
+        stake_program,
+        custodian,
+    } = args;
+    for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+        stake::authorize(
+            CpiContext::new(
+                stake_program.clone(),
+                Authorize {
+                    stake: stake_account.clone(),
+                    authorized: unstaker.clone(),
+                    new_authorized: pool_sol_reserves.clone(),
+                    clock: clock.clone(),
                 },
-            );
-
-        // Log Format:
-        //  "unstake-log: [instruction, unstaker, stake_account_address, stake_account_voter, stake_account_activation_epoch, FEE, recorded_lamports, paid_lamports, fee_lamports]"
-        //
-        // Fee Format (see SPEC.md or fee.rs for details):
-        //  "[fee_type; FEE_DETAILS]"
-        msg!(
-            "unstake-log: [{}, {}, {}, {}, {}, {}, {}, {}, {}]",
-            Self::LOG_IX_TAG,
-            ctx.accounts.unstaker().key(),
-            ctx.accounts.stake_account().key(),
-            voter_pubkey,
-            activation_epoch,
-            ctx.accounts.fee_account().fee,
-            unstake_result.stake_account_lamports,
-            unstake_result.lamports_to_unstaker,
-            unstake_result.fee_lamports,
-        );
+            ),
+            stake_authorize,
+            custodian.cloned(),
+        )?;
+    }
+    Ok(())
+}
+
+pub struct UnstakeLamports {
+    pub fee_lamports: u64,
+    pub lamports_to_unstaker: u64,
+    pub protocol_fee_lamports: u64,
+}
+
+pub struct CalcUnstakeLamportsArgs<'a> {
+    pub fee: &'a Fee,
+    pub protocol_fee: &'a ProtocolFee,
+    pub apply_fee_args: &'a ApplyFeeArgs,
+    /// charged on top of `fee` if provided
+    pub lockup_fee: Option<&'a LockupFee>,
+    pub min_lamports_out: u64,
+}
+
+/// Fails with LamportsOutBelowMinimum if the lamports paid out
+/// to the unstaker is below `min_lamports_out`
+pub fn calc_unstake_lamports(args: CalcUnstakeLamportsArgs) -> Result<UnstakeLamports> {
+    let CalcUnstakeLamportsArgs {
+        fee,
+        protocol_fee,
+        apply_fee_args,
+        lockup_fee,
+        min_lamports_out,
+    } = args;
+    let fee_lamports = fee
+        .apply(apply_fee_args)
+        .ok_or(UnstakeError::InternalError)?;
+    let fee_lamports = match lockup_fee {
+        None => fee_lamports,
+        Some(lockup_fee) => lockup_fee
+            .apply(apply_fee_args.stake_account_lamports)
+            .and_then(|lockup_fee_lamports| fee_lamports.checked_add(lockup_fee_lamports))
+            .ok_or(UnstakeError::InternalError)?
+            .min(apply_fee_args.stake_account_lamports),
+    };
+    let lamports_to_unstaker = apply_fee_args
+        .stake_account_lamports
+        .checked_sub(fee_lamports)
+        .ok_or(UnstakeError::InternalError)?;
+    if lamports_to_unstaker < min_lamports_out {
+        return Err(UnstakeError::LamportsOutBelowMinimum.into());
+    }
+    let protocol_fee_lamports = protocol_fee
+        .apply(fee_lamports)
+        .ok_or(UnstakeError::InternalError)?;
+    Ok(UnstakeLamports {
+        fee_lamports,
+        lamports_to_unstaker,
+        protocol_fee_lamports,
+    })
+}
+
+pub struct PayOutUnstakeArgs<'a, 'info> {
+    pub pool_account: &'a Pubkey,
+    pub pool_sol_reserves: AccountInfo<'info>,
+    pub pool_sol_reserves_bump: u8,
+    pub destination: AccountInfo<'info>,
+    pub lamports_to_unstaker: u64,
+    pub protocol_fee: &'a ProtocolFee,
+    pub protocol_fee_lamports: u64,
+    pub protocol_fee_destination: AccountInfo<'info>,
+    pub referrer: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+    /// lamports in pool_sol_reserves owed to withdraw tickets
+    pub earmarked_lamports: u64,
+}
+
+/// Pays the unstaker, referrer and protocol from the pool reserves.
+///
+/// Fails with NotEnoughLiquidity if the pool reserves,
+/// excluding lamports earmarked for withdraw tickets, cannot cover the payouts
+pub fn pay_out_unstake(args: PayOutUnstakeArgs) -> Result<()> {
+    let PayOutUnstakeArgs {
+        pool_account,
+        pool_sol_reserves,
+        pool_sol_reserves_bump,
+        destination,
+        lamports_to_unstaker,
+        protocol_fee,
+        protocol_fee_lamports,
+        protocol_fee_destination,
+        referrer,
+        system_program,
+        earmarked_lamports,
+    } = args;
+
+    let pool_sol_reserves_seeds: &[&[u8]] = &[&pool_account.to_bytes(), &[pool_sol_reserves_bump]];
+
+    let lamports_to_transfer = lamports_to_unstaker
+        .checked_add(protocol_fee_lamports)
+        .ok_or(UnstakeError::InternalError)?;
+    let available_lamports = pool_sol_reserves
+        .lamports()
+        .saturating_sub(earmarked_lamports);
+    if lamports_to_transfer > available_lamports {
+        return Err(UnstakeError::NotEnoughLiquidity.into());
     }
+
+    // pay the unstaker from the pool reserves
+    let user_transfer_cpi_accs = system_program::Transfer {
+        from: pool_sol_reserves.clone(),
+        to: destination,
+    };
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            user_transfer_cpi_accs,
+            &[pool_sol_reserves_seeds],
+        ),
+        lamports_to_unstaker,
+    )?;
+
+    pay_out_protocol_fee(PayOutProtocolFeeArgs {
+        pool_sol_reserves,
+        pool_sol_reserves_seeds,
+        protocol_fee,
+        protocol_fee_lamports,
+        protocol_fee_destination,
+        referrer,
+        system_program,
+    })
+}
+
+pub struct PayOutProtocolFeeArgs<'a, 'info> {
+    pub pool_sol_reserves: AccountInfo<'info>,
+    pub pool_sol_reserves_seeds: &'a [&'a [u8]],
+    pub protocol_fee: &'a ProtocolFee,
+    pub protocol_fee_lamports: u64,
+    pub protocol_fee_destination: AccountInfo<'info>,
+    pub referrer: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Pays the protocol fee from the pool reserves,
+/// splitting off the referrer's share if a referrer is provided
+pub fn pay_out_protocol_fee(args: PayOutProtocolFeeArgs) -> Result<()> {
+    let PayOutProtocolFeeArgs {
+        pool_sol_reserves,
+        pool_sol_reserves_seeds,
+        protocol_fee,
+        protocol_fee_lamports,
+        protocol_fee_destination,
+        referrer,
+        system_program,
+    } = args;
+
+    // further separate referrer fees and protocol fees
+    let lamports_to_protocol = match referrer {
+        None => protocol_fee_lamports,
+        Some(referrer) => {
+            let lamports_to_referrer = protocol_fee
+                .apply_referrer_fee(protocol_fee_lamports)
+                .ok_or(UnstakeError::InternalError)?;
+            let lamports_to_protocol = protocol_fee_lamports
+                .checked_sub(lamports_to_referrer)
+                .ok_or(UnstakeError::InternalError)?;
+
+            // pay the referrer fees from the pool reserves
+            let referrer_fee_transfer_cpi_accs = system_program::Transfer {
+                from: pool_sol_reserves.clone(),
+                to: referrer,
+            };
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    referrer_fee_transfer_cpi_accs,
+                    &[pool_sol_reserves_seeds],
+                ),
+                lamports_to_referrer,
+            )?;
+
+            lamports_to_protocol
+        }
+    };
+
+    // pay the protocol fees from the pool reserves
+    let protocol_fee_transfer_cpi_accs = system_program::Transfer {
+        from: pool_sol_reserves,
+        to: protocol_fee_destination,
+    };
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program,
+            protocol_fee_transfer_cpi_accs,
+            &[pool_sol_reserves_seeds],
+        ),
+        lamports_to_protocol,
+    )?;
+
+    Ok(())
+}
+
+pub struct LogUnstakeArgs<'a> {
+    pub log_ix_tag: u8,
+    pub unstaker: &'a Pubkey,
+    pub stake_account: &'a Pubkey,
+    pub delegation: Option<Delegation>,
+    pub unstake_result: &'a UnstakeResult,
+}
+
+pub fn log_unstake(args: LogUnstakeArgs) {
+    let LogUnstakeArgs {
+        log_ix_tag,
+        unstaker,
+        stake_account,
+        delegation,
+        unstake_result,
+    } = args;
+
+    // emit analytics log
+    let (voter_pubkey, activation_epoch) = delegation.map_or_else(
+        || (String::from(""), String::from("")),
+        |delegation| {
+            (
+                delegation.voter_pubkey.to_string(),
+                delegation.activation_epoch.to_string(),
+            )
+        },
+    );
+
+    // Log Format:
+    //  "unstake-log: [instruction, unstaker, stake_account_address, stake_account_voter, stake_account_activation_epoch, FEE, recorded_lamports, paid_lamports, fee_lamports, stake_account_state]"
+    //
+    // Fee Format (see SPEC.md or fee.rs for details):
+    //  "[fee_type; FEE_DETAILS]"
+    msg!(
+        "unstake-log: [{}, {}, {}, {}, {}, {}, {}, {}, {}, {}]",
+        log_ix_tag,
+        unstaker,
+        stake_account,
+        voter_pubkey,
+        activation_epoch,
+        unstake_result.fee,
+        unstake_result.stake_account_lamports,
+        unstake_result.lamports_to_unstaker,
+        unstake_result.fee_lamports,
+        unstake_result.stake_account_state,
+    );
 }
 
 macro_rules! impl_unstake_accounts {
@@ -300,22 +745,26 @@ macro_rules! impl_unstake_accounts {
                 &self.fee_account
             }
 
-            fn stake_account_record_account_immut(
-                &self,
-            ) -> &anchor_lang::prelude::UncheckedAccount<'info> {
-                &self.stake_account_record_account
+            fn validator_fee_account(&self) -> &anchor_lang::prelude::UncheckedAccount<'info> {
+                &self.validator_fee_account
             }
 
             fn stake_account_record_account(
-                &mut self,
-            ) -> &mut anchor_lang::prelude::UncheckedAccount<'info> {
-                &mut self.stake_account_record_account
+                &self,
+            ) -> &anchor_lang::prelude::UncheckedAccount<'info> {
+                &self.stake_account_record_account
             }
 
             fn clock(&self) -> &anchor_lang::prelude::Sysvar<'info, anchor_lang::prelude::Clock> {
                 &self.clock
             }
 
+            fn stake_history(
+                &self,
+            ) -> &anchor_lang::prelude::Sysvar<'info, anchor_lang::prelude::StakeHistory> {
+                &self.stake_history
+            }
+
             fn stake_program(
                 &self,
             ) -> &anchor_lang::prelude::Program<'info, anchor_spl::stake::Stake> {
@@ -335,6 +784,22 @@ macro_rules! impl_unstake_accounts {
             fn protocol_fee_destination(&self) -> &AccountInfo<'info> {
                 &self.protocol_fee_destination
             }
+
+            fn custodian(&self) -> Option<&UncheckedAccount<'info>> {
+                self.custodian.as_ref()
+            }
+
+            fn lockup_fee_account(&self) -> Option<&Account<'info, LockupFee>> {
+                self.lockup_fee_account.as_ref()
+            }
+
+            fn pool_config_account(&self) -> &UncheckedAccount<'info> {
+                &self.pool_config_account
+            }
+
+            fn withdraw_queue_account(&self) -> &UncheckedAccount<'info> {
+                &self.withdraw_queue_account
+            }
         }
     };
 }
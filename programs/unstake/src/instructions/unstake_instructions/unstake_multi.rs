@@ -0,0 +1,364 @@
+use std::convert::TryFrom;
+
+use anchor_lang::prelude::*;
+use anchor_spl::stake::{self, Stake, StakeAccount, Withdraw};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        ApplyFeeArgs, Fee, PausableInstruction, Pool, PoolConfig, PoolPause, ProtocolFee,
+        ProtocolPause, StakeAccountState, WithdrawQueue, FEE_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX,
+        POOL_PAUSE_SEED_SUFFIX, PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED, VALIDATOR_FEE_SEED_SUFFIX,
+        WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::{epoch_progress, resolve_fee, stake_account_state, stake_account_voter},
+};
+
+use super::unstake_accounts::{
+    authorize_pool_sol_reserves, calc_unstake_lamports, init_stake_account_record, log_unstake,
+    pay_out_unstake, write_stake_account_record, AuthorizePoolSolReservesArgs,
+    CalcUnstakeLamportsArgs, InitStakeAccountRecordArgs, LogUnstakeArgs, PayOutUnstakeArgs,
+    UnstakeLamports, UnstakeResult,
+};
+
+/// Stake accounts to unstake are passed via remaining_accounts as consecutive
+/// (stake_account, stake_account_record_account, validator_fee_account) triples.
+/// If there is one account left over, it is the referrer.
+#[derive(Accounts)]
+pub struct UnstakeMulti<'info> {
+    /// owner of all the stake accounts
+    pub unstaker: Signer<'info>,
+
+    /// Solana native wallet pubkey to receive the unstaked amount
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    /// pool account that SOL reserves belong to
+    #[account(mut)]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's fee account
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    #[account(
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// CHECK: address-check checks that its the correct
+    /// destination specified in `protocol_fee_account`
+    #[account(
+        mut,
+        address = protocol_fee_account.destination @ UnstakeError::WrongProtocolFeeDestination,
+    )]
+    pub protocol_fee_destination: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_history: Sysvar<'info, StakeHistory>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no unstake limits if it is not initialized
+    /// CHECK: PDA checks address. Deserialized and updated in run() if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+}
+
+struct StakeAccountToUnstake<'info> {
+    stake_account: Account<'info, StakeAccount>,
+    stake_account_record_account: AccountInfo<'info>,
+    stake_account_record_account_bump: u8,
+    stake_account_state: StakeAccountState,
+    stake_account_lamports: u64,
+}
+
+impl<'info> UnstakeMulti<'info> {
+    #[inline(always)]
+    pub fn run(ctx: Context<'_, '_, '_, 'info, Self>, min_lamports_out: u64) -> Result<()> {
+        let accounts = ctx.accounts;
+        let pool_account_key = accounts.pool_account.key();
+        let pool_sol_reserves_bump = *ctx
+            .bumps
+            .get("pool_sol_reserves")
+            .ok_or(UnstakeError::PdaBumpNotCached)?;
+        let pool_sol_reserves_seeds: &[&[u8]] =
+            &[&pool_account_key.to_bytes(), &[pool_sol_reserves_bump]];
+
+        let (triples, referrer) = match ctx.remaining_accounts.len() % 3 {
+            0 => (ctx.remaining_accounts, None),
+            1 => ctx
+                .remaining_accounts
+                .split_last()
+                .map(|(referrer, triples)| (triples, Some(referrer.to_account_info())))
+                .ok_or(UnstakeError::InternalError)?,
+            _ => return Err(ErrorCode::AccountNotEnoughKeys.into()),
+        };
+        if triples.is_empty() {
+            return Err(UnstakeError::NoStakeAccountsToUnstake.into());
+        }
+
+        let mut to_unstake: Vec<StakeAccountToUnstake> = Vec::with_capacity(triples.len() / 3);
+        let mut fee: Option<Fee> = None;
+        for triple in triples.chunks_exact(3) {
+            let stake_account: Account<'info, StakeAccount> = Account::try_from(&triple[0])?;
+            if to_unstake
+                .iter()
+                .any(|s| s.stake_account.key() == stake_account.key())
+            {
+                return Err(UnstakeError::DuplicateStakeAccount.into());
+            }
+            // see Unstake
+            if stake_account
+                .lockup()
+                .ok_or(UnstakeError::StakeAccountLockupNotRetrievable)?
+                .is_in_force(&accounts.clock, None)
+            {
+                return Err(UnstakeError::StakeAccountLockupInForce.into());
+            }
+            let (stake_account_record_account_key, stake_account_record_account_bump) =
+                Pubkey::find_program_address(
+                    &[
+                        &pool_account_key.to_bytes(),
+                        &stake_account.key().to_bytes(),
+                    ],
+                    &crate::ID,
+                );
+            if triple[1].key() != stake_account_record_account_key {
+                return Err(UnstakeError::InvalidStakeAccountRecordAccount.into());
+            }
+            let (validator_fee_account_key, _bump) = Pubkey::find_program_address(
+                &[
+                    &pool_account_key.to_bytes(),
+                    &stake_account_voter(&stake_account).to_bytes(),
+                    VALIDATOR_FEE_SEED_SUFFIX,
+                ],
+                &crate::ID,
+            );
+            if triple[2].key() != validator_fee_account_key {
+                return Err(UnstakeError::InvalidValidatorFeeAccount.into());
+            }
+            // the fee curve is applied once to the total,
+            // so all stake accounts must be charged the same fee
+            let stake_account_fee = resolve_fee(&accounts.fee_account, &triple[2])?;
+            match &fee {
+                None => fee = Some(stake_account_fee),
+                Some(fee) => {
+                    if *fee != stake_account_fee {
+                        return Err(UnstakeError::StakeAccountFeesMismatch.into());
+                    }
+                }
+            }
+            let stake_account_state =
+                stake_account_state(&stake_account, &accounts.clock, &accounts.stake_history);
+            to_unstake.push(StakeAccountToUnstake {
+                stake_account,
+                stake_account_record_account: triple[1].to_account_info(),
+                stake_account_record_account_bump,
+                stake_account_state,
+                stake_account_lamports: 0,
+            });
+        }
+
+        for s in to_unstake.iter_mut() {
+            if s.stake_account_state != StakeAccountState::Inactive {
+                init_stake_account_record(InitStakeAccountRecordArgs {
+                    pool_account: &pool_account_key,
+                    stake_account: s.stake_account.as_ref(),
+                    stake_account_record_account: &s.stake_account_record_account,
+                    stake_account_record_account_bump: s.stake_account_record_account_bump,
+                    pool_sol_reserves: accounts.pool_sol_reserves.as_ref(),
+                    pool_sol_reserves_bump,
+                    system_program: accounts.system_program.as_ref(),
+                })?;
+            }
+            s.stake_account_lamports = s.stake_account.to_account_info().lamports();
+            authorize_pool_sol_reserves(AuthorizePoolSolReservesArgs {
+                unstaker: accounts.unstaker.as_ref(),
+                stake_account: s.stake_account.as_ref(),
+                pool_sol_reserves: accounts.pool_sol_reserves.as_ref(),
+                clock: accounts.clock.as_ref(),
+                stake_program: accounts.stake_program.as_ref(),
+                custodian: None,
+            })?;
+        }
+
+        let fee = fee.ok_or(UnstakeError::InternalError)?;
+        let earmarked_lamports =
+            WithdrawQueue::earmarked_lamports(&accounts.withdraw_queue_account)?;
+        let total_stake_account_lamports = to_unstake
+            .iter()
+            .try_fold(0u64, |acc, s| acc.checked_add(s.stake_account_lamports))
+            .ok_or(UnstakeError::InternalError)?;
+        let total_incoming_stake_lamports = to_unstake
+            .iter()
+            .filter(|s| s.stake_account_state != StakeAccountState::Inactive)
+            .try_fold(0u64, |acc, s| acc.checked_add(s.stake_account_lamports))
+            .ok_or(UnstakeError::InternalError)?;
+        // the limits apply to the combined amount
+        PoolConfig::record_unstake_if_initialized(
+            accounts.pool_config_account.as_ref(),
+            accounts.clock.epoch,
+            total_stake_account_lamports,
+            total_incoming_stake_lamports,
+        )?;
+        // price the combined amount as the least withdrawable of the stake accounts
+        let combined_stake_account_state = to_unstake
+            .iter()
+            .map(|s| s.stake_account_state)
+            .min()
+            .ok_or(UnstakeError::InternalError)?;
+        let UnstakeLamports {
+            fee_lamports,
+            lamports_to_unstaker,
+            protocol_fee_lamports,
+        } = calc_unstake_lamports(CalcUnstakeLamportsArgs {
+            fee: &fee,
+            protocol_fee: &accounts.protocol_fee_account,
+            apply_fee_args: &ApplyFeeArgs {
+                pool_incoming_stake: accounts.pool_account.incoming_stake,
+                sol_reserves_lamports: accounts
+                    .pool_sol_reserves
+                    .lamports()
+                    .saturating_sub(earmarked_lamports),
+                stake_account_lamports: total_stake_account_lamports,
+                epoch_progress: epoch_progress(&accounts.clock)?,
+                stake_account_state: combined_stake_account_state,
+            },
+            lockup_fee: None,
+            min_lamports_out,
+        })?;
+
+        // initialized or fully deactivated stake accounts are withdrawn from immediately
+        for s in to_unstake
+            .iter()
+            .filter(|s| s.stake_account_state == StakeAccountState::Inactive)
+        {
+            stake::withdraw(
+                CpiContext::new_with_signer(
+                    accounts.stake_program.to_account_info(),
+                    Withdraw {
+                        stake: s.stake_account.to_account_info(),
+                        withdrawer: accounts.pool_sol_reserves.to_account_info(),
+                        to: accounts.pool_sol_reserves.to_account_info(),
+                        clock: accounts.clock.to_account_info(),
+                        stake_history: accounts.stake_history.to_account_info(),
+                    },
+                    &[pool_sol_reserves_seeds],
+                ),
+                s.stake_account_lamports,
+                None, // custodian
+            )?;
+        }
+
+        pay_out_unstake(PayOutUnstakeArgs {
+            pool_account: &pool_account_key,
+            pool_sol_reserves: accounts.pool_sol_reserves.to_account_info(),
+            pool_sol_reserves_bump,
+            destination: accounts.destination.to_account_info(),
+            lamports_to_unstaker,
+            protocol_fee: &accounts.protocol_fee_account,
+            protocol_fee_lamports,
+            protocol_fee_destination: accounts.protocol_fee_destination.to_account_info(),
+            referrer,
+            system_program: accounts.system_program.to_account_info(),
+            earmarked_lamports,
+        })?;
+
+        let mut fee_lamports_remaining = fee_lamports;
+        for (i, s) in to_unstake.iter().enumerate() {
+            if s.stake_account_state != StakeAccountState::Inactive {
+                write_stake_account_record(
+                    &s.stake_account_record_account,
+                    s.stake_account_lamports,
+                )?;
+                accounts.pool_account.incoming_stake = accounts
+                    .pool_account
+                    .incoming_stake
+                    .checked_add(s.stake_account_lamports)
+                    .ok_or(UnstakeError::InternalError)?;
+            }
+
+            // emit analytics log,
+            // fee_lamports is split across stake accounts pro-rata by lamports,
+            // with the last stake account taking the remainder
+            let stake_account_fee_lamports = if i == to_unstake.len() - 1 {
+                fee_lamports_remaining
+            } else {
+                (u128::from(fee_lamports) * u128::from(s.stake_account_lamports))
+                    .checked_div(u128::from(total_stake_account_lamports))
+                    .and_then(|v| u64::try_from(v).ok())
+                    .ok_or(UnstakeError::InternalError)?
+            };
+            fee_lamports_remaining = fee_lamports_remaining
+                .checked_sub(stake_account_fee_lamports)
+                .ok_or(UnstakeError::InternalError)?;
+            log_unstake(LogUnstakeArgs {
+                log_ix_tag: 4,
+                unstaker: &accounts.unstaker.key(),
+                stake_account: &s.stake_account.key(),
+                delegation: s.stake_account.delegation(),
+                unstake_result: &UnstakeResult {
+                    fee: fee.clone(),
+                    stake_account_lamports: s.stake_account_lamports,
+                    lamports_to_unstaker: s
+                        .stake_account_lamports
+                        .checked_sub(stake_account_fee_lamports)
+                        .ok_or(UnstakeError::InternalError)?,
+                    fee_lamports: stake_account_fee_lamports,
+                    stake_account_state: s.stake_account_state,
+                },
+            });
+        }
+
+        Ok(())
+    }
+}
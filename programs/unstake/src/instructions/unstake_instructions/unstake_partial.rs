@@ -0,0 +1,256 @@
+use anchor_lang::{prelude::*, solana_program::stake::state::StakeState};
+use anchor_spl::stake::{Stake, StakeAccount};
+
+use crate::{
+    errors::UnstakeError,
+    state::{
+        Fee, PausableInstruction, Pool, PoolPause, ProtocolFee, ProtocolPause, WithdrawQueue,
+        FEE_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX, PROTOCOL_FEE_SEED,
+        PROTOCOL_PAUSE_SEED, VALIDATOR_FEE_SEED_SUFFIX, WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::{
+        allocate_assign_pda, resolve_fee, split_stake_account, stake_account_state,
+        stake_account_voter, AllocateAssignPdaArgs, SplitStakeAccountArgs,
+    },
+};
+
+use super::unstake_accounts::{
+    log_unstake, process_unstake, record_unstake_in_pool_config, LogUnstakeArgs, ProcessUnstakeArgs,
+};
+
+#[constant]
+pub const SPLIT_STAKE_ACCOUNT_SEED_SUFFIX: &[u8] = b"split";
+
+#[derive(Accounts)]
+pub struct UnstakePartial<'info> {
+    /// stake account owner.
+    /// Must be both the staker, to split, and the withdraw authority of stake_account
+    pub unstaker: Signer<'info>,
+
+    /// stake account to split the unstaked lamports from.
+    /// Remains owned by unstaker.
+    /// rely on stake program CPI call to ensure owned by unstaker
+    #[account(
+        mut,
+        // see Unstake
+        constraint = !stake_account.lockup()
+            .ok_or(UnstakeError::StakeAccountLockupNotRetrievable)?
+            .is_in_force(&clock, None)
+            @ UnstakeError::StakeAccountLockupInForce,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// new stake account to split the unstaked lamports into,
+    /// transferred to the pool.
+    /// Only one can exist per stake_account at any time,
+    /// stake_account cannot be partially unstaked again until this has been reclaimed.
+    /// CHECK: PDA checks address. Manually initialized in processor.
+    #[account(
+        mut,
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &stake_account.key().to_bytes(),
+            SPLIT_STAKE_ACCOUNT_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub split_stake_account: UncheckedAccount<'info>,
+
+    /// Solana native wallet pubkey to receive the unstaked amount
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+
+    /// pool account that SOL reserves belong to
+    #[account(mut)]
+    pub pool_account: Account<'info, Pool>,
+
+    /// pool's SOL reserves
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes()],
+        bump,
+    )]
+    pub pool_sol_reserves: SystemAccount<'info>,
+
+    /// pool's fee account
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub fee_account: Account<'info, Fee>,
+
+    /// pool's validator fee account for the validator stake_account is delegated to.
+    /// The pool's fee is charged if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in processor if initialized
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &stake_account_voter(&stake_account).to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: UncheckedAccount<'info>,
+
+    /// stake account record of split_stake_account to be created
+    /// CHECK: PDA checks address. Manually initialized and serialized in processor.
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), &split_stake_account.key().to_bytes()],
+        bump,
+    )]
+    pub stake_account_record_account: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_FEE_SEED],
+        bump,
+    )]
+    pub protocol_fee_account: Account<'info, ProtocolFee>,
+
+    /// CHECK: address-check checks that its the correct
+    /// destination specified in `protocol_fee_account`
+    #[account(
+        mut,
+        address = protocol_fee_account.destination @ UnstakeError::WrongProtocolFeeDestination,
+    )]
+    pub protocol_fee_destination: AccountInfo<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub stake_history: Sysvar<'info, StakeHistory>,
+    pub stake_program: Program<'info, Stake>,
+    pub system_program: Program<'info, System>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no unstake limits if it is not initialized
+    /// CHECK: PDA checks address. Deserialized and updated in run() if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
+}
+
+impl<'info> UnstakePartial<'info> {
+    #[inline(always)]
+    pub fn run(
+        ctx: Context<'_, '_, '_, 'info, Self>,
+        lamports: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        // split_stake_account inherits stake_account's delegation
+        let stake_account_state = stake_account_state(
+            &accounts.stake_account,
+            &accounts.clock,
+            &accounts.stake_history,
+        );
+
+        // initialize split_stake_account
+        let split_stake_account_seeds: &[&[u8]] = &[
+            &accounts.pool_account.key().to_bytes(),
+            &accounts.stake_account.key().to_bytes(),
+            SPLIT_STAKE_ACCOUNT_SEED_SUFFIX,
+            &[*ctx
+                .bumps
+                .get("split_stake_account")
+                .ok_or(UnstakeError::PdaBumpNotCached)?],
+        ];
+        allocate_assign_pda(AllocateAssignPdaArgs {
+            pda_account: accounts.split_stake_account.as_ref(),
+            system_program: accounts.system_program.as_ref(),
+            pda_account_len: StakeState::size_of() as u64,
+            pda_account_owner_program: &accounts.stake_program.key(),
+            pda_account_signer_seeds: &[split_stake_account_seeds],
+        })?;
+        split_stake_account(SplitStakeAccountArgs {
+            stake_program: accounts.stake_program.as_ref(),
+            stake_account: accounts.stake_account.as_ref(),
+            split_stake_account: accounts.split_stake_account.as_ref(),
+            staker: accounts.unstaker.as_ref(),
+            lamports,
+            staker_signer_seeds: &[],
+        })?;
+
+        let fee = resolve_fee(&accounts.fee_account, &accounts.validator_fee_account)?;
+        let unstake_result = process_unstake(ProcessUnstakeArgs {
+            unstaker: accounts.unstaker.to_account_info(),
+            stake_account: accounts.split_stake_account.to_account_info(),
+            stake_account_state,
+            destination: accounts.destination.to_account_info(),
+            pool_account: &mut accounts.pool_account,
+            pool_sol_reserves: accounts.pool_sol_reserves.to_account_info(),
+            pool_sol_reserves_bump: *ctx
+                .bumps
+                .get("pool_sol_reserves")
+                .ok_or(UnstakeError::PdaBumpNotCached)?,
+            fee: &fee,
+            stake_account_record_account: accounts.stake_account_record_account.to_account_info(),
+            stake_account_record_account_bump: *ctx
+                .bumps
+                .get("stake_account_record_account")
+                .ok_or(UnstakeError::PdaBumpNotCached)?,
+            protocol_fee: &accounts.protocol_fee_account,
+            protocol_fee_destination: accounts.protocol_fee_destination.to_account_info(),
+            referrer: ctx.remaining_accounts.first().map(|a| a.to_account_info()),
+            clock: accounts.clock.to_account_info(),
+            stake_history: accounts.stake_history.to_account_info(),
+            stake_program: accounts.stake_program.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            min_lamports_out,
+            custodian: None,
+            lockup_fee: None,
+            earmarked_lamports: WithdrawQueue::earmarked_lamports(
+                &accounts.withdraw_queue_account,
+            )?,
+        })?;
+
+        record_unstake_in_pool_config(
+            accounts.pool_config_account.as_ref(),
+            &accounts.clock,
+            &unstake_result,
+        )?;
+
+        // emit analytics log
+        log_unstake(LogUnstakeArgs {
+            log_ix_tag: 3,
+            unstaker: &accounts.unstaker.key(),
+            stake_account: &accounts.split_stake_account.key(),
+            delegation: accounts.stake_account.delegation(),
+            unstake_result: &unstake_result,
+        });
+
+        Ok(())
+    }
+}
@@ -6,14 +6,22 @@ use anchor_spl::{
 
 use crate::{
     errors::UnstakeError,
-    state::{Fee, Pool, ProtocolFee, FEE_SEED_SUFFIX, PROTOCOL_FEE_SEED},
+    state::{
+        Fee, LockupFee, PausableInstruction, Pool, PoolPause, ProtocolFee, ProtocolPause,
+        FEE_SEED_SUFFIX, LOCKUP_FEE_SEED_SUFFIX, POOL_CONFIG_SEED_SUFFIX, POOL_PAUSE_SEED_SUFFIX,
+        PROTOCOL_FEE_SEED, PROTOCOL_PAUSE_SEED, VALIDATOR_FEE_SEED_SUFFIX,
+        WITHDRAW_QUEUE_SEED_SUFFIX,
+    },
+    utils::{signing_custodian, stake_account_voter},
 };
 
 use super::unstake_accounts::UnstakeAccounts;
 
 #[derive(Accounts)]
-pub struct UnstakeWsol<'info> {
-    /// stake account owner
+pub struct UnstakeWsolV2<'info> {
+    /// stake account owner, the withdraw authority of stake_account.
+    /// stake_account's staker does not need to sign if it is a different key
+    /// since the withdraw authority can reassign the staker
     pub unstaker: Signer<'info>,
 
     /// stake account to be unstaked
@@ -27,9 +35,11 @@ pub struct UnstakeWsol<'info> {
         //  - since the instruction updates both staker and withdrawer, lockup
         //    cannot be updated by the custodian or unstaker after the instruction
         //    resolves
+        //  - if lockup is in force, the custodian must sign and the pool charges
+        //    its lockup fee on top since the custodian can still update the lockup
         constraint = !stake_account.lockup()
             .ok_or(UnstakeError::StakeAccountLockupNotRetrievable)?
-            .is_in_force(&clock, None)
+            .is_in_force(&clock, signing_custodian(custodian.as_ref()))
             @ UnstakeError::StakeAccountLockupInForce,
     )]
     pub stake_account: Account<'info, StakeAccount>,
@@ -60,6 +70,19 @@ pub struct UnstakeWsol<'info> {
     )]
     pub fee_account: Account<'info, Fee>,
 
+    /// pool's validator fee account for the validator stake_account is delegated to.
+    /// The pool's fee is charged if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in processor if initialized
+    #[account(
+        seeds = [
+            &pool_account.key().to_bytes(),
+            &stake_account_voter(&stake_account).to_bytes(),
+            VALIDATOR_FEE_SEED_SUFFIX,
+        ],
+        bump,
+    )]
+    pub validator_fee_account: UncheckedAccount<'info>,
+
     /// stake account record to be created
     /// CHECK: PDA checks address. Manually initialized and serialized in processor.
     #[account(
@@ -84,17 +107,73 @@ pub struct UnstakeWsol<'info> {
     pub protocol_fee_destination: AccountInfo<'info>,
 
     pub clock: Sysvar<'info, Clock>,
+    pub stake_history: Sysvar<'info, StakeHistory>,
     pub stake_program: Program<'info, Stake>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+
+    /// Optional. The stake account's lockup custodian.
+    /// Only used if it signed the transaction,
+    /// required to unstake a stake account whose lockup is in force
+    /// CHECK: stake_account constraint checks that this is the lockup custodian
+    pub custodian: Option<UncheckedAccount<'info>>,
+
+    /// Optional. The pool's lockup fee account,
+    /// required to unstake a stake account whose lockup is in force
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), LOCKUP_FEE_SEED_SUFFIX],
+        bump,
+    )]
+    pub lockup_fee_account: Option<Account<'info, LockupFee>>,
+
+    /// pool's pause account.
+    /// Nothing is paused for the pool if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), POOL_PAUSE_SEED_SUFFIX],
+        bump,
+        constraint = !PoolPause::is_paused(&pool_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::PoolPaused,
+    )]
+    pub pool_pause_account: UncheckedAccount<'info>,
+
+    /// protocol-wide pause account.
+    /// Nothing is paused protocol-wide if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in constraint if initialized
+    #[account(
+        seeds = [PROTOCOL_PAUSE_SEED],
+        bump,
+        constraint = !ProtocolPause::is_paused(&protocol_pause_account, PausableInstruction::Unstake)?
+            @ UnstakeError::ProtocolPaused,
+    )]
+    pub protocol_pause_account: UncheckedAccount<'info>,
+
+    /// pool's config account.
+    /// The pool has no unstake limits if it is not initialized
+    /// CHECK: PDA checks address. Deserialized and updated in run() if initialized
+    #[account(
+        mut,
+        seeds = [&pool_account.key().to_bytes(), POOL_CONFIG_SEED_SUFFIX],
+        bump,
+    )]
+    pub pool_config_account: UncheckedAccount<'info>,
+
+    /// pool's withdraw queue account.
+    /// No lamports are earmarked for withdraw tickets if it is not initialized
+    /// CHECK: PDA checks address. Deserialized in run() if initialized
+    #[account(
+        seeds = [&pool_account.key().to_bytes(), WITHDRAW_QUEUE_SEED_SUFFIX],
+        bump,
+    )]
+    pub withdraw_queue_account: UncheckedAccount<'info>,
 }
 
-impl_unstake_accounts!(UnstakeWsol, 2);
+impl_unstake_accounts!(UnstakeWsolV2, 2);
 
-impl<'info> UnstakeWsol<'info> {
+impl<'info> UnstakeWsolV2<'info> {
     #[inline(always)]
-    pub fn run(mut ctx: Context<'_, '_, '_, 'info, Self>) -> Result<()> {
-        let unstake_result = Self::run_unstake(&mut ctx)?;
+    pub fn run(mut ctx: Context<'_, '_, '_, 'info, Self>, min_lamports_out: u64) -> Result<()> {
+        let unstake_result = Self::run_unstake(&mut ctx, min_lamports_out)?;
 
         token::sync_native(CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
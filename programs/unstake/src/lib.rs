@@ -17,6 +17,7 @@ pub mod state;
 pub mod utils;
 
 use instructions::*;
+use rational::Rational;
 use state::*;
 
 #[program]
@@ -32,17 +33,106 @@ pub mod unstake {
         SetProtocolFee::run(ctx, protocol_fee)
     }
 
+    pub fn set_protocol_pause(
+        ctx: Context<SetProtocolPause>,
+        paused: PausedInstructions,
+    ) -> Result<()> {
+        SetProtocolPause::run(ctx, paused)
+    }
+
+    pub fn propose_protocol_fee(
+        ctx: Context<ProposeProtocolFee>,
+        fee_ratio: Rational,
+        referrer_fee_ratio: Rational,
+    ) -> Result<()> {
+        ProposeProtocolFee::run(ctx, fee_ratio, referrer_fee_ratio)
+    }
+
+    pub fn apply_pending_protocol_fee(ctx: Context<ApplyPendingProtocolFee>) -> Result<()> {
+        ApplyPendingProtocolFee::run(ctx)
+    }
+
+    pub fn propose_protocol_fee_authority(ctx: Context<ProposeProtocolFeeAuthority>) -> Result<()> {
+        ProposeProtocolFeeAuthority::run(ctx)
+    }
+
+    pub fn accept_protocol_fee_authority(ctx: Context<AcceptProtocolFeeAuthority>) -> Result<()> {
+        AcceptProtocolFeeAuthority::run(ctx)
+    }
+
     pub fn create_pool(ctx: Context<CreatePool>, fee: Fee) -> Result<()> {
         CreatePool::validate(&fee)?;
         CreatePool::run(ctx, fee)
     }
 
-    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
-        AddLiquidity::run(ctx, amount)
+    pub fn create_pool_with_liquidity(
+        ctx: Context<CreatePoolWithLiquidity>,
+        fee: Fee,
+        initial_lamports: u64,
+    ) -> Result<()> {
+        CreatePoolWithLiquidity::validate(&fee)?;
+        CreatePoolWithLiquidity::run(ctx, fee, initial_lamports)
+    }
+
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, _amount: u64) -> Result<()> {
+        AddLiquidity::run(ctx)
     }
 
-    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, amount_lp: u64) -> Result<()> {
-        RemoveLiquidity::run(ctx, amount_lp)
+    pub fn add_liquidity_v2(
+        ctx: Context<AddLiquidityV2>,
+        amount: u64,
+        min_lp_tokens_out: u64,
+    ) -> Result<()> {
+        AddLiquidityV2::run(ctx, amount, min_lp_tokens_out)
+    }
+
+    pub fn add_liquidity_stake<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddLiquidityStake<'info>>,
+    ) -> Result<()> {
+        AddLiquidityStake::run(ctx)
+    }
+
+    pub fn add_liquidity_wsol(
+        ctx: Context<AddLiquidityWsol>,
+        amount: u64,
+        min_lp_tokens_out: u64,
+    ) -> Result<()> {
+        AddLiquidityWsol::run(ctx, amount, min_lp_tokens_out)
+    }
+
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, _amount_lp: u64) -> Result<()> {
+        RemoveLiquidity::run(ctx)
+    }
+
+    pub fn remove_liquidity_v2(
+        ctx: Context<RemoveLiquidityV2>,
+        amount_lp: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        RemoveLiquidityV2::run(ctx, amount_lp, min_lamports_out)
+    }
+
+    pub fn remove_liquidity_in_kind(
+        ctx: Context<RemoveLiquidityInKind>,
+        lamports: u64,
+    ) -> Result<()> {
+        RemoveLiquidityInKind::run(ctx, lamports)
+    }
+
+    pub fn remove_liquidity_wsol(
+        ctx: Context<RemoveLiquidityWsol>,
+        amount_lp: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        RemoveLiquidityWsol::run(ctx, amount_lp, min_lamports_out)
+    }
+
+    pub fn request_withdrawal(ctx: Context<RequestWithdrawal>, amount_lp: u64) -> Result<()> {
+        RequestWithdrawal::run(ctx, amount_lp)
+    }
+
+    pub fn claim_withdraw_ticket(ctx: Context<ClaimWithdrawTicket>) -> Result<()> {
+        ClaimWithdrawTicket::run(ctx)
     }
 
     pub fn set_fee(ctx: Context<SetFee>, fee: Fee) -> Result<()> {
@@ -50,8 +140,56 @@ pub mod unstake {
         SetFee::run(ctx, fee)
     }
 
-    pub fn set_fee_authority(ctx: Context<SetFeeAuthority>) -> Result<()> {
-        SetFeeAuthority::run(ctx)
+    pub fn set_fee_delay(ctx: Context<SetFeeDelay>, min_delay_seconds: u64) -> Result<()> {
+        SetFeeDelay::run(ctx, min_delay_seconds)
+    }
+
+    pub fn apply_pending_fee(ctx: Context<ApplyPendingFee>) -> Result<()> {
+        ApplyPendingFee::run(ctx)
+    }
+
+    pub fn propose_fee_authority(ctx: Context<ProposeFeeAuthority>) -> Result<()> {
+        ProposeFeeAuthority::run(ctx)
+    }
+
+    pub fn accept_fee_authority(ctx: Context<AcceptFeeAuthority>) -> Result<()> {
+        AcceptFeeAuthority::run(ctx)
+    }
+
+    pub fn cancel_fee_authority_proposal(ctx: Context<CancelFeeAuthorityProposal>) -> Result<()> {
+        CancelFeeAuthorityProposal::run(ctx)
+    }
+
+    pub fn set_pause_guardian(ctx: Context<SetPauseGuardian>) -> Result<()> {
+        SetPauseGuardian::run(ctx)
+    }
+
+    pub fn set_pool_pause(ctx: Context<SetPoolPause>, paused: PausedInstructions) -> Result<()> {
+        SetPoolPause::run(ctx, paused)
+    }
+
+    pub fn set_pool_config(
+        ctx: Context<SetPoolConfig>,
+        max_unstake_lamports: Option<u64>,
+        max_epoch_incoming_stake_lamports: Option<u64>,
+        max_pool_owned_lamports: Option<u64>,
+        lp_allowlist_enabled: bool,
+    ) -> Result<()> {
+        SetPoolConfig::run(
+            ctx,
+            max_unstake_lamports,
+            max_epoch_incoming_stake_lamports,
+            max_pool_owned_lamports,
+            lp_allowlist_enabled,
+        )
+    }
+
+    pub fn add_allowed_lp(ctx: Context<AddAllowedLp>) -> Result<()> {
+        AddAllowedLp::run(ctx)
+    }
+
+    pub fn remove_allowed_lp(ctx: Context<RemoveAllowedLp>) -> Result<()> {
+        RemoveAllowedLp::run(ctx)
     }
 
     pub fn set_lp_token_metadata(
@@ -61,6 +199,10 @@ pub mod unstake {
         SetLpTokenMetadata::run(ctx, data)
     }
 
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        ClosePool::run(ctx)
+    }
+
     pub fn deactivate_stake_account(ctx: Context<DeactivateStakeAccount>) -> Result<()> {
         DeactivateStakeAccount::run(ctx)
     }
@@ -69,14 +211,76 @@ pub mod unstake {
         ReclaimStakeAccount::run(ctx)
     }
 
-    pub fn unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>) -> Result<()> {
+    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
         Unstake::run(ctx)
     }
 
-    pub fn unstake_wsol<'info>(ctx: Context<'_, '_, '_, 'info, UnstakeWsol<'info>>) -> Result<()> {
+    pub fn unstake_wsol(ctx: Context<UnstakeWsol>) -> Result<()> {
         UnstakeWsol::run(ctx)
     }
 
+    pub fn unstake_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeV2<'info>>,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        UnstakeV2::run(ctx, min_lamports_out)
+    }
+
+    pub fn unstake_wsol_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeWsolV2<'info>>,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        UnstakeWsolV2::run(ctx, min_lamports_out)
+    }
+
+    pub fn unstake_partial<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakePartial<'info>>,
+        lamports: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        UnstakePartial::run(ctx, lamports, min_lamports_out)
+    }
+
+    pub fn unstake_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeMulti<'info>>,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        UnstakeMulti::run(ctx, min_lamports_out)
+    }
+
+    pub fn set_lockup_fee(ctx: Context<SetLockupFee>, lockup_fee: LockupFee) -> Result<()> {
+        SetLockupFee::validate(&lockup_fee)?;
+        SetLockupFee::run(ctx, lockup_fee)
+    }
+
+    pub fn apply_pending_lockup_fee(ctx: Context<ApplyPendingLockupFee>) -> Result<()> {
+        ApplyPendingLockupFee::run(ctx)
+    }
+
+    pub fn set_stake_deposit_fee(
+        ctx: Context<SetStakeDepositFee>,
+        stake_deposit_fee: StakeDepositFee,
+    ) -> Result<()> {
+        SetStakeDepositFee::validate(&stake_deposit_fee)?;
+        SetStakeDepositFee::run(ctx, stake_deposit_fee)
+    }
+
+    pub fn set_validator_fee(
+        ctx: Context<SetValidatorFee>,
+        validator_fee: ValidatorFee,
+    ) -> Result<()> {
+        SetValidatorFee::validate(&validator_fee)?;
+        SetValidatorFee::run(ctx, validator_fee)
+    }
+
+    pub fn remove_validator_fee(ctx: Context<RemoveValidatorFee>) -> Result<()> {
+        RemoveValidatorFee::run(ctx)
+    }
+
+    pub fn apply_pending_validator_fee(ctx: Context<ApplyPendingValidatorFee>) -> Result<()> {
+        ApplyPendingValidatorFee::run(ctx)
+    }
+
     pub fn set_flash_loan_fee<'info>(
         ctx: Context<'_, '_, '_, 'info, SetFlashLoanFee<'info>>,
         flash_loan_fee: FlashLoanFee,
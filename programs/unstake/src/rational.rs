@@ -5,7 +5,9 @@ use spl_math::precise_number::PreciseNumber;
 use std::{convert::TryInto, fmt};
 
 /// A ratio. Denom should not = 0
-#[derive(Debug, PartialEq, Clone, Copy, AnchorSerialize, AnchorDeserialize, Deserialize)]
+#[derive(
+    Debug, Default, PartialEq, Clone, Copy, AnchorSerialize, AnchorDeserialize, Deserialize,
+)]
 pub struct Rational {
     pub num: u64,
     pub denom: u64,
@@ -24,6 +26,12 @@ impl Rational {
         self.num <= self.denom
     }
 
+    /// Both rationals must be valid
+    pub fn is_lte(&self, other: &Rational) -> bool {
+        u128::from(self.num) * u128::from(other.denom)
+            <= u128::from(other.num) * u128::from(self.denom)
+    }
+
     pub fn floor_mul(&self, value: u64) -> Option<u64> {
         u128::from(value)
             .checked_mul(self.num.into())
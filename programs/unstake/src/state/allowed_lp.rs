@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const ALLOWED_LP_SEED_SUFFIX: &[u8] = b"allowedlp";
+
+/// Marks a depositor as allowed to add liquidity to a pool
+/// whose PoolConfig has `lp_allowlist_enabled`.
+/// Located at PDA [pool_account, lp, ALLOWED_LP_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct AllowedLp {}
+
+impl AllowedLp {
+    /// The PDA is only ever initialized as an AllowedLp account,
+    /// so `allowed_lp_account` marks its depositor as allowed
+    /// if it's owned by the program
+    pub fn is_allowed(allowed_lp_account: &AccountInfo) -> bool {
+        allowed_lp_account.owner == &crate::ID
+    }
+}
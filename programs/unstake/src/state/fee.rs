@@ -9,35 +9,126 @@ use crate::{errors::UnstakeError, rational::Rational};
 #[constant]
 pub const FEE_SEED_SUFFIX: &[u8] = b"fee";
 
+/// Maximum number of breakpoints a PiecewiseLinear fee can have
+pub const PIECEWISE_LINEAR_MAX_POINTS: usize = 5;
+
 // Anchor can't derive account for enum, so wrap it in a struct
 #[account]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize)]
 pub struct Fee {
     pub fee: FeeEnum,
+
+    /// Minimum number of lamports charged as fees per unstake, if set.
+    /// Capped at the stake account's lamports
+    #[serde(default)]
+    pub min_fee_lamports: Option<u64>,
+
+    /// Maximum number of lamports charged as fees per unstake, if set
+    #[serde(default)]
+    pub max_fee_lamports: Option<u64>,
 }
 
 impl Fee {
+    /// Invariants:
+    ///  - fee is a valid FeeEnum
+    ///  - min_fee_lamports <= max_fee_lamports if both are set
     pub fn validate(&self) -> Result<()> {
-        self.fee.validate()
+        self.fee.validate()?;
+        if let (Some(min), Some(max)) = (self.min_fee_lamports, self.max_fee_lamports) {
+            if min > max {
+                return Err(UnstakeError::InvalidFee.into());
+            }
+        }
+        Ok(())
     }
 
-    /// Applies the contained fee model to the unstake parameters
+    /// Applies the contained fee model to the unstake parameters,
+    /// clamped to [min_fee_lamports, max_fee_lamports]
     /// Returns number of lamports to collect/retain as fees
-    pub fn apply(
-        &self,
-        pool_incoming_stake: u64,
-        sol_reserves_lamports: u64,
-        stake_account_lamports: u64,
-    ) -> Option<u64> {
-        self.fee.apply(
-            pool_incoming_stake,
-            sol_reserves_lamports,
-            stake_account_lamports,
-        )
+    pub fn apply(&self, args: &ApplyFeeArgs) -> Option<u64> {
+        let mut fee_lamports = self.fee.apply(args)?;
+        if let Some(min) = self.min_fee_lamports {
+            fee_lamports = fee_lamports.max(min.min(args.stake_account_lamports));
+        }
+        if let Some(max) = self.max_fee_lamports {
+            fee_lamports = fee_lamports.min(max);
+        }
+        Some(fee_lamports)
     }
 }
 
-#[derive(Debug, Clone, Copy, AnchorDeserialize, AnchorSerialize, Deserialize)]
+// used for analytics log emission
+//
+// Log Format:
+//  - no bounds set: same as FeeEnum
+//  - otherwise: "[5, FEE, min_fee_lamports, max_fee_lamports]",
+//    where FEE is the FeeEnum and unset bounds are "null"
+impl fmt::Display for Fee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.min_fee_lamports.is_none() && self.max_fee_lamports.is_none() {
+            return write!(f, "{}", self.fee);
+        }
+        write!(f, "[5, {}", self.fee)?;
+        for bound in [self.min_fee_lamports, self.max_fee_lamports] {
+            match bound {
+                Some(lamports) => write!(f, ", {}", lamports)?,
+                None => write!(f, ", null")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/// Inputs to the fee models
+#[derive(Debug, Clone, Copy)]
+pub struct ApplyFeeArgs {
+    pub pool_incoming_stake: u64,
+    pub sol_reserves_lamports: u64,
+    pub stake_account_lamports: u64,
+
+    /// Fraction of the current epoch that has elapsed,
+    /// slot index in epoch / slots in epoch
+    pub epoch_progress: Rational,
+
+    /// Delegation state of the stake account being unstaked
+    pub stake_account_state: StakeAccountState,
+}
+
+/// Delegation state of a stake account at the current epoch.
+/// Ordered from furthest to closest to being withdrawable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StakeAccountState {
+    /// Activating or active.
+    /// Needs to be deactivated and can only be reclaimed after the next epoch boundary
+    Active,
+
+    /// Deactivation already requested.
+    /// Can be reclaimed at the next epoch boundary
+    Deactivating,
+
+    /// Initialized or fully deactivated.
+    /// Can be withdrawn from immediately
+    Inactive,
+}
+
+// used for analytics log emission
+//
+// Log Format:
+//  - Active: "0"
+//  - Deactivating: "1"
+//  - Inactive: "2"
+impl fmt::Display for StakeAccountState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tag: u8 = match self {
+            StakeAccountState::Active => 0,
+            StakeAccountState::Deactivating => 1,
+            StakeAccountState::Inactive => 2,
+        };
+        write!(f, "{}", tag)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize)]
 #[repr(C)]
 pub enum FeeEnum {
     /// Charges a flat fee based on a set fee ratio
@@ -61,9 +152,48 @@ pub enum FeeEnum {
     ///  - zero_liq_remaining <= 1
     ///  - max_liq_remaining <= zero_liq_remaining
     LiquidityLinear { params: LiquidityLinearParams },
+
+    /// Charges a fee based on the pool's utilization (fraction of
+    /// pool owned lamports consumed) after a swap,
+    /// linearly interpolated between consecutive breakpoints.
+    /// Generalization of LiquidityLinear to multiple segments
+    /// so that the curve can have a kink.
+    ///
+    /// Invariants:
+    ///  - 2 <= points_len <= PIECEWISE_LINEAR_MAX_POINTS
+    ///  - all utilizations and fee_ratios of the first points_len points
+    ///    are valid Rationals <= 1
+    ///  - first point's utilization = 0
+    ///  - last point's utilization = 1
+    ///  - utilizations are strictly increasing
+    ///  - fee_ratios are non-decreasing
+    PiecewiseLinear { params: PiecewiseLinearParams },
+
+    /// Charges base_ratio at the start of an epoch,
+    /// decreasing linearly with epoch progress to
+    /// base_ratio * epoch_end_multiplier at the end of the epoch,
+    /// since unstaked stake accounts can be reclaimed sooner
+    /// the closer the epoch is to its boundary.
+    ///
+    /// Invariants:
+    ///  - base_ratio is a valid Rational
+    ///  - base_ratio <= 1
+    ///  - epoch_end_multiplier is a valid Rational
+    ///  - epoch_end_multiplier <= 1
+    EpochProgress { params: EpochProgressParams },
+
+    /// Charges a flat fee ratio that depends on the
+    /// delegation state of the stake account being unstaked,
+    /// since deactivating and inactive stake accounts
+    /// can be reclaimed sooner than active ones.
+    ///
+    /// Invariants:
+    ///  - active_ratio, deactivating_ratio, inactive_ratio are valid Rationals
+    ///  - active_ratio, deactivating_ratio, inactive_ratio <= 1
+    DelegationState { params: DelegationStateParams },
 }
 
-#[derive(Debug, Clone, Copy, AnchorDeserialize, AnchorSerialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize)]
 pub struct LiquidityLinearParams {
     /// The fee applied to a swap that leaves
     /// 100% of all liquidity in the SOL reserves account
@@ -74,6 +204,197 @@ pub struct LiquidityLinearParams {
     pub zero_liq_remaining: Rational,
 }
 
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize,
+)]
+pub struct PiecewiseLinearPoint {
+    /// Fraction of pool owned lamports consumed
+    pub utilization: Rational,
+
+    /// The fee applied to a swap that results in `utilization`
+    pub fee_ratio: Rational,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize)]
+pub struct PiecewiseLinearParams {
+    /// Breakpoints of the fee curve, in increasing order of utilization.
+    /// Only the first `points_len` entries are used, the rest are ignored.
+    pub points: [PiecewiseLinearPoint; PIECEWISE_LINEAR_MAX_POINTS],
+
+    /// Number of breakpoints in use
+    pub points_len: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize)]
+pub struct EpochProgressParams {
+    /// The fee applied to a swap at the start of an epoch
+    pub base_ratio: Rational,
+
+    /// Multiplier applied to base_ratio for a swap at the end of an epoch
+    pub epoch_end_multiplier: Rational,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AnchorDeserialize, AnchorSerialize, Deserialize)]
+pub struct DelegationStateParams {
+    /// The fee applied to a swap of an activating or active stake account
+    pub active_ratio: Rational,
+
+    /// The fee applied to a swap of a deactivating stake account
+    pub deactivating_ratio: Rational,
+
+    /// The fee applied to a swap of an initialized or fully deactivated stake account
+    pub inactive_ratio: Rational,
+}
+
+impl DelegationStateParams {
+    /// The fee ratio to charge for a stake account in the given state
+    pub fn ratio(&self, stake_account_state: StakeAccountState) -> &Rational {
+        match stake_account_state {
+            StakeAccountState::Active => &self.active_ratio,
+            StakeAccountState::Deactivating => &self.deactivating_ratio,
+            StakeAccountState::Inactive => &self.inactive_ratio,
+        }
+    }
+}
+
+impl PiecewiseLinearParams {
+    /// The breakpoints in use
+    pub fn active_points(&self) -> &[PiecewiseLinearPoint] {
+        let len = (self.points_len as usize).min(PIECEWISE_LINEAR_MAX_POINTS);
+        &self.points[..len]
+    }
+
+    fn validate(&self) -> Result<()> {
+        let points_len = self.points_len as usize;
+        if !(2..=PIECEWISE_LINEAR_MAX_POINTS).contains(&points_len) {
+            return Err(UnstakeError::InvalidFee.into());
+        }
+        let points = self.active_points();
+        if points.iter().any(|p| {
+            !p.utilization.validate()
+                || !p.utilization.is_lte_one()
+                || !p.fee_ratio.validate()
+                || !p.fee_ratio.is_lte_one()
+        }) {
+            return Err(UnstakeError::InvalidFee.into());
+        }
+        // safe to index: points_len >= 2
+        let first = &points[0];
+        let last = &points[points_len - 1];
+        if first.utilization.num != 0 || last.utilization.num != last.utilization.denom {
+            return Err(UnstakeError::InvalidFee.into());
+        }
+        for pair in points.windows(2) {
+            let prev_utilization = pair[0]
+                .utilization
+                .into_precise_number()
+                .ok_or(UnstakeError::InternalError)?;
+            let next_utilization = pair[1]
+                .utilization
+                .into_precise_number()
+                .ok_or(UnstakeError::InternalError)?;
+            let prev_fee = pair[0]
+                .fee_ratio
+                .into_precise_number()
+                .ok_or(UnstakeError::InternalError)?;
+            let next_fee = pair[1]
+                .fee_ratio
+                .into_precise_number()
+                .ok_or(UnstakeError::InternalError)?;
+            if !next_utilization.greater_than(&prev_utilization) || prev_fee.greater_than(&next_fee)
+            {
+                return Err(UnstakeError::InvalidFee.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the fee ratio to charge for the swap
+    fn fee_ratio(
+        &self,
+        pool_incoming_stake: u64,
+        sol_reserves_lamports: u64,
+        stake_account_lamports: u64,
+    ) -> Option<PreciseNumber> {
+        // Same self-consistent solve as LiquidityLinear, applied to
+        // the segment the resulting utilization falls in.
+        // let I = pool_incoming_stake, S = stake_account_lamports,
+        // O = pool owned lamports, (u_k, f_k) = k-th breakpoint,
+        // x_k = u_k * O = liquidity consumed at k-th breakpoint in lamports
+        //
+        // new liquidity consumed after unstake = I + (1 - y)S
+        // f_k * S + x_k is strictly increasing in k,
+        // so the resulting liquidity consumed lies in segment k
+        // where k is the first segment such that
+        // I + (1 - f_{k+1})S <= x_{k+1}
+        // i.e. I + S <= f_{k+1} * S + x_{k+1}
+        // If no such segment exists, utilization > 1 and
+        // the last segment is extrapolated, like LiquidityLinear.
+        //
+        // Within segment k, let n = (x_{k+1} - x_k) / (f_{k+1} - f_k) = 1/m
+        // y = f_k + (I + (1 - y)S - x_k) / n
+        // yn = f_k * n + I + S - yS - x_k
+        // y = (f_k * n + I + S - x_k) / (n + S)
+        //
+        // I + S - x_k >= 0 because k = 0 (x_0 = 0) or
+        // I + S > f_k * S + x_k since segment k - 1 was not selected
+        let owned_lamports = PreciseNumber::new(
+            (pool_incoming_stake as u128).checked_add(sol_reserves_lamports as u128)?,
+        )?;
+        let incoming_plus_stake = PreciseNumber::new(
+            (pool_incoming_stake as u128).checked_add(stake_account_lamports as u128)?,
+        )?;
+        let stake_lamports = PreciseNumber::new(stake_account_lamports as u128)?;
+
+        let points = self.active_points();
+        let last_segment = points.len().checked_sub(2)?;
+        let mut segment = last_segment;
+        for k in 0..last_segment {
+            let next = &points[k + 1];
+            let next_fee = next.fee_ratio.into_precise_number()?;
+            let next_consumed = next
+                .utilization
+                .into_precise_number()?
+                .checked_mul(&owned_lamports)?;
+            let threshold = next_fee
+                .checked_mul(&stake_lamports)?
+                .checked_add(&next_consumed)?;
+            if !incoming_plus_stake.greater_than(&threshold) {
+                segment = k;
+                break;
+            }
+        }
+
+        let start = &points[segment];
+        let end = &points[segment + 1];
+        let start_fee = start.fee_ratio.into_precise_number()?;
+        let end_fee = end.fee_ratio.into_precise_number()?;
+        let fee_delta = end_fee.checked_sub(&start_fee)?;
+        // flat segment
+        if fee_delta == PreciseNumber::new(0)? {
+            return Some(start_fee);
+        }
+        let start_consumed = start
+            .utilization
+            .into_precise_number()?
+            .checked_mul(&owned_lamports)?;
+        let end_consumed = end
+            .utilization
+            .into_precise_number()?
+            .checked_mul(&owned_lamports)?;
+        let inv_slope = end_consumed
+            .checked_sub(&start_consumed)?
+            .checked_div(&fee_delta)?;
+
+        let num = start_fee
+            .checked_mul(&inv_slope)?
+            .checked_add(&incoming_plus_stake)?
+            .checked_sub(&start_consumed)?;
+        let denom = inv_slope.checked_add(&stake_lamports)?;
+        num.checked_div(&denom)
+    }
+}
+
 impl FeeEnum {
     pub fn validate(&self) -> Result<()> {
         match self {
@@ -102,18 +423,42 @@ impl FeeEnum {
                     return Err(UnstakeError::InvalidFee.into());
                 }
             }
+            FeeEnum::PiecewiseLinear { params } => params.validate()?,
+            FeeEnum::EpochProgress { params } => {
+                if !params.base_ratio.validate()
+                    || !params.base_ratio.is_lte_one()
+                    || !params.epoch_end_multiplier.validate()
+                    || !params.epoch_end_multiplier.is_lte_one()
+                {
+                    return Err(UnstakeError::InvalidFee.into());
+                }
+            }
+            FeeEnum::DelegationState { params } => {
+                if [
+                    params.active_ratio,
+                    params.deactivating_ratio,
+                    params.inactive_ratio,
+                ]
+                .iter()
+                .any(|ratio| !ratio.validate() || !ratio.is_lte_one())
+                {
+                    return Err(UnstakeError::InvalidFee.into());
+                }
+            }
         }
 
         Ok(())
     }
 
     /// Applies swap fee to given swap amount and pool's liquidity
-    pub fn apply(
-        &self,
-        pool_incoming_stake: u64,
-        sol_reserves_lamports: u64,
-        stake_account_lamports: u64,
-    ) -> Option<u64> {
+    pub fn apply(&self, args: &ApplyFeeArgs) -> Option<u64> {
+        let ApplyFeeArgs {
+            pool_incoming_stake,
+            sol_reserves_lamports,
+            stake_account_lamports,
+            epoch_progress,
+            stake_account_state,
+        } = *args;
         let fee_ratio = match self {
             FeeEnum::Flat { ratio } => ratio.into_precise_number()?,
             FeeEnum::LiquidityLinear { params } => {
@@ -175,6 +520,26 @@ impl FeeEnum {
                     .checked_add(&PreciseNumber::new(stake_account_lamports as u128)?)?;
                 num.checked_div(&denom)?
             }
+            FeeEnum::PiecewiseLinear { params } => params.fee_ratio(
+                pool_incoming_stake,
+                sol_reserves_lamports,
+                stake_account_lamports,
+            )?,
+            FeeEnum::EpochProgress { params } => {
+                // let p = epoch_progress, b = base_ratio, k = epoch_end_multiplier
+                // y = b(1 - p) + bkp = b(1 - p(1 - k))
+                let one = PreciseNumber::new(1)?;
+                let progress = epoch_progress.into_precise_number()?;
+                let multiplier = params.epoch_end_multiplier.into_precise_number()?;
+                let discount = progress.checked_mul(&one.checked_sub(&multiplier)?)?;
+                params
+                    .base_ratio
+                    .into_precise_number()?
+                    .checked_mul(&one.checked_sub(&discount)?)?
+            }
+            FeeEnum::DelegationState { params } => {
+                params.ratio(stake_account_state).into_precise_number()?
+            }
         };
 
         PreciseNumber::new(stake_account_lamports as u128)?
@@ -190,6 +555,9 @@ impl FeeEnum {
 // Log Format:
 //  - Flat: "[0, ratio]"
 //  - LiquidityLinear: "[1, max_liq_remaining, zero_liq_remaining]"
+//  - PiecewiseLinear: "[2, [utilization_0, fee_ratio_0], [utilization_1, fee_ratio_1], ...]"
+//  - EpochProgress: "[3, base_ratio, epoch_end_multiplier]"
+//  - DelegationState: "[4, active_ratio, deactivating_ratio, inactive_ratio]"
 impl fmt::Display for FeeEnum {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -199,6 +567,402 @@ impl fmt::Display for FeeEnum {
                 "[1, {}, {}]",
                 params.max_liq_remaining, params.zero_liq_remaining
             ),
+            FeeEnum::PiecewiseLinear { params } => {
+                write!(f, "[2")?;
+                for point in params.active_points() {
+                    write!(f, ", [{}, {}]", point.utilization, point.fee_ratio)?;
+                }
+                write!(f, "]")
+            }
+            FeeEnum::EpochProgress { params } => write!(
+                f,
+                "[3, {}, {}]",
+                params.base_ratio, params.epoch_end_multiplier
+            ),
+            FeeEnum::DelegationState { params } => write!(
+                f,
+                "[4, {}, {}, {}]",
+                params.active_ratio, params.deactivating_ratio, params.inactive_ratio
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn apply_fee_args(
+        pool_incoming_stake: u64,
+        sol_reserves_lamports: u64,
+        stake_account_lamports: u64,
+    ) -> ApplyFeeArgs {
+        ApplyFeeArgs {
+            pool_incoming_stake,
+            sol_reserves_lamports,
+            stake_account_lamports,
+            epoch_progress: Rational { num: 0, denom: 1 },
+            stake_account_state: StakeAccountState::Active,
+        }
+    }
+
+    fn piecewise_linear(points: &[(Rational, Rational)]) -> FeeEnum {
+        let mut params = PiecewiseLinearParams {
+            points: Default::default(),
+            points_len: points.len() as u8,
+        };
+        for (point, (utilization, fee_ratio)) in params.points.iter_mut().zip(points) {
+            *point = PiecewiseLinearPoint {
+                utilization: *utilization,
+                fee_ratio: *fee_ratio,
+            };
+        }
+        FeeEnum::PiecewiseLinear { params }
+    }
+
+    fn ratio(num: u64, denom: u64) -> Rational {
+        Rational { num, denom }
+    }
+
+    prop_compose! {
+        fn ratio_lte_one()
+            (denom in 1..=1_000_000u64)
+            (num in 0..=denom, denom in Just(denom))
+        -> Rational {
+            Rational { num, denom }
+        }
+    }
+
+    prop_compose! {
+        /// (zero_fee, max_fee) with zero_fee <= max_fee
+        fn ordered_ratios()
+            (a in ratio_lte_one(), b in ratio_lte_one())
+        -> (Rational, Rational) {
+            let a_precise = a.into_precise_number().unwrap();
+            let b_precise = b.into_precise_number().unwrap();
+            match a_precise.greater_than(&b_precise) {
+                true => (b, a),
+                false => (a, b),
+            }
+        }
+    }
+
+    prop_compose! {
+        /// (pool_incoming_stake, sol_reserves_lamports, stake_account_lamports)
+        /// where the unstake does not consume more than the pool's liquidity
+        fn pool_within_liquidity()
+            (sol_reserves_lamports in 1..=1_000_000_000_000_000u64)
+            (
+                sol_reserves_lamports in Just(sol_reserves_lamports),
+                pool_incoming_stake in 0..=1_000_000_000_000_000u64,
+                stake_account_lamports in 1..=sol_reserves_lamports,
+            )
+        -> (u64, u64, u64) {
+            (pool_incoming_stake, sol_reserves_lamports, stake_account_lamports)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_piecewise_linear_two_points_matches_liquidity_linear(
+            (max_liq_remaining, zero_liq_remaining) in ordered_ratios(),
+            (pool_incoming_stake, sol_reserves_lamports, stake_account_lamports) in pool_within_liquidity(),
+        ) {
+            prop_assume!(max_liq_remaining != zero_liq_remaining);
+            let args = apply_fee_args(pool_incoming_stake, sol_reserves_lamports, stake_account_lamports);
+            let liquidity_linear = FeeEnum::LiquidityLinear {
+                params: LiquidityLinearParams { max_liq_remaining, zero_liq_remaining },
+            }
+            .apply(&args)
+            .unwrap();
+            let piecewise = piecewise_linear(&[
+                (ratio(0, 1), max_liq_remaining),
+                (ratio(1, 1), zero_liq_remaining),
+            ])
+            .apply(&args)
+            .unwrap();
+            // both are ceiled from slightly different intermediate precise values
+            prop_assert!(liquidity_linear.abs_diff(piecewise) <= 1);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_piecewise_linear_bounded_by_first_and_last_fee(
+            (first_fee, kink_fee) in ordered_ratios(),
+            last_fee_num in 0..=1_000_000u64,
+            (pool_incoming_stake, sol_reserves_lamports, stake_account_lamports) in pool_within_liquidity(),
+        ) {
+            // last fee between kink_fee and 1
+            let last_fee = Rational {
+                num: kink_fee.num + (kink_fee.denom - kink_fee.num) * last_fee_num / 1_000_000,
+                denom: kink_fee.denom,
+            };
+            let fee = piecewise_linear(&[
+                (ratio(0, 1), first_fee),
+                (ratio(4, 5), kink_fee),
+                (ratio(1, 1), last_fee),
+            ]);
+            fee.validate().unwrap();
+            let fee_lamports = fee
+                .apply(&apply_fee_args(pool_incoming_stake, sol_reserves_lamports, stake_account_lamports))
+                .unwrap();
+            let lower = first_fee.floor_mul(stake_account_lamports).unwrap();
+            let upper = last_fee.ceil_mul(stake_account_lamports).unwrap();
+            prop_assert!(lower <= fee_lamports && fee_lamports <= upper + 1);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_piecewise_linear_utilization_at_breakpoint(
+            half_owned_lamports in 1..=1_000_000_000_000_000u64,
+            incoming_share in 0..=1_000_000u64,
+        ) {
+            // free up to half utilization, then charges up to 10%
+            let fee = piecewise_linear(&[
+                (ratio(0, 1), ratio(0, 1)),
+                (ratio(1, 2), ratio(0, 1)),
+                (ratio(1, 1), ratio(1, 10)),
+            ]);
+            fee.validate().unwrap();
+            let owned_lamports = half_owned_lamports * 2;
+            let pool_incoming_stake = half_owned_lamports / 1_000_000 * incoming_share;
+            let sol_reserves_lamports = owned_lamports - pool_incoming_stake;
+            // unstake ends exactly at the breakpoint
+            let stake_account_lamports = half_owned_lamports - pool_incoming_stake;
+            prop_assume!(stake_account_lamports > 0);
+
+            let at_breakpoint = fee
+                .apply(&apply_fee_args(pool_incoming_stake, sol_reserves_lamports, stake_account_lamports))
+                .unwrap();
+            prop_assert_eq!(at_breakpoint, 0);
+
+            // overshoot by enough for the fee ratio to be representable in PreciseNumber's precision
+            let overshoot = half_owned_lamports / 1_000 + 1;
+            let past_breakpoint = fee
+                .apply(&apply_fee_args(pool_incoming_stake, sol_reserves_lamports, stake_account_lamports + overshoot))
+                .unwrap();
+            prop_assert!(past_breakpoint > 0);
+        }
+    }
+
+    prop_compose! {
+        /// (slot_index, slots_in_epoch)
+        fn epoch_slot()
+            (slots_in_epoch in 32..=432_000u64)
+            (slot_index in 0..slots_in_epoch, slots_in_epoch in Just(slots_in_epoch))
+        -> (u64, u64) {
+            (slot_index, slots_in_epoch)
+        }
+    }
+
+    /// PreciseNumber only has 12 decimal places of precision,
+    /// so fee ratios are off by up to a few parts in 10^12
+    fn precision_tolerance(lamports: u64) -> u64 {
+        lamports / 100_000_000_000 + 1
+    }
+
+    fn epoch_progress_fee_lamports(
+        params: EpochProgressParams,
+        slot_index: u64,
+        slots_in_epoch: u64,
+        stake_account_lamports: u64,
+    ) -> u64 {
+        let args = ApplyFeeArgs {
+            epoch_progress: ratio(slot_index, slots_in_epoch),
+            ..apply_fee_args(0, stake_account_lamports, stake_account_lamports)
+        };
+        FeeEnum::EpochProgress { params }.apply(&args).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_epoch_progress_first_slot_charges_base_ratio(
+            base_ratio in ratio_lte_one(),
+            epoch_end_multiplier in ratio_lte_one(),
+            slots_in_epoch in 32..=432_000u64,
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+        ) {
+            let fee_lamports = epoch_progress_fee_lamports(
+                EpochProgressParams { base_ratio, epoch_end_multiplier },
+                0,
+                slots_in_epoch,
+                stake_account_lamports,
+            );
+            let expected = base_ratio.ceil_mul(stake_account_lamports).unwrap();
+            prop_assert!(fee_lamports.abs_diff(expected) <= precision_tolerance(stake_account_lamports));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_epoch_progress_last_slot_approaches_end_multiplier(
+            base_ratio in ratio_lte_one(),
+            epoch_end_multiplier in ratio_lte_one(),
+            slots_in_epoch in 32..=432_000u64,
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+        ) {
+            let params = EpochProgressParams { base_ratio, epoch_end_multiplier };
+            let last_slot = epoch_progress_fee_lamports(
+                params,
+                slots_in_epoch - 1,
+                slots_in_epoch,
+                stake_account_lamports,
+            );
+            let epoch_end = epoch_progress_fee_lamports(
+                params,
+                slots_in_epoch,
+                slots_in_epoch,
+                stake_account_lamports,
+            );
+            let base = base_ratio.ceil_mul(stake_account_lamports).unwrap();
+            // y = b(1 - p(1 - k)), so the fee at p = 1 is b * k
+            let base_times_multiplier = u128::from(stake_account_lamports)
+                * u128::from(base_ratio.num)
+                * u128::from(epoch_end_multiplier.num)
+                / (u128::from(base_ratio.denom) * u128::from(epoch_end_multiplier.denom));
+            let tolerance = precision_tolerance(stake_account_lamports);
+            prop_assert!(u128::from(epoch_end).abs_diff(base_times_multiplier) <= u128::from(tolerance));
+            prop_assert!(epoch_end <= last_slot && last_slot <= base + tolerance);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_epoch_progress_non_increasing_with_progress(
+            base_ratio in ratio_lte_one(),
+            epoch_end_multiplier in ratio_lte_one(),
+            (slot_index, slots_in_epoch) in epoch_slot(),
+            later_slots in 0..=432_000u64,
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+        ) {
+            let params = EpochProgressParams { base_ratio, epoch_end_multiplier };
+            let later_slot_index = (slot_index + later_slots).min(slots_in_epoch - 1);
+            let earlier = epoch_progress_fee_lamports(params, slot_index, slots_in_epoch, stake_account_lamports);
+            let later = epoch_progress_fee_lamports(params, later_slot_index, slots_in_epoch, stake_account_lamports);
+            prop_assert!(later <= earlier);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_delegation_state_charges_ratio_of_state(
+            active_ratio in ratio_lte_one(),
+            deactivating_ratio in ratio_lte_one(),
+            inactive_ratio in ratio_lte_one(),
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+        ) {
+            let fee = FeeEnum::DelegationState {
+                params: DelegationStateParams { active_ratio, deactivating_ratio, inactive_ratio },
+            };
+            for (stake_account_state, state_ratio) in [
+                (StakeAccountState::Active, active_ratio),
+                (StakeAccountState::Deactivating, deactivating_ratio),
+                (StakeAccountState::Inactive, inactive_ratio),
+            ] {
+                let args = ApplyFeeArgs {
+                    stake_account_state,
+                    ..apply_fee_args(0, stake_account_lamports, stake_account_lamports)
+                };
+                let fee_lamports = fee.apply(&args).unwrap();
+                let expected = state_ratio.ceil_mul(stake_account_lamports).unwrap();
+                prop_assert!(fee_lamports.abs_diff(expected) <= precision_tolerance(stake_account_lamports));
+            }
+        }
+    }
+
+    fn flat_fee_with_bounds(
+        ratio: Rational,
+        min_fee_lamports: Option<u64>,
+        max_fee_lamports: Option<u64>,
+    ) -> Fee {
+        Fee {
+            fee: FeeEnum::Flat { ratio },
+            min_fee_lamports,
+            max_fee_lamports,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_fee_clamping_orderings(
+            ratio in ratio_lte_one(),
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+            min_offset in 1..=1_000_000_000u64,
+            max_offset in 1..=1_000_000_000u64,
+        ) {
+            let args = apply_fee_args(0, stake_account_lamports, stake_account_lamports);
+            let computed = FeeEnum::Flat { ratio }.apply(&args).unwrap();
+
+            // min <= computed <= max: unchanged
+            let fee = flat_fee_with_bounds(
+                ratio,
+                Some(computed.saturating_sub(min_offset)),
+                Some(computed.saturating_add(max_offset)),
+            );
+            prop_assert!(fee.validate().is_ok());
+            prop_assert_eq!(fee.apply(&args).unwrap(), computed);
+
+            // computed < min <= max: raised to min, capped at stake account lamports
+            let min = computed.saturating_add(min_offset);
+            let fee = flat_fee_with_bounds(ratio, Some(min), Some(min.saturating_add(max_offset)));
+            prop_assert!(fee.validate().is_ok());
+            prop_assert_eq!(fee.apply(&args).unwrap(), min.min(stake_account_lamports));
+
+            // min <= max < computed: lowered to max
+            prop_assume!(computed > 0);
+            let max = computed - 1;
+            let fee = flat_fee_with_bounds(ratio, Some(max.saturating_sub(min_offset)), Some(max));
+            prop_assert!(fee.validate().is_ok());
+            prop_assert_eq!(fee.apply(&args).unwrap(), max);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_fee_min_above_stake_account_lamports_charges_whole_stake(
+            ratio in ratio_lte_one(),
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+            min_excess in 1..=1_000_000_000_000u64,
+        ) {
+            let args = apply_fee_args(0, stake_account_lamports, stake_account_lamports);
+            let fee = flat_fee_with_bounds(ratio, Some(stake_account_lamports + min_excess), None);
+            prop_assert_eq!(fee.apply(&args).unwrap(), stake_account_lamports);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_fee_min_above_max_is_invalid(
+            ratio in ratio_lte_one(),
+            max in 0..=1_000_000_000_000_000u64,
+            min_excess in 1..=1_000_000_000_000u64,
+        ) {
+            // min > computed > max can never be configured
+            let fee = flat_fee_with_bounds(ratio, Some(max + min_excess), Some(max));
+            prop_assert!(fee.validate().is_err());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_fee_clamped_never_exceeds_max_or_stake(
+            ratio in ratio_lte_one(),
+            stake_account_lamports in 1..=1_000_000_000_000_000u64,
+            min_fee_lamports in proptest::option::of(0..=1_000_000_000_000_000u64),
+            max_fee_lamports in proptest::option::of(0..=1_000_000_000_000_000u64),
+        ) {
+            let fee = flat_fee_with_bounds(ratio, min_fee_lamports, max_fee_lamports);
+            prop_assume!(fee.validate().is_ok());
+            let args = apply_fee_args(0, stake_account_lamports, stake_account_lamports);
+            let fee_lamports = fee.apply(&args).unwrap();
+            prop_assert!(fee_lamports <= stake_account_lamports);
+            if let Some(max) = max_fee_lamports {
+                prop_assert!(fee_lamports <= max);
+            }
         }
     }
 }
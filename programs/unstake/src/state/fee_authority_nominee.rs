@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const FEE_AUTHORITY_NOMINEE_SEED_SUFFIX: &[u8] = b"feeauthoritynominee";
+
+/// The pubkey proposed by a pool's fee authority to take over as the pool's fee authority.
+/// Only takes over once it accepts by signing `AcceptFeeAuthority`
+#[account]
+#[derive(Debug)]
+pub struct FeeAuthorityNominee {
+    pub nominee: Pubkey,
+
+    /// The fee authority that proposed nominee.
+    /// The nomination can only be accepted while it is still the pool's fee authority
+    pub proposer: Pubkey,
+}
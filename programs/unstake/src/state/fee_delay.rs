@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+
+use anchor_lang::prelude::*;
+
+use crate::errors::UnstakeError;
+
+#[constant]
+pub const FEE_DELAY_SEED_SUFFIX: &[u8] = b"feedelay";
+
+/// Maximum `min_delay_seconds`, 30 days.
+/// Bounded so that fee changes can always be scheduled
+#[constant]
+pub const MAX_FEE_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Minimum delay between a pool's fee change being queued and it taking effect.
+/// Pools without one apply fee changes immediately.
+/// Located at PDA [pool_account, FEE_DELAY_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct FeeDelay {
+    /// Minimum number of seconds between `SetFee` and `ApplyPendingFee`.
+    /// Can only be increased, so that a compromised fee authority
+    /// cannot shorten it to change fees without notice
+    pub min_delay_seconds: u64,
+}
+
+impl FeeDelay {
+    /// `fee_delay_account` may not be initialized,
+    /// in which case the pool has no fee delay
+    pub fn min_delay_seconds(fee_delay_account: &AccountInfo) -> Result<u64> {
+        if fee_delay_account.owner != &crate::ID {
+            return Ok(0);
+        }
+        let fee_delay: Account<FeeDelay> = Account::try_from(fee_delay_account)?;
+        Ok(fee_delay.min_delay_seconds)
+    }
+
+    /// Unix timestamp from which a fee change queued now can be applied
+    pub fn activation_timestamp(min_delay_seconds: u64, clock: &Clock) -> Result<i64> {
+        let delay = i64::try_from(min_delay_seconds).map_err(|_| UnstakeError::InternalError)?;
+        clock
+            .unix_timestamp
+            .checked_add(delay)
+            .ok_or_else(|| UnstakeError::InternalError.into())
+    }
+}
@@ -0,0 +1,24 @@
+//! An LP token account PDA owned by the pool's SOL reserves.
+//! Holds the initial LP tokens of pools created with CreatePoolWithLiquidity
+//! without an LP token account to mint them to. The pool never transfers
+//! them, so they are locked until ClosePool burns them
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+
+#[constant]
+pub const LOCKED_LP_SEED_SUFFIX: &[u8] = b"lockedlp";
+
+pub struct LockedLp;
+
+impl LockedLp {
+    /// `locked_lp_token_account` may not be initialized,
+    /// in which case no LP tokens are locked
+    pub fn amount(locked_lp_token_account: &AccountInfo) -> Result<u64> {
+        if locked_lp_token_account.owner != &token::ID {
+            return Ok(0);
+        }
+        let locked_lp: Account<TokenAccount> = Account::try_from(locked_lp_token_account)?;
+        Ok(locked_lp.amount)
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::UnstakeError, rational::Rational};
+
+#[constant]
+pub const LOCKUP_FEE_SEED_SUFFIX: &[u8] = b"lockupfee";
+
+#[account]
+pub struct LockupFee {
+    /// The proportion of a stake account's lamports that is levied as fees,
+    /// on top of the pool's fee, when unstaking a stake account whose
+    /// lockup is in force with the custodian's signature
+    pub fee_ratio: Rational,
+}
+
+impl LockupFee {
+    pub fn validate(&self) -> Result<()> {
+        match self.fee_ratio.validate() {
+            true => Ok(()),
+            false => Err(UnstakeError::InvalidFee.into()),
+        }
+    }
+
+    pub fn apply(&self, stake_account_lamports: u64) -> Option<u64> {
+        self.fee_ratio.ceil_mul(stake_account_lamports)
+    }
+}
@@ -1,13 +1,47 @@
+mod allowed_lp;
 mod fee;
+mod fee_authority_nominee;
+mod fee_delay;
 mod flash_account;
 mod flash_loan_fee;
+mod locked_lp;
+mod lockup_fee;
+mod pending_fee;
+mod pending_lockup_fee;
+mod pending_protocol_fee;
+mod pending_validator_fee;
 mod pool;
+mod pool_config;
+mod pool_pause;
 mod protocol_fee;
+mod protocol_fee_authority_nominee;
+mod protocol_pause;
 mod stake_account_record;
+mod stake_deposit_fee;
+mod validator_fee;
+mod withdraw_ticket;
+mod wsol_staging;
 
+pub use allowed_lp::*;
 pub use fee::*;
+pub use fee_authority_nominee::*;
+pub use fee_delay::*;
 pub use flash_account::*;
 pub use flash_loan_fee::*;
+pub use locked_lp::*;
+pub use lockup_fee::*;
+pub use pending_fee::*;
+pub use pending_lockup_fee::*;
+pub use pending_protocol_fee::*;
+pub use pending_validator_fee::*;
 pub use pool::*;
+pub use pool_config::*;
+pub use pool_pause::*;
 pub use protocol_fee::*;
+pub use protocol_fee_authority_nominee::*;
+pub use protocol_pause::*;
 pub use stake_account_record::*;
+pub use stake_deposit_fee::*;
+pub use validator_fee::*;
+pub use withdraw_ticket::*;
+pub use wsol_staging::*;
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Fee;
+
+#[constant]
+pub const PENDING_FEE_SEED_SUFFIX: &[u8] = b"pendingfee";
+
+/// A fee change queued by `SetFee` for a pool with a `FeeDelay`,
+/// committed to the pool's fee account by `ApplyPendingFee`.
+/// Located at PDA [pool_account, PENDING_FEE_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct PendingFee {
+    /// The new fee
+    pub fee: Fee,
+
+    /// Unix timestamp from which the new fee can be applied
+    pub activation_timestamp: i64,
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::LockupFee;
+
+#[constant]
+pub const PENDING_LOCKUP_FEE_SEED_SUFFIX: &[u8] = b"pendinglockupfee";
+
+/// A lockup fee change queued by `SetLockupFee` for a pool with a `FeeDelay`,
+/// committed to the pool's lockup fee account by `ApplyPendingLockupFee`.
+/// Located at PDA [pool_account, PENDING_LOCKUP_FEE_SEED_SUFFIX]
+#[account]
+pub struct PendingLockupFee {
+    /// The new lockup fee
+    pub lockup_fee: LockupFee,
+
+    /// Unix timestamp from which the new lockup fee can be applied
+    pub activation_timestamp: i64,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{rational::Rational, state::validate_protocol_fee_ratios};
+
+#[constant]
+pub const PENDING_PROTOCOL_FEE_SEED: &[u8] = b"pending-protocol-fee";
+
+/// New protocol fee ratios proposed by the protocol fee authority,
+/// committed to the global protocol fee account by `ApplyPendingProtocolFee`
+/// once PROTOCOL_FEE_DELAY_SECONDS has passed
+#[account]
+#[derive(Debug)]
+pub struct PendingProtocolFee {
+    /// The new proportion of unstake fees that go to the protocol
+    pub fee_ratio: Rational,
+
+    /// The new proportion of the protocol fees that go to the referrer
+    pub referrer_fee_ratio: Rational,
+
+    /// Unix timestamp from which the new ratios can be applied
+    pub activation_timestamp: i64,
+}
+
+impl PendingProtocolFee {
+    pub fn validate(&self) -> Result<()> {
+        validate_protocol_fee_ratios(&self.fee_ratio, &self.referrer_fee_ratio)
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ValidatorFeeEnum;
+
+#[constant]
+pub const PENDING_VALIDATOR_FEE_SEED_SUFFIX: &[u8] = b"pendingvalidatorfee";
+
+/// A validator fee change queued by `SetValidatorFee` or `RemoveValidatorFee`
+/// for a pool with a `FeeDelay`, committed to the validator's ValidatorFee account
+/// by `ApplyPendingValidatorFee`.
+/// Located at PDA [pool_account, vote_account, PENDING_VALIDATOR_FEE_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct PendingValidatorFee {
+    /// The new validator fee, or None if the ValidatorFee account is to be removed
+    pub validator_fee: Option<ValidatorFeeEnum>,
+
+    /// Unix timestamp from which the new validator fee can be applied
+    pub activation_timestamp: i64,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::UnstakeError;
+
+#[constant]
+pub const POOL_CONFIG_SEED_SUFFIX: &[u8] = b"config";
+
+/// Limits on the stake and liquidity a pool takes in.
+/// Located at PDA [pool_account, POOL_CONFIG_SEED_SUFFIX].
+/// Pools without one have no limits other than their liquidity
+#[account]
+#[derive(Debug)]
+pub struct PoolConfig {
+    /// Maximum lamports that can be unstaked in a single instruction.
+    /// No maximum if None
+    pub max_unstake_lamports: Option<u64>,
+
+    /// Maximum lamports that can be added to the pool's incoming_stake per epoch.
+    /// No maximum if None
+    pub max_epoch_incoming_stake_lamports: Option<u64>,
+
+    /// Epoch that `epoch_incoming_stake_lamports` was last updated in
+    pub epoch: u64,
+
+    /// Lamports added to the pool's incoming_stake in `epoch`
+    pub epoch_incoming_stake_lamports: u64,
+
+    /// Maximum lamports the pool can own after adding liquidity.
+    /// No maximum if None
+    pub max_pool_owned_lamports: Option<u64>,
+
+    /// If true, only depositors with an AllowedLp account can add liquidity
+    pub lp_allowlist_enabled: bool,
+}
+
+impl PoolConfig {
+    /// Records an unstake of `unstake_lamports`, of which `incoming_stake_lamports`
+    /// are added to the pool's incoming_stake, rolling the per-epoch count over to
+    /// `current_epoch` if required.
+    ///
+    /// Fails with UnstakeAboveMaximum or EpochIncomingStakeCapExceeded
+    /// if the unstake exceeds the limits
+    pub fn record_unstake(
+        &mut self,
+        current_epoch: u64,
+        unstake_lamports: u64,
+        incoming_stake_lamports: u64,
+    ) -> Result<()> {
+        if let Some(max) = self.max_unstake_lamports {
+            if unstake_lamports > max {
+                return Err(UnstakeError::UnstakeAboveMaximum.into());
+            }
+        }
+        if self.epoch != current_epoch {
+            self.epoch = current_epoch;
+            self.epoch_incoming_stake_lamports = 0;
+        }
+        self.epoch_incoming_stake_lamports = self
+            .epoch_incoming_stake_lamports
+            .checked_add(incoming_stake_lamports)
+            .ok_or(UnstakeError::InternalError)?;
+        if let Some(max) = self.max_epoch_incoming_stake_lamports {
+            if self.epoch_incoming_stake_lamports > max {
+                return Err(UnstakeError::EpochIncomingStakeCapExceeded.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails with LpNotAllowed if the allowlist is enabled and the depositor is not on it,
+    /// or DepositCapExceeded if adding `amount` to `pool_owned_lamports` exceeds the cap
+    pub fn validate_add_liquidity(
+        &self,
+        pool_owned_lamports: u64,
+        amount: u64,
+        is_allowed_lp: bool,
+    ) -> Result<()> {
+        if self.lp_allowlist_enabled && !is_allowed_lp {
+            return Err(UnstakeError::LpNotAllowed.into());
+        }
+        if let Some(max) = self.max_pool_owned_lamports {
+            let new_pool_owned_lamports = pool_owned_lamports
+                .checked_add(amount)
+                .ok_or(UnstakeError::InternalError)?;
+            if new_pool_owned_lamports > max {
+                return Err(UnstakeError::DepositCapExceeded.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// `pool_config_account` may not be initialized,
+    /// in which case there are no limits to enforce
+    pub fn record_unstake_if_initialized(
+        pool_config_account: &AccountInfo,
+        current_epoch: u64,
+        unstake_lamports: u64,
+        incoming_stake_lamports: u64,
+    ) -> Result<()> {
+        if pool_config_account.owner != &crate::ID {
+            return Ok(());
+        }
+        let mut pool_config: Account<PoolConfig> = Account::try_from(pool_config_account)?;
+        pool_config.record_unstake(current_epoch, unstake_lamports, incoming_stake_lamports)?;
+        pool_config.exit(&crate::ID)
+    }
+}
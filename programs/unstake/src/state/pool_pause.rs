@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const POOL_PAUSE_SEED_SUFFIX: &[u8] = b"pause";
+
+/// Instruction classes of a pool that can be paused.
+/// Located at PDA [pool_account, POOL_PAUSE_SEED_SUFFIX].
+/// Nothing is paused for pools without one
+#[account]
+#[derive(Debug)]
+pub struct PoolPause {
+    /// Signer that, in addition to the pool's fee authority,
+    /// is authorized to pause and unpause the pool
+    pub guardian: Pubkey,
+
+    pub paused: PausedInstructions,
+}
+
+/// Whether each pausable instruction class is paused.
+/// `remove_liquidity` can never be paused so that LPs can always withdraw
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+pub struct PausedInstructions {
+    /// `unstake`, `unstake_wsol` and their variants
+    pub unstake: bool,
+
+    /// `take_flash_loan`
+    pub take_flash_loan: bool,
+
+    /// `add_liquidity`
+    pub add_liquidity: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PausableInstruction {
+    Unstake,
+    TakeFlashLoan,
+    AddLiquidity,
+}
+
+impl PausedInstructions {
+    pub fn is_paused(&self, ix: PausableInstruction) -> bool {
+        match ix {
+            PausableInstruction::Unstake => self.unstake,
+            PausableInstruction::TakeFlashLoan => self.take_flash_loan,
+            PausableInstruction::AddLiquidity => self.add_liquidity,
+        }
+    }
+}
+
+impl PoolPause {
+    /// `pool_pause_account` may not be initialized,
+    /// in which case nothing is paused
+    pub fn is_paused(pool_pause_account: &AccountInfo, ix: PausableInstruction) -> Result<bool> {
+        if pool_pause_account.owner != &crate::ID {
+            return Ok(false);
+        }
+        let pool_pause: Account<PoolPause> = Account::try_from(pool_pause_account)?;
+        Ok(pool_pause.paused.is_paused(ix))
+    }
+}
@@ -7,6 +7,9 @@ use crate::{errors::UnstakeError, rational::Rational};
 #[constant]
 pub const PROTOCOL_FEE_SEED: &[u8] = b"protocol-fee";
 
+/// Minimum delay between new protocol fee ratios being proposed and them taking effect
+pub const PROTOCOL_FEE_DELAY_SECONDS: i64 = 3 * 24 * 60 * 60;
+
 /// Global singleton containing protocol fee parameters
 #[account]
 #[derive(Debug)]
@@ -63,15 +66,7 @@ impl Default for ProtocolFee {
 
 impl ProtocolFee {
     pub fn validate(&self) -> Result<()> {
-        if !self.fee_ratio.validate()
-            || !self.fee_ratio.is_lte_one()
-            || !self.referrer_fee_ratio.validate()
-            || !self.referrer_fee_ratio.is_lte_one()
-        {
-            return Err(UnstakeError::InvalidFee.into());
-        }
-
-        Ok(())
+        validate_protocol_fee_ratios(&self.fee_ratio, &self.referrer_fee_ratio)
     }
 
     /// Applies the protocol fee on a given fee amount
@@ -96,3 +91,18 @@ impl ProtocolFee {
         self.referrer_fee_ratio.floor_mul(protocol_fee_lamports)
     }
 }
+
+pub fn validate_protocol_fee_ratios(
+    fee_ratio: &Rational,
+    referrer_fee_ratio: &Rational,
+) -> Result<()> {
+    if !fee_ratio.validate()
+        || !fee_ratio.is_lte_one()
+        || !referrer_fee_ratio.validate()
+        || !referrer_fee_ratio.is_lte_one()
+    {
+        return Err(UnstakeError::InvalidFee.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const PROTOCOL_FEE_AUTHORITY_NOMINEE_SEED: &[u8] = b"protocol-fee-authority-nominee";
+
+/// The pubkey proposed by the protocol fee authority to take over as protocol fee authority.
+/// Only takes over once it accepts by signing `AcceptProtocolFeeAuthority`
+#[account]
+#[derive(Debug)]
+pub struct ProtocolFeeAuthorityNominee {
+    pub nominee: Pubkey,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{PausableInstruction, PausedInstructions};
+
+#[constant]
+pub const PROTOCOL_PAUSE_SEED: &[u8] = b"protocol-pause";
+
+/// Global singleton for instruction classes paused across all pools,
+/// managed by the protocol fee authority.
+/// Nothing is paused protocol-wide if it does not exist
+#[account]
+#[derive(Debug)]
+pub struct ProtocolPause {
+    pub paused: PausedInstructions,
+}
+
+impl ProtocolPause {
+    /// `protocol_pause_account` may not be initialized,
+    /// in which case nothing is paused
+    pub fn is_paused(
+        protocol_pause_account: &AccountInfo,
+        ix: PausableInstruction,
+    ) -> Result<bool> {
+        if protocol_pause_account.owner != &crate::ID {
+            return Ok(false);
+        }
+        let protocol_pause: Account<ProtocolPause> = Account::try_from(protocol_pause_account)?;
+        Ok(protocol_pause.paused.is_paused(ix))
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::UnstakeError, rational::Rational};
+
+#[constant]
+pub const STAKE_DEPOSIT_FEE_SEED_SUFFIX: &[u8] = b"stakedepositfee";
+
+/// Discount applied to stake accounts deposited with AddLiquidityStake.
+/// Located at PDA [pool_account, STAKE_DEPOSIT_FEE_SEED_SUFFIX].
+/// Stake accounts are only charged the unstake fee for pools without one
+#[account]
+pub struct StakeDepositFee {
+    /// The proportion of a deposited stake account's lamports that is not
+    /// credited to the depositor, accruing to the pool's existing LPs instead
+    pub fee_ratio: Rational,
+}
+
+impl StakeDepositFee {
+    /// Fails with InvalidFee if the fee ratio is invalid or greater than 1
+    pub fn validate(&self) -> Result<()> {
+        match self.fee_ratio.validate() && self.fee_ratio.is_lte_one() {
+            true => Ok(()),
+            false => Err(UnstakeError::InvalidFee.into()),
+        }
+    }
+
+    pub fn apply(&self, stake_account_lamports: u64) -> Option<u64> {
+        self.fee_ratio.ceil_mul(stake_account_lamports)
+    }
+
+    /// `stake_deposit_fee_account` may not be initialized,
+    /// in which case no fee is charged
+    pub fn fee_lamports(
+        stake_deposit_fee_account: &AccountInfo,
+        stake_account_lamports: u64,
+    ) -> Result<u64> {
+        if stake_deposit_fee_account.owner != &crate::ID {
+            return Ok(0);
+        }
+        let stake_deposit_fee: Account<StakeDepositFee> =
+            Account::try_from(stake_deposit_fee_account)?;
+        stake_deposit_fee
+            .apply(stake_account_lamports)
+            .ok_or_else(|| UnstakeError::InternalError.into())
+    }
+}
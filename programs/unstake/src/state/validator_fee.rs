@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FeeEnum;
+
+#[constant]
+pub const VALIDATOR_FEE_SEED_SUFFIX: &[u8] = b"validatorfee";
+
+/// Pool-specific treatment of stake delegated to a validator.
+/// Located at PDA [pool_account, vote_account, VALIDATOR_FEE_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct ValidatorFee {
+    pub fee: ValidatorFeeEnum,
+}
+
+#[derive(Debug, Clone, Copy, AnchorDeserialize, AnchorSerialize)]
+pub enum ValidatorFeeEnum {
+    /// Stake accounts delegated to the validator are charged
+    /// this fee instead of the pool's fee
+    Override { fee: FeeEnum },
+
+    /// Stake accounts delegated to the validator cannot be unstaked
+    Deny,
+}
+
+impl ValidatorFee {
+    pub fn validate(&self) -> Result<()> {
+        match &self.fee {
+            ValidatorFeeEnum::Override { fee } => fee.validate(),
+            ValidatorFeeEnum::Deny => Ok(()),
+        }
+    }
+}
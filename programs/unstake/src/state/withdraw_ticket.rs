@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const WITHDRAW_QUEUE_SEED_SUFFIX: &[u8] = b"withdrawqueue";
+
+#[constant]
+pub const WITHDRAW_TICKET_SEED_SUFFIX: &[u8] = b"withdrawticket";
+
+/// Tracks the SOL in the pool's reserves that is owed to its outstanding WithdrawTickets.
+/// Earmarked SOL is no longer owned by the pool's LPs and cannot be used to service unstakes.
+/// Located at PDA [pool_account, WITHDRAW_QUEUE_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct WithdrawQueue {
+    /// Total lamports owed to the pool's outstanding WithdrawTickets
+    pub earmarked_lamports: u64,
+}
+
+impl WithdrawQueue {
+    /// `withdraw_queue_account` may not be initialized,
+    /// in which case no lamports are earmarked
+    pub fn earmarked_lamports(withdraw_queue_account: &AccountInfo) -> Result<u64> {
+        if withdraw_queue_account.owner != &crate::ID {
+            return Ok(0);
+        }
+        let withdraw_queue: Account<WithdrawQueue> = Account::try_from(withdraw_queue_account)?;
+        Ok(withdraw_queue.earmarked_lamports)
+    }
+}
+
+/// LP tokens burnt for SOL that the owner can claim
+/// once the pool's reserves can cover it.
+/// Located at PDA [pool_account, owner, WITHDRAW_TICKET_SEED_SUFFIX]
+#[account]
+#[derive(Debug)]
+pub struct WithdrawTicket {
+    /// lamports locked in at the time the LP tokens were burnt
+    pub lamports: u64,
+}
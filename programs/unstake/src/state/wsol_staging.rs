@@ -0,0 +1,8 @@
+//! A transient wSOL token account PDA owned by the pool's SOL reserves.
+//! Created and closed within AddLiquidityWsol to unwrap the deposited wSOL
+//! into the pool's SOL reserves
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const WSOL_STAGING_SEED_SUFFIX: &[u8] = b"wsolstaging";
@@ -1,14 +1,24 @@
+use std::convert::TryFrom;
+
 use anchor_lang::{
     prelude::{
-        Account, AccountInfo, CpiContext, Pubkey, Rent, Result, SolanaSysvar, SystemAccount,
-        UncheckedAccount,
+        Account, AccountInfo, Clock, CpiContext, EpochSchedule, Pubkey, Rent, Result, SolanaSysvar,
+        StakeHistory, SystemAccount, UncheckedAccount,
+    },
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+        stake::{self, instruction::StakeInstruction},
     },
-    system_program::{allocate, assign, transfer, Allocate, Assign, Transfer},
+    system_program::{self, allocate, assign, transfer, Allocate, Assign, Transfer},
 };
+use anchor_spl::stake::StakeAccount;
 
 use crate::{
+    consts::VIRTUAL_LP_OFFSET,
     errors::UnstakeError,
-    state::{FlashAccount, Pool},
+    rational::Rational,
+    state::{Fee, FlashAccount, Pool, StakeAccountState, ValidatorFee, ValidatorFeeEnum},
 };
 
 pub struct AllocateAssignPdaArgs<'a, 'info: 'a, 's1: 'a, 's2: 'a> {
@@ -86,10 +96,74 @@ pub fn make_rent_exempt_with_pda_payer(args: MakeRentExemptWithPdaPayerArgs) ->
     Ok(())
 }
 
+/// Closes `account` to `destination` if it is initialized and owned by this program,
+/// no-op otherwise
+pub fn close_pda_if_initialized(account: &AccountInfo, destination: &AccountInfo) -> Result<()> {
+    if account.owner != &crate::ID {
+        return Ok(());
+    }
+    let destination_starting_lamports = destination.lamports();
+    **destination.lamports.borrow_mut() = destination_starting_lamports
+        .checked_add(account.lamports())
+        .ok_or(UnstakeError::InternalError)?;
+    **account.lamports.borrow_mut() = 0;
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+pub struct SplitStakeAccountArgs<'a, 'info: 'a, 's1: 'a, 's2: 'a> {
+    pub stake_program: &'a AccountInfo<'info>,
+    pub stake_account: &'a AccountInfo<'info>,
+    pub split_stake_account: &'a AccountInfo<'info>,
+    pub staker: &'a AccountInfo<'info>,
+    pub lamports: u64,
+    /// empty if `staker` is not a PDA
+    pub staker_signer_seeds: &'a [&'s1 [&'s2 [u8]]],
+}
+
+/// Assumes split_stake_account has already been allocated and assigned to the stake program.
+/// `staker` must be a signer of the transaction or a PDA signing with `staker_signer_seeds`
+pub fn split_stake_account(args: SplitStakeAccountArgs) -> Result<()> {
+    let SplitStakeAccountArgs {
+        stake_program,
+        stake_account,
+        split_stake_account,
+        staker,
+        lamports,
+        staker_signer_seeds,
+    } = args;
+    // anchor_spl::stake does not have split, and
+    // stake::instruction::split() also includes the allocate and assign instructions
+    let ix = Instruction::new_with_bincode(
+        stake::program::ID,
+        &StakeInstruction::Split(lamports),
+        vec![
+            AccountMeta::new(*stake_account.key, false),
+            AccountMeta::new(*split_stake_account.key, false),
+            AccountMeta::new_readonly(*staker.key, true),
+        ],
+    );
+    invoke_signed(
+        &ix,
+        &[
+            stake_account.to_owned(),
+            split_stake_account.to_owned(),
+            staker.to_owned(),
+            stake_program.to_owned(),
+        ],
+        staker_signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// `earmarked_lamports` are owed to the pool's outstanding WithdrawTickets
+/// and are excluded from the lamports owned by the pool's LPs
 pub fn calc_pool_owned_lamports(
     pool_sol_reserves: &SystemAccount<'_>,
     pool_account: &Account<'_, Pool>,
     flash_account: &UncheckedAccount<'_>,
+    earmarked_lamports: u64,
 ) -> Result<u64> {
     let flash_loaned_lamports = match flash_account.data_is_empty() {
         true => 0,
@@ -100,5 +174,114 @@ pub fn calc_pool_owned_lamports(
         .checked_add(pool_account.incoming_stake)
         .ok_or(UnstakeError::InternalError)?
         .checked_add(flash_loaned_lamports)
+        .ok_or(UnstakeError::InternalError)?
+        .checked_sub(earmarked_lamports)
         .ok_or(UnstakeError::InternalError)?)
 }
+
+pub fn calc_lamports_to_return(
+    pool_owned_lamports: u64,
+    lp_mint_supply: u64,
+    amount_lp_to_burn: u64,
+) -> std::result::Result<u64, UnstakeError> {
+    // 0 edge-cases: return 0
+    if pool_owned_lamports == 0 || lp_mint_supply == 0 {
+        return Ok(0);
+    }
+    // burning the entire supply returns all owned lamports
+    if amount_lp_to_burn == lp_mint_supply {
+        return Ok(pool_owned_lamports);
+    }
+    // return = amount_lp_to_burn * (owned_lamports BEFORE BURN + offset) / (lp_mint.supply BEFORE BURN + offset)
+    u128::from(amount_lp_to_burn)
+        .checked_mul(u128::from(pool_owned_lamports) + u128::from(VIRTUAL_LP_OFFSET))
+        .and_then(|v| v.checked_div(u128::from(lp_mint_supply) + u128::from(VIRTUAL_LP_OFFSET)))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(UnstakeError::InternalError)
+}
+
+/// Rounds up so that redeeming lamports never costs less LP tokens than
+/// `calc_lamports_to_return()` would return them for
+pub fn calc_lp_tokens_to_burn(
+    pool_owned_lamports: u64,
+    lp_mint_supply: u64,
+    lamports_to_return: u64,
+) -> std::result::Result<u64, UnstakeError> {
+    if pool_owned_lamports == 0 || lp_mint_supply == 0 {
+        return Err(UnstakeError::InternalError);
+    }
+    // burn = ceil(lamports_to_return * (lp_mint.supply BEFORE BURN + offset) / (owned_lamports BEFORE BURN + offset))
+    let owned_lamports_with_offset =
+        u128::from(pool_owned_lamports) + u128::from(VIRTUAL_LP_OFFSET);
+    u128::from(lamports_to_return)
+        .checked_mul(u128::from(lp_mint_supply) + u128::from(VIRTUAL_LP_OFFSET))
+        .and_then(|v| v.checked_add(owned_lamports_with_offset - 1))
+        .and_then(|v| v.checked_div(owned_lamports_with_offset))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(UnstakeError::InternalError)
+}
+
+/// Returns the fraction of the current epoch that has elapsed
+pub fn epoch_progress(clock: &Clock) -> Result<Rational> {
+    let epoch_schedule = EpochSchedule::get()?;
+    let (epoch, slot_index) = epoch_schedule.get_epoch_and_slot_index(clock.slot);
+    Ok(Rational {
+        num: slot_index,
+        denom: epoch_schedule.get_slots_in_epoch(epoch),
+    })
+}
+
+/// Returns the delegation state of a stake account at the current epoch
+pub fn stake_account_state(
+    stake_account: &StakeAccount,
+    clock: &Clock,
+    stake_history: &StakeHistory,
+) -> StakeAccountState {
+    let delegation = match stake_account.delegation() {
+        Some(d) => d,
+        None => return StakeAccountState::Inactive,
+    };
+    let status = delegation.stake_activating_and_deactivating(clock.epoch, Some(stake_history));
+    // deactivating stake is a subset of effective stake
+    if status.effective == 0 && status.activating == 0 {
+        StakeAccountState::Inactive
+    } else if delegation.deactivation_epoch != u64::MAX {
+        StakeAccountState::Deactivating
+    } else {
+        StakeAccountState::Active
+    }
+}
+
+/// Returns the custodian's pubkey if it signed the transaction,
+/// to be used for stake account lockup checks
+pub fn signing_custodian<'a>(custodian: Option<&'a UncheckedAccount>) -> Option<&'a Pubkey> {
+    custodian.filter(|c| c.is_signer).map(|c| c.key)
+}
+
+/// The vote account stake_account is delegated to,
+/// Pubkey::default() if it has never been delegated
+pub fn stake_account_voter(stake_account: &StakeAccount) -> Pubkey {
+    stake_account
+        .delegation()
+        .map_or_else(Pubkey::default, |delegation| delegation.voter_pubkey)
+}
+
+/// Returns the fee to charge for unstaking stake delegated to the validator of
+/// `validator_fee_account`, or `pool_fee` if `validator_fee_account` is not initialized.
+/// Validator overrides keep `pool_fee`'s fee lamport bounds.
+///
+/// Fails with ValidatorDenied if the pool denies the validator
+pub fn resolve_fee(pool_fee: &Fee, validator_fee_account: &AccountInfo) -> Result<Fee> {
+    if validator_fee_account.owner != &crate::ID {
+        return Ok(pool_fee.clone());
+    }
+    let validator_fee: Account<ValidatorFee> = Account::try_from(validator_fee_account)?;
+    match validator_fee.fee {
+        ValidatorFeeEnum::Override { fee } => Ok(Fee {
+            fee,
+            min_fee_lamports: pool_fee.min_fee_lamports,
+            max_fee_lamports: pool_fee.max_fee_lamports,
+        }),
+        ValidatorFeeEnum::Deny => Err(UnstakeError::ValidatorDenied.into()),
+    }
+}
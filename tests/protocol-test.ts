@@ -5,12 +5,22 @@
  */
 
 import * as anchor from "@project-serum/anchor";
-import { Keypair, PublicKey, sendAndConfirmTransaction } from "@solana/web3.js";
+import {
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
 import { expect, use as chaiUse } from "chai";
 import chaiAsPromised from "chai-as-promised";
-import { findProtocolFeeAccount } from "../ts/src";
+import {
+  findPendingProtocolFeeAccount,
+  findProtocolFeeAccount,
+  findProtocolFeeAuthorityNomineeAccount,
+  findProtocolPauseAccount,
+} from "../ts/src";
 import { Unstake } from "../target/types/unstake";
-import { airdrop, checkSystemError } from "./utils";
+import { airdrop, checkAnchorError, checkSystemError } from "./utils";
 import { readFileSync } from "fs";
 import { BN } from "bn.js";
 
@@ -88,9 +98,8 @@ describe("protocol-level", () => {
     ).to.be.eventually.rejected.and.satisfy(checkSystemError(0));
   });
 
-  it("it sets protocol fee destination and authority", async () => {
+  it("it sets protocol fee destination", async () => {
     const tempDestination = Keypair.generate();
-    const tempAuthority = Keypair.generate();
 
     const currentProtocolFee = await program.account.protocolFee.fetch(
       protocolFeeAccount
@@ -98,7 +107,6 @@ describe("protocol-level", () => {
     const newProtocolFee = {
       ...currentProtocolFee,
       destination: tempDestination.publicKey,
-      authority: tempAuthority.publicKey,
     };
     const tx1 = await program.methods
       .setProtocolFee(newProtocolFee)
@@ -117,29 +125,17 @@ describe("protocol-level", () => {
 
     await program.account.protocolFee
       .fetch(protocolFeeAccount)
-      .then(({ destination, authority, feeRatio, referrerFeeRatio }) => {
+      .then(({ destination, authority }) => {
         expect(destination.equals(tempDestination.publicKey)).to.be.true;
-        expect(authority.equals(tempAuthority.publicKey)).to.be.true;
-        expect(feeRatio.num.toNumber()).to.eq(
-          currentProtocolFee.feeRatio.num.toNumber()
-        );
-        expect(feeRatio.denom.toNumber()).to.eq(
-          currentProtocolFee.feeRatio.denom.toNumber()
-        );
-        expect(referrerFeeRatio.num.toNumber()).to.eq(
-          currentProtocolFee.referrerFeeRatio.num.toNumber()
-        );
-        expect(referrerFeeRatio.denom.toNumber()).to.eq(
-          currentProtocolFee.referrerFeeRatio.denom.toNumber()
-        );
+        expect(authority.equals(protocolFeeAuthorityKeypair.publicKey)).to.be
+          .true;
       });
 
     // revert the changes
-    await airdrop(provider.connection, tempAuthority.publicKey);
     const tx2 = await program.methods
       .setProtocolFee(currentProtocolFee)
       .accounts({
-        authority: tempAuthority.publicKey,
+        authority: protocolFeeAuthorityKeypair.publicKey,
         protocolFeeAccount,
       })
       .transaction();
@@ -147,81 +143,280 @@ describe("protocol-level", () => {
     await sendAndConfirmTransaction(
       program.provider.connection,
       tx2,
-      [tempAuthority],
+      [protocolFeeAuthorityKeypair],
       { skipPreflight: true }
     );
 
     await program.account.protocolFee
       .fetch(protocolFeeAccount)
-      .then(({ destination, authority, feeRatio, referrerFeeRatio }) => {
+      .then(({ destination }) => {
         expect(destination.equals(protocolFeeDestinationKeypair.publicKey)).to
           .be.true;
-        expect(authority.equals(protocolFeeAuthorityKeypair.publicKey)).to.be
-          .true;
-        expect(feeRatio.num.toNumber()).to.eq(
-          currentProtocolFee.feeRatio.num.toNumber()
-        );
-        expect(feeRatio.denom.toNumber()).to.eq(
-          currentProtocolFee.feeRatio.denom.toNumber()
-        );
-        expect(referrerFeeRatio.num.toNumber()).to.eq(
-          currentProtocolFee.referrerFeeRatio.num.toNumber()
-        );
-        expect(referrerFeeRatio.denom.toNumber()).to.eq(
-          currentProtocolFee.referrerFeeRatio.denom.toNumber()
-        );
       });
   });
 
-  it("it sets protocol fee ratio and referrer fee ratio", async () => {
+  it("it rejects direct protocol fee authority and ratio changes", async () => {
     const currentProtocolFee = await program.account.protocolFee.fetch(
       protocolFeeAccount
     );
-    const newProtocolFee = {
-      ...currentProtocolFee,
-      feeRatio: {
-        num: new BN(20),
-        denom: new BN(100),
+    const newProtocolFees = [
+      {
+        ...currentProtocolFee,
+        authority: Keypair.generate().publicKey,
       },
-      referrerFeeRatio: {
-        num: new BN(1),
-        denom: new BN(3),
+      {
+        ...currentProtocolFee,
+        feeRatio: {
+          num: new BN(20),
+          denom: new BN(100),
+        },
       },
-    };
-    const tx = await program.methods
-      .setProtocolFee(newProtocolFee)
+    ];
+    for (const newProtocolFee of newProtocolFees) {
+      await expect(
+        program.methods
+          .setProtocolFee(newProtocolFee)
+          .accounts({
+            authority: protocolFeeAuthorityKeypair.publicKey,
+            protocolFeeAccount,
+          })
+          .signers([protocolFeeAuthorityKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6026,
+          "The protocol fee authority and fee ratios can only be changed by proposing new values"
+        )
+      );
+    }
+  });
+
+  it("it hands over protocol fee authority in two steps", async () => {
+    const tempAuthority = Keypair.generate();
+    await airdrop(provider.connection, tempAuthority.publicKey);
+    const [nomineeAccount] = await findProtocolFeeAuthorityNomineeAccount(
+      program.programId
+    );
+
+    await program.methods
+      .proposeProtocolFeeAuthority()
       .accounts({
         authority: protocolFeeAuthorityKeypair.publicKey,
         protocolFeeAccount,
+        newAuthority: tempAuthority.publicKey,
+        nomineeAccount,
       })
-      .transaction();
+      .signers([protocolFeeAuthorityKeypair])
+      .rpc({ skipPreflight: true });
 
-    await sendAndConfirmTransaction(
-      program.provider.connection,
-      tx,
-      [protocolFeeAuthorityKeypair],
-      { skipPreflight: true }
+    await program.account.protocolFee
+      .fetch(protocolFeeAccount)
+      .then(({ authority }) => {
+        expect(authority.equals(protocolFeeAuthorityKeypair.publicKey)).to.be
+          .true;
+      });
+
+    await expect(
+      program.methods
+        .acceptProtocolFeeAuthority()
+        .accounts({
+          newAuthority: payerKeypair.publicKey,
+          authority: protocolFeeAuthorityKeypair.publicKey,
+          protocolFeeAccount,
+          nomineeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true })
+    ).to.be.eventually.rejected.and.satisfy(
+      checkAnchorError(6025, "The signer is not the nominated authority")
     );
 
+    await program.methods
+      .acceptProtocolFeeAuthority()
+      .accounts({
+        newAuthority: tempAuthority.publicKey,
+        authority: protocolFeeAuthorityKeypair.publicKey,
+        protocolFeeAccount,
+        nomineeAccount,
+      })
+      .signers([tempAuthority])
+      .rpc({ skipPreflight: true });
+
     await program.account.protocolFee
       .fetch(protocolFeeAccount)
-      .then(({ destination, authority, feeRatio, referrerFeeRatio }) => {
-        expect(destination.equals(protocolFeeDestinationKeypair.publicKey)).to
-          .be.true;
+      .then(({ authority }) => {
+        expect(authority.equals(tempAuthority.publicKey)).to.be.true;
+      });
+    expect(
+      await program.account.protocolFeeAuthorityNominee.fetchNullable(
+        nomineeAccount
+      )
+    ).to.be.null;
+
+    // revert the changes
+    await program.methods
+      .proposeProtocolFeeAuthority()
+      .accounts({
+        authority: tempAuthority.publicKey,
+        protocolFeeAccount,
+        newAuthority: protocolFeeAuthorityKeypair.publicKey,
+        nomineeAccount,
+      })
+      .signers([tempAuthority])
+      .rpc({ skipPreflight: true });
+    await program.methods
+      .acceptProtocolFeeAuthority()
+      .accounts({
+        newAuthority: protocolFeeAuthorityKeypair.publicKey,
+        authority: tempAuthority.publicKey,
+        protocolFeeAccount,
+        nomineeAccount,
+      })
+      .signers([protocolFeeAuthorityKeypair])
+      .rpc({ skipPreflight: true });
+
+    await program.account.protocolFee
+      .fetch(protocolFeeAccount)
+      .then(({ authority }) => {
         expect(authority.equals(protocolFeeAuthorityKeypair.publicKey)).to.be
           .true;
-        expect(feeRatio.num.toNumber()).to.eq(
-          newProtocolFee.feeRatio.num.toNumber()
-        );
-        expect(feeRatio.denom.toNumber()).to.eq(
-          newProtocolFee.feeRatio.denom.toNumber()
-        );
-        expect(referrerFeeRatio.num.toNumber()).to.eq(
-          newProtocolFee.referrerFeeRatio.num.toNumber()
-        );
-        expect(referrerFeeRatio.denom.toNumber()).to.eq(
-          newProtocolFee.referrerFeeRatio.denom.toNumber()
-        );
+      });
+  });
+
+  it("it proposes protocol fee ratio and referrer fee ratio", async () => {
+    const currentProtocolFee = await program.account.protocolFee.fetch(
+      protocolFeeAccount
+    );
+    const [pendingProtocolFeeAccount] = await findPendingProtocolFeeAccount(
+      program.programId
+    );
+    const feeRatio = {
+      num: new BN(20),
+      denom: new BN(100),
+    };
+    const referrerFeeRatio = {
+      num: new BN(1),
+      denom: new BN(3),
+    };
+
+    await program.methods
+      .proposeProtocolFee(feeRatio, referrerFeeRatio)
+      .accounts({
+        authority: protocolFeeAuthorityKeypair.publicKey,
+        protocolFeeAccount,
+        pendingProtocolFeeAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([protocolFeeAuthorityKeypair])
+      .rpc({ skipPreflight: true });
+
+    await program.account.pendingProtocolFee
+      .fetch(pendingProtocolFeeAccount)
+      .then((pendingProtocolFee) => {
+        expect(pendingProtocolFee.feeRatio.num.eq(feeRatio.num)).to.be.true;
+        expect(pendingProtocolFee.feeRatio.denom.eq(feeRatio.denom)).to.be
+          .true;
+        expect(
+          pendingProtocolFee.referrerFeeRatio.num.eq(referrerFeeRatio.num)
+        ).to.be.true;
+        expect(
+          pendingProtocolFee.referrerFeeRatio.denom.eq(referrerFeeRatio.denom)
+        ).to.be.true;
+      });
+
+    await expect(
+      program.methods
+        .applyPendingProtocolFee()
+        .accounts({
+          authority: protocolFeeAuthorityKeypair.publicKey,
+          protocolFeeAccount,
+          pendingProtocolFeeAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+        })
+        .rpc({ skipPreflight: true })
+    ).to.be.eventually.rejected.and.satisfy(
+      checkAnchorError(6023, "The pending fee cannot be applied yet")
+    );
+
+    await program.account.protocolFee
+      .fetch(protocolFeeAccount)
+      .then(({ feeRatio, referrerFeeRatio }) => {
+        expect(feeRatio.num.eq(currentProtocolFee.feeRatio.num)).to.be.true;
+        expect(feeRatio.denom.eq(currentProtocolFee.feeRatio.denom)).to.be
+          .true;
+        expect(
+          referrerFeeRatio.num.eq(currentProtocolFee.referrerFeeRatio.num)
+        ).to.be.true;
+        expect(
+          referrerFeeRatio.denom.eq(currentProtocolFee.referrerFeeRatio.denom)
+        ).to.be.true;
+      });
+  });
+
+  it("it pauses and unpauses instructions protocol-wide", async () => {
+    const [protocolPauseAccount] = await findProtocolPauseAccount(
+      program.programId
+    );
+    const paused = {
+      unstake: true,
+      takeFlashLoan: true,
+      addLiquidity: true,
+    };
+
+    await expect(
+      program.methods
+        .setProtocolPause(paused)
+        .accounts({
+          authority: payerKeypair.publicKey,
+          protocolFeeAccount,
+          protocolPauseAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true })
+    ).to.be.eventually.rejected.and.satisfy(
+      checkAnchorError(
+        6012,
+        "The provided protocol fee authority does not have the authority over the protocol fee account"
+      )
+    );
+
+    await program.methods
+      .setProtocolPause(paused)
+      .accounts({
+        authority: protocolFeeAuthorityKeypair.publicKey,
+        protocolFeeAccount,
+        protocolPauseAccount,
+      })
+      .signers([protocolFeeAuthorityKeypair])
+      .rpc({ skipPreflight: true });
+    await program.account.protocolPause
+      .fetch(protocolPauseAccount)
+      .then(({ paused: p }) => {
+        expect(p.unstake).to.be.true;
+        expect(p.takeFlashLoan).to.be.true;
+        expect(p.addLiquidity).to.be.true;
+      });
+
+    // unpause to keep the other tests unaffected by this test
+    await program.methods
+      .setProtocolPause({
+        unstake: false,
+        takeFlashLoan: false,
+        addLiquidity: false,
+      })
+      .accounts({
+        authority: protocolFeeAuthorityKeypair.publicKey,
+        protocolFeeAccount,
+        protocolPauseAccount,
+      })
+      .signers([protocolFeeAuthorityKeypair])
+      .rpc({ skipPreflight: true });
+    await program.account.protocolPause
+      .fetch(protocolPauseAccount)
+      .then(({ paused: p }) => {
+        expect(p.unstake).to.be.false;
+        expect(p.takeFlashLoan).to.be.false;
+        expect(p.addLiquidity).to.be.false;
       });
   });
 
@@ -13,6 +13,7 @@ import {
   StakeProgram,
   LAMPORTS_PER_SOL,
   SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
   Transaction,
 } from "@solana/web3.js";
 import { BN } from "bn.js";
@@ -25,14 +26,26 @@ import {
   findStakeAccountRecordAccount,
   previewUnstake,
   unstakeTx,
+  unstakePartialTx,
+  unstakeMultiTx,
   unstakeWsolTx,
   addLiquidityTx,
+  addLiquidityStakeTx,
+  addLiquidityWsolTx,
   createPoolTx,
+  createPoolWithLiquidityTx,
   deactivateStakeAccountTx,
   reclaimStakeAccountTx,
   removeLiquidityTx,
+  removeLiquidityInKindTx,
+  removeLiquidityWsolTx,
+  requestWithdrawalTx,
+  claimWithdrawTicketTx,
+  closePoolTx,
+  applyPendingLockupFeeTx,
+  applyPendingValidatorFeeTx,
   setFeeTx,
-  setFeeAuthorityTx,
+  proposeFeeAuthorityTx,
   Unstake,
   ProtocolFeeAccount,
   findProtocolFeeAccount,
@@ -40,12 +53,21 @@ import {
   previewUnstakeWsol,
   applyFee,
   takeFlashLoanTx,
+  findValidatorFeeAccount,
+  findPendingValidatorFeeAccount,
+  findAllowedLpAccount,
+  findPoolConfigAccount,
+  findWithdrawQueueAccount,
+  findPoolPauseAccount,
+  findProtocolPauseAccount,
+  findSplitStakeAccount,
 } from "../ts/src";
 import {
   airdrop,
   createDelegateStakeTx,
   EPSILON_FLOAT_UPPER_BOUND,
   LAMPORTS_PER_SIGNATURE,
+  testVoteAccount,
   transferStakeAuthTx,
   waitForEpochToPass,
 } from "./utils";
@@ -67,6 +89,12 @@ describe("ts bindings", () => {
 
   let [poolSolReserves, poolSolReservesBump] = [null as PublicKey, 0];
   let [feeAccount, feeAccountBump] = [null as PublicKey, 0];
+  let [validatorFeeAccount] = [null as PublicKey, 0];
+  let [poolPauseAccount] = [null as PublicKey, 0];
+  let [poolConfigAccount] = [null as PublicKey, 0];
+  let [allowedLpAccount] = [null as PublicKey, 0];
+  let [withdrawQueueAccount] = [null as PublicKey, 0];
+  let [protocolPauseAccount] = [null as PublicKey, 0];
   let protocolFeeAddr = null as PublicKey;
   let protocolFee = null as ProgramAccount<ProtocolFeeAccount>;
 
@@ -92,6 +120,29 @@ describe("ts bindings", () => {
       program.programId,
       poolKeypair.publicKey
     );
+    [validatorFeeAccount] = await findValidatorFeeAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      testVoteAccount()
+    );
+    [poolPauseAccount] = await findPoolPauseAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [poolConfigAccount] = await findPoolConfigAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [withdrawQueueAccount] = await findWithdrawQueueAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [allowedLpAccount] = await findAllowedLpAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      lperKeypair.publicKey
+    );
+    [protocolPauseAccount] = await findProtocolPauseAccount(program.programId);
     [protocolFeeAddr] = await findProtocolFeeAccount(program.programId);
     protocolFee = {
       publicKey: protocolFeeAddr,
@@ -129,7 +180,7 @@ describe("ts bindings", () => {
       )
     ).address;
     await program.methods
-      .addLiquidity(liquidityAmountLamports)
+      .addLiquidityV2(liquidityAmountLamports, new BN(0))
       .accounts({
         from: lperKeypair.publicKey,
         poolAccount: poolKeypair.publicKey,
@@ -137,6 +188,11 @@ describe("ts bindings", () => {
         lpMint: lpMintKeypair.publicKey,
         mintLpTokensTo: lperAta,
         flashAccount,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        allowedLpAccount,
+        withdrawQueueAccount,
       })
       .signers([lperKeypair])
       .rpc({ skipPreflight: true });
@@ -212,7 +268,7 @@ describe("ts bindings", () => {
       console.log("awaiting epoch to pass");
       await waitForEpochToPass(provider.connection);
       await program.methods
-        .unstake()
+        .unstakeV2(new BN(0))
         .accounts({
           unstaker: payerKeypair.publicKey,
           stakeAccount: stakeAccKeypair.publicKey,
@@ -220,11 +276,19 @@ describe("ts bindings", () => {
           poolAccount: poolKeypair.publicKey,
           poolSolReserves,
           feeAccount,
+          validatorFeeAccount,
           stakeAccountRecordAccount,
           protocolFeeAccount: protocolFee.publicKey,
           protocolFeeDestination: protocolFee.account.destination,
           clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
           stakeProgram: StakeProgram.programId,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
@@ -381,6 +445,97 @@ describe("ts bindings", () => {
         expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
       });
 
+      it("it generates CreatePoolWithLiquidity tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "createPoolWithLiquidity"
+        ).accounts.length;
+
+        const tx = await createPoolWithLiquidityTx(
+          program,
+          {
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(0),
+                  denom: new BN(1000),
+                },
+              },
+            },
+          },
+          new BN(LAMPORTS_PER_SOL),
+          {
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: Keypair.generate().publicKey,
+          }
+        );
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates ClosePool tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "closePool"
+        ).accounts.length;
+
+        const tx = await closePoolTx(program, {
+          poolAccount: poolProgramAccount,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates ApplyPendingLockupFee tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "applyPendingLockupFee"
+        ).accounts.length;
+
+        const tx = await applyPendingLockupFeeTx(program, {
+          poolAccount: poolKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates ApplyPendingValidatorFee tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "applyPendingValidatorFee"
+        ).accounts.length;
+        const [expectedPendingValidatorFeeAccount] =
+          await findPendingValidatorFeeAccount(
+            program.programId,
+            poolKeypair.publicKey,
+            testVoteAccount()
+          );
+
+        const tx = await applyPendingValidatorFeeTx(program, {
+          payer: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          voteAccount: testVoteAccount(),
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        const keys = tx.instructions[0].keys;
+        expect(keys.length).to.eq(expectedAccountsLength);
+        expect(keys[4].pubkey.equals(validatorFeeAccount)).to.be.true;
+        expect(keys[5].pubkey.equals(expectedPendingValidatorFeeAccount)).to
+          .be.true;
+      });
+
       it("it generates SetFee tx", async () => {
         const expectedAccountsLength = program.idl.instructions.find(
           (ix) => ix.name === "setFee"
@@ -410,14 +565,14 @@ describe("ts bindings", () => {
         expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
       });
 
-      it("it generates SetFeeAuthority tx", async () => {
+      it("it generates ProposeFeeAuthority tx", async () => {
         const newFeeAuthority = Keypair.generate().publicKey;
         const expectedAccountsLength = program.idl.instructions.find(
-          (ix) => ix.name === "setFeeAuthority"
+          (ix) => ix.name === "proposeFeeAuthority"
         ).accounts.length;
 
         // case 1 (trivial): poolAccount is Address type
-        const tx1 = await setFeeAuthorityTx(program, {
+        const tx1 = await proposeFeeAuthorityTx(program, {
           poolAccount: poolKeypair.publicKey,
           feeAuthority: payerKeypair.publicKey,
           newFeeAuthority,
@@ -429,7 +584,7 @@ describe("ts bindings", () => {
         expect(tx1.instructions[0].keys.length).to.eq(expectedAccountsLength);
 
         // case 2: poolAccount is ProgramAccount type and feeAuthority is given
-        const tx2 = await setFeeAuthorityTx(program, {
+        const tx2 = await proposeFeeAuthorityTx(program, {
           poolAccount: poolProgramAccount,
           feeAuthority: payerKeypair.publicKey,
           newFeeAuthority,
@@ -441,7 +596,7 @@ describe("ts bindings", () => {
         expect(tx2.instructions[0].keys.length).to.eq(expectedAccountsLength);
 
         // case 3: poolAccount is ProgramAccount type and feeAuthority is not given
-        const tx3 = await setFeeAuthorityTx(program, {
+        const tx3 = await proposeFeeAuthorityTx(program, {
           poolAccount: poolProgramAccount,
           newFeeAuthority,
         });
@@ -491,7 +646,7 @@ describe("ts bindings", () => {
       it("it generates AddLiquidity tx", async () => {
         const amountLamports = new BN(1);
         const expectedAccountsLength = program.idl.instructions.find(
-          (ix) => ix.name === "addLiquidity"
+          (ix) => ix.name === "addLiquidityV2"
         ).accounts.length;
 
         // case 1 (trivial): poolAccount is Address type
@@ -536,7 +691,7 @@ describe("ts bindings", () => {
       it("it generates RemoveLiquidity tx", async () => {
         const amountLPAtomics = new BN(1);
         const expectedAccountsLength = program.idl.instructions.find(
-          (ix) => ix.name === "removeLiquidity"
+          (ix) => ix.name === "removeLiquidityV2"
         ).accounts.length;
 
         // case 1 (trivial): poolAccount is Address type
@@ -574,12 +729,112 @@ describe("ts bindings", () => {
           .true;
         expect(tx3.instructions[0].keys.length).to.eq(expectedAccountsLength);
       });
+
+      it("it generates AddLiquidityStake tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "addLiquidityStake"
+        ).accounts.length;
+
+        const tx = await addLiquidityStakeTx(program, {
+          depositor: lperKeypair.publicKey,
+          stakeAccount: stakeAccKeypair.publicKey,
+          poolAccount: poolProgramAccount,
+          protocolFee,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates RemoveLiquidityInKind tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "removeLiquidityInKind"
+        ).accounts.length;
+
+        const tx = await removeLiquidityInKindTx(program, new BN(1), {
+          authority: lperKeypair.publicKey,
+          poolAccount: poolProgramAccount,
+          stakeAccount: stakeAccKeypair.publicKey,
+          splitStakeAccount: Keypair.generate().publicKey,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates AddLiquidityWsol tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "addLiquidityWsol"
+        ).accounts.length;
+
+        const tx = await addLiquidityWsolTx(program, new BN(1), {
+          authority: lperKeypair.publicKey,
+          poolAccount: poolProgramAccount,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates RemoveLiquidityWsol tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "removeLiquidityWsol"
+        ).accounts.length;
+
+        const tx = await removeLiquidityWsolTx(program, new BN(1), {
+          authority: lperKeypair.publicKey,
+          poolAccount: poolProgramAccount,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates RequestWithdrawal tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "requestWithdrawal"
+        ).accounts.length;
+
+        const tx = await requestWithdrawalTx(program, new BN(1), {
+          authority: lperKeypair.publicKey,
+          poolAccount: poolProgramAccount,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
+
+      it("it generates ClaimWithdrawTicket tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "claimWithdrawTicket"
+        ).accounts.length;
+
+        const tx = await claimWithdrawTicketTx(program, {
+          owner: lperKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+        });
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        expect(tx.instructions[0].keys.length).to.eq(expectedAccountsLength);
+      });
     });
 
     describe("User facing", () => {
       it("it generates Unstake tx", async () => {
         const expectedAccountsLength = program.idl.instructions.find(
-          (ix) => ix.name === "unstake"
+          (ix) => ix.name === "unstakeV2"
         ).accounts.length;
 
         const tx = await unstakeTx(program, {
@@ -598,8 +853,8 @@ describe("ts bindings", () => {
       it("it generates Unstake tx with referrer", async () => {
         const expectedReferrer = Keypair.generate().publicKey;
         const expectedAccountsLength =
-          program.idl.instructions.find((ix) => ix.name === "unstake").accounts
-            .length + 1;
+          program.idl.instructions.find((ix) => ix.name === "unstakeV2")
+            .accounts.length + 1;
 
         const tx = await unstakeTx(program, {
           poolAccount: poolKeypair.publicKey,
@@ -624,7 +879,7 @@ describe("ts bindings", () => {
 
       it("it generates UnstakeWsol tx", async () => {
         const expectedAccountsLength = program.idl.instructions.find(
-          (ix) => ix.name === "unstakeWsol"
+          (ix) => ix.name === "unstakeWsolV2"
         ).accounts.length;
 
         const tx = await unstakeWsolTx(program, {
@@ -643,7 +898,7 @@ describe("ts bindings", () => {
       it("it generates UnstakeWsol tx with referrer", async () => {
         const expectedReferrer = Keypair.generate().publicKey;
         const expectedAccountsLength =
-          program.idl.instructions.find((ix) => ix.name === "unstakeWsol")
+          program.idl.instructions.find((ix) => ix.name === "unstakeWsolV2")
             .accounts.length + 1;
 
         const tx = await unstakeWsolTx(program, {
@@ -666,6 +921,80 @@ describe("ts bindings", () => {
         expect(referrerMeta.isSigner).to.be.false;
         expect(referrerMeta.isWritable).to.be.true;
       });
+
+      it("it generates UnstakePartial tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "unstakePartial"
+        ).accounts.length;
+        const [expectedSplitStakeAccount] = await findSplitStakeAccount(
+          program.programId,
+          poolKeypair.publicKey,
+          stakeAccKeypair.publicKey
+        );
+
+        const tx = await unstakePartialTx(
+          program,
+          {
+            poolAccount: poolKeypair.publicKey,
+            stakeAccount: stakeAccKeypair.publicKey,
+            unstaker: unstakerKeypair.publicKey,
+            protocolFee,
+          },
+          new BN(1)
+        );
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        const keys = tx.instructions[0].keys;
+        expect(keys.length).to.eq(expectedAccountsLength);
+        expect(keys[2].pubkey.equals(expectedSplitStakeAccount)).to.be.true;
+      });
+
+      it("it generates UnstakeMulti tx", async () => {
+        const expectedAccountsLength = program.idl.instructions.find(
+          (ix) => ix.name === "unstakeMulti"
+        ).accounts.length;
+        const stakeAccounts = [Keypair.generate(), Keypair.generate()].map(
+          (kp) => kp.publicKey
+        );
+        const referrer = Keypair.generate().publicKey;
+
+        const tx = await unstakeMultiTx(
+          program,
+          {
+            poolAccount: poolKeypair.publicKey,
+            stakeAccounts,
+            unstaker: unstakerKeypair.publicKey,
+            protocolFee,
+            referrer,
+            voteAccounts: stakeAccounts.map(() => testVoteAccount()),
+          },
+          new BN(1)
+        );
+        expect(tx instanceof Transaction).to.be.true;
+        expect(tx.instructions.length).to.eq(1);
+        expect(tx.instructions[0].programId.equals(program.programId)).to.be
+          .true;
+        const keys = tx.instructions[0].keys;
+        // 2 (stake account, record, validator fee) triples and the referrer
+        expect(keys.length).to.eq(expectedAccountsLength + 7);
+        for (const [i, stakeAccount] of stakeAccounts.entries()) {
+          const [expectedRecord] = await findStakeAccountRecordAccount(
+            program.programId,
+            poolKeypair.publicKey,
+            stakeAccount
+          );
+          const triple = keys.slice(
+            expectedAccountsLength + 3 * i,
+            expectedAccountsLength + 3 * i + 3
+          );
+          expect(triple[0].pubkey.equals(stakeAccount)).to.be.true;
+          expect(triple[1].pubkey.equals(expectedRecord)).to.be.true;
+          expect(triple[2].pubkey.equals(validatorFeeAccount)).to.be.true;
+        }
+        expect(keys[keys.length - 1].pubkey.equals(referrer)).to.be.true;
+      });
     });
   });
 
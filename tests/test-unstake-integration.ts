@@ -2,9 +2,12 @@ import BN from "bn.js";
 import * as anchor from "@project-serum/anchor";
 import {
   sendAndConfirmTransaction,
+  Authorized,
   Keypair,
   PublicKey,
   LAMPORTS_PER_SOL,
+  Lockup,
+  StakeAuthorizationLayout,
   StakeProgram,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_STAKE_HISTORY_PUBKEY,
@@ -12,6 +15,7 @@ import {
 import {
   createAssociatedTokenAccount,
   getAccount,
+  getMint,
   NATIVE_MINT,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -22,15 +26,31 @@ import {
   findPoolSolReserves,
   findProtocolFeeAccount,
   findStakeAccountRecordAccount,
+  findStakeDepositFeeAccount,
+  findFeeDelayAccount,
+  findLockupFeeAccount,
+  findSplitStakeAccount,
+  unstakePartialTx,
+  unstakeMultiTx,
   LiquidityLinearFeeInner,
   ProtocolFeeAccount,
+  findValidatorFeeAccount,
+  findAllowedLpAccount,
+  findPoolConfigAccount,
+  findWithdrawQueueAccount,
+  findPoolPauseAccount,
+  findProtocolPauseAccount,
 } from "../ts/src";
 import { Unstake } from "../target/types/unstake";
 import {
   airdrop,
+  checkAnchorError,
+  checkSystemError,
   createDelegateStakeTx,
   EPSILON_UPPER_BOUND,
   fetchLpFacingTestParams,
+  stakeAccMinLamports,
+  testVoteAccount,
   waitForEpochToPass,
 } from "./utils";
 import { expect, use as chaiUse } from "chai";
@@ -54,11 +74,20 @@ describe("integration", () => {
   const unstakerKeypair = Keypair.generate();
   const stakeAccountKeypair = Keypair.generate();
   const stakeAccountWSolKeypair = Keypair.generate();
+  // stake account whose staker is a different key from its withdrawer, unstakerKeypair
+  const stakeAccountSeparateStakerKeypair = Keypair.generate();
+  const stakerKeypair = Keypair.generate();
 
   const liquidityAmount = new BN(10 * LAMPORTS_PER_SOL);
 
   let [poolSolReserves] = [null as PublicKey, 0];
   let [feeAccount] = [null as PublicKey, 0];
+  let [validatorFeeAccount] = [null as PublicKey, 0];
+  let [poolPauseAccount] = [null as PublicKey, 0];
+  let [poolConfigAccount] = [null as PublicKey, 0];
+  let [allowedLpAccount] = [null as PublicKey, 0];
+  let [withdrawQueueAccount] = [null as PublicKey, 0];
+  let [protocolPauseAccount] = [null as PublicKey, 0];
   let lperAta = null as PublicKey;
   let unstakerWSol = null as PublicKey;
   let protocolFeeAddr = null as PublicKey;
@@ -84,6 +113,29 @@ describe("integration", () => {
       program.programId,
       poolKeypair.publicKey
     );
+    [validatorFeeAccount] = await findValidatorFeeAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      testVoteAccount()
+    );
+    [poolPauseAccount] = await findPoolPauseAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [poolConfigAccount] = await findPoolConfigAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [withdrawQueueAccount] = await findWithdrawQueueAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [allowedLpAccount] = await findAllowedLpAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      lperKeypair.publicKey
+    );
+    [protocolPauseAccount] = await findProtocolPauseAccount(program.programId);
     [protocolFeeAddr] = await findProtocolFeeAccount(program.programId);
     protocolFee = {
       publicKey: protocolFeeAddr,
@@ -128,7 +180,7 @@ describe("integration", () => {
 
     console.log("adding some liquidity");
     await program.methods
-      .addLiquidity(liquidityAmount)
+      .addLiquidityV2(liquidityAmount, new BN(0))
       .accounts({
         from: lperKeypair.publicKey,
         poolAccount: poolKeypair.publicKey,
@@ -136,6 +188,11 @@ describe("integration", () => {
         lpMint: lpMintKeypair.publicKey,
         mintLpTokensTo: lperAta,
         flashAccount,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        allowedLpAccount,
+        withdrawQueueAccount,
       })
       .signers([lperKeypair])
       .rpc({ skipPreflight: true });
@@ -150,7 +207,11 @@ describe("integration", () => {
 
     console.log("preparing stake accounts");
     await Promise.all(
-      [stakeAccountKeypair, stakeAccountWSolKeypair].map((kp) =>
+      [
+        stakeAccountKeypair,
+        stakeAccountWSolKeypair,
+        stakeAccountSeparateStakerKeypair,
+      ].map((kp) =>
         createDelegateStakeTx({
           connection: provider.connection,
           stakeAccount: kp.publicKey,
@@ -164,6 +225,18 @@ describe("integration", () => {
       )
     );
 
+    console.log("reassigning staker of stake account to a different key");
+    await sendAndConfirmTransaction(
+      provider.connection,
+      StakeProgram.authorize({
+        authorizedPubkey: unstakerKeypair.publicKey,
+        newAuthorizedPubkey: stakerKeypair.publicKey,
+        stakeAuthorizationType: StakeAuthorizationLayout.Staker,
+        stakePubkey: stakeAccountSeparateStakerKeypair.publicKey,
+      }),
+      [unstakerKeypair]
+    );
+
     console.log("awaiting epoch to pass");
     await waitForEpochToPass(provider.connection);
   });
@@ -188,7 +261,7 @@ describe("integration", () => {
     );
 
     await program.methods
-      .unstake()
+      .unstakeV2(new BN(0))
       .accounts({
         unstaker: unstakerKeypair.publicKey,
         stakeAccount: stakeAccountKeypair.publicKey,
@@ -196,11 +269,19 @@ describe("integration", () => {
         poolAccount: poolKeypair.publicKey,
         poolSolReserves,
         feeAccount,
+        validatorFeeAccount,
         stakeAccountRecordAccount,
         protocolFeeAccount: protocolFee.publicKey,
         protocolFeeDestination: protocolFee.account.destination,
         clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: program.programId,
+        lockupFeeAccount: program.programId,
         stakeProgram: StakeProgram.programId,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
       })
       .signers([unstakerKeypair])
       .rpc({ skipPreflight: true });
@@ -286,7 +367,7 @@ describe("integration", () => {
     );
 
     await program.methods
-      .unstakeWsol()
+      .unstakeWsolV2(new BN(0))
       .accounts({
         unstaker: unstakerKeypair.publicKey,
         stakeAccount: stakeAccountWSolKeypair.publicKey,
@@ -294,12 +375,20 @@ describe("integration", () => {
         poolAccount: poolKeypair.publicKey,
         poolSolReserves,
         feeAccount,
+        validatorFeeAccount,
         stakeAccountRecordAccount,
         protocolFeeAccount: protocolFee.publicKey,
         protocolFeeDestination: protocolFee.account.destination,
         clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: program.programId,
+        lockupFeeAccount: program.programId,
         stakeProgram: StakeProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
       })
       .signers([unstakerKeypair])
       .rpc({ skipPreflight: true });
@@ -366,6 +455,84 @@ describe("integration", () => {
     expect(withdrawerPost.equals(poolSolReserves)).to.be.true;
   });
 
+  it("it unstakes with only the withdraw authority signing", async () => {
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountSeparateStakerKeypair.publicKey
+    );
+    const getAuthorized = () =>
+      getStakeAccount(
+        provider.connection,
+        stakeAccountSeparateStakerKeypair.publicKey
+      ).then(
+        ({
+          data: {
+            info: {
+              meta: {
+                authorized: { staker, withdrawer },
+              },
+            },
+          },
+        }) => [staker, withdrawer]
+      );
+
+    const [stakerPre, withdrawerPre] = await getAuthorized();
+    expect(stakerPre.equals(stakerKeypair.publicKey)).to.be.true;
+    expect(withdrawerPre.equals(unstakerKeypair.publicKey)).to.be.true;
+
+    const stakeAccountLamports = await provider.connection.getBalance(
+      stakeAccountSeparateStakerKeypair.publicKey
+    );
+    const unstakerBalancePre = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+
+    // stakerKeypair does not sign
+    await program.methods
+      .unstakeV2(new BN(0))
+      .accounts({
+        unstaker: unstakerKeypair.publicKey,
+        stakeAccount: stakeAccountSeparateStakerKeypair.publicKey,
+        destination: unstakerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        feeAccount,
+        validatorFeeAccount,
+        stakeAccountRecordAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: program.programId,
+        lockupFeeAccount: program.programId,
+        stakeProgram: StakeProgram.programId,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
+      })
+      .signers([unstakerKeypair])
+      .rpc({ skipPreflight: true });
+
+    const [stakerPost, withdrawerPost] = await getAuthorized();
+    const unstakerBalancePost = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const { lamportsAtCreation } =
+      await program.account.stakeAccountRecord.fetch(
+        stakeAccountRecordAccount
+      );
+
+    expect(stakerPost.equals(poolSolReserves)).to.be.true;
+    expect(withdrawerPost.equals(poolSolReserves)).to.be.true;
+    expect(lamportsAtCreation.toNumber()).to.eq(stakeAccountLamports);
+    expect(unstakerBalancePost).to.be.gt(unstakerBalancePre);
+    expect(unstakerBalancePost).to.be.lt(
+      unstakerBalancePre + stakeAccountLamports
+    );
+  });
+
   it("it deactivates", async () => {
     const stakeAccActive = await getStakeAccount(
       provider.connection,
@@ -377,7 +544,11 @@ describe("integration", () => {
     );
 
     await Promise.all(
-      [stakeAccountKeypair, stakeAccountWSolKeypair].map(async (sakp) => {
+      [
+        stakeAccountKeypair,
+        stakeAccountWSolKeypair,
+        stakeAccountSeparateStakerKeypair,
+      ].map(async (sakp) => {
         await program.methods
           .deactivateStakeAccount()
           .accounts({
@@ -417,7 +588,11 @@ describe("integration", () => {
     // must be ran sequentially
     let ownedLamportsPost: BN;
     let solReservesLamportsPost: number;
-    for (const sakp of [stakeAccountKeypair, stakeAccountWSolKeypair]) {
+    for (const sakp of [
+      stakeAccountKeypair,
+      stakeAccountWSolKeypair,
+      stakeAccountSeparateStakerKeypair,
+    ]) {
       const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
         program.programId,
         poolKeypair.publicKey,
@@ -516,7 +691,7 @@ describe("integration", () => {
     const ownedLamportsPre = incomingStakePre.add(new BN(reservesLamportsPre));
 
     await program.methods
-      .removeLiquidity(new BN(lperAtaPre.toString()))
+      .removeLiquidityV2(new BN(lperAtaPre.toString()), new BN(0))
       .accounts({
         burnLpTokensFromAuthority: lperKeypair.publicKey,
         to: lperKeypair.publicKey,
@@ -525,6 +700,7 @@ describe("integration", () => {
         lpMint: lpMintKeypair.publicKey,
         burnLpTokensFrom: lperAta,
         flashAccount,
+        withdrawQueueAccount,
       })
       .signers([lperKeypair])
       .rpc({ skipPreflight: true });
@@ -554,4 +730,1082 @@ describe("integration", () => {
     expect(reservesLamportsPost).to.eq(0);
     expect(lamportsReceived).to.be.gt(liquidityAmount.toNumber());
   });
+
+  it("it removes liquidity in kind", async () => {
+    const stakeAccountInKindKeypair = Keypair.generate();
+    const splitStakeAccountKeypair = Keypair.generate();
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountInKindKeypair.publicKey
+    );
+
+    await program.methods
+      .addLiquidityV2(liquidityAmount, new BN(0))
+      .accounts({
+        from: lperKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        lpMint: lpMintKeypair.publicKey,
+        mintLpTokensTo: lperAta,
+        flashAccount,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        allowedLpAccount,
+        withdrawQueueAccount,
+      })
+      .signers([lperKeypair])
+      .rpc({ skipPreflight: true });
+
+    // large enough to be split in half
+    const stakeAccountLamports =
+      3 * (await stakeAccMinLamports(provider.connection));
+    await createDelegateStakeTx({
+      connection: provider.connection,
+      stakeAccount: stakeAccountInKindKeypair.publicKey,
+      payer: unstakerKeypair.publicKey,
+      lamports: stakeAccountLamports,
+    }).then((tx) =>
+      sendAndConfirmTransaction(provider.connection, tx, [
+        unstakerKeypair,
+        stakeAccountInKindKeypair,
+      ])
+    );
+    await program.methods
+      .unstakeV2(new BN(0))
+      .accounts({
+        unstaker: unstakerKeypair.publicKey,
+        stakeAccount: stakeAccountInKindKeypair.publicKey,
+        destination: unstakerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        feeAccount,
+        validatorFeeAccount,
+        stakeAccountRecordAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: program.programId,
+        lockupFeeAccount: program.programId,
+        stakeProgram: StakeProgram.programId,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
+      })
+      .signers([unstakerKeypair])
+      .rpc({ skipPreflight: true });
+
+    const fetchStakeAuthorities = (stakeAccount: PublicKey) =>
+      getStakeAccount(provider.connection, stakeAccount).then(
+        ({
+          data: {
+            info: {
+              meta: {
+                authorized: { staker, withdrawer },
+              },
+            },
+          },
+        }) => [staker, withdrawer]
+      );
+    const redeemInKind = (lamports: BN) =>
+      program.methods
+        .removeLiquidityInKind(lamports)
+        .accounts({
+          burnLpTokensFromAuthority: lperKeypair.publicKey,
+          newStakeAuthority: lperKeypair.publicKey,
+          stakeAccount: stakeAccountInKindKeypair.publicKey,
+          splitStakeAccount: splitStakeAccountKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          stakeAccountRecordAccount,
+          lpMint: lpMintKeypair.publicKey,
+          burnLpTokensFrom: lperAta,
+          flashAccount,
+          withdrawQueueAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          stakeProgram: StakeProgram.programId,
+          custodian: program.programId,
+        })
+        .signers([lperKeypair, splitStakeAccountKeypair])
+        .rpc({ skipPreflight: true });
+
+    const { lamportsAtCreation } =
+      await program.account.stakeAccountRecord.fetch(
+        stakeAccountRecordAccount
+      );
+    expect(lamportsAtCreation.toNumber()).to.eq(stakeAccountLamports);
+    const splitLamports = lamportsAtCreation.divn(2);
+
+    // redeeming more than the stake account's recorded lamports fails
+    await expect(
+      redeemInKind(lamportsAtCreation.addn(1))
+    ).to.be.eventually.rejected.and.satisfy(
+      checkAnchorError(
+        6035,
+        "The amount to redeem in kind exceeds the stake account's recorded lamports"
+      )
+    );
+
+    // case 1: split off part of the stake account
+    const {
+      lperAtaAmount: lperAtaPre,
+      incomingStake: incomingStakePre,
+      reserveLamports: reservesLamportsPre,
+    } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+
+    await redeemInKind(splitLamports);
+
+    const {
+      lperAtaAmount: lperAtaSplit,
+      incomingStake: incomingStakeSplit,
+      reserveLamports: reservesLamportsSplit,
+    } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    const [splitStaker, splitWithdrawer] = await fetchStakeAuthorities(
+      splitStakeAccountKeypair.publicKey
+    );
+    const { lamportsAtCreation: lamportsAtCreationSplit } =
+      await program.account.stakeAccountRecord.fetch(
+        stakeAccountRecordAccount
+      );
+
+    expect(splitStaker.equals(lperKeypair.publicKey)).to.be.true;
+    expect(splitWithdrawer.equals(lperKeypair.publicKey)).to.be.true;
+    expect(
+      await provider.connection.getBalance(splitStakeAccountKeypair.publicKey)
+    ).to.eq(splitLamports.toNumber());
+    expect(lamportsAtCreationSplit.toNumber()).to.eq(
+      lamportsAtCreation.sub(splitLamports).toNumber()
+    );
+    expect(incomingStakeSplit.toNumber()).to.eq(
+      incomingStakePre.sub(splitLamports).toNumber()
+    );
+    expect(reservesLamportsSplit).to.eq(reservesLamportsPre);
+    expect(lperAtaSplit < lperAtaPre).to.be.true;
+
+    // case 2: redeem the rest of the stake account
+    const stakeAccountRecordRentLamports = await provider.connection.getBalance(
+      stakeAccountRecordAccount
+    );
+
+    await redeemInKind(lamportsAtCreationSplit);
+
+    const {
+      lperAtaAmount: lperAtaPost,
+      incomingStake: incomingStakePost,
+      reserveLamports: reservesLamportsPost,
+    } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    const [stakerPost, withdrawerPost] = await fetchStakeAuthorities(
+      stakeAccountInKindKeypair.publicKey
+    );
+
+    expect(stakerPost.equals(lperKeypair.publicKey)).to.be.true;
+    expect(withdrawerPost.equals(lperKeypair.publicKey)).to.be.true;
+    await expect(
+      program.account.stakeAccountRecord.fetch(stakeAccountRecordAccount)
+    ).to.be.rejectedWith("Account does not exist");
+    expect(incomingStakePost.toNumber()).to.eq(
+      incomingStakeSplit.sub(lamportsAtCreationSplit).toNumber()
+    );
+    expect(reservesLamportsPost).to.eq(
+      reservesLamportsSplit + stakeAccountRecordRentLamports
+    );
+    expect(lperAtaPost < lperAtaSplit).to.be.true;
+  });
+
+  it("it adds liquidity by depositing a stake account", async () => {
+    const stakeAccountDepositKeypair = Keypair.generate();
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountDepositKeypair.publicKey
+    );
+    const [stakeDepositFeeAccount] = await findStakeDepositFeeAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    // above the pool's max unstake fee of 4.2% so that the deposit fee is charged
+    const feeRatio = { num: new BN(5), denom: new BN(100) };
+
+    await program.methods
+      .setStakeDepositFee({ feeRatio })
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        stakeDepositFeeAccount,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+
+    await createDelegateStakeTx({
+      connection: provider.connection,
+      stakeAccount: stakeAccountDepositKeypair.publicKey,
+      payer: lperKeypair.publicKey,
+    }).then((tx) =>
+      sendAndConfirmTransaction(provider.connection, tx, [
+        lperKeypair,
+        stakeAccountDepositKeypair,
+      ])
+    );
+    const stakeAccountLamports = await provider.connection.getBalance(
+      stakeAccountDepositKeypair.publicKey
+    );
+
+    const {
+      lperAtaAmount: lperAtaPre,
+      incomingStake: incomingStakePre,
+      reserveLamports: reservesLamportsPre,
+    } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    const ownedLamportsPre = incomingStakePre.add(new BN(reservesLamportsPre));
+    const { supply: lpSupplyPre } = await getMint(
+      provider.connection,
+      lpMintKeypair.publicKey
+    );
+    const protocolFeeDestBalancePre = await provider.connection.getBalance(
+      protocolFee.account.destination
+    );
+
+    await program.methods
+      .addLiquidityStake()
+      .accounts({
+        depositor: lperKeypair.publicKey,
+        stakeAccount: stakeAccountDepositKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        lpMint: lpMintKeypair.publicKey,
+        mintLpTokensTo: lperAta,
+        stakeAccountRecordAccount,
+        stakeDepositFeeAccount,
+        feeAccount,
+        validatorFeeAccount,
+        flashAccount,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        allowedLpAccount,
+        withdrawQueueAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        stakeProgram: StakeProgram.programId,
+      })
+      .signers([lperKeypair])
+      .rpc({ skipPreflight: true });
+
+    const {
+      lperAtaAmount: lperAtaPost,
+      incomingStake: incomingStakePost,
+      reserveLamports: reservesLamportsPost,
+    } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    const protocolFeeDestBalancePost = await provider.connection.getBalance(
+      protocolFee.account.destination
+    );
+    const [stakerPost, withdrawerPost] = await getStakeAccount(
+      provider.connection,
+      stakeAccountDepositKeypair.publicKey
+    ).then(
+      ({
+        data: {
+          info: {
+            meta: {
+              authorized: { staker, withdrawer },
+            },
+          },
+        },
+      }) => [staker, withdrawer]
+    );
+    const { lamportsAtCreation } =
+      await program.account.stakeAccountRecord.fetch(
+        stakeAccountRecordAccount
+      );
+
+    // fee is rounded up, LP tokens minted are rounded down
+    const feeLamports = new BN(stakeAccountLamports)
+      .mul(feeRatio.num)
+      .add(feeRatio.denom)
+      .subn(1)
+      .div(feeRatio.denom);
+    // protocol fee is rounded down, paid from the reserves
+    const protocolFeeLamports = feeLamports
+      .mul(protocolFee.account.feeRatio.num)
+      .div(protocolFee.account.feeRatio.denom);
+    // see VIRTUAL_LP_OFFSET in the program's consts.rs
+    const virtualLpOffset = new BN(1_000_000);
+    const lpMintedExpected = new BN(stakeAccountLamports)
+      .sub(feeLamports)
+      .mul(new BN(lpSupplyPre.toString()).add(virtualLpOffset))
+      .div(ownedLamportsPre.add(virtualLpOffset));
+
+    expect(stakerPost.equals(poolSolReserves)).to.be.true;
+    expect(withdrawerPost.equals(poolSolReserves)).to.be.true;
+    expect(lamportsAtCreation.toNumber()).to.eq(stakeAccountLamports);
+    expect(incomingStakePost.toNumber()).to.eq(
+      incomingStakePre.toNumber() + stakeAccountLamports
+    );
+    expect(protocolFeeDestBalancePost - protocolFeeDestBalancePre).to.eq(
+      protocolFeeLamports.toNumber()
+    );
+    expect(reservesLamportsPost).to.eq(
+      reservesLamportsPre - protocolFeeLamports.toNumber()
+    );
+    expect(feeLamports.toNumber()).to.be.gt(0);
+    expect(protocolFeeLamports.toNumber()).to.be.gt(0);
+    expect((lperAtaPost - lperAtaPre).toString()).to.eq(
+      lpMintedExpected.toString()
+    );
+  });
+
+  it("it charges at least the protocol fee of unstaking when depositing a stake account then removing liquidity", async () => {
+    const stakeAccountUnstakeKeypair = Keypair.generate();
+    const stakeAccountDepositKeypair = Keypair.generate();
+    const [unstakeRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountUnstakeKeypair.publicKey
+    );
+    const [depositRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountDepositKeypair.publicKey
+    );
+    const [stakeDepositFeeAccount] = await findStakeDepositFeeAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    const protocolFeeDestBalance = () =>
+      provider.connection.getBalance(protocolFee.account.destination);
+
+    // only the unstake fee is charged
+    await program.methods
+      .setStakeDepositFee({ feeRatio: { num: new BN(0), denom: new BN(1) } })
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        stakeDepositFeeAccount,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+
+    // same size stake accounts
+    await Promise.all(
+      [
+        [stakeAccountUnstakeKeypair, unstakerKeypair],
+        [stakeAccountDepositKeypair, lperKeypair],
+      ].map(([kp, owner]) =>
+        createDelegateStakeTx({
+          connection: provider.connection,
+          stakeAccount: kp.publicKey,
+          payer: owner.publicKey,
+        }).then((tx) =>
+          sendAndConfirmTransaction(provider.connection, tx, [owner, kp])
+        )
+      )
+    );
+
+    // unstake first: it leaves the pool with less liquidity,
+    // so the deposit cannot be charged less than this because of the pool's state
+    const unstakeProtocolFeePre = await protocolFeeDestBalance();
+    await program.methods
+      .unstakeV2(new BN(0))
+      .accounts({
+        unstaker: unstakerKeypair.publicKey,
+        stakeAccount: stakeAccountUnstakeKeypair.publicKey,
+        destination: unstakerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        feeAccount,
+        validatorFeeAccount,
+        stakeAccountRecordAccount: unstakeRecordAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: program.programId,
+        lockupFeeAccount: program.programId,
+        stakeProgram: StakeProgram.programId,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
+      })
+      .signers([unstakerKeypair])
+      .rpc({ skipPreflight: true });
+    const unstakeProtocolFee =
+      (await protocolFeeDestBalance()) - unstakeProtocolFeePre;
+
+    const depositProtocolFeePre = await protocolFeeDestBalance();
+    const { lperAtaAmount: lperAtaPre } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    await program.methods
+      .addLiquidityStake()
+      .accounts({
+        depositor: lperKeypair.publicKey,
+        stakeAccount: stakeAccountDepositKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        lpMint: lpMintKeypair.publicKey,
+        mintLpTokensTo: lperAta,
+        stakeAccountRecordAccount: depositRecordAccount,
+        stakeDepositFeeAccount,
+        feeAccount,
+        validatorFeeAccount,
+        flashAccount,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        allowedLpAccount,
+        withdrawQueueAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        stakeProgram: StakeProgram.programId,
+      })
+      .signers([lperKeypair])
+      .rpc({ skipPreflight: true });
+    const { lperAtaAmount: lperAtaMinted } = await fetchLpFacingTestParams({
+      program,
+      lper: lperKeypair.publicKey,
+      lperAta,
+      poolSolReserves,
+      pool: poolKeypair.publicKey,
+    });
+    await program.methods
+      .removeLiquidityV2(
+        new BN((lperAtaMinted - lperAtaPre).toString()),
+        new BN(0)
+      )
+      .accounts({
+        burnLpTokensFromAuthority: lperKeypair.publicKey,
+        to: lperKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        lpMint: lpMintKeypair.publicKey,
+        burnLpTokensFrom: lperAta,
+        flashAccount,
+        withdrawQueueAccount,
+      })
+      .signers([lperKeypair])
+      .rpc({ skipPreflight: true });
+    const depositProtocolFee =
+      (await protocolFeeDestBalance()) - depositProtocolFeePre;
+
+    expect(unstakeProtocolFee).to.be.gt(0);
+    expect(depositProtocolFee).to.be.gte(unstakeProtocolFee);
+  });
+
+  it("it unstakes with the exact minimum lamports out", async () => {
+    const stakeAccountSlippageKeypair = Keypair.generate();
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountSlippageKeypair.publicKey
+    );
+    await createDelegateStakeTx({
+      connection: provider.connection,
+      stakeAccount: stakeAccountSlippageKeypair.publicKey,
+      payer: unstakerKeypair.publicKey,
+    }).then((tx) =>
+      sendAndConfirmTransaction(provider.connection, tx, [
+        unstakerKeypair,
+        stakeAccountSlippageKeypair,
+      ])
+    );
+
+    const unstakeV2 = (minLamportsOut: BN) =>
+      program.methods
+        .unstakeV2(minLamportsOut)
+        .accounts({
+          unstaker: unstakerKeypair.publicKey,
+          stakeAccount: stakeAccountSlippageKeypair.publicKey,
+          destination: unstakerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          feeAccount,
+          validatorFeeAccount,
+          stakeAccountRecordAccount,
+          protocolFeeAccount: protocolFee.publicKey,
+          protocolFeeDestination: protocolFee.account.destination,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
+          stakeProgram: StakeProgram.programId,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
+        })
+        .signers([unstakerKeypair]);
+
+    // paid_lamports of the unstake-log, see SPEC.md
+    const { raw: logs } = await unstakeV2(new BN(0)).simulate();
+    const [, paidLamports] = logs
+      .map((log) => log.match(/unstake-log: \[.*, (\d+), \d+, \d+\]$/))
+      .find((match) => match !== null);
+    const lamportsOut = new BN(paidLamports);
+    expect(lamportsOut.toNumber()).to.be.gt(0);
+
+    await expect(
+      unstakeV2(lamportsOut.addn(1)).rpc({ skipPreflight: true })
+    ).to.be.eventually.rejected.and.satisfy(
+      checkAnchorError(
+        6015,
+        "Lamports to be received is below the specified minimum"
+      )
+    );
+
+    const unstakerBalancePre = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    await unstakeV2(lamportsOut).rpc({ skipPreflight: true });
+    const unstakerBalancePost = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+
+    expect(unstakerBalancePost - unstakerBalancePre).to.eq(
+      lamportsOut.toNumber()
+    );
+  });
+
+  it("it withdraws an inactive stake account into reserves immediately", async () => {
+    const stakeAccountInactiveKeypair = Keypair.generate();
+    const custodianKeypair = Keypair.generate();
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountInactiveKeypair.publicKey
+    );
+    const [lockupFeeAccount] = await findLockupFeeAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    const [feeDelayAccount] = await findFeeDelayAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+
+    // large enough to be charged if the lockup fee were applied
+    await program.methods
+      .setLockupFee({ feeRatio: { num: new BN(1), denom: new BN(2) } })
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        lockupFeeAccount,
+        feeDelayAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        pendingLockupFeeAccount: program.programId,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+
+    // activated and deactivated in the same epoch,
+    // so it is inactive once the epoch passes
+    const { epoch } = await provider.connection.getEpochInfo();
+    const lockup = new Lockup(0, epoch + 10, custodianKeypair.publicKey);
+    const tx = await createDelegateStakeTx({
+      connection: provider.connection,
+      stakeAccount: stakeAccountInactiveKeypair.publicKey,
+      payer: unstakerKeypair.publicKey,
+      lockup,
+    });
+    tx.add(
+      StakeProgram.deactivate({
+        authorizedPubkey: unstakerKeypair.publicKey,
+        stakePubkey: stakeAccountInactiveKeypair.publicKey,
+      })
+    );
+    await sendAndConfirmTransaction(provider.connection, tx, [
+      unstakerKeypair,
+      stakeAccountInactiveKeypair,
+    ]);
+
+    console.log("awaiting epoch to pass");
+    await waitForEpochToPass(provider.connection);
+
+    const stakeAcc = await getStakeAccount(
+      provider.connection,
+      stakeAccountInactiveKeypair.publicKey
+    );
+    const { epoch: inactiveEpoch } = await provider.connection.getEpochInfo();
+    expect(stakeAccountState(stakeAcc.data, new BN(inactiveEpoch))).to.eq(
+      "inactive"
+    );
+    expect(inactiveEpoch).to.be.lt(epoch + 10);
+
+    const stakeAccountLamports = await provider.connection.getBalance(
+      stakeAccountInactiveKeypair.publicKey
+    );
+    const unstakerBalancePre = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const protocolFeeDestBalancePre = await provider.connection.getBalance(
+      protocolFee.account.destination
+    );
+    const { incomingStake: incomingStakePre } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+    const solReservesLamportsPre = await provider.connection.getBalance(
+      poolSolReserves
+    );
+
+    await program.methods
+      .unstakeV2(new BN(0))
+      .accounts({
+        unstaker: unstakerKeypair.publicKey,
+        stakeAccount: stakeAccountInactiveKeypair.publicKey,
+        destination: unstakerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolSolReserves,
+        feeAccount,
+        validatorFeeAccount,
+        stakeAccountRecordAccount,
+        protocolFeeAccount: protocolFee.publicKey,
+        protocolFeeDestination: protocolFee.account.destination,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        custodian: custodianKeypair.publicKey,
+        lockupFeeAccount,
+        stakeProgram: StakeProgram.programId,
+        poolPauseAccount,
+        protocolPauseAccount,
+        poolConfigAccount,
+        withdrawQueueAccount,
+      })
+      .signers([unstakerKeypair, custodianKeypair])
+      .rpc({ skipPreflight: true });
+
+    const unstakerBalancePost = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const protocolFeeDestBalancePost = await provider.connection.getBalance(
+      protocolFee.account.destination
+    );
+    const { incomingStake: incomingStakePost } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+    const solReservesLamportsPost = await provider.connection.getBalance(
+      poolSolReserves
+    );
+
+    // TODO: this depends on tsBindings (applyFee) being correct, should write less coupled tests
+    const feeLamportsExpected = await program.account.fee
+      .fetch(feeAccount)
+      .then((fee) =>
+        applyFee(fee as unknown as Fee, {
+          poolIncomingStake: incomingStakePre,
+          solReservesLamports: new BN(solReservesLamportsPre),
+          stakeAccountLamports: new BN(stakeAccountLamports),
+        }).toNumber()
+      );
+    const lamportsToUnstaker = unstakerBalancePost - unstakerBalancePre;
+    const protocolFeeLamports =
+      protocolFeeDestBalancePost - protocolFeeDestBalancePre;
+    const feeLamportsCharged = stakeAccountLamports - lamportsToUnstaker;
+    const epsilon = Math.abs(feeLamportsExpected - feeLamportsCharged);
+
+    // withdrawn into the reserves, which pays out the unstaker and protocol fee
+    expect(
+      await provider.connection.getBalance(
+        stakeAccountInactiveKeypair.publicKey
+      )
+    ).to.eq(0);
+    expect(solReservesLamportsPost - solReservesLamportsPre).to.eq(
+      stakeAccountLamports - lamportsToUnstaker - protocolFeeLamports
+    );
+    expect(incomingStakePost.toString()).to.eq(incomingStakePre.toString());
+    await expect(
+      program.account.stakeAccountRecord.fetch(stakeAccountRecordAccount)
+    ).to.be.rejectedWith("Account does not exist");
+    // the lockup fee of half the stake account is not charged
+    expect(epsilon).to.be.below(EPSILON_UPPER_BOUND);
+    expect(feeLamportsCharged).to.be.lt(stakeAccountLamports / 2);
+  });
+
+  it("it partially unstakes into the split stake account", async () => {
+    const stakeAccountPartialKeypair = Keypair.generate();
+    const stakeAccMin = await stakeAccMinLamports(provider.connection);
+    const stakeAccountLamports = 3 * stakeAccMin;
+    await createDelegateStakeTx({
+      connection: provider.connection,
+      stakeAccount: stakeAccountPartialKeypair.publicKey,
+      payer: unstakerKeypair.publicKey,
+      lamports: stakeAccountLamports,
+    }).then((tx) =>
+      sendAndConfirmTransaction(provider.connection, tx, [
+        unstakerKeypair,
+        stakeAccountPartialKeypair,
+      ])
+    );
+    const [splitStakeAccount] = await findSplitStakeAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      stakeAccountPartialKeypair.publicKey
+    );
+    const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      splitStakeAccount
+    );
+    const fetchStakeAuthorities = (stakeAccount: PublicKey) =>
+      getStakeAccount(provider.connection, stakeAccount).then(
+        ({
+          data: {
+            info: {
+              meta: {
+                authorized: { staker, withdrawer },
+              },
+            },
+          },
+        }) => [staker, withdrawer]
+      );
+    // payerKeypair pays for the transaction fees
+    const unstakePartial = (lamports: BN) =>
+      unstakePartialTx(
+        program,
+        {
+          poolAccount: poolKeypair.publicKey,
+          stakeAccount: stakeAccountPartialKeypair.publicKey,
+          unstaker: unstakerKeypair.publicKey,
+          protocolFee,
+        },
+        lamports
+      ).then((tx) =>
+        sendAndConfirmTransaction(
+          provider.connection,
+          tx,
+          [payerKeypair, unstakerKeypair],
+          { skipPreflight: true }
+        )
+      );
+
+    // case 1: split stake account would not be rent-exempt
+    const rentExemptLamports =
+      await provider.connection.getMinimumBalanceForRentExemption(
+        StakeProgram.space
+      );
+    await expect(unstakePartial(new BN(rentExemptLamports - 1))).to.be
+      .eventually.rejected;
+    expect(await provider.connection.getBalance(splitStakeAccount)).to.eq(0);
+
+    // case 2: split off enough for both stake accounts to stay delegated
+    const splitLamports = stakeAccMin;
+    const unstakerBalancePre = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const { incomingStake: incomingStakePre } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+
+    await unstakePartial(new BN(splitLamports));
+
+    const unstakerBalancePost = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const { incomingStake: incomingStakePost } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+    const { lamportsAtCreation } =
+      await program.account.stakeAccountRecord.fetch(
+        stakeAccountRecordAccount
+      );
+    const [splitStaker, splitWithdrawer] = await fetchStakeAuthorities(
+      splitStakeAccount
+    );
+    const [stakerPost, withdrawerPost] = await fetchStakeAuthorities(
+      stakeAccountPartialKeypair.publicKey
+    );
+
+    expect(await provider.connection.getBalance(splitStakeAccount)).to.eq(
+      splitLamports
+    );
+    expect(splitStaker.equals(poolSolReserves)).to.be.true;
+    expect(splitWithdrawer.equals(poolSolReserves)).to.be.true;
+    expect(lamportsAtCreation.toNumber()).to.eq(splitLamports);
+    expect(incomingStakePost.toNumber()).to.eq(
+      incomingStakePre.toNumber() + splitLamports
+    );
+    // the remainder stays in the unstaker's stake account
+    expect(
+      await provider.connection.getBalance(
+        stakeAccountPartialKeypair.publicKey
+      )
+    ).to.eq(stakeAccountLamports - splitLamports);
+    expect(stakerPost.equals(unstakerKeypair.publicKey)).to.be.true;
+    expect(withdrawerPost.equals(unstakerKeypair.publicKey)).to.be.true;
+    expect(unstakerBalancePost).to.be.gt(unstakerBalancePre);
+    expect(unstakerBalancePost).to.be.lt(unstakerBalancePre + splitLamports);
+
+    // case 3: split stake account already exists until it is reclaimed
+    await expect(
+      unstakePartial(new BN(splitLamports))
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(0));
+    expect(
+      await provider.connection.getBalance(
+        stakeAccountPartialKeypair.publicKey
+      )
+    ).to.eq(stakeAccountLamports - splitLamports);
+  });
+
+  it("it unstakes multiple stake accounts together", async () => {
+    const stakeAccountAKeypair = Keypair.generate();
+    const stakeAccountBKeypair = Keypair.generate();
+    // never delegated, so it is charged the fee of the PublicKey.default ValidatorFee
+    const undelegatedStakeAccountKeypair = Keypair.generate();
+    const stakeAccMin = await stakeAccMinLamports(provider.connection);
+    await Promise.all(
+      [stakeAccountAKeypair, stakeAccountBKeypair].map((kp) =>
+        createDelegateStakeTx({
+          connection: provider.connection,
+          stakeAccount: kp.publicKey,
+          payer: unstakerKeypair.publicKey,
+          lamports: stakeAccMin,
+        }).then((tx) =>
+          sendAndConfirmTransaction(provider.connection, tx, [
+            unstakerKeypair,
+            kp,
+          ])
+        )
+      )
+    );
+    await sendAndConfirmTransaction(
+      provider.connection,
+      StakeProgram.createAccount({
+        fromPubkey: unstakerKeypair.publicKey,
+        stakePubkey: undelegatedStakeAccountKeypair.publicKey,
+        authorized: new Authorized(
+          unstakerKeypair.publicKey,
+          unstakerKeypair.publicKey
+        ),
+        lamports: stakeAccMin,
+      }),
+      [unstakerKeypair, undelegatedStakeAccountKeypair]
+    );
+    const [undelegatedValidatorFeeAccount] = await findValidatorFeeAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      PublicKey.default
+    );
+    const [feeDelayAccount] = await findFeeDelayAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    const numNamedAccounts = program.idl.instructions.find(
+      ({ name }) => name === "unstakeMulti"
+    ).accounts.length;
+
+    // payerKeypair pays for the transaction fees
+    const sendUnstakeMulti = (tx: anchor.web3.Transaction) =>
+      sendAndConfirmTransaction(
+        provider.connection,
+        tx,
+        [payerKeypair, unstakerKeypair],
+        { skipPreflight: true }
+      );
+    const unstakeMulti = (stakeAccounts: PublicKey[]) =>
+      unstakeMultiTx(program, {
+        poolAccount: poolKeypair.publicKey,
+        stakeAccounts,
+        unstaker: unstakerKeypair.publicKey,
+        protocolFee,
+      });
+
+    // case 1: remaining accounts are not triples plus an optional referrer
+    const badLengthTx = await unstakeMulti([
+      stakeAccountAKeypair.publicKey,
+      stakeAccountBKeypair.publicKey,
+    ]);
+    const [badLengthIx] = badLengthTx.instructions;
+    badLengthIx.keys.push(
+      ...badLengthIx.keys.slice(numNamedAccounts, numNamedAccounts + 2)
+    );
+    await expect(
+      sendUnstakeMulti(badLengthTx)
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(3005));
+
+    // case 2: records in the wrong triples
+    const swappedRecordsTx = await unstakeMulti([
+      stakeAccountAKeypair.publicKey,
+      stakeAccountBKeypair.publicKey,
+    ]);
+    const [swappedRecordsIx] = swappedRecordsTx.instructions;
+    const recordA = swappedRecordsIx.keys[numNamedAccounts + 1];
+    swappedRecordsIx.keys[numNamedAccounts + 1] =
+      swappedRecordsIx.keys[numNamedAccounts + 4];
+    swappedRecordsIx.keys[numNamedAccounts + 4] = recordA;
+    await expect(
+      sendUnstakeMulti(swappedRecordsTx)
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(6017));
+
+    // case 3: the same stake account twice
+    await expect(
+      unstakeMulti([
+        stakeAccountAKeypair.publicKey,
+        stakeAccountAKeypair.publicKey,
+      ]).then(sendUnstakeMulti)
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(6046));
+
+    // case 4: stake accounts charged different fees
+    await program.methods
+      .setValidatorFee({
+        fee: {
+          override: {
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(1),
+                  denom: new BN(100),
+                },
+              },
+            },
+          },
+        },
+      })
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        voteAccount: PublicKey.default,
+        validatorFeeAccount: undelegatedValidatorFeeAccount,
+        feeDelayAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        pendingValidatorFeeAccount: program.programId,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+    await expect(
+      unstakeMulti([
+        stakeAccountAKeypair.publicKey,
+        undelegatedStakeAccountKeypair.publicKey,
+      ]).then(sendUnstakeMulti)
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(6020));
+    await program.methods
+      .removeValidatorFee()
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        voteAccount: PublicKey.default,
+        validatorFeeAccount: undelegatedValidatorFeeAccount,
+        refundRentTo: payerKeypair.publicKey,
+        feeDelayAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        pendingValidatorFeeAccount: program.programId,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+
+    // case 5: each stake account is within the pool's max unstake size
+    // but their total is not
+    await program.methods
+      .setPoolConfig(new BN(stakeAccMin + 1), null, null, false)
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolConfigAccount,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+    await expect(
+      unstakeMulti([
+        stakeAccountAKeypair.publicKey,
+        stakeAccountBKeypair.publicKey,
+      ]).then(sendUnstakeMulti)
+    ).to.be.eventually.rejected.and.satisfy(checkSystemError(6030));
+    await program.methods
+      .setPoolConfig(null, null, null, false)
+      .accounts({
+        payer: payerKeypair.publicKey,
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: poolKeypair.publicKey,
+        poolConfigAccount,
+      })
+      .signers([payerKeypair])
+      .rpc({ skipPreflight: true });
+
+    // case 6: unstake both together
+    const stakeAccounts = [
+      stakeAccountAKeypair.publicKey,
+      stakeAccountBKeypair.publicKey,
+    ];
+    const unstakerBalancePre = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const { incomingStake: incomingStakePre } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+
+    await unstakeMulti(stakeAccounts).then(sendUnstakeMulti);
+
+    const unstakerBalancePost = await provider.connection.getBalance(
+      unstakerKeypair.publicKey
+    );
+    const { incomingStake: incomingStakePost } =
+      await program.account.pool.fetch(poolKeypair.publicKey);
+    expect(incomingStakePost.toNumber()).to.eq(
+      incomingStakePre.toNumber() + 2 * stakeAccMin
+    );
+    expect(unstakerBalancePost).to.be.gt(unstakerBalancePre);
+    expect(unstakerBalancePost).to.be.lt(unstakerBalancePre + 2 * stakeAccMin);
+    for (const stakeAccount of stakeAccounts) {
+      const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+        program.programId,
+        poolKeypair.publicKey,
+        stakeAccount
+      );
+      const { lamportsAtCreation } =
+        await program.account.stakeAccountRecord.fetch(
+          stakeAccountRecordAccount
+        );
+      expect(lamportsAtCreation.toNumber()).to.eq(stakeAccMin);
+      const {
+        data: {
+          info: {
+            meta: {
+              authorized: { staker, withdrawer },
+            },
+          },
+        },
+      } = await getStakeAccount(provider.connection, stakeAccount);
+      expect(staker.equals(poolSolReserves)).to.be.true;
+      expect(withdrawer.equals(poolSolReserves)).to.be.true;
+    }
+  });
 });
@@ -7,13 +7,17 @@ import {
   Lockup,
   LAMPORTS_PER_SOL,
   StakeProgram,
+  SystemProgram,
+  Transaction,
   VersionedTransaction,
   TransactionMessage,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
 } from "@solana/web3.js";
 import {
   createAssociatedTokenAccount,
+  createWrappedNativeAccount,
   getAccount,
   getAssociatedTokenAddress,
   getMint,
@@ -29,15 +33,37 @@ import {
   findStakeAccountRecordAccount,
   LiquidityLinearFeeInner,
   ProtocolFeeAccount,
+  findValidatorFeeAccount,
+  findAllowedLpAccount,
+  findPoolConfigAccount,
+  findWithdrawQueueAccount,
+  findWithdrawTicketAccount,
+  findWsolStagingAccount,
+  findPoolPauseAccount,
+  findProtocolPauseAccount,
+  findFeeDelayAccount,
+  findFeeAuthorityNomineeAccount,
+  findLockedLpTokenAccount,
+  findLockupFeeAccount,
+  findPendingLockupFeeAccount,
+  findPendingValidatorFeeAccount,
+  applyPendingLockupFeeTx,
+  applyPendingValidatorFeeTx,
+  addLiquidityTx,
+  closePoolTx,
+  removeLiquidityTx,
 } from "../ts/src";
 import { Unstake } from "../target/types/unstake";
 import {
   airdrop,
+  checkAnchorError,
+  checkSystemError,
   createDelegateStakeTx,
+  EPSILON_UPPER_BOUND,
   fetchLpFacingTestParams,
+  testVoteAccount,
   waitForEpochToPass,
-  checkAnchorError,
-  EPSILON_UPPER_BOUND,
+  waitForUnixTimestamp,
 } from "./utils";
 import { expect, use as chaiUse } from "chai";
 import chaiAsPromised from "chai-as-promised";
@@ -62,6 +88,13 @@ describe("internals", () => {
 
   let [poolSolReserves] = [null as PublicKey, 0];
   let [feeAccount] = [null as PublicKey, 0];
+  let [validatorFeeAccount] = [null as PublicKey, 0];
+  let [poolPauseAccount] = [null as PublicKey, 0];
+  let [poolConfigAccount] = [null as PublicKey, 0];
+  let [allowedLpAccount] = [null as PublicKey, 0];
+  let [withdrawQueueAccount] = [null as PublicKey, 0];
+  let [protocolPauseAccount] = [null as PublicKey, 0];
+  let [feeDelayAccount] = [null as PublicKey, 0];
   let lperAta = null as PublicKey;
   let protocolFeeAddr = null as PublicKey;
   let protocolFee = null as ProtocolFeeAccount;
@@ -117,6 +150,33 @@ describe("internals", () => {
       program.programId,
       poolKeypair.publicKey
     );
+    [validatorFeeAccount] = await findValidatorFeeAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      testVoteAccount()
+    );
+    [poolPauseAccount] = await findPoolPauseAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [poolConfigAccount] = await findPoolConfigAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [withdrawQueueAccount] = await findWithdrawQueueAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
+    [allowedLpAccount] = await findAllowedLpAccount(
+      program.programId,
+      poolKeypair.publicKey,
+      lperKeypair.publicKey
+    );
+    [protocolPauseAccount] = await findProtocolPauseAccount(program.programId);
+    [feeDelayAccount] = await findFeeDelayAccount(
+      program.programId,
+      poolKeypair.publicKey
+    );
     lperAta = await getAssociatedTokenAddress(
       lpMintKeypair.publicKey,
       lperKeypair.publicKey
@@ -195,6 +255,114 @@ describe("internals", () => {
     expect(mint.supply).to.eq(BigInt(0));
   });
 
+  it("it initializes a liquidity pool with initial liquidity", async () => {
+    const initialLamports = new BN(0.1 * LAMPORTS_PER_SOL);
+    const fee = {
+      fee: {
+        flat: {
+          ratio: {
+            num: new BN(1),
+            denom: new BN(1000),
+          },
+        },
+      },
+    };
+
+    for (const lockInitialLp of [false, true]) {
+      const seededPoolKeypair = Keypair.generate();
+      const seededLpMintKeypair = Keypair.generate();
+      const lpTokenAccountKeypair = Keypair.generate();
+      const [seededPoolSolReserves] = await findPoolSolReserves(
+        program.programId,
+        seededPoolKeypair.publicKey
+      );
+      const [seededFeeAccount] = await findPoolFeeAccount(
+        program.programId,
+        seededPoolKeypair.publicKey
+      );
+      const [lockedLpTokenAccount] = await findLockedLpTokenAccount(
+        program.programId,
+        seededPoolKeypair.publicKey
+      );
+      // unused optional account is set to the program ID
+      const lpTokenAccount = lockInitialLp
+        ? lockedLpTokenAccount
+        : lpTokenAccountKeypair.publicKey;
+      const lpTokenAccountOwner = lockInitialLp
+        ? seededPoolSolReserves
+        : payerKeypair.publicKey;
+      const createPoolWithLiquidity = (
+        mintLpTokensTo: PublicKey,
+        lockedLpTokenAccountOption: PublicKey
+      ) =>
+        program.methods
+          .createPoolWithLiquidity(fee, initialLamports)
+          .accounts({
+            payer: payerKeypair.publicKey,
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: seededPoolKeypair.publicKey,
+            poolSolReserves: seededPoolSolReserves,
+            feeAccount: seededFeeAccount,
+            lpMint: seededLpMintKeypair.publicKey,
+            lpTokenAccountOwner: payerKeypair.publicKey,
+            mintLpTokensTo,
+            lockedLpTokenAccount: lockedLpTokenAccountOption,
+            protocolPauseAccount,
+          })
+          .signers(
+            lockInitialLp
+              ? [payerKeypair, seededPoolKeypair, seededLpMintKeypair]
+              : [
+                  payerKeypair,
+                  seededPoolKeypair,
+                  seededLpMintKeypair,
+                  lpTokenAccountKeypair,
+                ]
+          )
+          .rpc({ skipPreflight: true });
+
+      if (lockInitialLp) {
+        await expect(
+          createPoolWithLiquidity(program.programId, program.programId)
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(
+            6043,
+            "Exactly one of mint_lp_tokens_to and locked_lp_token_account must be provided"
+          )
+        );
+        await createPoolWithLiquidity(program.programId, lockedLpTokenAccount);
+      } else {
+        await createPoolWithLiquidity(
+          lpTokenAccountKeypair.publicKey,
+          program.programId
+        );
+      }
+
+      const pool = await program.account.pool.fetch(
+        seededPoolKeypair.publicKey
+      );
+      expect(pool.lpMint.equals(seededLpMintKeypair.publicKey)).to.be.true;
+      expect(pool.incomingStake.toNumber()).to.eq(0);
+      const mint = await getMint(
+        provider.connection,
+        seededLpMintKeypair.publicKey
+      );
+      expect(mint.supply).to.eq(BigInt(initialLamports.toString()));
+      const lpTokenAccountState = await getAccount(
+        provider.connection,
+        lpTokenAccount
+      );
+      expect(lpTokenAccountState.owner.equals(lpTokenAccountOwner)).to.be.true;
+      expect(lpTokenAccountState.amount).to.eq(
+        BigInt(initialLamports.toString())
+      );
+      const reservesLamports = await provider.connection.getBalance(
+        seededPoolSolReserves
+      );
+      expect(reservesLamports).to.eq(initialLamports.toNumber());
+    }
+  });
+
   describe("LP facing", () => {
     const AMOUNT = new BN(0.1 * LAMPORTS_PER_SOL);
 
@@ -226,7 +394,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .addLiquidity(AMOUNT)
+        .addLiquidityV2(AMOUNT, new BN(0))
         .accounts({
           from: lperKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
@@ -234,6 +402,11 @@ describe("internals", () => {
           lpMint: lpMintKeypair.publicKey,
           mintLpTokensTo: lperAta,
           flashAccount,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          allowedLpAccount,
+          withdrawQueueAccount,
         })
         .signers([lperKeypair])
         .rpc({ skipPreflight: true });
@@ -286,7 +459,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .addLiquidity(AMOUNT)
+        .addLiquidityV2(AMOUNT, new BN(0))
         .accounts({
           from: lperKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
@@ -294,6 +467,11 @@ describe("internals", () => {
           lpMint: lpMintKeypair.publicKey,
           mintLpTokensTo: lperAta,
           flashAccount,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          allowedLpAccount,
+          withdrawQueueAccount,
         })
         .signers([lperKeypair])
         .rpc({ skipPreflight: true });
@@ -323,6 +501,99 @@ describe("internals", () => {
       );
     });
 
+    it("it rejects to add and remove liquidity below the minimum out", async () => {
+      // pool has 2*AMOUNT liquidity and 2*AMOUNT LP tokens
+      await expect(
+        program.methods
+          .addLiquidityV2(AMOUNT, AMOUNT.addn(1))
+          .accounts({
+            from: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: lperAta,
+            flashAccount,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            allowedLpAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6037,
+          "LP tokens to be minted is below the specified minimum"
+        )
+      );
+
+      await expect(
+        program.methods
+          .removeLiquidityV2(AMOUNT, AMOUNT.addn(1))
+          .accounts({
+            burnLpTokensFromAuthority: lperKeypair.publicKey,
+            to: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            burnLpTokensFrom: lperAta,
+            flashAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6038,
+          "Lamports of liquidity to be removed is below the specified minimum"
+        )
+      );
+    });
+
+    it("it rejects deprecated v1 add and remove liquidity", async () => {
+      await expect(
+        program.methods
+          .addLiquidity(AMOUNT)
+          .accounts({
+            from: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: lperAta,
+            flashAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6045,
+          "This instruction is deprecated, use its V2 version instead"
+        )
+      );
+
+      await expect(
+        program.methods
+          .removeLiquidity(AMOUNT)
+          .accounts({
+            burnLpTokensFromAuthority: lperKeypair.publicKey,
+            to: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            burnLpTokensFrom: lperAta,
+            flashAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6045,
+          "This instruction is deprecated, use its V2 version instead"
+        )
+      );
+    });
+
     it("it remove liquidity to non-zero", async () => {
       // remove AMOUNT liquidity from existing 2*AMOUNT -> LP tokens and liquidity should half
       const {
@@ -342,7 +613,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .removeLiquidity(AMOUNT)
+        .removeLiquidityV2(AMOUNT, new BN(0))
         .accounts({
           burnLpTokensFromAuthority: lperKeypair.publicKey,
           to: lperKeypair.publicKey,
@@ -351,6 +622,7 @@ describe("internals", () => {
           lpMint: lpMintKeypair.publicKey,
           burnLpTokensFrom: lperAta,
           flashAccount,
+          withdrawQueueAccount,
         })
         .signers([lperKeypair])
         .rpc({ skipPreflight: true });
@@ -398,7 +670,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .removeLiquidity(AMOUNT)
+        .removeLiquidityV2(AMOUNT, new BN(0))
         .accounts({
           burnLpTokensFromAuthority: lperKeypair.publicKey,
           to: lperKeypair.publicKey,
@@ -407,6 +679,7 @@ describe("internals", () => {
           lpMint: lpMintKeypair.publicKey,
           burnLpTokensFrom: lperAta,
           flashAccount,
+          withdrawQueueAccount,
         })
         .signers([lperKeypair])
         .rpc({ skipPreflight: true });
@@ -439,126 +712,648 @@ describe("internals", () => {
       expect(ownedLamportsPost.toString()).to.eq(new BN(0).toString());
       expect(reservesLamportsPost).to.eq(0);
     });
-  });
-
-  const LIQUIDITY_LINEAR_FEE = {
-    liquidityLinear: {
-      params: {
-        maxLiqRemaining: {
-          num: new BN(25),
-          denom: new BN(1000),
-        },
-        zeroLiqRemaining: {
-          num: new BN(42),
-          denom: new BN(1000),
-        },
-      },
-    },
-  };
-
-  describe("Admin facing", () => {
-    it("it sets fee", async () => {
-      // NOTE: assuming the fee account isn't previously set to Flat 69% fee
-      // set Flat fee
-      const FLAT_FEE = {
-        flat: {
-          ratio: {
-            num: new BN(69),
-            denom: new BN(100),
-          },
-        },
-      };
 
-      await program.methods
-        .setFee({ fee: FLAT_FEE })
-        .accounts({
-          feeAuthority: payerKeypair.publicKey,
-          poolAccount: poolKeypair.publicKey,
-          feeAccount,
-        })
-        .signers([payerKeypair])
-        .rpc({ skipPreflight: true });
+    it("it adds and removes liquidity with wSOL", async () => {
+      const lperWSolAcc = await createWrappedNativeAccount(
+        provider.connection,
+        lperKeypair,
+        lperKeypair.publicKey,
+        AMOUNT.toNumber()
+      );
+      const [wsolStagingAccount] = await findWsolStagingAccount(
+        program.programId,
+        poolKeypair.publicKey
+      );
 
-      await program.account.fee.fetch(feeAccount).then(({ fee }) => {
-        // NOTE: work around for BN's internal state differences
-        expect(JSON.stringify(fee)).to.eql(JSON.stringify(FLAT_FEE));
+      const {
+        lperAtaAmount: lperAtaPre,
+        lperLamports: lperLamportsPre,
+        reserveLamports: reservesLamportsPre,
+      } = await fetchLpFacingTestParams({
+        program,
+        lper: lperKeypair.publicKey,
+        lperAta,
+        poolSolReserves,
+        pool: poolKeypair.publicKey,
       });
 
-      // set LiquidityLinear fee
       await program.methods
-        .setFee({ fee: LIQUIDITY_LINEAR_FEE })
+        .addLiquidityWsol(AMOUNT, AMOUNT)
         .accounts({
-          feeAuthority: payerKeypair.publicKey,
+          fromAuthority: lperKeypair.publicKey,
+          payer: lperKeypair.publicKey,
+          from: lperWSolAcc,
+          wsolStagingAccount,
+          nativeMint: NATIVE_MINT,
           poolAccount: poolKeypair.publicKey,
-          feeAccount,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          mintLpTokensTo: lperAta,
+          flashAccount,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          allowedLpAccount,
+          withdrawQueueAccount,
         })
-        .signers([payerKeypair])
+        .signers([lperKeypair])
         .rpc({ skipPreflight: true });
 
-      await program.account.fee.fetch(feeAccount).then(({ fee }) => {
-        // NOTE: work around for BN's internal state differences
-        expect(JSON.stringify(fee)).to.eql(
-          JSON.stringify(LIQUIDITY_LINEAR_FEE)
-        );
+      const {
+        lperAtaAmount: lperAtaPost,
+        lperLamports: lperLamportsPost,
+        reserveLamports: reservesLamportsPost,
+      } = await fetchLpFacingTestParams({
+        program,
+        lper: lperKeypair.publicKey,
+        lperAta,
+        poolSolReserves,
+        pool: poolKeypair.publicKey,
       });
-    });
 
-    it("it rejects to set fee authority if the authority doesn't match", async () => {
-      const rando = Keypair.generate();
-      const tempFeeAuthority = Keypair.generate();
+      // staging account's rent is refunded to the payer
+      expect(lperLamportsPost).to.eq(lperLamportsPre);
+      expect(lperAtaPost).to.eq(lperAtaPre + BigInt(AMOUNT.toString()));
+      expect(reservesLamportsPost).to.eq(
+        reservesLamportsPre + AMOUNT.toNumber()
+      );
+      const { amount: lperWSolPost } = await getAccount(
+        provider.connection,
+        lperWSolAcc
+      );
+      expect(lperWSolPost).to.eq(BigInt(0));
+      const wsolStaging = await provider.connection.getAccountInfo(
+        wsolStagingAccount
+      );
+      expect(wsolStaging).to.be.null;
 
       await expect(
         program.methods
-          .setFeeAuthority()
+          .removeLiquidityWsol(AMOUNT, new BN(0))
           .accounts({
-            feeAuthority: rando.publicKey,
+            burnLpTokensFromAuthority: lperKeypair.publicKey,
+            to: lperAta,
             poolAccount: poolKeypair.publicKey,
-            newFeeAuthority: tempFeeAuthority.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            burnLpTokensFrom: lperAta,
+            flashAccount,
+            withdrawQueueAccount,
           })
-          .signers([rando])
+          .signers([lperKeypair])
           .rpc({ skipPreflight: true })
       ).to.be.eventually.rejected.and.satisfy(
         checkAnchorError(
-          6002,
-          "The provided fee authority does not have the authority over the provided pool account"
+          6010,
+          "Destination token account is not a wrapped SOL account"
         )
       );
-    });
 
-    it("it set fee authority", async () => {
-      const tempFeeAuthority = Keypair.generate();
       await program.methods
-        .setFeeAuthority()
+        .removeLiquidityWsol(AMOUNT, AMOUNT)
         .accounts({
-          feeAuthority: payerKeypair.publicKey,
+          burnLpTokensFromAuthority: lperKeypair.publicKey,
+          to: lperWSolAcc,
           poolAccount: poolKeypair.publicKey,
-          newFeeAuthority: tempFeeAuthority.publicKey,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          burnLpTokensFrom: lperAta,
+          flashAccount,
+          withdrawQueueAccount,
         })
-        .signers([payerKeypair])
+        .signers([lperKeypair])
         .rpc({ skipPreflight: true });
 
-      await program.account.pool
-        .fetch(poolKeypair.publicKey)
-        .then(({ feeAuthority }) => {
-          expect(feeAuthority.equals(tempFeeAuthority.publicKey)).to.be.true;
-        });
+      const {
+        lperAtaAmount: lperAtaFinal,
+        reserveLamports: reservesLamportsFinal,
+      } = await fetchLpFacingTestParams({
+        program,
+        lper: lperKeypair.publicKey,
+        lperAta,
+        poolSolReserves,
+        pool: poolKeypair.publicKey,
+      });
+
+      expect(lperAtaFinal).to.eq(lperAtaPre);
+      expect(reservesLamportsFinal).to.eq(reservesLamportsPre);
+      const { amount: lperWSolFinal } = await getAccount(
+        provider.connection,
+        lperWSolAcc
+      );
+      expect(lperWSolFinal).to.eq(BigInt(AMOUNT.toString()));
+    });
+
+    it("it requests a withdrawal and claims the ticket", async () => {
+      const [withdrawTicketAccount] = await findWithdrawTicketAccount(
+        program.programId,
+        poolKeypair.publicKey,
+        lperKeypair.publicKey
+      );
 
-      // revert the change to keep the tests bellow unaffected by this test
       await program.methods
-        .setFeeAuthority()
+        .addLiquidityV2(AMOUNT, new BN(0))
         .accounts({
-          feeAuthority: tempFeeAuthority.publicKey,
+          from: lperKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
-          newFeeAuthority: payerKeypair.publicKey,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          mintLpTokensTo: lperAta,
+          flashAccount,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          allowedLpAccount,
+          withdrawQueueAccount,
         })
-        .signers([tempFeeAuthority])
+        .signers([lperKeypair])
         .rpc({ skipPreflight: true });
 
-      await program.account.pool
-        .fetch(poolKeypair.publicKey)
-        .then(({ feeAuthority }) => {
-          expect(feeAuthority.equals(payerKeypair.publicKey)).to.be.true;
+      await program.methods
+        .requestWithdrawal(AMOUNT)
+        .accounts({
+          payer: lperKeypair.publicKey,
+          burnLpTokensFromAuthority: lperKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          burnLpTokensFrom: lperAta,
+          flashAccount,
+          withdrawQueueAccount,
+          withdrawTicketAccount,
+        })
+        .signers([lperKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.withdrawTicket
+        .fetch(withdrawTicketAccount)
+        .then(({ lamports }) => {
+          expect(lamports.toString()).to.eq(AMOUNT.toString());
+        });
+      await program.account.withdrawQueue
+        .fetch(withdrawQueueAccount)
+        .then(({ earmarkedLamports }) => {
+          expect(earmarkedLamports.toString()).to.eq(AMOUNT.toString());
         });
+
+      const { lperLamports: lperLamportsPre } = await fetchLpFacingTestParams({
+        program,
+        lper: lperKeypair.publicKey,
+        lperAta,
+        poolSolReserves,
+        pool: poolKeypair.publicKey,
+      });
+      const ticketRentLamports = (
+        await provider.connection.getAccountInfo(withdrawTicketAccount)
+      ).lamports;
+
+      await program.methods
+        .claimWithdrawTicket()
+        .accounts({
+          owner: lperKeypair.publicKey,
+          to: lperKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          withdrawQueueAccount,
+          withdrawTicketAccount,
+        })
+        .signers([lperKeypair])
+        .rpc({ skipPreflight: true });
+
+      const {
+        lperAtaAmount: lperAtaPost,
+        lperLamports: lperLamportsPost,
+        reserveLamports: reservesLamportsPost,
+      } = await fetchLpFacingTestParams({
+        program,
+        lper: lperKeypair.publicKey,
+        lperAta,
+        poolSolReserves,
+        pool: poolKeypair.publicKey,
+      });
+
+      expect(lperAtaPost).to.eq(BigInt(0));
+      expect(lperLamportsPost).to.eq(
+        lperLamportsPre + AMOUNT.toNumber() + ticketRentLamports
+      );
+      expect(reservesLamportsPost).to.eq(0);
+      expect(await provider.connection.getAccountInfo(withdrawTicketAccount))
+        .to.be.null;
+      await program.account.withdrawQueue
+        .fetch(withdrawQueueAccount)
+        .then(({ earmarkedLamports }) => {
+          expect(earmarkedLamports.toNumber()).to.eq(0);
+        });
+    });
+  });
+
+  const LIQUIDITY_LINEAR_FEE = {
+    liquidityLinear: {
+      params: {
+        maxLiqRemaining: {
+          num: new BN(25),
+          denom: new BN(1000),
+        },
+        zeroLiqRemaining: {
+          num: new BN(42),
+          denom: new BN(1000),
+        },
+      },
+    },
+  };
+
+  describe("Admin facing", () => {
+    it("it sets fee", async () => {
+      // NOTE: assuming the fee account isn't previously set to Flat 69% fee
+      // set Flat fee
+      const FLAT_FEE = {
+        flat: {
+          ratio: {
+            num: new BN(69),
+            denom: new BN(100),
+          },
+        },
+      };
+
+      await program.methods
+        .setFee({ fee: FLAT_FEE })
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.fee.fetch(feeAccount).then(({ fee }) => {
+        // NOTE: work around for BN's internal state differences
+        expect(JSON.stringify(fee)).to.eql(JSON.stringify(FLAT_FEE));
+      });
+
+      // set LiquidityLinear fee
+      await program.methods
+        .setFee({ fee: LIQUIDITY_LINEAR_FEE })
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.fee.fetch(feeAccount).then(({ fee }) => {
+        // NOTE: work around for BN's internal state differences
+        expect(JSON.stringify(fee)).to.eql(
+          JSON.stringify(LIQUIDITY_LINEAR_FEE)
+        );
+      });
+    });
+
+    it("it rejects to propose fee authority if the authority doesn't match", async () => {
+      const rando = Keypair.generate();
+      const tempFeeAuthority = Keypair.generate();
+      const [feeAuthorityNomineeAccount] = await findFeeAuthorityNomineeAccount(
+        program.programId,
+        poolKeypair.publicKey
+      );
+
+      await expect(
+        program.methods
+          .proposeFeeAuthority()
+          .accounts({
+            feeAuthority: rando.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            newFeeAuthority: tempFeeAuthority.publicKey,
+            feeAuthorityNomineeAccount,
+          })
+          .signers([rando])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6002,
+          "The provided fee authority does not have the authority over the provided pool account"
+        )
+      );
+    });
+
+    it("it transfers fee authority in two steps", async () => {
+      const rando = Keypair.generate();
+      const tempFeeAuthority = Keypair.generate();
+      await airdrop(provider.connection, tempFeeAuthority.publicKey);
+      const [feeAuthorityNomineeAccount] = await findFeeAuthorityNomineeAccount(
+        program.programId,
+        poolKeypair.publicKey
+      );
+
+      // proposer can cancel
+      await program.methods
+        .proposeFeeAuthority()
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          newFeeAuthority: rando.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .cancelFeeAuthorityProposal()
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      expect(
+        await program.account.feeAuthorityNominee.fetchNullable(
+          feeAuthorityNomineeAccount
+        )
+      ).to.be.null;
+
+      await program.methods
+        .proposeFeeAuthority()
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          newFeeAuthority: tempFeeAuthority.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      await program.account.feeAuthorityNominee
+        .fetch(feeAuthorityNomineeAccount)
+        .then(({ nominee, proposer }) => {
+          expect(nominee.equals(tempFeeAuthority.publicKey)).to.be.true;
+          expect(proposer.equals(payerKeypair.publicKey)).to.be.true;
+        });
+
+      await expect(
+        program.methods
+          .acceptFeeAuthority()
+          .accounts({
+            newFeeAuthority: rando.publicKey,
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            feeAuthorityNomineeAccount,
+          })
+          .signers([rando])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(6025, "The signer is not the nominated authority")
+      );
+
+      await program.methods
+        .acceptFeeAuthority()
+        .accounts({
+          newFeeAuthority: tempFeeAuthority.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([tempFeeAuthority])
+        .rpc({ skipPreflight: true });
+
+      await program.account.pool
+        .fetch(poolKeypair.publicKey)
+        .then(({ feeAuthority }) => {
+          expect(feeAuthority.equals(tempFeeAuthority.publicKey)).to.be.true;
+        });
+
+      // revert the change to keep the tests bellow unaffected by this test
+      await program.methods
+        .proposeFeeAuthority()
+        .accounts({
+          feeAuthority: tempFeeAuthority.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          newFeeAuthority: payerKeypair.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([tempFeeAuthority])
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .acceptFeeAuthority()
+        .accounts({
+          newFeeAuthority: payerKeypair.publicKey,
+          feeAuthority: tempFeeAuthority.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          feeAuthorityNomineeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.pool
+        .fetch(poolKeypair.publicKey)
+        .then(({ feeAuthority }) => {
+          expect(feeAuthority.equals(payerKeypair.publicKey)).to.be.true;
+        });
+    });
+
+    it("it pauses and unpauses add liquidity", async () => {
+      const rando = Keypair.generate();
+      const guardian = Keypair.generate();
+      const paused = {
+        unstake: false,
+        takeFlashLoan: false,
+        addLiquidity: true,
+      };
+
+      await expect(
+        program.methods
+          .setPoolPause(paused)
+          .accounts({
+            payer: payerKeypair.publicKey,
+            authority: rando.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolPauseAccount,
+          })
+          .signers([payerKeypair, rando])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6029,
+          "The signer is neither the pool's fee authority nor its pause guardian"
+        )
+      );
+
+      await program.methods
+        .setPauseGuardian()
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          newGuardian: guardian.publicKey,
+          poolPauseAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.methods
+        .setPoolPause(paused)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          authority: guardian.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolPauseAccount,
+        })
+        .signers([payerKeypair, guardian])
+        .rpc({ skipPreflight: true });
+
+      await expect(
+        program.methods
+          .addLiquidityV2(new BN(LAMPORTS_PER_SOL), new BN(0))
+          .accounts({
+            from: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: lperAta,
+            flashAccount,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            allowedLpAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(6027, "This instruction is paused for the pool")
+      );
+
+      // unpause to keep the tests bellow unaffected by this test
+      await program.methods
+        .setPoolPause({ ...paused, addLiquidity: false })
+        .accounts({
+          payer: payerKeypair.publicKey,
+          authority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolPauseAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.poolPause
+        .fetch(poolPauseAccount)
+        .then(({ guardian: g, paused: p }) => {
+          expect(g.equals(guardian.publicKey)).to.be.true;
+          expect(p.addLiquidity).to.be.false;
+        });
+    });
+
+    it("it enforces the deposit cap and LP allowlist", async () => {
+      await program.methods
+        .setPoolConfig(null, null, null, true)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolConfigAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await expect(
+        program.methods
+          .addLiquidityV2(new BN(LAMPORTS_PER_SOL), new BN(0))
+          .accounts({
+            from: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: lperAta,
+            flashAccount,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            allowedLpAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(6033, "The depositor is not on the pool's LP allowlist")
+      );
+
+      await program.methods
+        .addAllowedLp()
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          lp: lperKeypair.publicKey,
+          allowedLpAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .setPoolConfig(null, null, new BN(1), true)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolConfigAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await expect(
+        program.methods
+          .addLiquidityV2(new BN(LAMPORTS_PER_SOL), new BN(0))
+          .accounts({
+            from: lperKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            lpMint: lpMintKeypair.publicKey,
+            mintLpTokensTo: lperAta,
+            flashAccount,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            allowedLpAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lperKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6032,
+          "Adding this amount of liquidity exceeds the pool's deposit cap"
+        )
+      );
+
+      // remove the limits to keep the tests bellow unaffected by this test
+      await program.methods
+        .setPoolConfig(null, null, null, false)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolConfigAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.methods
+        .removeAllowedLp()
+        .accounts({
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          lp: lperKeypair.publicKey,
+          allowedLpAccount,
+          refundRentTo: payerKeypair.publicKey,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      expect(await provider.connection.getAccountInfo(allowedLpAccount)).to.be
+        .null;
     });
 
     it("it rejects to set fee when the authority does not match", async () => {
@@ -585,6 +1380,9 @@ describe("internals", () => {
             feeAuthority: rando.publicKey,
             poolAccount: poolKeypair.publicKey,
             feeAccount,
+            feeDelayAccount,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            pendingFeeAccount: program.programId,
           })
           .signers([rando])
           .rpc({ skipPreflight: true })
@@ -613,6 +1411,9 @@ describe("internals", () => {
             feeAuthority: payerKeypair.publicKey,
             poolAccount: poolKeypair.publicKey,
             feeAccount,
+            feeDelayAccount,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            pendingFeeAccount: program.programId,
           })
           .signers([payerKeypair])
           .rpc({ skipPreflight: true })
@@ -639,6 +1440,9 @@ describe("internals", () => {
             feeAuthority: payerKeypair.publicKey,
             poolAccount: poolKeypair.publicKey,
             feeAccount,
+            feeDelayAccount,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            pendingFeeAccount: program.programId,
           })
           .signers([payerKeypair])
           .rpc({ skipPreflight: true })
@@ -671,6 +1475,9 @@ describe("internals", () => {
             feeAuthority: payerKeypair.publicKey,
             poolAccount: poolKeypair.publicKey,
             feeAccount,
+            feeDelayAccount,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            pendingFeeAccount: program.programId,
           })
           .signers([payerKeypair])
           .rpc({ skipPreflight: true })
@@ -703,6 +1510,9 @@ describe("internals", () => {
             feeAuthority: payerKeypair.publicKey,
             poolAccount: poolKeypair.publicKey,
             feeAccount,
+            feeDelayAccount,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            pendingFeeAccount: program.programId,
           })
           .signers([payerKeypair])
           .rpc({ skipPreflight: true })
@@ -735,141 +1545,780 @@ describe("internals", () => {
           6002,
           "The provided fee authority does not have the authority over the provided pool account"
         )
-      );
-    });
-
-    it("it creates token metadata", async () => {
+      );
+    });
+
+    it("it creates token metadata", async () => {
+      await program.methods
+        .setLpTokenMetadata(metadata)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          metadata: metadataAccount,
+          metadataProgram,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      const createdMetadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        metadataAccount
+      );
+
+      expect(createdMetadata.data.name.replace(/\0/g, "")).to.eq(metadata.name);
+      expect(createdMetadata.data.symbol.replace(/\0/g, "")).to.eq(
+        metadata.symbol
+      );
+      expect(createdMetadata.data.uri.replace(/\0/g, "")).to.eq(metadata.uri);
+      expect(createdMetadata.tokenStandard).to.eq(TokenStandard.Fungible);
+    });
+
+    it("it updates token metadata", async () => {
+      const newMetadata = {
+        name: "new name",
+        symbol: "NEWSYM",
+        uri: "new.com",
+      };
+
+      await program.methods
+        .setLpTokenMetadata(newMetadata)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolSolReserves,
+          lpMint: lpMintKeypair.publicKey,
+          metadata: metadataAccount,
+          metadataProgram,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      const updatedMetadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        metadataAccount
+      );
+
+      expect(updatedMetadata.data.name.replace(/\0/g, "")).to.eq(
+        newMetadata.name
+      );
+      expect(updatedMetadata.data.symbol.replace(/\0/g, "")).to.eq(
+        newMetadata.symbol
+      );
+      expect(updatedMetadata.data.uri.replace(/\0/g, "")).to.eq(
+        newMetadata.uri
+      );
+      expect(updatedMetadata.tokenStandard).to.eq(TokenStandard.Fungible);
+    });
+
+    it("it rejects to set flash loan fee with invalid fee authority", async () => {
+      const fakeAuthKeypair = lperKeypair;
+      await expect(
+        program.methods
+          .setFlashLoanFee({
+            feeRatio: {
+              num: new BN(1),
+              denom: new BN(1_000),
+            },
+          })
+          .accounts({
+            payer: fakeAuthKeypair.publicKey,
+            feeAuthority: fakeAuthKeypair.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            flashLoanFeeAccount,
+          })
+          .signers([fakeAuthKeypair])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6002,
+          "The provided fee authority does not have the authority over the provided pool account"
+        )
+      );
+    });
+
+    it("it creates flash loan fee", async () => {
+      const fee = {
+        feeRatio: {
+          num: new BN(1),
+          denom: new BN(1_000),
+        },
+      };
+      await program.methods
+        .setFlashLoanFee(fee)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          flashLoanFeeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      await program.account.flashLoanFee
+        .fetch(flashLoanFeeAccount)
+        .then(({ feeRatio: { num, denom } }) => {
+          expect(num.eq(fee.feeRatio.num)).to.be.true;
+          expect(denom.eq(fee.feeRatio.denom)).to.be.true;
+        });
+    });
+
+    it("it updates flash loan fee", async () => {
+      await program.methods
+        .setFlashLoanFee(finalFlashLoanFee)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          flashLoanFeeAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+      await program.account.flashLoanFee
+        .fetch(flashLoanFeeAccount)
+        .then(({ feeRatio: { num, denom } }) => {
+          expect(num.eq(finalFlashLoanFee.feeRatio.num)).to.be.true;
+          expect(denom.eq(finalFlashLoanFee.feeRatio.denom)).to.be.true;
+        });
+    });
+
+    describe("Fee delay", () => {
+      const FEE_DELAY_SECONDS = 2;
+      const delayedPoolKeypair = Keypair.generate();
+      const delayedLpMintKeypair = Keypair.generate();
+      let [delayedFeeAccount] = [null as PublicKey, 0];
+      let [delayedFeeDelayAccount] = [null as PublicKey, 0];
+
+      const setFeeDelay = (minDelaySeconds: number) =>
+        program.methods
+          .setFeeDelay(new BN(minDelaySeconds))
+          .accounts({
+            payer: payerKeypair.publicKey,
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: delayedPoolKeypair.publicKey,
+            feeDelayAccount: delayedFeeDelayAccount,
+          })
+          .signers([payerKeypair])
+          .rpc({ skipPreflight: true });
+
+      before(async () => {
+        [delayedFeeAccount] = await findPoolFeeAccount(
+          program.programId,
+          delayedPoolKeypair.publicKey
+        );
+        [delayedFeeDelayAccount] = await findFeeDelayAccount(
+          program.programId,
+          delayedPoolKeypair.publicKey
+        );
+        const [delayedPoolSolReserves] = await findPoolSolReserves(
+          program.programId,
+          delayedPoolKeypair.publicKey
+        );
+        await program.methods
+          .createPool({
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(1),
+                  denom: new BN(1000),
+                },
+              },
+            },
+          })
+          .accounts({
+            payer: payerKeypair.publicKey,
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: delayedPoolKeypair.publicKey,
+            lpMint: delayedLpMintKeypair.publicKey,
+            poolSolReserves: delayedPoolSolReserves,
+            feeAccount: delayedFeeAccount,
+          })
+          .signers([payerKeypair, delayedPoolKeypair, delayedLpMintKeypair])
+          .rpc({ skipPreflight: true });
+      });
+
+      it("it queues validator fee changes and applies them after the fee delay", async () => {
+        const voteAccount = testVoteAccount();
+        const [delayedValidatorFeeAccount] = await findValidatorFeeAccount(
+          program.programId,
+          delayedPoolKeypair.publicKey,
+          voteAccount
+        );
+        const [pendingValidatorFeeAccount] =
+          await findPendingValidatorFeeAccount(
+            program.programId,
+            delayedPoolKeypair.publicKey,
+            voteAccount
+          );
+        const OVERRIDE = {
+          override: {
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(5),
+                  denom: new BN(1000),
+                },
+              },
+            },
+          },
+        };
+        const setValidatorFee = ({
+          validatorFeeAccount,
+          pendingValidatorFeeAccount,
+        }: {
+          validatorFeeAccount: PublicKey;
+          pendingValidatorFeeAccount: PublicKey;
+        }) =>
+          program.methods
+            .setValidatorFee({ fee: OVERRIDE })
+            .accounts({
+              payer: payerKeypair.publicKey,
+              feeAuthority: payerKeypair.publicKey,
+              poolAccount: delayedPoolKeypair.publicKey,
+              voteAccount,
+              validatorFeeAccount,
+              feeDelayAccount: delayedFeeDelayAccount,
+              clock: SYSVAR_CLOCK_PUBKEY,
+              pendingValidatorFeeAccount,
+            })
+            .signers([payerKeypair])
+            .rpc({ skipPreflight: true });
+        const applyPendingValidatorFee = async () =>
+          sendAndConfirmTransaction(
+            provider.connection,
+            await applyPendingValidatorFeeTx(program, {
+              payer: payerKeypair.publicKey,
+              poolAccount: delayedPoolKeypair.publicKey,
+              feeAuthority: payerKeypair.publicKey,
+              voteAccount,
+            }),
+            [payerKeypair],
+            { skipPreflight: true }
+          );
+        const waitForPendingValidatorFee = async () => {
+          const { activationTimestamp } =
+            await program.account.pendingValidatorFee.fetch(
+              pendingValidatorFeeAccount
+            );
+          await waitForUnixTimestamp(
+            provider.connection,
+            activationTimestamp.toNumber()
+          );
+        };
+
+        await setFeeDelay(FEE_DELAY_SECONDS);
+
+        // an Override cannot be set immediately
+        await expect(
+          setValidatorFee({
+            validatorFeeAccount: delayedValidatorFeeAccount,
+            pendingValidatorFeeAccount: program.programId,
+          })
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(
+            6041,
+            "This change could raise the pool's fees and is not allowed while the pool has a fee delay"
+          )
+        );
+        await expect(
+          setValidatorFee({
+            validatorFeeAccount: delayedValidatorFeeAccount,
+            pendingValidatorFeeAccount,
+          })
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(
+            6048,
+            "Exactly one of validator_fee_account and pending_validator_fee_account must be provided"
+          )
+        );
+
+        // queue the Override
+        await setValidatorFee({
+          validatorFeeAccount: program.programId,
+          pendingValidatorFeeAccount,
+        });
+        const { validatorFee } =
+          await program.account.pendingValidatorFee.fetch(
+            pendingValidatorFeeAccount
+          );
+        expect(JSON.stringify(validatorFee)).to.eql(JSON.stringify(OVERRIDE));
+        expect(
+          await provider.connection.getAccountInfo(delayedValidatorFeeAccount)
+        ).to.be.null;
+        await expect(
+          applyPendingValidatorFee()
+        ).to.be.eventually.rejected.and.satisfy(checkSystemError(6023));
+
+        await waitForPendingValidatorFee();
+        await applyPendingValidatorFee();
+        await program.account.validatorFee
+          .fetch(delayedValidatorFeeAccount)
+          .then(({ fee }) => {
+            expect(JSON.stringify(fee)).to.eql(JSON.stringify(OVERRIDE));
+          });
+        expect(
+          await provider.connection.getAccountInfo(pendingValidatorFeeAccount)
+        ).to.be.null;
+
+        // queue the Override's removal
+        const removeValidatorFee = (pendingValidatorFeeAccount: PublicKey) =>
+          program.methods
+            .removeValidatorFee()
+            .accounts({
+              payer: payerKeypair.publicKey,
+              feeAuthority: payerKeypair.publicKey,
+              poolAccount: delayedPoolKeypair.publicKey,
+              voteAccount,
+              validatorFeeAccount: delayedValidatorFeeAccount,
+              refundRentTo: payerKeypair.publicKey,
+              feeDelayAccount: delayedFeeDelayAccount,
+              clock: SYSVAR_CLOCK_PUBKEY,
+              pendingValidatorFeeAccount,
+            })
+            .signers([payerKeypair])
+            .rpc({ skipPreflight: true });
+        await expect(
+          removeValidatorFee(program.programId)
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(
+            6041,
+            "This change could raise the pool's fees and is not allowed while the pool has a fee delay"
+          )
+        );
+        await removeValidatorFee(pendingValidatorFeeAccount);
+        const { validatorFee: pendingRemoval } =
+          await program.account.pendingValidatorFee.fetch(
+            pendingValidatorFeeAccount
+          );
+        expect(pendingRemoval).to.be.null;
+        expect(
+          await provider.connection.getAccountInfo(delayedValidatorFeeAccount)
+        ).to.not.be.null;
+
+        await waitForPendingValidatorFee();
+        await applyPendingValidatorFee();
+        for (const closed of [
+          delayedValidatorFeeAccount,
+          pendingValidatorFeeAccount,
+        ]) {
+          expect(await provider.connection.getAccountInfo(closed)).to.be.null;
+        }
+      });
+
+      it("it queues lockup fee changes and applies them after the fee delay", async () => {
+        const [lockupFeeAccount] = await findLockupFeeAccount(
+          program.programId,
+          delayedPoolKeypair.publicKey
+        );
+        const [pendingLockupFeeAccount] = await findPendingLockupFeeAccount(
+          program.programId,
+          delayedPoolKeypair.publicKey
+        );
+        const setLockupFee = (
+          num: number,
+          pendingLockupFeeAccount: PublicKey
+        ) =>
+          program.methods
+            .setLockupFee({
+              feeRatio: { num: new BN(num), denom: new BN(100) },
+            })
+            .accounts({
+              payer: payerKeypair.publicKey,
+              feeAuthority: payerKeypair.publicKey,
+              poolAccount: delayedPoolKeypair.publicKey,
+              lockupFeeAccount,
+              feeDelayAccount: delayedFeeDelayAccount,
+              clock: SYSVAR_CLOCK_PUBKEY,
+              pendingLockupFeeAccount,
+            })
+            .signers([payerKeypair])
+            .rpc({ skipPreflight: true });
+        const applyPendingLockupFee = async () =>
+          sendAndConfirmTransaction(
+            provider.connection,
+            await applyPendingLockupFeeTx(program, {
+              poolAccount: delayedPoolKeypair.publicKey,
+              feeAuthority: payerKeypair.publicKey,
+            }),
+            [payerKeypair],
+            { skipPreflight: true }
+          );
+        const fetchLockupFeeNum = () =>
+          program.account.lockupFee
+            .fetch(lockupFeeAccount)
+            .then(({ feeRatio: { num } }) => num.toNumber());
+
+        await setFeeDelay(FEE_DELAY_SECONDS);
+
+        // initializing the lockup fee takes effect immediately
+        await setLockupFee(1, program.programId);
+        expect(await fetchLockupFeeNum()).to.eq(1);
+
+        // raising it does not
+        await expect(
+          setLockupFee(10, program.programId)
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(
+            6041,
+            "This change could raise the pool's fees and is not allowed while the pool has a fee delay"
+          )
+        );
+        await setLockupFee(10, pendingLockupFeeAccount);
+        expect(await fetchLockupFeeNum()).to.eq(1);
+        await expect(
+          applyPendingLockupFee()
+        ).to.be.eventually.rejected.and.satisfy(checkSystemError(6023));
+
+        const { activationTimestamp } =
+          await program.account.pendingLockupFee.fetch(pendingLockupFeeAccount);
+        await waitForUnixTimestamp(
+          provider.connection,
+          activationTimestamp.toNumber()
+        );
+        await applyPendingLockupFee();
+        expect(await fetchLockupFeeNum()).to.eq(10);
+        expect(
+          await provider.connection.getAccountInfo(pendingLockupFeeAccount)
+        ).to.be.null;
+      });
+
+      // leaves the pool with the maximum fee delay, keep last
+      it("it rejects to set a fee delay above the maximum", async () => {
+        const maxFeeDelaySeconds = 30 * 24 * 60 * 60;
+
+        await expect(
+          setFeeDelay(maxFeeDelaySeconds + 1)
+        ).to.be.eventually.rejected.and.satisfy(
+          checkAnchorError(6047, "The fee delay exceeds the maximum fee delay")
+        );
+
+        await setFeeDelay(maxFeeDelaySeconds);
+        const { minDelaySeconds } = await program.account.feeDelay.fetch(
+          delayedFeeDelayAccount
+        );
+        expect(minDelaySeconds.toNumber()).to.eq(maxFeeDelaySeconds);
+      });
+    });
+
+    it("it closes an empty pool", async () => {
+      const closedPoolKeypair = Keypair.generate();
+      const closedLpMintKeypair = Keypair.generate();
+      const lpTokenAccountKeypair = Keypair.generate();
+      const destination = Keypair.generate().publicKey;
+      const initialLamports = new BN(0.1 * LAMPORTS_PER_SOL);
+      const dustLamports = 0.01 * LAMPORTS_PER_SOL;
+      const [closedPoolSolReserves] = await findPoolSolReserves(
+        program.programId,
+        closedPoolKeypair.publicKey
+      );
+      const [closedFeeAccount] = await findPoolFeeAccount(
+        program.programId,
+        closedPoolKeypair.publicKey
+      );
+      const [closedFlashLoanFeeAccount] = findProgramAddressSync(
+        [closedPoolKeypair.publicKey.toBuffer(), Buffer.from("flashloanfee")],
+        program.programId
+      );
+      const [closedAllowedLpAccount] = await findAllowedLpAccount(
+        program.programId,
+        closedPoolKeypair.publicKey,
+        lperKeypair.publicKey
+      );
+
+      await program.methods
+        .createPoolWithLiquidity(
+          {
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(1),
+                  denom: new BN(1000),
+                },
+              },
+            },
+          },
+          initialLamports
+        )
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: closedPoolKeypair.publicKey,
+          poolSolReserves: closedPoolSolReserves,
+          feeAccount: closedFeeAccount,
+          lpMint: closedLpMintKeypair.publicKey,
+          lpTokenAccountOwner: payerKeypair.publicKey,
+          mintLpTokensTo: lpTokenAccountKeypair.publicKey,
+          lockedLpTokenAccount: program.programId,
+          protocolPauseAccount,
+        })
+        .signers([
+          payerKeypair,
+          closedPoolKeypair,
+          closedLpMintKeypair,
+          lpTokenAccountKeypair,
+        ])
+        .rpc({ skipPreflight: true });
       await program.methods
-        .setLpTokenMetadata(metadata)
+        .setFlashLoanFee(finalFlashLoanFee)
         .accounts({
           payer: payerKeypair.publicKey,
           feeAuthority: payerKeypair.publicKey,
-          poolAccount: poolKeypair.publicKey,
-          poolSolReserves,
-          lpMint: lpMintKeypair.publicKey,
-          metadata: metadataAccount,
-          metadataProgram,
+          poolAccount: closedPoolKeypair.publicKey,
+          flashLoanFeeAccount: closedFlashLoanFeeAccount,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
-      const createdMetadata = await Metadata.fromAccountAddress(
-        program.provider.connection,
-        metadataAccount
-      );
-
-      expect(createdMetadata.data.name.replace(/\0/g, "")).to.eq(metadata.name);
-      expect(createdMetadata.data.symbol.replace(/\0/g, "")).to.eq(
-        metadata.symbol
-      );
-      expect(createdMetadata.data.uri.replace(/\0/g, "")).to.eq(metadata.uri);
-      expect(createdMetadata.tokenStandard).to.eq(TokenStandard.Fungible);
-    });
-
-    it("it updates token metadata", async () => {
-      const newMetadata = {
-        name: "new name",
-        symbol: "NEWSYM",
-        uri: "new.com",
-      };
-
       await program.methods
-        .setLpTokenMetadata(newMetadata)
+        .addAllowedLp()
         .accounts({
           payer: payerKeypair.publicKey,
           feeAuthority: payerKeypair.publicKey,
-          poolAccount: poolKeypair.publicKey,
-          poolSolReserves,
-          lpMint: lpMintKeypair.publicKey,
-          metadata: metadataAccount,
-          metadataProgram,
+          poolAccount: closedPoolKeypair.publicKey,
+          lp: lperKeypair.publicKey,
+          allowedLpAccount: closedAllowedLpAccount,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
-      const updatedMetadata = await Metadata.fromAccountAddress(
-        program.provider.connection,
-        metadataAccount
-      );
 
-      expect(updatedMetadata.data.name.replace(/\0/g, "")).to.eq(
-        newMetadata.name
-      );
-      expect(updatedMetadata.data.symbol.replace(/\0/g, "")).to.eq(
-        newMetadata.symbol
+      const closePool = async () =>
+        sendAndConfirmTransaction(
+          provider.connection,
+          await closePoolTx(program, {
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: closedPoolKeypair.publicKey,
+            lpMint: closedLpMintKeypair.publicKey,
+            destination,
+            allowedLps: [lperKeypair.publicKey],
+          }),
+          [payerKeypair],
+          { skipPreflight: true }
+        );
+
+      await expect(closePool()).to.be.eventually.rejected.and.satisfy(
+        checkSystemError(6040)
       );
-      expect(updatedMetadata.data.uri.replace(/\0/g, "")).to.eq(
-        newMetadata.uri
+
+      // all LPs exit, then some SOL is sent to the empty pool's reserves
+      await sendAndConfirmTransaction(
+        provider.connection,
+        await removeLiquidityTx(program, initialLamports, {
+          authority: payerKeypair.publicKey,
+          poolAccount: closedPoolKeypair.publicKey,
+          lpMint: closedLpMintKeypair.publicKey,
+          from: lpTokenAccountKeypair.publicKey,
+        }),
+        [payerKeypair]
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: payerKeypair.publicKey,
+            toPubkey: closedPoolSolReserves,
+            lamports: dustLamports,
+          })
+        ),
+        [payerKeypair]
       );
-      expect(updatedMetadata.tokenStandard).to.eq(TokenStandard.Fungible);
-    });
 
-    it("it rejects to set flash loan fee with invalid fee authority", async () => {
-      const fakeAuthKeypair = lperKeypair;
+      const rentLamports = (
+        await Promise.all(
+          [
+            closedPoolKeypair.publicKey,
+            closedFeeAccount,
+            closedFlashLoanFeeAccount,
+            closedAllowedLpAccount,
+          ].map((pk) => provider.connection.getBalance(pk))
+        )
+      ).reduce((sum, lamports) => sum + lamports, 0);
+
+      // accounts other than the pool's ValidatorFee and AllowedLp PDAs cannot be closed
+      const closeLpTokenAccountTx = await closePoolTx(program, {
+        feeAuthority: payerKeypair.publicKey,
+        poolAccount: closedPoolKeypair.publicKey,
+        lpMint: closedLpMintKeypair.publicKey,
+        destination,
+      });
+      closeLpTokenAccountTx.instructions[0].keys.push(
+        { pubkey: lperKeypair.publicKey, isSigner: false, isWritable: false },
+        {
+          pubkey: lpTokenAccountKeypair.publicKey,
+          isSigner: false,
+          isWritable: true,
+        }
+      );
       await expect(
-        program.methods
-          .setFlashLoanFee({
-            feeRatio: {
-              num: new BN(1),
-              denom: new BN(1_000),
-            },
-          })
-          .accounts({
-            payer: fakeAuthKeypair.publicKey,
-            feeAuthority: fakeAuthKeypair.publicKey,
-            poolAccount: poolKeypair.publicKey,
-            flashLoanFeeAccount,
-          })
-          .signers([fakeAuthKeypair])
-          .rpc({ skipPreflight: true })
-      ).to.be.eventually.rejected.and.satisfy(
-        checkAnchorError(
-          6002,
-          "The provided fee authority does not have the authority over the provided pool account"
+        sendAndConfirmTransaction(
+          provider.connection,
+          closeLpTokenAccountTx,
+          [payerKeypair],
+          { skipPreflight: true }
         )
+      ).to.be.eventually.rejected.and.satisfy(checkSystemError(6044));
+
+      await closePool();
+
+      for (const closed of [
+        closedPoolKeypair.publicKey,
+        closedFeeAccount,
+        closedFlashLoanFeeAccount,
+        closedAllowedLpAccount,
+      ]) {
+        expect(await provider.connection.getAccountInfo(closed)).to.be.null;
+      }
+      expect(
+        await provider.connection.getBalance(closedPoolSolReserves)
+      ).to.eq(0);
+      expect(await provider.connection.getBalance(destination)).to.eq(
+        dustLamports + rentLamports
+      );
+      const mint = await getMint(
+        provider.connection,
+        closedLpMintKeypair.publicKey
       );
-    });
+      expect(mint.mintAuthority).to.be.null;
+      expect(mint.supply).to.eq(BigInt(0));
 
-    it("it creates flash loan fee", async () => {
-      const fee = {
-        feeRatio: {
-          num: new BN(1),
-          denom: new BN(1_000),
-        },
-      };
-      await program.methods
-        .setFlashLoanFee(fee)
-        .accounts({
-          payer: payerKeypair.publicKey,
-          feeAuthority: payerKeypair.publicKey,
-          poolAccount: poolKeypair.publicKey,
-          flashLoanFeeAccount,
-        })
-        .signers([payerKeypair])
-        .rpc({ skipPreflight: true });
-      await program.account.flashLoanFee
-        .fetch(flashLoanFeeAccount)
-        .then(({ feeRatio: { num, denom } }) => {
-          expect(num.eq(fee.feeRatio.num)).to.be.true;
-          expect(denom.eq(fee.feeRatio.denom)).to.be.true;
-        });
+      await expect(
+        program.account.pool.fetch(closedPoolKeypair.publicKey)
+      ).to.be.eventually.rejected;
     });
 
-    it("it updates flash loan fee", async () => {
+    it("it closes a pool with locked LP tokens", async () => {
+      const lockedPoolKeypair = Keypair.generate();
+      const lockedLpMintKeypair = Keypair.generate();
+      const destination = Keypair.generate().publicKey;
+      const initialLamports = new BN(0.1 * LAMPORTS_PER_SOL);
+      const [lockedPoolSolReserves] = await findPoolSolReserves(
+        program.programId,
+        lockedPoolKeypair.publicKey
+      );
+      const [lockedFeeAccount] = await findPoolFeeAccount(
+        program.programId,
+        lockedPoolKeypair.publicKey
+      );
+      const [lockedLpTokenAccount] = await findLockedLpTokenAccount(
+        program.programId,
+        lockedPoolKeypair.publicKey
+      );
+
       await program.methods
-        .setFlashLoanFee(finalFlashLoanFee)
+        .createPoolWithLiquidity(
+          {
+            fee: {
+              flat: {
+                ratio: {
+                  num: new BN(1),
+                  denom: new BN(1000),
+                },
+              },
+            },
+          },
+          initialLamports
+        )
         .accounts({
           payer: payerKeypair.publicKey,
           feeAuthority: payerKeypair.publicKey,
-          poolAccount: poolKeypair.publicKey,
-          flashLoanFeeAccount,
+          poolAccount: lockedPoolKeypair.publicKey,
+          poolSolReserves: lockedPoolSolReserves,
+          feeAccount: lockedFeeAccount,
+          lpMint: lockedLpMintKeypair.publicKey,
+          lpTokenAccountOwner: payerKeypair.publicKey,
+          mintLpTokensTo: program.programId,
+          lockedLpTokenAccount,
+          protocolPauseAccount,
         })
-        .signers([payerKeypair])
+        .signers([payerKeypair, lockedPoolKeypair, lockedLpMintKeypair])
         .rpc({ skipPreflight: true });
-      await program.account.flashLoanFee
-        .fetch(flashLoanFeeAccount)
-        .then(({ feeRatio: { num, denom } }) => {
-          expect(num.eq(finalFlashLoanFee.feeRatio.num)).to.be.true;
-          expect(denom.eq(finalFlashLoanFee.feeRatio.denom)).to.be.true;
-        });
+
+      // another LP joins the pool
+      const payerLpTokenAccount = await createAssociatedTokenAccount(
+        provider.connection,
+        payerKeypair,
+        lockedLpMintKeypair.publicKey,
+        payerKeypair.publicKey
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        await addLiquidityTx(program, initialLamports, {
+          from: payerKeypair.publicKey,
+          poolAccount: lockedPoolKeypair.publicKey,
+          lpMint: lockedLpMintKeypair.publicKey,
+          mintLpTokensTo: payerLpTokenAccount,
+        }),
+        [payerKeypair]
+      );
+
+      const closePool = async () =>
+        sendAndConfirmTransaction(
+          provider.connection,
+          await closePoolTx(program, {
+            feeAuthority: payerKeypair.publicKey,
+            poolAccount: lockedPoolKeypair.publicKey,
+            lpMint: lockedLpMintKeypair.publicKey,
+            destination,
+          }),
+          [payerKeypair],
+          { skipPreflight: true }
+        );
+
+      // only the locked LP tokens may be left
+      await expect(closePool()).to.be.eventually.rejected.and.satisfy(
+        checkSystemError(6040)
+      );
+
+      const { amount: payerLpTokens } = await getAccount(
+        provider.connection,
+        payerLpTokenAccount
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        await removeLiquidityTx(program, new BN(payerLpTokens.toString()), {
+          authority: payerKeypair.publicKey,
+          poolAccount: lockedPoolKeypair.publicKey,
+          lpMint: lockedLpMintKeypair.publicKey,
+          from: payerLpTokenAccount,
+        }),
+        [payerKeypair]
+      );
+
+      const { amount: lockedLpTokens } = await getAccount(
+        provider.connection,
+        lockedLpTokenAccount
+      );
+      const sweptLamports = (
+        await Promise.all(
+          [
+            lockedPoolKeypair.publicKey,
+            lockedPoolSolReserves,
+            lockedFeeAccount,
+            lockedLpTokenAccount,
+          ].map((pk) => provider.connection.getBalance(pk))
+        )
+      ).reduce((sum, lamports) => sum + lamports, 0);
+
+      await closePool();
+
+      for (const closed of [
+        lockedPoolKeypair.publicKey,
+        lockedFeeAccount,
+        lockedLpTokenAccount,
+      ]) {
+        expect(await provider.connection.getAccountInfo(closed)).to.be.null;
+      }
+      expect(
+        await provider.connection.getBalance(lockedPoolSolReserves)
+      ).to.eq(0);
+      expect(await provider.connection.getBalance(destination)).to.eq(
+        sweptLamports
+      );
+      const mint = await getMint(
+        provider.connection,
+        lockedLpMintKeypair.publicKey
+      );
+      expect(lockedLpTokens > BigInt(0)).to.be.true;
+      expect(mint.mintAuthority).to.be.null;
+      expect(mint.supply).to.eq(BigInt(0));
     });
   });
 
@@ -914,7 +2363,7 @@ describe("internals", () => {
       // add some liquidity to the pool
       // NOTE: assuming the pool has been initialized with lpMintKeypair and has 0 liquidity, and lperAta is initialized
       await program.methods
-        .addLiquidity(liquidityLamports)
+        .addLiquidityV2(liquidityLamports, new BN(0))
         .accounts({
           from: lperKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
@@ -922,6 +2371,11 @@ describe("internals", () => {
           lpMint: lpMintKeypair.publicKey,
           mintLpTokensTo: lperAta,
           flashAccount,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          allowedLpAccount,
+          withdrawQueueAccount,
         })
         .signers([lperKeypair])
         .rpc({ skipPreflight: true });
@@ -1019,7 +2473,7 @@ describe("internals", () => {
       await waitForEpochToPass(provider.connection);
     });
 
-    it("it rejects to unstake a locked up stake account", async () => {
+    it("it rejects deprecated v1 unstake", async () => {
       const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
         program.programId,
         poolKeypair.publicKey,
@@ -1044,6 +2498,47 @@ describe("internals", () => {
           })
           .signers([lockedUpUnstaker])
           .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6045,
+          "This instruction is deprecated, use its V2 version instead"
+        )
+      );
+    });
+
+    it("it rejects to unstake a locked up stake account", async () => {
+      const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+        program.programId,
+        poolKeypair.publicKey,
+        lockedUpStakeAcc.publicKey
+      );
+
+      await expect(
+        program.methods
+          .unstakeV2(new BN(0))
+          .accounts({
+            unstaker: lockedUpUnstaker.publicKey,
+            stakeAccount: lockedUpStakeAcc.publicKey,
+            destination: lockedUpUnstaker.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            feeAccount,
+            validatorFeeAccount,
+            stakeAccountRecordAccount,
+            protocolFeeAccount: protocolFeeAddr,
+            protocolFeeDestination,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
+            stakeProgram: StakeProgram.programId,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
+          })
+          .signers([lockedUpUnstaker])
+          .rpc({ skipPreflight: true })
       ).to.be.eventually.rejected.and.satisfy(
         checkAnchorError(6005, "The provided stake account is locked up")
       );
@@ -1058,7 +2553,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstakeWsol()
+          .unstakeWsolV2(new BN(0))
           .accounts({
             unstaker: lockedUpUnstaker.publicKey,
             stakeAccount: lockedUpStakeAcc.publicKey,
@@ -1066,12 +2561,20 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([lockedUpUnstaker])
           .rpc({ skipPreflight: true })
@@ -1089,7 +2592,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstake()
+          .unstakeV2(new BN(0))
           .accounts({
             unstaker: notEnoughLiquidityUnstaker.publicKey,
             stakeAccount: notEnoughLiquidityStakeAcc.publicKey,
@@ -1097,11 +2600,19 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([notEnoughLiquidityUnstaker])
           .rpc({ skipPreflight: true })
@@ -1119,7 +2630,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstakeWsol()
+          .unstakeWsolV2(new BN(0))
           .accounts({
             unstaker: notEnoughLiquidityUnstaker.publicKey,
             stakeAccount: notEnoughLiquidityStakeAcc.publicKey,
@@ -1127,12 +2638,20 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([notEnoughLiquidityUnstaker])
           .rpc({ skipPreflight: true })
@@ -1141,6 +2660,77 @@ describe("internals", () => {
       );
     });
 
+    it("it rejects to unstake above the maximum unstake size", async () => {
+      const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+        program.programId,
+        poolKeypair.publicKey,
+        flatFeeStakeAcc.publicKey
+      );
+
+      await program.methods
+        .setPoolConfig(new BN(1), null, null, false)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolConfigAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await expect(
+        program.methods
+          .unstakeV2(new BN(0))
+          .accounts({
+            unstaker: flatFeeUnstaker.publicKey,
+            stakeAccount: flatFeeStakeAcc.publicKey,
+            destination: flatFeeUnstaker.publicKey,
+            poolAccount: poolKeypair.publicKey,
+            poolSolReserves,
+            feeAccount,
+            validatorFeeAccount,
+            stakeAccountRecordAccount,
+            protocolFeeAccount: protocolFeeAddr,
+            protocolFeeDestination,
+            clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
+            stakeProgram: StakeProgram.programId,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
+          })
+          .signers([flatFeeUnstaker])
+          .rpc({ skipPreflight: true })
+      ).to.be.eventually.rejected.and.satisfy(
+        checkAnchorError(
+          6030,
+          "The amount to unstake exceeds the pool's maximum unstake size"
+        )
+      );
+
+      // remove the limits to keep the tests bellow unaffected by this test
+      await program.methods
+        .setPoolConfig(null, null, null, false)
+        .accounts({
+          payer: payerKeypair.publicKey,
+          feeAuthority: payerKeypair.publicKey,
+          poolAccount: poolKeypair.publicKey,
+          poolConfigAccount,
+        })
+        .signers([payerKeypair])
+        .rpc({ skipPreflight: true });
+
+      await program.account.poolConfig
+        .fetch(poolConfigAccount)
+        .then(({ maxUnstakeLamports, epochIncomingStakeLamports }) => {
+          expect(maxUnstakeLamports).to.be.null;
+          expect(epochIncomingStakeLamports.toNumber()).to.eq(0);
+        });
+    });
+
     it("it fails to unstakeWsol different mint", async () => {
       const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
         program.programId,
@@ -1150,7 +2740,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstakeWsol()
+          .unstakeWsolV2(new BN(0))
           .accounts({
             unstaker: notEnoughLiquidityUnstaker.publicKey,
             stakeAccount: notEnoughLiquidityStakeAcc.publicKey,
@@ -1158,12 +2748,20 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([notEnoughLiquidityUnstaker])
           .rpc({ skipPreflight: true })
@@ -1184,7 +2782,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstake()
+          .unstakeV2(new BN(0))
           .accounts({
             unstaker: flatFeeUnstaker.publicKey,
             stakeAccount: flatFeeStakeAcc.publicKey,
@@ -1192,11 +2790,19 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination: Keypair.generate().publicKey,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([flatFeeUnstaker])
           .rpc({ skipPreflight: true })
@@ -1214,7 +2820,7 @@ describe("internals", () => {
 
       await expect(
         program.methods
-          .unstakeWsol()
+          .unstakeWsolV2(new BN(0))
           .accounts({
             unstaker: flatFeeWSolUnstaker.publicKey,
             stakeAccount: flatFeeWSolStakeAcc.publicKey,
@@ -1222,12 +2828,20 @@ describe("internals", () => {
             poolAccount: poolKeypair.publicKey,
             poolSolReserves,
             feeAccount,
+            validatorFeeAccount,
             stakeAccountRecordAccount,
             protocolFeeAccount: protocolFeeAddr,
             protocolFeeDestination: Keypair.generate().publicKey,
             clock: SYSVAR_CLOCK_PUBKEY,
+            stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+            custodian: program.programId,
+            lockupFeeAccount: program.programId,
             stakeProgram: StakeProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            poolPauseAccount,
+            protocolPauseAccount,
+            poolConfigAccount,
+            withdrawQueueAccount,
           })
           .signers([flatFeeWSolUnstaker])
           .rpc({ skipPreflight: true })
@@ -1252,6 +2866,9 @@ describe("internals", () => {
           feeAuthority: payerKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
           feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
@@ -1273,7 +2890,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .unstake()
+        .unstakeV2(new BN(0))
         .accounts({
           unstaker: flatFeeUnstaker.publicKey,
           stakeAccount: flatFeeStakeAcc.publicKey,
@@ -1281,11 +2898,19 @@ describe("internals", () => {
           poolAccount: poolKeypair.publicKey,
           poolSolReserves,
           feeAccount,
+          validatorFeeAccount,
           stakeAccountRecordAccount,
           protocolFeeAccount: protocolFeeAddr,
           protocolFeeDestination,
           clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
           stakeProgram: StakeProgram.programId,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
         })
         .signers([flatFeeUnstaker])
         .rpc({ skipPreflight: true });
@@ -1337,6 +2962,9 @@ describe("internals", () => {
           feeAuthority: payerKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
           feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
@@ -1358,7 +2986,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .unstakeWsol()
+        .unstakeWsolV2(new BN(0))
         .accounts({
           unstaker: flatFeeWSolUnstaker.publicKey,
           stakeAccount: flatFeeWSolStakeAcc.publicKey,
@@ -1366,12 +2994,20 @@ describe("internals", () => {
           poolAccount: poolKeypair.publicKey,
           poolSolReserves,
           feeAccount,
+          validatorFeeAccount,
           stakeAccountRecordAccount,
           protocolFeeAccount: protocolFeeAddr,
           protocolFeeDestination,
           clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
           stakeProgram: StakeProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
         })
         .signers([flatFeeWSolUnstaker])
         .rpc({ skipPreflight: true });
@@ -1430,6 +3066,9 @@ describe("internals", () => {
           feeAuthority: payerKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
           feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
@@ -1456,7 +3095,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .unstake()
+        .unstakeV2(new BN(0))
         .accounts({
           unstaker: liquidityLinearFeeUnstaker.publicKey,
           stakeAccount: liquidityLinearFeeStakeAcc.publicKey,
@@ -1464,11 +3103,19 @@ describe("internals", () => {
           poolAccount: poolKeypair.publicKey,
           poolSolReserves,
           feeAccount,
+          validatorFeeAccount,
           stakeAccountRecordAccount,
           protocolFeeAccount: protocolFeeAddr,
           protocolFeeDestination,
           clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
           stakeProgram: StakeProgram.programId,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
         })
         .signers([liquidityLinearFeeUnstaker])
         .rpc({ skipPreflight: true });
@@ -1551,6 +3198,9 @@ describe("internals", () => {
           feeAuthority: payerKeypair.publicKey,
           poolAccount: poolKeypair.publicKey,
           feeAccount,
+          feeDelayAccount,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          pendingFeeAccount: program.programId,
         })
         .signers([payerKeypair])
         .rpc({ skipPreflight: true });
@@ -1581,7 +3231,7 @@ describe("internals", () => {
       );
 
       await program.methods
-        .unstakeWsol()
+        .unstakeWsolV2(new BN(0))
         .accounts({
           unstaker: liquidityLinearFeeWSolUnstaker.publicKey,
           stakeAccount: liquidityLinearFeeWSolStakeAcc.publicKey,
@@ -1589,12 +3239,20 @@ describe("internals", () => {
           poolAccount: poolKeypair.publicKey,
           poolSolReserves,
           feeAccount,
+          validatorFeeAccount,
           stakeAccountRecordAccount,
           protocolFeeAccount: protocolFeeAddr,
           protocolFeeDestination,
           clock: SYSVAR_CLOCK_PUBKEY,
+          stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+          custodian: program.programId,
+          lockupFeeAccount: program.programId,
           stakeProgram: StakeProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          poolPauseAccount,
+          protocolPauseAccount,
+          poolConfigAccount,
+          withdrawQueueAccount,
         })
         .signers([liquidityLinearFeeWSolUnstaker])
         .rpc({ skipPreflight: true });
@@ -1669,6 +3327,8 @@ describe("internals", () => {
             poolSolReserves,
             flashAccount,
             instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            poolPauseAccount,
+            protocolPauseAccount,
           })
           .rpc({ skipPreflight: true })
       ).to.be.eventually.rejected.and.satisfy(
@@ -1696,6 +3356,8 @@ describe("internals", () => {
           poolSolReserves,
           flashAccount,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolPauseAccount,
+          protocolPauseAccount,
         })
         .instruction();
       const repayIx = await program.methods
@@ -1759,6 +3421,8 @@ describe("internals", () => {
           poolSolReserves,
           flashAccount,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolPauseAccount,
+          protocolPauseAccount,
         })
         .instruction();
       const repayIx = await program.methods
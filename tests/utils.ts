@@ -5,6 +5,7 @@ import {
   Keypair,
   PublicKey,
   StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
   Transaction,
   Lockup,
   LAMPORTS_PER_SOL,
@@ -135,6 +136,28 @@ export async function waitForEpochToPass(
   }
 }
 
+// waits until the cluster's Clock sysvar reaches the given unix timestamp,
+// which can lag behind wall-clock time on the test validator
+export async function waitForUnixTimestamp(
+  connection: Connection,
+  unixTimestamp: number
+): Promise<void> {
+  const SLOT_DURATION_MS = 400;
+  // unix_timestamp follows slot, epoch_start_timestamp, epoch and leader_schedule_epoch
+  const UNIX_TIMESTAMP_OFFSET = 32;
+  for (;;) {
+    const { data } = await connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY);
+    const clockUnixTimestamp = new BN(
+      data.subarray(UNIX_TIMESTAMP_OFFSET, UNIX_TIMESTAMP_OFFSET + 8),
+      "le"
+    ).toNumber();
+    if (clockUnixTimestamp >= unixTimestamp) {
+      return;
+    }
+    await sleep(SLOT_DURATION_MS);
+  }
+}
+
 export async function stakeAccMinLamports(
   connection: Connection
 ): Promise<number> {
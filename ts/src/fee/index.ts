@@ -12,15 +12,31 @@ export * from "./liquidityLinear";
  * @param fee
  * @param args
  * @returns the fee in lamports to be charged for unstaking a stake account
- *          with lamports = stakeAccountLamports
+ *          with lamports = stakeAccountLamports,
+ *          bounded by the fee's `minFeeLamports` and `maxFeeLamports` if set
  */
-export function applyFee({ fee }: Fee, args: ApplyFeeArgs): BN {
+export function applyFee(
+  { fee, minFeeLamports, maxFeeLamports }: Fee,
+  args: ApplyFeeArgs
+): BN {
+  let feeLamports: BN;
   if ("liquidityLinear" in fee) {
-    return applyLiquidityLinearFee(fee, args);
+    feeLamports = applyLiquidityLinearFee(fee, args);
   } else if ("flat" in fee) {
-    return applyFlatFee(fee, args);
+    feeLamports = applyFlatFee(fee, args);
+  } else {
+    throw new Error(`Unknown fee type: ${fee}`);
   }
-  throw new Error(`Unknown fee type: ${fee}`);
+  if (minFeeLamports) {
+    feeLamports = BN.max(
+      feeLamports,
+      BN.min(minFeeLamports, args.stakeAccountLamports)
+    );
+  }
+  if (maxFeeLamports) {
+    feeLamports = BN.min(feeLamports, maxFeeLamports);
+  }
+  return feeLamports;
 }
 
 export type ProtocolFeesLevied = {
@@ -37,6 +37,24 @@ export function findProtocolFeeAccount(
   );
 }
 
+export function findPendingProtocolFeeAccount(
+  unstakeProg: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("pending-protocol-fee")],
+    unstakeProg
+  );
+}
+
+export function findProtocolFeeAuthorityNomineeAccount(
+  unstakeProg: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("protocol-fee-authority-nominee")],
+    unstakeProg
+  );
+}
+
 export function findFlashAccount(
   unstakeProg: PublicKey,
   pool: PublicKey
@@ -56,3 +74,181 @@ export function findFlashLoanFeeAccount(
     unstakeProg
   );
 }
+
+export function findLockupFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("lockupfee")],
+    unstakeProg
+  );
+}
+
+export function findStakeDepositFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("stakedepositfee")],
+    unstakeProg
+  );
+}
+
+export function findValidatorFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey,
+  voteAccount: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), voteAccount.toBuffer(), Buffer.from("validatorfee")],
+    unstakeProg
+  );
+}
+
+export function findFeeAuthorityNomineeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("feeauthoritynominee")],
+    unstakeProg
+  );
+}
+
+export function findFeeDelayAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("feedelay")],
+    unstakeProg
+  );
+}
+
+export function findPendingFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("pendingfee")],
+    unstakeProg
+  );
+}
+
+export function findPendingLockupFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("pendinglockupfee")],
+    unstakeProg
+  );
+}
+
+export function findPendingValidatorFeeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey,
+  voteAccount: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [
+      pool.toBuffer(),
+      voteAccount.toBuffer(),
+      Buffer.from("pendingvalidatorfee"),
+    ],
+    unstakeProg
+  );
+}
+
+export function findPoolPauseAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("pause")],
+    unstakeProg
+  );
+}
+
+export function findPoolConfigAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("config")],
+    unstakeProg
+  );
+}
+
+export function findAllowedLpAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey,
+  lp: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), lp.toBuffer(), Buffer.from("allowedlp")],
+    unstakeProg
+  );
+}
+
+export function findProtocolPauseAccount(
+  unstakeProg: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [Buffer.from("protocol-pause")],
+    unstakeProg
+  );
+}
+
+export function findWithdrawQueueAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("withdrawqueue")],
+    unstakeProg
+  );
+}
+
+export function findWithdrawTicketAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey,
+  owner: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), owner.toBuffer(), Buffer.from("withdrawticket")],
+    unstakeProg
+  );
+}
+
+export function findWsolStagingAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("wsolstaging")],
+    unstakeProg
+  );
+}
+
+export function findLockedLpTokenAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), Buffer.from("lockedlp")],
+    unstakeProg
+  );
+}
+
+export function findSplitStakeAccount(
+  unstakeProg: PublicKey,
+  pool: PublicKey,
+  stakeAccount: PublicKey
+): Promise<[PublicKey, number]> {
+  return PublicKey.findProgramAddress(
+    [pool.toBuffer(), stakeAccount.toBuffer(), Buffer.from("split")],
+    unstakeProg
+  );
+}
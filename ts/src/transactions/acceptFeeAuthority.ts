@@ -4,25 +4,27 @@ import {
   Program,
   ProgramAccount,
 } from "@project-serum/anchor";
-import { Transaction } from "@solana/web3.js";
+import { PublicKey, Transaction } from "@solana/web3.js";
 import { Unstake } from "../idl/idl";
+import { findFeeAuthorityNomineeAccount } from "../pda";
 import { derivePoolFeeAuthority } from "./utils";
 
-export type SetFeeAuthorityAccounts = {
+export type AcceptFeeAuthorityAccounts = {
   /**
-   * The liquidity pool's fee authority
+   * The liquidity pool's current fee authority,
+   * receives the fee authority nominee account's rent.
    * Must be provided if `poolAccount` is `Address`.
    * Otherwise, uses the one read from `poolAccount`
    */
   feeAuthority?: Address;
 
   /**
-   * The liquidity pool to set fee authority of
+   * The liquidity pool to take over the fee authority of
    */
   poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
 
   /**
-   * The new fee authority to update the fee authority with
+   * The nominated fee authority
    */
   newFeeAuthority: Address;
 };
@@ -31,27 +33,32 @@ export type SetFeeAuthorityAccounts = {
  *
  * @param program
  * @param accounts
- * @returns the created set_fee_authority transaction
+ * @returns the created accept_fee_authority transaction
  */
-export async function setFeeAuthorityTx(
+export async function acceptFeeAuthorityTx(
   program: Program<Unstake>,
   {
     feeAuthority: feeAuthorityOption,
     poolAccount: poolAccountUnion,
     newFeeAuthority,
-  }: SetFeeAuthorityAccounts
+  }: AcceptFeeAuthorityAccounts
 ): Promise<Transaction> {
   const { feeAuthority, poolAccount } = derivePoolFeeAuthority(
     poolAccountUnion,
     feeAuthorityOption
   );
+  const [feeAuthorityNomineeAccount] = await findFeeAuthorityNomineeAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
 
   return program.methods
-    .setFeeAuthority()
+    .acceptFeeAuthority()
     .accounts({
+      newFeeAuthority,
       feeAuthority,
       poolAccount,
-      newFeeAuthority,
+      feeAuthorityNomineeAccount,
     })
     .transaction();
 }
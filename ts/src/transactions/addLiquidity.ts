@@ -7,7 +7,15 @@ import {
 import { PublicKey, Transaction } from "@solana/web3.js";
 import { getAssociatedTokenAddress } from "@solana/spl-token";
 import { Unstake } from "../idl/idl";
-import { findFlashAccount, findPoolSolReserves } from "../pda";
+import {
+  findAllowedLpAccount,
+  findFlashAccount,
+  findPoolConfigAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findProtocolPauseAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
 import BN from "bn.js";
 import { derivePoolLpMint } from "./utils";
 
@@ -40,6 +48,8 @@ export type AddLiquidityAccounts = {
  *
  * @param program
  * @param accounts
+ * @param minLpTokensOut - if provided, the transaction fails if
+ *                         the LP tokens to be minted is below this amount
  * @returns the created add_liquidity transaction
  */
 export async function addLiquidityTx(
@@ -50,7 +60,8 @@ export async function addLiquidityTx(
     poolAccount: poolAccountUnion,
     lpMint: lpMintOption,
     mintLpTokensTo: mintLpTokensToOption,
-  }: AddLiquidityAccounts
+  }: AddLiquidityAccounts,
+  minLpTokensOut?: BN
 ): Promise<Transaction> {
   const { lpMint, poolAccount } = derivePoolLpMint(
     poolAccountUnion,
@@ -64,14 +75,37 @@ export async function addLiquidityTx(
     program.programId,
     new PublicKey(poolAccount)
   );
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [allowedLpAccount] = await findAllowedLpAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(from)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
   const mintLpTokensTo =
     mintLpTokensToOption ??
     (await getAssociatedTokenAddress(
       new PublicKey(lpMint),
       new PublicKey(from)
     ));
-  return program.methods
-    .addLiquidity(amountLamports)
+  const methodsBuilder = program.methods.addLiquidityV2(
+    amountLamports,
+    minLpTokensOut ?? new BN(0)
+  );
+  return methodsBuilder
     .accounts({
       from,
       poolAccount,
@@ -79,6 +113,11 @@ export async function addLiquidityTx(
       lpMint,
       mintLpTokensTo,
       flashAccount,
+      poolPauseAccount,
+      protocolPauseAccount,
+      poolConfigAccount,
+      allowedLpAccount,
+      withdrawQueueAccount,
     })
     .transaction();
 }
@@ -0,0 +1,208 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import { Unstake } from "../idl/idl";
+import {
+  findAllowedLpAccount,
+  findFlashAccount,
+  findPoolConfigAccount,
+  findPoolFeeAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findProtocolFeeAccount,
+  findProtocolPauseAccount,
+  findStakeAccountRecordAccount,
+  findStakeDepositFeeAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
+import { ProtocolFeeAccount } from "../types";
+import {
+  derivePoolLpMint,
+  deriveProtocolFeeAddresses,
+  deriveValidatorFeeAccount,
+} from "./utils";
+
+export type AddLiquidityStakeAccounts = {
+  /**
+   * The withdraw authority of the stake account to deposit
+   */
+  depositor: Address;
+
+  /**
+   * The stake account to deposit
+   */
+  stakeAccount: Address;
+
+  /**
+   * The liquidity pool to add liquidity to
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The LP token account to mint tokens to.
+   * Defaults to `depositor`'s ATA.
+   */
+  mintLpTokensTo?: Address;
+
+  /**
+   * The vote account `stakeAccount` is delegated to.
+   * Fetched from `stakeAccount` if unspecified
+   */
+  voteAccount?: Address;
+
+  /**
+   * The program's protocol fee account
+   */
+  protocolFee?: ProgramAccount<ProtocolFeeAccount>;
+
+  /**
+   * The protocol fee payment destination.
+   * Must be provided if `protocolFee` is not provided.
+   * Otherwise, uses the one read from `protocolFee`
+   */
+  protocolFeeDestination?: Address;
+
+  /**
+   * The referrer for this deposit.
+   * SOL is transferred directly to this account. Please make sure
+   * that this is already initialized and rent-exempt
+   */
+  referrer?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created add_liquidity_stake transaction
+ */
+export async function addLiquidityStakeTx(
+  program: Program<Unstake>,
+  {
+    depositor,
+    stakeAccount,
+    poolAccount: poolAccountUnion,
+    lpMint: lpMintOption,
+    mintLpTokensTo: mintLpTokensToOption,
+    voteAccount: voteAccountOption,
+    protocolFee: protocolFeeOption,
+    protocolFeeDestination: protocolFeeDestinationOption,
+    referrer: referrerOption,
+  }: AddLiquidityStakeAccounts
+): Promise<Transaction> {
+  const { protocolFeeAccount, protocolFeeDestination } =
+    deriveProtocolFeeAddresses(
+      protocolFeeOption ?? (await findProtocolFeeAccount(program.programId))[0],
+      protocolFeeDestinationOption
+    );
+  const { lpMint, poolAccount } = derivePoolLpMint(
+    poolAccountUnion,
+    lpMintOption
+  );
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(stakeAccount)
+  );
+  const [stakeDepositFeeAccount] = await findStakeDepositFeeAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [feeAccount] = await findPoolFeeAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const validatorFeeAccount = await deriveValidatorFeeAccount(
+    program,
+    new PublicKey(poolAccount),
+    new PublicKey(stakeAccount),
+    voteAccountOption
+  );
+  const [flashAccount] = await findFlashAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [allowedLpAccount] = await findAllowedLpAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(depositor)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const mintLpTokensTo =
+    mintLpTokensToOption ??
+    (await getAssociatedTokenAddress(
+      new PublicKey(lpMint),
+      new PublicKey(depositor)
+    ));
+  let builder = program.methods.addLiquidityStake().accounts({
+    depositor,
+    stakeAccount,
+    poolAccount,
+    poolSolReserves,
+    lpMint,
+    mintLpTokensTo,
+    stakeAccountRecordAccount,
+    stakeDepositFeeAccount,
+    feeAccount,
+    validatorFeeAccount,
+    flashAccount,
+    poolPauseAccount,
+    protocolPauseAccount,
+    poolConfigAccount,
+    allowedLpAccount,
+    withdrawQueueAccount,
+    protocolFeeAccount,
+    protocolFeeDestination,
+    clock: SYSVAR_CLOCK_PUBKEY,
+    stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+    stakeProgram: StakeProgram.programId,
+  });
+
+  if (referrerOption) {
+    builder = builder.remainingAccounts([
+      {
+        pubkey: new PublicKey(referrerOption),
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+  }
+
+  return builder.transaction();
+}
@@ -0,0 +1,149 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { getAssociatedTokenAddress, NATIVE_MINT } from "@solana/spl-token";
+import { Unstake } from "../idl/idl";
+import {
+  findAllowedLpAccount,
+  findFlashAccount,
+  findPoolConfigAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findProtocolPauseAccount,
+  findWithdrawQueueAccount,
+  findWsolStagingAccount,
+} from "../pda";
+import BN from "bn.js";
+import { derivePoolLpMint } from "./utils";
+
+export type AddLiquidityWsolAccounts = {
+  /**
+   * Signer with authority over the wSOL token account to add liquidity from
+   */
+  authority: Address;
+
+  /**
+   * The liquidity pool to add liquidity to
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The wSOL token account to add liquidity from.
+   * Defaults to wSOL ATA of `authority`.
+   */
+  from?: Address;
+
+  /**
+   * Signer that temporarily pays for the pool's wSOL staging account's rent.
+   * Defaults to `authority`
+   */
+  payer?: Address;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The LP token account to mint tokens to.
+   * Defaults to `authority`'s ATA.
+   */
+  mintLpTokensTo?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param amountLamports amount of wSOL to add as liquidity
+ * @param accounts
+ * @param minLpTokensOut - the transaction fails if
+ *                         the LP tokens to be minted is below this amount.
+ *                         Defaults to 0
+ * @returns the created add_liquidity_wsol transaction
+ */
+export async function addLiquidityWsolTx(
+  program: Program<Unstake>,
+  amountLamports: BN,
+  {
+    authority,
+    poolAccount: poolAccountUnion,
+    from: fromOption,
+    payer: payerOption,
+    lpMint: lpMintOption,
+    mintLpTokensTo: mintLpTokensToOption,
+  }: AddLiquidityWsolAccounts,
+  minLpTokensOut: BN = new BN(0)
+): Promise<Transaction> {
+  const { lpMint, poolAccount } = derivePoolLpMint(
+    poolAccountUnion,
+    lpMintOption
+  );
+  const from =
+    fromOption ??
+    (await getAssociatedTokenAddress(NATIVE_MINT, new PublicKey(authority)));
+  const payer = payerOption ?? authority;
+  const [wsolStagingAccount] = await findWsolStagingAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [flashAccount] = await findFlashAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [allowedLpAccount] = await findAllowedLpAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(authority)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const mintLpTokensTo =
+    mintLpTokensToOption ??
+    (await getAssociatedTokenAddress(
+      new PublicKey(lpMint),
+      new PublicKey(authority)
+    ));
+  return program.methods
+    .addLiquidityWsol(amountLamports, minLpTokensOut)
+    .accounts({
+      fromAuthority: authority,
+      payer,
+      from,
+      wsolStagingAccount,
+      nativeMint: NATIVE_MINT,
+      poolAccount,
+      poolSolReserves,
+      lpMint,
+      mintLpTokensTo,
+      flashAccount,
+      poolPauseAccount,
+      protocolPauseAccount,
+      poolConfigAccount,
+      allowedLpAccount,
+      withdrawQueueAccount,
+    })
+    .transaction();
+}
@@ -0,0 +1,49 @@
+import { Address, Program } from "@project-serum/anchor";
+import { PublicKey, SYSVAR_CLOCK_PUBKEY, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import { findPendingFeeAccount, findPoolFeeAccount } from "../pda";
+
+export type ApplyPendingFeeAccounts = {
+  /**
+   * The liquidity pool to apply the pending fee of
+   */
+  poolAccount: Address;
+
+  /**
+   * The liquidity pool's fee authority,
+   * receives the pending fee account's rent
+   */
+  feeAuthority: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created apply_pending_fee transaction
+ */
+export async function applyPendingFeeTx(
+  program: Program<Unstake>,
+  { poolAccount, feeAuthority }: ApplyPendingFeeAccounts
+): Promise<Transaction> {
+  const poolAccountPk = new PublicKey(poolAccount);
+  const [feeAccount] = await findPoolFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [pendingFeeAccount] = await findPendingFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  return program.methods
+    .applyPendingFee()
+    .accounts({
+      feeAuthority,
+      poolAccount,
+      feeAccount,
+      pendingFeeAccount,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .transaction();
+}
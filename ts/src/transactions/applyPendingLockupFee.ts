@@ -0,0 +1,49 @@
+import { Address, Program } from "@project-serum/anchor";
+import { PublicKey, SYSVAR_CLOCK_PUBKEY, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import { findLockupFeeAccount, findPendingLockupFeeAccount } from "../pda";
+
+export type ApplyPendingLockupFeeAccounts = {
+  /**
+   * The liquidity pool to apply the pending lockup fee of
+   */
+  poolAccount: Address;
+
+  /**
+   * The liquidity pool's fee authority,
+   * receives the pending lockup fee account's rent
+   */
+  feeAuthority: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created apply_pending_lockup_fee transaction
+ */
+export async function applyPendingLockupFeeTx(
+  program: Program<Unstake>,
+  { poolAccount, feeAuthority }: ApplyPendingLockupFeeAccounts
+): Promise<Transaction> {
+  const poolAccountPk = new PublicKey(poolAccount);
+  const [lockupFeeAccount] = await findLockupFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [pendingLockupFeeAccount] = await findPendingLockupFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  return program.methods
+    .applyPendingLockupFee()
+    .accounts({
+      feeAuthority,
+      poolAccount,
+      lockupFeeAccount,
+      pendingLockupFeeAccount,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .transaction();
+}
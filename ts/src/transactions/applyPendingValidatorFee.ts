@@ -0,0 +1,73 @@
+import { Address, Program } from "@project-serum/anchor";
+import { PublicKey, SYSVAR_CLOCK_PUBKEY, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findPendingValidatorFeeAccount,
+  findValidatorFeeAccount,
+} from "../pda";
+
+export type ApplyPendingValidatorFeeAccounts = {
+  /**
+   * Pays for the validator fee account's rent if it does not exist yet
+   */
+  payer: Address;
+
+  /**
+   * The liquidity pool to apply the pending validator fee of
+   */
+  poolAccount: Address;
+
+  /**
+   * The liquidity pool's fee authority,
+   * receives the pending validator fee account's rent,
+   * and the validator fee account's if it is removed
+   */
+  feeAuthority: Address;
+
+  /**
+   * The vote account of the validator to apply the pending validator fee of
+   */
+  voteAccount: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created apply_pending_validator_fee transaction
+ */
+export async function applyPendingValidatorFeeTx(
+  program: Program<Unstake>,
+  {
+    payer,
+    poolAccount,
+    feeAuthority,
+    voteAccount,
+  }: ApplyPendingValidatorFeeAccounts
+): Promise<Transaction> {
+  const poolAccountPk = new PublicKey(poolAccount);
+  const voteAccountPk = new PublicKey(voteAccount);
+  const [validatorFeeAccount] = await findValidatorFeeAccount(
+    program.programId,
+    poolAccountPk,
+    voteAccountPk
+  );
+  const [pendingValidatorFeeAccount] = await findPendingValidatorFeeAccount(
+    program.programId,
+    poolAccountPk,
+    voteAccountPk
+  );
+
+  return program.methods
+    .applyPendingValidatorFee()
+    .accounts({
+      payer,
+      feeAuthority,
+      poolAccount,
+      voteAccount,
+      validatorFeeAccount,
+      pendingValidatorFeeAccount,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .transaction();
+}
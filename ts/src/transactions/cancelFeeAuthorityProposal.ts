@@ -0,0 +1,57 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import { findFeeAuthorityNomineeAccount } from "../pda";
+import { derivePoolFeeAuthority } from "./utils";
+
+export type CancelFeeAuthorityProposalAccounts = {
+  /**
+   * The liquidity pool's fee authority,
+   * receives the fee authority nominee account's rent.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  feeAuthority?: Address;
+
+  /**
+   * The liquidity pool to cancel the fee authority nomination of
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created cancel_fee_authority_proposal transaction
+ */
+export async function cancelFeeAuthorityProposalTx(
+  program: Program<Unstake>,
+  {
+    feeAuthority: feeAuthorityOption,
+    poolAccount: poolAccountUnion,
+  }: CancelFeeAuthorityProposalAccounts
+): Promise<Transaction> {
+  const { feeAuthority, poolAccount } = derivePoolFeeAuthority(
+    poolAccountUnion,
+    feeAuthorityOption
+  );
+  const [feeAuthorityNomineeAccount] = await findFeeAuthorityNomineeAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+
+  return program.methods
+    .cancelFeeAuthorityProposal()
+    .accounts({
+      feeAuthority,
+      poolAccount,
+      feeAuthorityNomineeAccount,
+    })
+    .transaction();
+}
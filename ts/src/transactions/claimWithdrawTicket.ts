@@ -0,0 +1,67 @@
+import { Address, Program } from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findPoolSolReserves,
+  findWithdrawQueueAccount,
+  findWithdrawTicketAccount,
+} from "../pda";
+
+export type ClaimWithdrawTicketAccounts = {
+  /**
+   * Owner of the withdraw ticket. Refunded the ticket's rent
+   */
+  owner: Address;
+
+  /**
+   * The liquidity pool the withdraw ticket is for
+   */
+  poolAccount: Address;
+
+  /**
+   * The SOL account to send the claimed SOL to.
+   * Defaults to `owner`
+   */
+  sendLamportsTo?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created claim_withdraw_ticket transaction
+ */
+export async function claimWithdrawTicketTx(
+  program: Program<Unstake>,
+  {
+    owner,
+    poolAccount,
+    sendLamportsTo: sendLamportsToOption,
+  }: ClaimWithdrawTicketAccounts
+): Promise<Transaction> {
+  const sendLamportsTo = sendLamportsToOption ?? owner;
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawTicketAccount] = await findWithdrawTicketAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(owner)
+  );
+  return program.methods
+    .claimWithdrawTicket()
+    .accounts({
+      owner,
+      to: sendLamportsTo,
+      poolAccount,
+      poolSolReserves,
+      withdrawQueueAccount,
+      withdrawTicketAccount,
+    })
+    .transaction();
+}
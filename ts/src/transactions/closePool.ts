@@ -0,0 +1,170 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { AccountMeta, PublicKey, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findAllowedLpAccount,
+  findFeeAuthorityNomineeAccount,
+  findFeeDelayAccount,
+  findFlashLoanFeeAccount,
+  findLockedLpTokenAccount,
+  findLockupFeeAccount,
+  findPendingFeeAccount,
+  findPendingLockupFeeAccount,
+  findPendingValidatorFeeAccount,
+  findPoolConfigAccount,
+  findPoolFeeAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findStakeDepositFeeAccount,
+  findValidatorFeeAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
+import { derivePoolFeeAuthority, derivePoolLpMint } from "./utils";
+
+export type ClosePoolAccounts = {
+  /**
+   * The liquidity pool's fee authority
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  feeAuthority?: Address;
+
+  /**
+   * The liquidity pool to close
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The account to sweep the pool's leftover SOL and refund rent to.
+   * Defaults to feeAuthority if not provided
+   */
+  destination?: Address;
+
+  /**
+   * The vote accounts whose ValidatorFee and PendingValidatorFee
+   * accounts to close.
+   * ValidatorFee accounts not listed are left open
+   */
+  validatorFeeVoteAccounts?: Address[];
+
+  /**
+   * The LPs whose AllowedLp accounts to close.
+   * AllowedLp accounts not listed are left open
+   */
+  allowedLps?: Address[];
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created close_pool transaction
+ */
+export async function closePoolTx(
+  program: Program<Unstake>,
+  {
+    feeAuthority: feeAuthorityOption,
+    poolAccount: poolAccountUnion,
+    lpMint: lpMintOption,
+    destination: destinationOption,
+    validatorFeeVoteAccounts = [],
+    allowedLps = [],
+  }: ClosePoolAccounts
+): Promise<Transaction> {
+  const { feeAuthority, poolAccount } = derivePoolFeeAuthority(
+    poolAccountUnion,
+    feeAuthorityOption
+  );
+  const { lpMint } = derivePoolLpMint(poolAccountUnion, lpMintOption);
+  const destination = destinationOption ?? feeAuthority;
+
+  const poolAccountPk = new PublicKey(poolAccount);
+  const [
+    [poolSolReserves],
+    [lockedLpTokenAccount],
+    [feeAccount],
+    [flashLoanFeeAccount],
+    [feeDelayAccount],
+    [pendingFeeAccount],
+    [feeAuthorityNomineeAccount],
+    [poolConfigAccount],
+    [poolPauseAccount],
+    [stakeDepositFeeAccount],
+    [lockupFeeAccount],
+    [pendingLockupFeeAccount],
+    [withdrawQueueAccount],
+  ] = await Promise.all(
+    [
+      findPoolSolReserves,
+      findLockedLpTokenAccount,
+      findPoolFeeAccount,
+      findFlashLoanFeeAccount,
+      findFeeDelayAccount,
+      findPendingFeeAccount,
+      findFeeAuthorityNomineeAccount,
+      findPoolConfigAccount,
+      findPoolPauseAccount,
+      findStakeDepositFeeAccount,
+      findLockupFeeAccount,
+      findPendingLockupFeeAccount,
+      findWithdrawQueueAccount,
+    ].map((findPda) => findPda(program.programId, poolAccountPk))
+  );
+
+  // (pubkey, pda) pairs of the ValidatorFee, PendingValidatorFee
+  // and AllowedLp accounts to close
+  const remainingAccounts = await Promise.all(
+    [
+      ...validatorFeeVoteAccounts.map(
+        (voteAccount) => [voteAccount, findValidatorFeeAccount] as const
+      ),
+      ...validatorFeeVoteAccounts.map(
+        (voteAccount) => [voteAccount, findPendingValidatorFeeAccount] as const
+      ),
+      ...allowedLps.map((lp) => [lp, findAllowedLpAccount] as const),
+    ].map(async ([key, findPda]) => {
+      const keyPk = new PublicKey(key);
+      const [pda] = await findPda(program.programId, poolAccountPk, keyPk);
+      return [
+        { pubkey: keyPk, isSigner: false, isWritable: false },
+        { pubkey: pda, isSigner: false, isWritable: true },
+      ];
+    })
+  );
+
+  return program.methods
+    .closePool()
+    .accounts({
+      feeAuthority,
+      destination,
+      poolAccount,
+      poolSolReserves,
+      lpMint,
+      lockedLpTokenAccount,
+      feeAccount,
+      flashLoanFeeAccount,
+      feeDelayAccount,
+      pendingFeeAccount,
+      feeAuthorityNomineeAccount,
+      poolConfigAccount,
+      poolPauseAccount,
+      stakeDepositFeeAccount,
+      lockupFeeAccount,
+      pendingLockupFeeAccount,
+      withdrawQueueAccount,
+    })
+    .remainingAccounts(([] as AccountMeta[]).concat(...remainingAccounts))
+    .transaction();
+}
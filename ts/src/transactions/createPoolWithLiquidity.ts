@@ -0,0 +1,120 @@
+import { Address, Program } from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findLockedLpTokenAccount,
+  findPoolFeeAccount,
+  findPoolSolReserves,
+  findProtocolPauseAccount,
+} from "../pda";
+import { Fee } from "../types";
+import BN from "bn.js";
+
+export type CreatePoolWithLiquidityAccounts = {
+  /**
+   * The feeAuthority of the new liquidity pool
+   */
+  feeAuthority: Address;
+
+  /**
+   * The liquidity pool to be created.
+   */
+  poolAccount: Address;
+
+  /**
+   * The LP mint of the liquidity pool to be created.
+   */
+  lpMint: Address;
+
+  /**
+   * The LP token account to be created to mint the initial LP tokens to.
+   * Must sign the transaction if provided.
+   * The initial LP tokens are permanently locked in the pool's
+   * locked LP token account if not provided
+   */
+  mintLpTokensTo?: Address;
+
+  /**
+   * The owner of `mintLpTokensTo`.
+   * Defaults to payer if not provided
+   */
+  lpTokenAccountOwner?: Address;
+
+  /**
+   * The SOL account paying for the transaction, rent and initial liquidity.
+   * Defaults to feeAuthority if not provided
+   */
+  payer?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param fee
+ * @param initialLamports amount of SOL liquidity the pool is created with, in lamports
+ * @param accounts
+ * @returns the created create_pool_with_liquidity transaction
+ */
+export async function createPoolWithLiquidityTx(
+  program: Program<Unstake>,
+  fee: Fee,
+  initialLamports: BN,
+  {
+    feeAuthority,
+    poolAccount,
+    lpMint,
+    mintLpTokensTo: mintLpTokensToOption,
+    lpTokenAccountOwner: lpTokenAccountOwnerOption,
+    payer: payerOption,
+  }: CreatePoolWithLiquidityAccounts
+): Promise<Transaction> {
+  const payer = payerOption ?? feeAuthority;
+  const lpTokenAccountOwner = lpTokenAccountOwnerOption ?? payer;
+
+  const poolAccountPk = new PublicKey(poolAccount);
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    poolAccountPk
+  );
+  const [feeAccount] = await findPoolFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  // unused optional account is set to the program ID
+  const [mintLpTokensTo, lockedLpTokenAccount] = mintLpTokensToOption
+    ? [mintLpTokensToOption, program.programId]
+    : [
+        program.programId,
+        (await findLockedLpTokenAccount(program.programId, poolAccountPk))[0],
+      ];
+
+  const tx = await program.methods
+    .createPoolWithLiquidity(fee, initialLamports)
+    .accounts({
+      payer,
+      feeAuthority,
+      poolAccount,
+      poolSolReserves,
+      feeAccount,
+      lpMint,
+      lpTokenAccountOwner,
+      mintLpTokensTo,
+      lockedLpTokenAccount,
+      protocolPauseAccount,
+    })
+    .transaction();
+  if (mintLpTokensToOption) {
+    const mintLpTokensToPk = new PublicKey(mintLpTokensToOption);
+    tx.instructions.forEach((ix) =>
+      ix.keys
+        .filter(({ pubkey }) => pubkey.equals(mintLpTokensToPk))
+        .forEach((meta) => {
+          meta.isSigner = true;
+        })
+    );
+  }
+  return tx;
+}
@@ -2,13 +2,28 @@
 // but the presence of multiple @solana/web3.js packages (one within anchor, one for package)
 // breaks `instanceof Transaction` checks everywhere
 
+export * from "./acceptFeeAuthority";
 export * from "./addLiquidity";
+export * from "./addLiquidityStake";
+export * from "./addLiquidityWsol";
+export * from "./applyPendingFee";
+export * from "./applyPendingLockupFee";
+export * from "./applyPendingValidatorFee";
+export * from "./cancelFeeAuthorityProposal";
+export * from "./claimWithdrawTicket";
+export * from "./closePool";
 export * from "./createPool";
+export * from "./createPoolWithLiquidity";
 export * from "./deactivateStakeAccount";
+export * from "./proposeFeeAuthority";
 export * from "./reclaimStakeAccount";
 export * from "./removeLiquidity";
+export * from "./removeLiquidityInKind";
+export * from "./removeLiquidityWsol";
+export * from "./requestWithdrawal";
 export * from "./setFee";
-export * from "./setFeeAuthority";
 export * from "./takeFlashLoan";
 export * from "./unstake";
+export * from "./unstakeMulti";
+export * from "./unstakePartial";
 export * from "./unstakeWsol";
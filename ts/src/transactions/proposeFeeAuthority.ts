@@ -0,0 +1,64 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import { findFeeAuthorityNomineeAccount } from "../pda";
+import { derivePoolFeeAuthority } from "./utils";
+
+export type ProposeFeeAuthorityAccounts = {
+  /**
+   * The liquidity pool's fee authority.
+   * Pays for the fee authority nominee account's rent if it does not exist yet.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  feeAuthority?: Address;
+
+  /**
+   * The liquidity pool to nominate a new fee authority for
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The nominated fee authority, which must sign `acceptFeeAuthorityTx()` to take over
+   */
+  newFeeAuthority: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @returns the created propose_fee_authority transaction
+ */
+export async function proposeFeeAuthorityTx(
+  program: Program<Unstake>,
+  {
+    feeAuthority: feeAuthorityOption,
+    poolAccount: poolAccountUnion,
+    newFeeAuthority,
+  }: ProposeFeeAuthorityAccounts
+): Promise<Transaction> {
+  const { feeAuthority, poolAccount } = derivePoolFeeAuthority(
+    poolAccountUnion,
+    feeAuthorityOption
+  );
+  const [feeAuthorityNomineeAccount] = await findFeeAuthorityNomineeAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+
+  return program.methods
+    .proposeFeeAuthority()
+    .accounts({
+      feeAuthority,
+      poolAccount,
+      newFeeAuthority,
+      feeAuthorityNomineeAccount,
+    })
+    .transaction();
+}
@@ -7,7 +7,11 @@ import {
 import { PublicKey, Transaction } from "@solana/web3.js";
 import { getAssociatedTokenAddress } from "@solana/spl-token";
 import { Unstake } from "../idl/idl";
-import { findFlashAccount, findPoolSolReserves } from "../pda";
+import {
+  findFlashAccount,
+  findPoolSolReserves,
+  findWithdrawQueueAccount,
+} from "../pda";
 import BN from "bn.js";
 import { derivePoolLpMint } from "./utils";
 
@@ -46,7 +50,9 @@ export type RemoveLiquidityAccounts = {
  *
  * @param program
  * @param accounts
- * @returns the created add_liquidity transaction
+ * @param minLamportsOut - if provided, the transaction fails if
+ *                         the lamports to be received is below this amount
+ * @returns the created remove_liquidity transaction
  */
 export async function removeLiquidityTx(
   program: Program<Unstake>,
@@ -57,7 +63,8 @@ export async function removeLiquidityTx(
     from: fromOption,
     lpMint: lpMintOption,
     sendLamportsTo: sendLamportsToOption,
-  }: RemoveLiquidityAccounts
+  }: RemoveLiquidityAccounts,
+  minLamportsOut?: BN
 ): Promise<Transaction> {
   const { lpMint, poolAccount } = derivePoolLpMint(
     poolAccountUnion,
@@ -78,8 +85,15 @@ export async function removeLiquidityTx(
     program.programId,
     new PublicKey(poolAccount)
   );
-  return program.methods
-    .removeLiquidity(amountLPAtomics)
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const methodsBuilder = program.methods.removeLiquidityV2(
+    amountLPAtomics,
+    minLamportsOut ?? new BN(0)
+  );
+  return methodsBuilder
     .accounts({
       burnLpTokensFromAuthority: authority,
       to: sendLamportsTo,
@@ -88,6 +102,7 @@ export async function removeLiquidityTx(
       lpMint,
       burnLpTokensFrom: from,
       flashAccount,
+      withdrawQueueAccount,
     })
     .transaction();
 }
@@ -0,0 +1,155 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import { Unstake } from "../idl/idl";
+import {
+  findFlashAccount,
+  findPoolSolReserves,
+  findStakeAccountRecordAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
+import BN from "bn.js";
+import { derivePoolLpMint } from "./utils";
+
+export type RemoveLiquidityInKindAccounts = {
+  /**
+   * Signer with authority over the LP token account to burn from
+   */
+  authority: Address;
+
+  /**
+   * The liquidity pool to remove liquidity from
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The pool's stake account to redeem
+   */
+  stakeAccount: Address;
+
+  /**
+   * New stake account to split the redeemed lamports into.
+   * Must sign the transaction even if the whole stake account is redeemed
+   */
+  splitStakeAccount: Address;
+
+  /**
+   * The new staker and withdraw authority of the redeemed stake account.
+   * Defaults to `authority`
+   */
+  newStakeAuthority?: Address;
+
+  /**
+   * The LP token account to burn LP tokens from.
+   * Defaults to ATA of `authority`.
+   */
+  from?: Address;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The stake account's lockup custodian.
+   * Must be provided, and must sign the transaction,
+   * if the stake account's lockup is in force
+   */
+  custodian?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param lamports amount of the stake account's recorded lamports to redeem
+ * @param accounts
+ * @returns the created remove_liquidity_in_kind transaction
+ */
+export async function removeLiquidityInKindTx(
+  program: Program<Unstake>,
+  lamports: BN,
+  {
+    authority,
+    poolAccount: poolAccountUnion,
+    stakeAccount,
+    splitStakeAccount,
+    newStakeAuthority: newStakeAuthorityOption,
+    from: fromOption,
+    lpMint: lpMintOption,
+    custodian: custodianOption,
+  }: RemoveLiquidityInKindAccounts
+): Promise<Transaction> {
+  const { lpMint, poolAccount } = derivePoolLpMint(
+    poolAccountUnion,
+    lpMintOption
+  );
+  const from =
+    fromOption ??
+    (await getAssociatedTokenAddress(
+      new PublicKey(lpMint),
+      new PublicKey(authority)
+    ));
+  const newStakeAuthority = newStakeAuthorityOption ?? authority;
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(stakeAccount)
+  );
+  const [flashAccount] = await findFlashAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  // optional accounts are set to the program ID if not used
+  const custodian = custodianOption ?? program.programId;
+
+  const tx = await program.methods
+    .removeLiquidityInKind(lamports)
+    .accounts({
+      burnLpTokensFromAuthority: authority,
+      newStakeAuthority,
+      stakeAccount,
+      splitStakeAccount,
+      poolAccount,
+      poolSolReserves,
+      stakeAccountRecordAccount,
+      lpMint,
+      burnLpTokensFrom: from,
+      flashAccount,
+      withdrawQueueAccount,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+      custodian,
+    })
+    .transaction();
+  if (custodianOption) {
+    const custodianPk = new PublicKey(custodianOption);
+    tx.instructions.forEach((ix) =>
+      ix.keys
+        .filter(({ pubkey }) => pubkey.equals(custodianPk))
+        .forEach((meta) => {
+          meta.isSigner = true;
+        })
+    );
+  }
+  return tx;
+}
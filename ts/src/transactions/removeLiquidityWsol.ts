@@ -0,0 +1,109 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { getAssociatedTokenAddress, NATIVE_MINT } from "@solana/spl-token";
+import { Unstake } from "../idl/idl";
+import {
+  findFlashAccount,
+  findPoolSolReserves,
+  findWithdrawQueueAccount,
+} from "../pda";
+import BN from "bn.js";
+import { derivePoolLpMint } from "./utils";
+
+export type RemoveLiquidityWsolAccounts = {
+  /**
+   * Signer with authority over the LP token account to burn from
+   */
+  authority: Address;
+
+  /**
+   * The liquidity pool to remove liquidity from
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The LP token account to burn LP tokens from.
+   * Defaults to ATA of `authority`.
+   */
+  from?: Address;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The wSOL token account to receive the removed liquidity.
+   * Defaults to wSOL ATA of `authority`
+   */
+  destination?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param amountLPAtomics amount of LP tokens to burn
+ * @param accounts
+ * @param minLamportsOut - the transaction fails if
+ *                         the lamports to be received is below this amount.
+ *                         Defaults to 0
+ * @returns the created remove_liquidity_wsol transaction
+ */
+export async function removeLiquidityWsolTx(
+  program: Program<Unstake>,
+  amountLPAtomics: BN,
+  {
+    authority,
+    poolAccount: poolAccountUnion,
+    from: fromOption,
+    lpMint: lpMintOption,
+    destination: destinationOption,
+  }: RemoveLiquidityWsolAccounts,
+  minLamportsOut: BN = new BN(0)
+): Promise<Transaction> {
+  const { lpMint, poolAccount } = derivePoolLpMint(
+    poolAccountUnion,
+    lpMintOption
+  );
+  const from =
+    fromOption ??
+    (await getAssociatedTokenAddress(
+      new PublicKey(lpMint),
+      new PublicKey(authority)
+    ));
+  const destination =
+    destinationOption ??
+    (await getAssociatedTokenAddress(NATIVE_MINT, new PublicKey(authority)));
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [flashAccount] = await findFlashAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  return program.methods
+    .removeLiquidityWsol(amountLPAtomics, minLamportsOut)
+    .accounts({
+      burnLpTokensFromAuthority: authority,
+      to: destination,
+      poolAccount,
+      poolSolReserves,
+      lpMint,
+      burnLpTokensFrom: from,
+      flashAccount,
+      withdrawQueueAccount,
+    })
+    .transaction();
+}
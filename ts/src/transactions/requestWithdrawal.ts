@@ -0,0 +1,111 @@
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { PublicKey, Transaction } from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import { Unstake } from "../idl/idl";
+import {
+  findFlashAccount,
+  findPoolSolReserves,
+  findWithdrawQueueAccount,
+  findWithdrawTicketAccount,
+} from "../pda";
+import BN from "bn.js";
+import { derivePoolLpMint } from "./utils";
+
+export type RequestWithdrawalAccounts = {
+  /**
+   * Signer with authority over the LP token account to burn from.
+   * Owner of the withdraw ticket
+   */
+  authority: Address;
+
+  /**
+   * The liquidity pool to withdraw liquidity from
+   */
+  poolAccount: Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
+
+  /**
+   * The LP token account to burn LP tokens from.
+   * Defaults to ATA of `authority`.
+   */
+  from?: Address;
+
+  /**
+   * The LP mint of the liquidity pool.
+   * Must be provided if `poolAccount` is `Address`.
+   * Otherwise, uses the one read from `poolAccount`
+   */
+  lpMint?: Address;
+
+  /**
+   * The account paying for the withdraw ticket's rent.
+   * Defaults to `authority`
+   */
+  payer?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param amountLPAtomics
+ * @param accounts
+ * @returns the created request_withdrawal transaction
+ */
+export async function requestWithdrawalTx(
+  program: Program<Unstake>,
+  amountLPAtomics: BN,
+  {
+    authority,
+    poolAccount: poolAccountUnion,
+    from: fromOption,
+    lpMint: lpMintOption,
+    payer: payerOption,
+  }: RequestWithdrawalAccounts
+): Promise<Transaction> {
+  const { lpMint, poolAccount } = derivePoolLpMint(
+    poolAccountUnion,
+    lpMintOption
+  );
+  const from =
+    fromOption ??
+    (await getAssociatedTokenAddress(
+      new PublicKey(lpMint),
+      new PublicKey(authority)
+    ));
+  const payer = payerOption ?? authority;
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [flashAccount] = await findFlashAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [withdrawTicketAccount] = await findWithdrawTicketAccount(
+    program.programId,
+    new PublicKey(poolAccount),
+    new PublicKey(authority)
+  );
+  return program.methods
+    .requestWithdrawal(amountLPAtomics)
+    .accounts({
+      payer,
+      burnLpTokensFromAuthority: authority,
+      poolAccount,
+      poolSolReserves,
+      lpMint,
+      burnLpTokensFrom: from,
+      flashAccount,
+      withdrawQueueAccount,
+      withdrawTicketAccount,
+    })
+    .transaction();
+}
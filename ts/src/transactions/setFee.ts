@@ -1,7 +1,11 @@
 import { Address, Program } from "@project-serum/anchor";
-import { PublicKey, Transaction } from "@solana/web3.js";
+import { PublicKey, SYSVAR_CLOCK_PUBKEY, Transaction } from "@solana/web3.js";
 import { Unstake } from "../idl/idl";
-import { findPoolFeeAccount } from "../pda";
+import {
+  findFeeDelayAccount,
+  findPendingFeeAccount,
+  findPoolFeeAccount,
+} from "../pda";
 import { Fee } from "../types";
 
 export type SetFeeAccounts = {
@@ -17,7 +21,8 @@ export type SetFeeAccounts = {
 };
 
 /**
- *
+ * If the pool has a fee delay, the new fee is queued
+ * and must be applied with `applyPendingFeeTx()` once the delay has passed.
  * @param program
  * @param newFee
  * @param accounts
@@ -28,10 +33,23 @@ export async function setFeeTx(
   newFee: Fee,
   { poolAccount, feeAuthority }: SetFeeAccounts
 ): Promise<Transaction> {
+  const poolAccountPk = new PublicKey(poolAccount);
   const [feeAccount] = await findPoolFeeAccount(
     program.programId,
-    new PublicKey(poolAccount)
+    poolAccountPk
   );
+  const [feeDelayAccount] = await findFeeDelayAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const feeDelay = await program.account.feeDelay.fetchNullable(
+    feeDelayAccount
+  );
+  // optional accounts are set to the program ID if not used
+  const pendingFeeAccount =
+    feeDelay && !feeDelay.minDelaySeconds.isZero()
+      ? (await findPendingFeeAccount(program.programId, poolAccountPk))[0]
+      : program.programId;
 
   return program.methods
     .setFee(newFee)
@@ -39,6 +57,9 @@ export async function setFeeTx(
       feeAuthority,
       poolAccount,
       feeAccount,
+      feeDelayAccount,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      pendingFeeAccount,
     })
     .transaction();
 }
@@ -8,8 +8,10 @@ import { Unstake } from "../idl/idl";
 import {
   findFlashAccount,
   findFlashLoanFeeAccount,
+  findPoolPauseAccount,
   findPoolSolReserves,
   findProtocolFeeAccount,
+  findProtocolPauseAccount,
 } from "../pda";
 import BN from "bn.js";
 import { deriveProtocolFeeAddresses } from "./utils";
@@ -66,6 +68,13 @@ export async function takeFlashLoanTx(
     program.programId,
     new PublicKey(poolAccount)
   );
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    new PublicKey(poolAccount)
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
   const takeFlashLoanIx = await program.methods
     .takeFlashLoan(amountLamports)
     .accounts({
@@ -74,6 +83,8 @@ export async function takeFlashLoanTx(
       poolSolReserves,
       flashAccount,
       instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      poolPauseAccount,
+      protocolPauseAccount,
     })
     .instruction();
 
@@ -1,19 +1,29 @@
+import BN from "bn.js";
 import { Address, Program, ProgramAccount } from "@project-serum/anchor";
 import {
   PublicKey,
   StakeProgram,
   SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
   Transaction,
 } from "@solana/web3.js";
 import { Unstake } from "../idl/idl";
 import {
+  findLockupFeeAccount,
+  findPoolConfigAccount,
   findPoolFeeAccount,
+  findPoolPauseAccount,
   findPoolSolReserves,
   findProtocolFeeAccount,
+  findProtocolPauseAccount,
   findStakeAccountRecordAccount,
+  findWithdrawQueueAccount,
 } from "../pda";
 import { ProtocolFeeAccount } from "../types";
-import { deriveProtocolFeeAddresses } from "./utils";
+import {
+  deriveProtocolFeeAddresses,
+  deriveValidatorFeeAccount,
+} from "./utils";
 
 export type UnstakeAccounts = {
   /**
@@ -50,6 +60,19 @@ export type UnstakeAccounts = {
    */
   referrer?: Address;
 
+  /**
+   * The stake account's lockup custodian.
+   * Must be provided, and must sign the transaction,
+   * if the stake account's lockup is in force
+   */
+  custodian?: Address;
+
+  /**
+   * The vote account `stakeAccount` is delegated to.
+   * Fetched from `stakeAccount` if unspecified
+   */
+  voteAccount?: Address;
+
   /**
    * The SOL account to receive the unstaked SOL.
    * Defaults to `unstaker` if unspecified
@@ -61,6 +84,8 @@ export type UnstakeAccounts = {
  *
  * @param program
  * @param accounts
+ * @param minLamportsOut - if provided, the transaction fails if
+ *                         the lamports to be received is below this amount
  * @returns the created unstake transaction
  */
 export async function unstakeTx(
@@ -73,7 +98,10 @@ export async function unstakeTx(
     protocolFee: protocolFeeOption,
     protocolFeeDestination: protocolFeeDestinationOption,
     referrer: referrerOption,
-  }: UnstakeAccounts
+    custodian: custodianOption,
+    voteAccount: voteAccountOption,
+  }: UnstakeAccounts,
+  minLamportsOut?: BN
 ): Promise<Transaction> {
   const destination = destinationOption ?? unstaker;
 
@@ -98,19 +126,58 @@ export async function unstakeTx(
     poolAccountPk,
     stakeAccountPk
   );
+  const validatorFeeAccount = await deriveValidatorFeeAccount(
+    program,
+    poolAccountPk,
+    stakeAccountPk,
+    voteAccountOption
+  );
+
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  // optional accounts are set to the program ID if not used
+  const custodian = custodianOption ?? program.programId;
+  const lockupFeeAccount = custodianOption
+    ? (await findLockupFeeAccount(program.programId, poolAccountPk))[0]
+    : program.programId;
 
-  let builder = program.methods.unstake().accounts({
+  const methodsBuilder = program.methods.unstakeV2(
+    minLamportsOut ?? new BN(0)
+  );
+  let builder = methodsBuilder.accounts({
     unstaker,
     stakeAccount,
     destination,
     poolAccount,
     poolSolReserves,
     feeAccount,
+    validatorFeeAccount,
     stakeAccountRecordAccount,
     protocolFeeAccount,
     protocolFeeDestination,
     clock: SYSVAR_CLOCK_PUBKEY,
+    stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
     stakeProgram: StakeProgram.programId,
+    custodian,
+    lockupFeeAccount,
+    poolPauseAccount,
+    protocolPauseAccount,
+    poolConfigAccount,
+    withdrawQueueAccount,
   });
 
   if (referrerOption) {
@@ -123,5 +190,16 @@ export async function unstakeTx(
     ]);
   }
 
-  return builder.transaction();
+  const tx = await builder.transaction();
+  if (custodianOption) {
+    const custodianPk = new PublicKey(custodianOption);
+    tx.instructions.forEach((ix) =>
+      ix.keys
+        .filter(({ pubkey }) => pubkey.equals(custodianPk))
+        .forEach((meta) => {
+          meta.isSigner = true;
+        })
+    );
+  }
+  return tx;
 }
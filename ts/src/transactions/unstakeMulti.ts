@@ -0,0 +1,187 @@
+import BN from "bn.js";
+import { Address, Program, ProgramAccount } from "@project-serum/anchor";
+import {
+  AccountMeta,
+  PublicKey,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findPoolConfigAccount,
+  findPoolFeeAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findProtocolFeeAccount,
+  findProtocolPauseAccount,
+  findStakeAccountRecordAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
+import { ProtocolFeeAccount } from "../types";
+import {
+  deriveProtocolFeeAddresses,
+  deriveValidatorFeeAccount,
+} from "./utils";
+
+export type UnstakeMultiAccounts = {
+  /**
+   * The liquidity pool to unstake from
+   */
+  poolAccount: Address;
+
+  /**
+   * The stake accounts to unstake.
+   * They must all be charged the same fee by the pool
+   */
+  stakeAccounts: Address[];
+
+  /**
+   * The withdraw authority of all of `stakeAccounts`
+   */
+  unstaker: Address;
+
+  /**
+   * The program's protocol fee account
+   */
+  protocolFee?: ProgramAccount<ProtocolFeeAccount>;
+
+  /**
+   * The protocol fee payment destination.
+   * Must be provided if `protocolFee` is not provided.
+   * Otherwise, uses the one read from `protocolFee`
+   */
+  protocolFeeDestination?: Address;
+
+  /**
+   * The referrer for this unstake.
+   * SOL is transferred directly to this account. Please make sure
+   * that this is already initialized and rent-exempt
+   */
+  referrer?: Address;
+
+  /**
+   * The vote accounts `stakeAccounts` are delegated to, in the same order.
+   * Each stake account is fetched if unspecified
+   */
+  voteAccounts?: Address[];
+
+  /**
+   * The SOL account to receive the unstaked SOL.
+   * Defaults to `unstaker` if unspecified
+   */
+  destination?: Address;
+};
+
+/**
+ *
+ * @param program
+ * @param accounts
+ * @param minLamportsOut - if provided, the transaction fails if
+ *                         the lamports to be received is below this amount
+ * @returns the created unstake multi transaction
+ */
+export async function unstakeMultiTx(
+  program: Program<Unstake>,
+  {
+    poolAccount,
+    stakeAccounts,
+    unstaker,
+    destination: destinationOption,
+    protocolFee: protocolFeeOption,
+    protocolFeeDestination: protocolFeeDestinationOption,
+    referrer: referrerOption,
+    voteAccounts: voteAccountsOption,
+  }: UnstakeMultiAccounts,
+  minLamportsOut?: BN
+): Promise<Transaction> {
+  const destination = destinationOption ?? unstaker;
+
+  const { protocolFeeAccount, protocolFeeDestination } =
+    deriveProtocolFeeAddresses(
+      protocolFeeOption ?? (await findProtocolFeeAccount(program.programId))[0],
+      protocolFeeDestinationOption
+    );
+
+  const poolAccountPk = new PublicKey(poolAccount);
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    poolAccountPk
+  );
+  const [feeAccount] = await findPoolFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  // remaining accounts are (stake account, record, validator fee) triples
+  const triples = await Promise.all(
+    stakeAccounts.map(async (stakeAccount, i): Promise<AccountMeta[]> => {
+      const stakeAccountPk = new PublicKey(stakeAccount);
+      const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+        program.programId,
+        poolAccountPk,
+        stakeAccountPk
+      );
+      const validatorFeeAccount = await deriveValidatorFeeAccount(
+        program,
+        poolAccountPk,
+        stakeAccountPk,
+        voteAccountsOption?.[i]
+      );
+      return [
+        { pubkey: stakeAccountPk, isSigner: false, isWritable: true },
+        {
+          pubkey: stakeAccountRecordAccount,
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: validatorFeeAccount, isSigner: false, isWritable: false },
+      ];
+    })
+  );
+  const remainingAccounts = ([] as AccountMeta[]).concat(...triples);
+  if (referrerOption) {
+    remainingAccounts.push({
+      pubkey: new PublicKey(referrerOption),
+      isSigner: false,
+      isWritable: true,
+    });
+  }
+
+  return program.methods
+    .unstakeMulti(minLamportsOut ?? new BN(0))
+    .accounts({
+      unstaker,
+      destination,
+      poolAccount,
+      poolSolReserves,
+      feeAccount,
+      protocolFeeAccount,
+      protocolFeeDestination,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+      poolPauseAccount,
+      protocolPauseAccount,
+      poolConfigAccount,
+      withdrawQueueAccount,
+    })
+    .remainingAccounts(remainingAccounts)
+    .transaction();
+}
@@ -0,0 +1,189 @@
+import BN from "bn.js";
+import { Address, Program, ProgramAccount } from "@project-serum/anchor";
+import {
+  PublicKey,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import { Unstake } from "../idl/idl";
+import {
+  findPoolConfigAccount,
+  findPoolFeeAccount,
+  findPoolPauseAccount,
+  findPoolSolReserves,
+  findProtocolFeeAccount,
+  findProtocolPauseAccount,
+  findSplitStakeAccount,
+  findStakeAccountRecordAccount,
+  findWithdrawQueueAccount,
+} from "../pda";
+import { ProtocolFeeAccount } from "../types";
+import {
+  deriveProtocolFeeAddresses,
+  deriveValidatorFeeAccount,
+} from "./utils";
+
+export type UnstakePartialAccounts = {
+  /**
+   * The liquidity pool to unstake from
+   */
+  poolAccount: Address;
+
+  /**
+   * The stake account to split the unstaked lamports from
+   */
+  stakeAccount: Address;
+
+  /**
+   * `stakeAccount`'s staker and withdraw authority
+   */
+  unstaker: Address;
+
+  /**
+   * The program's protocol fee account
+   */
+  protocolFee?: ProgramAccount<ProtocolFeeAccount>;
+
+  /**
+   * The protocol fee payment destination.
+   * Must be provided if `protocolFee` is not provided.
+   * Otherwise, uses the one read from `protocolFee`
+   */
+  protocolFeeDestination?: Address;
+
+  /**
+   * The referrer for this unstake.
+   * SOL is transferred directly to this account. Please make sure
+   * that this is already initialized and rent-exempt
+   */
+  referrer?: Address;
+
+  /**
+   * The vote account `stakeAccount` is delegated to.
+   * Fetched from `stakeAccount` if unspecified
+   */
+  voteAccount?: Address;
+
+  /**
+   * The SOL account to receive the unstaked SOL.
+   * Defaults to `unstaker` if unspecified
+   */
+  destination?: Address;
+};
+
+/**
+ * Splits `lamports` off the stake account into the pool's split stake account
+ * for it, `findSplitStakeAccount()`, and unstakes that.
+ * The split stake account must be reclaimed before the same stake account
+ * can be partially unstaked again.
+ *
+ * @param program
+ * @param accounts
+ * @param lamports - the amount of lamports to split off and unstake
+ * @param minLamportsOut - if provided, the transaction fails if
+ *                         the lamports to be received is below this amount
+ * @returns the created unstake partial transaction
+ */
+export async function unstakePartialTx(
+  program: Program<Unstake>,
+  {
+    poolAccount,
+    stakeAccount,
+    unstaker,
+    destination: destinationOption,
+    protocolFee: protocolFeeOption,
+    protocolFeeDestination: protocolFeeDestinationOption,
+    referrer: referrerOption,
+    voteAccount: voteAccountOption,
+  }: UnstakePartialAccounts,
+  lamports: BN,
+  minLamportsOut?: BN
+): Promise<Transaction> {
+  const destination = destinationOption ?? unstaker;
+
+  const { protocolFeeAccount, protocolFeeDestination } =
+    deriveProtocolFeeAddresses(
+      protocolFeeOption ?? (await findProtocolFeeAccount(program.programId))[0],
+      protocolFeeDestinationOption
+    );
+
+  const poolAccountPk = new PublicKey(poolAccount);
+  const stakeAccountPk = new PublicKey(stakeAccount);
+  const [poolSolReserves] = await findPoolSolReserves(
+    program.programId,
+    poolAccountPk
+  );
+  const [feeAccount] = await findPoolFeeAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [splitStakeAccount] = await findSplitStakeAccount(
+    program.programId,
+    poolAccountPk,
+    stakeAccountPk
+  );
+  const [stakeAccountRecordAccount] = await findStakeAccountRecordAccount(
+    program.programId,
+    poolAccountPk,
+    splitStakeAccount
+  );
+  const validatorFeeAccount = await deriveValidatorFeeAccount(
+    program,
+    poolAccountPk,
+    stakeAccountPk,
+    voteAccountOption
+  );
+
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  let builder = program.methods
+    .unstakePartial(lamports, minLamportsOut ?? new BN(0))
+    .accounts({
+      unstaker,
+      stakeAccount,
+      splitStakeAccount,
+      destination,
+      poolAccount,
+      poolSolReserves,
+      feeAccount,
+      validatorFeeAccount,
+      stakeAccountRecordAccount,
+      protocolFeeAccount,
+      protocolFeeDestination,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+      poolPauseAccount,
+      protocolPauseAccount,
+      poolConfigAccount,
+      withdrawQueueAccount,
+    });
+
+  if (referrerOption) {
+    builder = builder.remainingAccounts([
+      {
+        pubkey: new PublicKey(referrerOption),
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+  }
+
+  return builder.transaction();
+}
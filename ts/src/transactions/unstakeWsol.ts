@@ -1,3 +1,4 @@
+import BN from "bn.js";
 import { Address, Program, ProgramAccount } from "@project-serum/anchor";
 import {
   getAssociatedTokenAddress,
@@ -8,17 +9,26 @@ import {
   PublicKey,
   StakeProgram,
   SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
   Transaction,
 } from "@solana/web3.js";
 import { Unstake } from "../idl/idl";
 import {
+  findLockupFeeAccount,
+  findPoolConfigAccount,
   findPoolFeeAccount,
+  findPoolPauseAccount,
   findPoolSolReserves,
   findProtocolFeeAccount,
+  findProtocolPauseAccount,
   findStakeAccountRecordAccount,
+  findWithdrawQueueAccount,
 } from "../pda";
 import { ProtocolFeeAccount } from "../types";
-import { deriveProtocolFeeAddresses } from "./utils";
+import {
+  deriveProtocolFeeAddresses,
+  deriveValidatorFeeAccount,
+} from "./utils";
 
 export type UnstakeWSolAccounts = {
   /**
@@ -55,6 +65,19 @@ export type UnstakeWSolAccounts = {
    */
   referrer?: Address;
 
+  /**
+   * The stake account's lockup custodian.
+   * Must be provided, and must sign the transaction,
+   * if the stake account's lockup is in force
+   */
+  custodian?: Address;
+
+  /**
+   * The vote account `stakeAccount` is delegated to.
+   * Fetched from `stakeAccount` if unspecified
+   */
+  voteAccount?: Address;
+
   /**
    * The wSOL account to receive the unstaked wSOL.
    * Defaults to wSOL ATA of `unstaker` if unspecified
@@ -66,6 +89,8 @@ export type UnstakeWSolAccounts = {
  *
  * @param program
  * @param accounts
+ * @param minLamportsOut - if provided, the transaction fails if
+ *                         the lamports to be received is below this amount
  * @returns the created unstake transaction
  */
 export async function unstakeWsolTx(
@@ -78,7 +103,10 @@ export async function unstakeWsolTx(
     protocolFee: protocolFeeOption,
     protocolFeeDestination: protocolFeeDestinationOption,
     referrer: referrerOption,
-  }: UnstakeWSolAccounts
+    custodian: custodianOption,
+    voteAccount: voteAccountOption,
+  }: UnstakeWSolAccounts,
+  minLamportsOut?: BN
 ): Promise<Transaction> {
   const poolAccountPk = new PublicKey(poolAccount);
   const stakeAccountPk = new PublicKey(stakeAccount);
@@ -107,20 +135,59 @@ export async function unstakeWsolTx(
     poolAccountPk,
     stakeAccountPk
   );
+  const validatorFeeAccount = await deriveValidatorFeeAccount(
+    program,
+    poolAccountPk,
+    stakeAccountPk,
+    voteAccountOption
+  );
+
+  const [poolPauseAccount] = await findPoolPauseAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [protocolPauseAccount] = await findProtocolPauseAccount(
+    program.programId
+  );
+  const [poolConfigAccount] = await findPoolConfigAccount(
+    program.programId,
+    poolAccountPk
+  );
+  const [withdrawQueueAccount] = await findWithdrawQueueAccount(
+    program.programId,
+    poolAccountPk
+  );
+
+  // optional accounts are set to the program ID if not used
+  const custodian = custodianOption ?? program.programId;
+  const lockupFeeAccount = custodianOption
+    ? (await findLockupFeeAccount(program.programId, poolAccountPk))[0]
+    : program.programId;
 
-  let builder = program.methods.unstakeWsol().accounts({
+  const methodsBuilder = program.methods.unstakeWsolV2(
+    minLamportsOut ?? new BN(0)
+  );
+  let builder = methodsBuilder.accounts({
     unstaker,
     stakeAccount,
     destination,
     poolAccount,
     poolSolReserves,
     feeAccount,
+    validatorFeeAccount,
     stakeAccountRecordAccount,
     protocolFeeAccount,
     protocolFeeDestination,
     clock: SYSVAR_CLOCK_PUBKEY,
+    stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
     stakeProgram: StakeProgram.programId,
     tokenProgram: TOKEN_PROGRAM_ID,
+    custodian,
+    lockupFeeAccount,
+    poolPauseAccount,
+    protocolPauseAccount,
+    poolConfigAccount,
+    withdrawQueueAccount,
   });
 
   if (referrerOption) {
@@ -133,5 +200,16 @@ export async function unstakeWsolTx(
     ]);
   }
 
-  return builder.transaction();
+  const tx = await builder.transaction();
+  if (custodianOption) {
+    const custodianPk = new PublicKey(custodianOption);
+    tx.instructions.forEach((ix) =>
+      ix.keys
+        .filter(({ pubkey }) => pubkey.equals(custodianPk))
+        .forEach((meta) => {
+          meta.isSigner = true;
+        })
+    );
+  }
+  return tx;
 }
@@ -1,5 +1,13 @@
-import { Address, IdlAccounts, ProgramAccount } from "@project-serum/anchor";
+import {
+  Address,
+  IdlAccounts,
+  Program,
+  ProgramAccount,
+} from "@project-serum/anchor";
+import { getStakeAccount } from "@soceanfi/solana-stake-sdk";
+import { PublicKey } from "@solana/web3.js";
 import { Unstake } from "../idl/idl";
+import { findValidatorFeeAccount } from "../pda";
 import { ProtocolFeeAccount } from "../types";
 
 type PoolAccountUnion = Address | ProgramAccount<IdlAccounts<Unstake>["pool"]>;
@@ -85,3 +93,28 @@ export function deriveProtocolFeeAddresses(
     protocolFeeDestination: protocolFeeAccountUnion.account.destination,
   };
 }
+
+/**
+ * Derives the pool's `ValidatorFee` account for the validator `stakeAccount` is delegated to.
+ * Fetches `stakeAccount` to read its voter if `voteAccountOption` is not provided.
+ * Stake accounts that were never delegated use the default pubkey as voter.
+ */
+export async function deriveValidatorFeeAccount(
+  program: Program<Unstake>,
+  poolAccount: PublicKey,
+  stakeAccount: PublicKey,
+  voteAccountOption?: Address
+): Promise<PublicKey> {
+  const voteAccount = voteAccountOption
+    ? new PublicKey(voteAccountOption)
+    : await getStakeAccount(program.provider.connection, stakeAccount).then(
+        ({ data: { info } }) =>
+          info.stake?.delegation.voter ?? PublicKey.default
+      );
+  const [validatorFeeAccount] = await findValidatorFeeAccount(
+    program.programId,
+    poolAccount,
+    voteAccount
+  );
+  return validatorFeeAccount;
+}
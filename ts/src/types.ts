@@ -31,6 +31,8 @@ export type FlatFeeInner = {
 // probably due to anchor not handling enums properly
 export type Fee = {
   fee: LiquidityLinearFeeInner | FlatFeeInner;
+  minFeeLamports?: BN | null;
+  maxFeeLamports?: BN | null;
 };
 
 // IdlAccounts<Unstake>["fee"] returns { feeRatio: never, referrerFeeRatio: never },
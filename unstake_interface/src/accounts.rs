@@ -1,11 +1,30 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
+pub const ALLOWED_LP_ACCOUNT_DISCM: [u8; 8] = [79, 186, 202, 212, 182, 2, 103, 54];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllowedLp {}
 pub const FEE_ACCOUNT_DISCM: [u8; 8] = [24, 55, 150, 250, 168, 27, 101, 178];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fee {
     pub fee: FeeEnum,
+    pub min_fee_lamports: Option<u64>,
+    pub max_fee_lamports: Option<u64>,
+}
+pub const FEE_AUTHORITY_NOMINEE_ACCOUNT_DISCM: [u8; 8] = [177, 42, 198, 62, 189, 50, 4, 71];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeAuthorityNominee {
+    pub nominee: Pubkey,
+    pub proposer: Pubkey,
+}
+pub const FEE_DELAY_ACCOUNT_DISCM: [u8; 8] = [24, 37, 99, 209, 89, 150, 40, 144];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeDelay {
+    pub min_delay_seconds: u64,
 }
 pub const FLASH_LOAN_FEE_ACCOUNT_DISCM: [u8; 8] = [211, 113, 211, 138, 191, 108, 64, 160];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
@@ -13,6 +32,41 @@ pub const FLASH_LOAN_FEE_ACCOUNT_DISCM: [u8; 8] = [211, 113, 211, 138, 191, 108,
 pub struct FlashLoanFee {
     pub fee_ratio: Rational,
 }
+pub const LOCKUP_FEE_ACCOUNT_DISCM: [u8; 8] = [253, 100, 47, 19, 55, 106, 67, 105];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockupFee {
+    pub fee_ratio: Rational,
+}
+pub const PENDING_FEE_ACCOUNT_DISCM: [u8; 8] = [206, 66, 103, 163, 160, 230, 82, 125];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingFee {
+    pub fee: Fee,
+    pub activation_timestamp: i64,
+}
+pub const PENDING_LOCKUP_FEE_ACCOUNT_DISCM: [u8; 8] = [197, 170, 160, 212, 109, 56, 81, 69];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingLockupFee {
+    pub lockup_fee: LockupFee,
+    pub activation_timestamp: i64,
+}
+pub const PENDING_PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [132, 176, 143, 38, 149, 24, 221, 82];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingProtocolFee {
+    pub fee_ratio: Rational,
+    pub referrer_fee_ratio: Rational,
+    pub activation_timestamp: i64,
+}
+pub const PENDING_VALIDATOR_FEE_ACCOUNT_DISCM: [u8; 8] = [172, 113, 201, 18, 211, 184, 92, 248];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PendingValidatorFee {
+    pub validator_fee: Option<ValidatorFeeEnum>,
+    pub activation_timestamp: i64,
+}
 pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21,6 +75,24 @@ pub struct Pool {
     pub lp_mint: Pubkey,
     pub incoming_stake: u64,
 }
+pub const POOL_CONFIG_ACCOUNT_DISCM: [u8; 8] = [26, 108, 14, 123, 116, 230, 129, 43];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolConfig {
+    pub max_unstake_lamports: Option<u64>,
+    pub max_epoch_incoming_stake_lamports: Option<u64>,
+    pub epoch: u64,
+    pub epoch_incoming_stake_lamports: u64,
+    pub max_pool_owned_lamports: Option<u64>,
+    pub lp_allowlist_enabled: bool,
+}
+pub const POOL_PAUSE_ACCOUNT_DISCM: [u8; 8] = [46, 223, 125, 48, 226, 28, 89, 170];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoolPause {
+    pub guardian: Pubkey,
+    pub paused: PausedInstructions,
+}
 pub const PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [121, 127, 98, 139, 72, 110, 44, 118];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,9 +102,46 @@ pub struct ProtocolFee {
     pub fee_ratio: Rational,
     pub referrer_fee_ratio: Rational,
 }
+pub const PROTOCOL_FEE_AUTHORITY_NOMINEE_ACCOUNT_DISCM: [u8; 8] =
+    [24, 168, 197, 118, 244, 146, 131, 221];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolFeeAuthorityNominee {
+    pub nominee: Pubkey,
+}
+pub const PROTOCOL_PAUSE_ACCOUNT_DISCM: [u8; 8] = [175, 93, 63, 209, 227, 53, 157, 147];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolPause {
+    pub paused: PausedInstructions,
+}
 pub const STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM: [u8; 8] = [144, 205, 183, 241, 3, 250, 208, 215];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeAccountRecord {
     pub lamports_at_creation: u64,
 }
+pub const STAKE_DEPOSIT_FEE_ACCOUNT_DISCM: [u8; 8] = [176, 220, 251, 235, 166, 145, 135, 143];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeDepositFee {
+    pub fee_ratio: Rational,
+}
+pub const VALIDATOR_FEE_ACCOUNT_DISCM: [u8; 8] = [7, 120, 227, 228, 1, 243, 160, 217];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidatorFee {
+    pub fee: ValidatorFeeEnum,
+}
+pub const WITHDRAW_QUEUE_ACCOUNT_DISCM: [u8; 8] = [172, 44, 207, 86, 230, 93, 40, 173];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawQueue {
+    pub earmarked_lamports: u64,
+}
+pub const WITHDRAW_TICKET_ACCOUNT_DISCM: [u8; 8] = [237, 23, 164, 58, 53, 248, 240, 94];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawTicket {
+    pub lamports: u64,
+}
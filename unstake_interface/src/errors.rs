@@ -42,6 +42,76 @@ pub enum UnstakeError {
     InvalidInstructionsSysvar = 6013u32,
     #[error("No succeeding repay flash loan instruction found")]
     NoSucceedingRepayFlashLoan = 6014u32,
+    #[error("Lamports to be received is below the specified minimum")]
+    LamportsOutBelowMinimum = 6015u32,
+    #[error("No stake accounts to unstake were provided")]
+    NoStakeAccountsToUnstake = 6016u32,
+    #[error("The provided stake account record account is not the PDA of the stake account")]
+    InvalidStakeAccountRecordAccount = 6017u32,
+    #[error(
+        "The pool's lockup fee account must be provided to unstake a stake account that is locked up"
+    )]
+    LockupFeeAccountRequired = 6018u32,
+    #[error("The pool does not accept stake delegated to this validator")]
+    ValidatorDenied = 6019u32,
+    #[error("Stake accounts unstaked together must be charged the same fee")]
+    StakeAccountFeesMismatch = 6020u32,
+    #[error("The provided validator fee account is not the PDA of the stake account's validator")]
+    InvalidValidatorFeeAccount = 6021u32,
+    #[error("The pool's pending fee account must be provided to set the fee of a pool with a fee delay")]
+    PendingFeeAccountRequired = 6022u32,
+    #[error("The pending fee cannot be applied yet")]
+    PendingFeeNotActive = 6023u32,
+    #[error("The pool's fee delay cannot be decreased")]
+    FeeDelayDecreaseNotAllowed = 6024u32,
+    #[error("The signer is not the nominated authority")]
+    InvalidAuthorityNominee = 6025u32,
+    #[error("The protocol fee authority and fee ratios can only be changed by proposing new values")]
+    ProtocolFeeChangeNotAllowed = 6026u32,
+    #[error("This instruction is paused for the pool")]
+    PoolPaused = 6027u32,
+    #[error("This instruction is paused for all pools")]
+    ProtocolPaused = 6028u32,
+    #[error("The signer is neither the pool's fee authority nor its pause guardian")]
+    InvalidPauseAuthority = 6029u32,
+    #[error("The amount to unstake exceeds the pool's maximum unstake size")]
+    UnstakeAboveMaximum = 6030u32,
+    #[error("The amount to unstake exceeds the pool's remaining incoming stake cap for the current epoch")]
+    EpochIncomingStakeCapExceeded = 6031u32,
+    #[error("Adding this amount of liquidity exceeds the pool's deposit cap")]
+    DepositCapExceeded = 6032u32,
+    #[error("The depositor is not on the pool's LP allowlist")]
+    LpNotAllowed = 6033u32,
+    #[error("Not enough SOL in the pool reserves to service this withdrawal")]
+    InsufficientReservesForWithdrawal = 6034u32,
+    #[error("The amount to redeem in kind exceeds the stake account's recorded lamports")]
+    InKindRedemptionExceedsStakeAccount = 6035u32,
+    #[error("A new stake account to split into is required to redeem part of a stake account")]
+    SplitStakeAccountRequired = 6036u32,
+    #[error("LP tokens to be minted is below the specified minimum")]
+    LpTokensOutBelowMinimum = 6037u32,
+    #[error("Lamports of liquidity to be removed is below the specified minimum")]
+    LiquidityOutBelowMinimum = 6038u32,
+    #[error("Source token account is not a wrapped SOL account")]
+    SourceNotWSol = 6039u32,
+    #[error("The pool still has LP tokens, incoming stake or SOL owed to withdraw tickets")]
+    PoolNotEmpty = 6040u32,
+    #[error("This change could raise the pool's fees and is not allowed while the pool has a fee delay")]
+    FeeChangeRequiresDelay = 6041u32,
+    #[error("The nominee was proposed by a previous authority")]
+    StaleAuthorityNominee = 6042u32,
+    #[error("Exactly one of mint_lp_tokens_to and locked_lp_token_account must be provided")]
+    InvalidLpTokenDestination = 6043u32,
+    #[error("Account is not the pool's ValidatorFee, PendingValidatorFee or AllowedLp account for the provided pubkey")]
+    InvalidAccountToClose = 6044u32,
+    #[error("This instruction is deprecated, use its V2 version instead")]
+    DeprecatedInstruction = 6045u32,
+    #[error("A stake account was provided more than once")]
+    DuplicateStakeAccount = 6046u32,
+    #[error("The fee delay exceeds the maximum fee delay")]
+    FeeDelayTooLong = 6047u32,
+    #[error("Exactly one of validator_fee_account and pending_validator_fee_account must be provided")]
+    InvalidValidatorFeeDestination = 6048u32,
 }
 impl From<UnstakeError> for ProgramError {
     fn from(e: UnstakeError) -> Self {
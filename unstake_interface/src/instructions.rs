@@ -184,9 +184,4727 @@ pub fn set_protocol_fee_invoke_signed<'a, A: Into<SetProtocolFeeIxArgs>>(
     let account_info: [AccountInfo<'a>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub const SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN: usize = 4usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolPauseAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me> {
+    pub authority: &'me AccountInfo<'a0>,
+    pub protocol_fee_account: &'me AccountInfo<'a1>,
+    pub protocol_pause_account: &'me AccountInfo<'a2>,
+    pub system_program: &'me AccountInfo<'a3>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolPauseKeys {
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetProtocolPauseAccounts<'me, '_, '_, '_, '_>> for SetProtocolPauseKeys {
+    fn from(accounts: &SetProtocolPauseAccounts<'me, '_, '_, '_, '_>) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetProtocolPauseKeys> for [AccountMeta; SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetProtocolPauseKeys) -> Self {
+        [
+            AccountMeta::new(keys.authority, true),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_pause_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetProtocolPauseAccounts<'_, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetProtocolPauseAccounts<'_, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetProtocolPauseIxArgs {
+    pub paused: PausedInstructions,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetProtocolPauseIxData<'me>(pub &'me SetProtocolPauseIxArgs);
+pub const SET_PROTOCOL_PAUSE_IX_DISCM: [u8; 8] = [19, 235, 135, 250, 184, 114, 209, 89];
+impl<'me> From<&'me SetProtocolPauseIxArgs> for SetProtocolPauseIxData<'me> {
+    fn from(args: &'me SetProtocolPauseIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetProtocolPauseIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_PROTOCOL_PAUSE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_protocol_pause_ix<K: Into<SetProtocolPauseKeys>, A: Into<SetProtocolPauseIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetProtocolPauseKeys = accounts.into();
+    let metas: [AccountMeta; SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetProtocolPauseIxArgs = args.into();
+    let data: SetProtocolPauseIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_protocol_pause_invoke<'a, A: Into<SetProtocolPauseIxArgs>>(
+    accounts: &SetProtocolPauseAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_protocol_pause_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_protocol_pause_invoke_signed<'a, A: Into<SetProtocolPauseIxArgs>>(
+    accounts: &SetProtocolPauseAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_protocol_pause_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_PROTOCOL_PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub authority: &'me AccountInfo<'a0>,
+    pub protocol_fee_account: &'me AccountInfo<'a1>,
+    pub pending_protocol_fee_account: &'me AccountInfo<'a2>,
+    pub clock: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeKeys {
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub pending_protocol_fee_account: Pubkey,
+    pub clock: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&ProposeProtocolFeeAccounts<'me, '_, '_, '_, '_, '_>> for ProposeProtocolFeeKeys {
+    fn from(accounts: &ProposeProtocolFeeAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            pending_protocol_fee_account: *accounts.pending_protocol_fee_account.key,
+            clock: *accounts.clock.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&ProposeProtocolFeeKeys> for [AccountMeta; PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ProposeProtocolFeeKeys) -> Self {
+        [
+            AccountMeta::new(keys.authority, true),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.pending_protocol_fee_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&ProposeProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ProposeProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.pending_protocol_fee_account.clone(),
+            accounts.clock.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposeProtocolFeeIxArgs {
+    pub fee_ratio: Rational,
+    pub referrer_fee_ratio: Rational,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeIxData<'me>(pub &'me ProposeProtocolFeeIxArgs);
+pub const PROPOSE_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [162, 74, 138, 165, 56, 3, 247, 193];
+impl<'me> From<&'me ProposeProtocolFeeIxArgs> for ProposeProtocolFeeIxData<'me> {
+    fn from(args: &'me ProposeProtocolFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ProposeProtocolFeeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&PROPOSE_PROTOCOL_FEE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn propose_protocol_fee_ix<
+    K: Into<ProposeProtocolFeeKeys>,
+    A: Into<ProposeProtocolFeeIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ProposeProtocolFeeKeys = accounts.into();
+    let metas: [AccountMeta; PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ProposeProtocolFeeIxArgs = args.into();
+    let data: ProposeProtocolFeeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn propose_protocol_fee_invoke<'a, A: Into<ProposeProtocolFeeIxArgs>>(
+    accounts: &ProposeProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = propose_protocol_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn propose_protocol_fee_invoke_signed<'a, A: Into<ProposeProtocolFeeIxArgs>>(
+    accounts: &ProposeProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = propose_protocol_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 4usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingProtocolFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me> {
+    pub authority: &'me AccountInfo<'a0>,
+    pub protocol_fee_account: &'me AccountInfo<'a1>,
+    pub pending_protocol_fee_account: &'me AccountInfo<'a2>,
+    pub clock: &'me AccountInfo<'a3>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingProtocolFeeKeys {
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub pending_protocol_fee_account: Pubkey,
+    pub clock: Pubkey,
+}
+impl<'me> From<&ApplyPendingProtocolFeeAccounts<'me, '_, '_, '_, '_>>
+    for ApplyPendingProtocolFeeKeys
+{
+    fn from(accounts: &ApplyPendingProtocolFeeAccounts<'me, '_, '_, '_, '_>) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            pending_protocol_fee_account: *accounts.pending_protocol_fee_account.key,
+            clock: *accounts.clock.key,
+        }
+    }
+}
+impl From<&ApplyPendingProtocolFeeKeys>
+    for [AccountMeta; APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &ApplyPendingProtocolFeeKeys) -> Self {
+        [
+            AccountMeta::new(keys.authority, false),
+            AccountMeta::new(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.pending_protocol_fee_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+        ]
+    }
+}
+impl<'a> From<&ApplyPendingProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ApplyPendingProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.pending_protocol_fee_account.clone(),
+            accounts.clock.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApplyPendingProtocolFeeIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingProtocolFeeIxData<'me>(pub &'me ApplyPendingProtocolFeeIxArgs);
+pub const APPLY_PENDING_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [1, 214, 60, 77, 241, 207, 163, 52];
+impl<'me> From<&'me ApplyPendingProtocolFeeIxArgs> for ApplyPendingProtocolFeeIxData<'me> {
+    fn from(args: &'me ApplyPendingProtocolFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ApplyPendingProtocolFeeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&APPLY_PENDING_PROTOCOL_FEE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn apply_pending_protocol_fee_ix<
+    K: Into<ApplyPendingProtocolFeeKeys>,
+    A: Into<ApplyPendingProtocolFeeIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ApplyPendingProtocolFeeKeys = accounts.into();
+    let metas: [AccountMeta; APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ApplyPendingProtocolFeeIxArgs = args.into();
+    let data: ApplyPendingProtocolFeeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn apply_pending_protocol_fee_invoke<'a, A: Into<ApplyPendingProtocolFeeIxArgs>>(
+    accounts: &ApplyPendingProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = apply_pending_protocol_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn apply_pending_protocol_fee_invoke_signed<'a, A: Into<ApplyPendingProtocolFeeIxArgs>>(
+    accounts: &ApplyPendingProtocolFeeAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = apply_pending_protocol_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_PROTOCOL_FEE_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeAuthorityAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+> {
+    pub authority: &'me AccountInfo<'a0>,
+    pub protocol_fee_account: &'me AccountInfo<'a1>,
+    pub new_authority: &'me AccountInfo<'a2>,
+    pub nominee_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeAuthorityKeys {
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub new_authority: Pubkey,
+    pub nominee_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&ProposeProtocolFeeAuthorityAccounts<'me, '_, '_, '_, '_, '_>>
+    for ProposeProtocolFeeAuthorityKeys
+{
+    fn from(accounts: &ProposeProtocolFeeAuthorityAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            authority: *accounts.authority.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            new_authority: *accounts.new_authority.key,
+            nominee_account: *accounts.nominee_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&ProposeProtocolFeeAuthorityKeys>
+    for [AccountMeta; PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &ProposeProtocolFeeAuthorityKeys) -> Self {
+        [
+            AccountMeta::new(keys.authority, true),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new_readonly(keys.new_authority, false),
+            AccountMeta::new(keys.nominee_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&ProposeProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ProposeProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.authority.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.new_authority.clone(),
+            accounts.nominee_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposeProtocolFeeAuthorityIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeProtocolFeeAuthorityIxData<'me>(pub &'me ProposeProtocolFeeAuthorityIxArgs);
+pub const PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [23, 152, 79, 48, 231, 25, 37, 135];
+impl<'me> From<&'me ProposeProtocolFeeAuthorityIxArgs> for ProposeProtocolFeeAuthorityIxData<'me> {
+    fn from(args: &'me ProposeProtocolFeeAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ProposeProtocolFeeAuthorityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn propose_protocol_fee_authority_ix<
+    K: Into<ProposeProtocolFeeAuthorityKeys>,
+    A: Into<ProposeProtocolFeeAuthorityIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ProposeProtocolFeeAuthorityKeys = accounts.into();
+    let metas: [AccountMeta; PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ProposeProtocolFeeAuthorityIxArgs = args.into();
+    let data: ProposeProtocolFeeAuthorityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn propose_protocol_fee_authority_invoke<'a, A: Into<ProposeProtocolFeeAuthorityIxArgs>>(
+    accounts: &ProposeProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = propose_protocol_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn propose_protocol_fee_authority_invoke_signed<
+    'a,
+    A: Into<ProposeProtocolFeeAuthorityIxArgs>,
+>(
+    accounts: &ProposeProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = propose_protocol_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptProtocolFeeAuthorityAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me> {
+    pub new_authority: &'me AccountInfo<'a0>,
+    pub authority: &'me AccountInfo<'a1>,
+    pub protocol_fee_account: &'me AccountInfo<'a2>,
+    pub nominee_account: &'me AccountInfo<'a3>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptProtocolFeeAuthorityKeys {
+    pub new_authority: Pubkey,
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub nominee_account: Pubkey,
+}
+impl<'me> From<&AcceptProtocolFeeAuthorityAccounts<'me, '_, '_, '_, '_>>
+    for AcceptProtocolFeeAuthorityKeys
+{
+    fn from(accounts: &AcceptProtocolFeeAuthorityAccounts<'me, '_, '_, '_, '_>) -> Self {
+        Self {
+            new_authority: *accounts.new_authority.key,
+            authority: *accounts.authority.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            nominee_account: *accounts.nominee_account.key,
+        }
+    }
+}
+impl From<&AcceptProtocolFeeAuthorityKeys>
+    for [AccountMeta; ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &AcceptProtocolFeeAuthorityKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.new_authority, true),
+            AccountMeta::new(keys.authority, false),
+            AccountMeta::new(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.nominee_account, false),
+        ]
+    }
+}
+impl<'a> From<&AcceptProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &AcceptProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.new_authority.clone(),
+            accounts.authority.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.nominee_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcceptProtocolFeeAuthorityIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptProtocolFeeAuthorityIxData<'me>(pub &'me AcceptProtocolFeeAuthorityIxArgs);
+pub const ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [132, 152, 29, 95, 61, 2, 28, 220];
+impl<'me> From<&'me AcceptProtocolFeeAuthorityIxArgs> for AcceptProtocolFeeAuthorityIxData<'me> {
+    fn from(args: &'me AcceptProtocolFeeAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AcceptProtocolFeeAuthorityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn accept_protocol_fee_authority_ix<
+    K: Into<AcceptProtocolFeeAuthorityKeys>,
+    A: Into<AcceptProtocolFeeAuthorityIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AcceptProtocolFeeAuthorityKeys = accounts.into();
+    let metas: [AccountMeta; ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AcceptProtocolFeeAuthorityIxArgs = args.into();
+    let data: AcceptProtocolFeeAuthorityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn accept_protocol_fee_authority_invoke<'a, A: Into<AcceptProtocolFeeAuthorityIxArgs>>(
+    accounts: &AcceptProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = accept_protocol_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn accept_protocol_fee_authority_invoke_signed<
+    'a,
+    A: Into<AcceptProtocolFeeAuthorityIxArgs>,
+>(
+    accounts: &AcceptProtocolFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = accept_protocol_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ACCEPT_PROTOCOL_FEE_AUTHORITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
 pub const CREATE_POOL_IX_ACCOUNTS_LEN: usize = 9usize;
 #[derive(Copy, Clone, Debug)]
-pub struct CreatePoolAccounts<
+pub struct CreatePoolAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub fee_account: &'me AccountInfo<'a4>,
+    pub lp_mint: &'me AccountInfo<'a5>,
+    pub token_program: &'me AccountInfo<'a6>,
+    pub system_program: &'me AccountInfo<'a7>,
+    pub rent: &'me AccountInfo<'a8>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePoolKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+impl<'me> From<&CreatePoolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>> for CreatePoolKeys {
+    fn from(accounts: &CreatePoolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            fee_account: *accounts.fee_account.key,
+            lp_mint: *accounts.lp_mint.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+            rent: *accounts.rent.key,
+        }
+    }
+}
+impl From<&CreatePoolKeys> for [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &CreatePoolKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new(keys.pool_account, true),
+            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new(keys.lp_mint, true),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.rent, false),
+        ]
+    }
+}
+impl<'a> From<&CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.fee_account.clone(),
+            accounts.lp_mint.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePoolIxArgs {
+    pub fee: Fee,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePoolIxData<'me>(pub &'me CreatePoolIxArgs);
+pub const CREATE_POOL_IX_DISCM: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+impl<'me> From<&'me CreatePoolIxArgs> for CreatePoolIxData<'me> {
+    fn from(args: &'me CreatePoolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for CreatePoolIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_POOL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn create_pool_ix<K: Into<CreatePoolKeys>, A: Into<CreatePoolIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: CreatePoolKeys = accounts.into();
+    let metas: [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: CreatePoolIxArgs = args.into();
+    let data: CreatePoolIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_pool_invoke<'a, A: Into<CreatePoolIxArgs>>(
+    accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = create_pool_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn create_pool_invoke_signed<'a, A: Into<CreatePoolIxArgs>>(
+    accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = create_pool_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 13usize;
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePoolWithLiquidityAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub fee_account: &'me AccountInfo<'a4>,
+    pub lp_mint: &'me AccountInfo<'a5>,
+    pub lp_token_account_owner: &'me AccountInfo<'a6>,
+    pub mint_lp_tokens_to: &'me AccountInfo<'a7>,
+    pub locked_lp_token_account: &'me AccountInfo<'a8>,
+    pub protocol_pause_account: &'me AccountInfo<'a9>,
+    pub token_program: &'me AccountInfo<'a10>,
+    pub system_program: &'me AccountInfo<'a11>,
+    pub rent: &'me AccountInfo<'a12>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePoolWithLiquidityKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_token_account_owner: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub locked_lp_token_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+impl<'me>
+    From<&CreatePoolWithLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for CreatePoolWithLiquidityKeys
+{
+    fn from(
+        accounts: &CreatePoolWithLiquidityAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            fee_account: *accounts.fee_account.key,
+            lp_mint: *accounts.lp_mint.key,
+            lp_token_account_owner: *accounts.lp_token_account_owner.key,
+            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
+            locked_lp_token_account: *accounts.locked_lp_token_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+            rent: *accounts.rent.key,
+        }
+    }
+}
+impl From<&CreatePoolWithLiquidityKeys>
+    for [AccountMeta; CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &CreatePoolWithLiquidityKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new(keys.pool_account, true),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new(keys.lp_mint, true),
+            AccountMeta::new_readonly(keys.lp_token_account_owner, false),
+            AccountMeta::new(keys.mint_lp_tokens_to, false),
+            AccountMeta::new(keys.locked_lp_token_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.rent, false),
+        ]
+    }
+}
+impl<'a>
+    From<&CreatePoolWithLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &CreatePoolWithLiquidityAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.fee_account.clone(),
+            accounts.lp_mint.clone(),
+            accounts.lp_token_account_owner.clone(),
+            accounts.mint_lp_tokens_to.clone(),
+            accounts.locked_lp_token_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePoolWithLiquidityIxArgs {
+    pub fee: Fee,
+    pub initial_lamports: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreatePoolWithLiquidityIxData<'me>(pub &'me CreatePoolWithLiquidityIxArgs);
+pub const CREATE_POOL_WITH_LIQUIDITY_IX_DISCM: [u8; 8] = [46, 16, 45, 7, 175, 14, 107, 200];
+impl<'me> From<&'me CreatePoolWithLiquidityIxArgs> for CreatePoolWithLiquidityIxData<'me> {
+    fn from(args: &'me CreatePoolWithLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for CreatePoolWithLiquidityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&CREATE_POOL_WITH_LIQUIDITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn create_pool_with_liquidity_ix<
+    K: Into<CreatePoolWithLiquidityKeys>,
+    A: Into<CreatePoolWithLiquidityIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: CreatePoolWithLiquidityKeys = accounts.into();
+    let metas: [AccountMeta; CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: CreatePoolWithLiquidityIxArgs = args.into();
+    let data: CreatePoolWithLiquidityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_pool_with_liquidity_invoke<'a, A: Into<CreatePoolWithLiquidityIxArgs>>(
+    accounts: &CreatePoolWithLiquidityAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+) -> ProgramResult {
+    let ix = create_pool_with_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn create_pool_with_liquidity_invoke_signed<'a, A: Into<CreatePoolWithLiquidityIxArgs>>(
+    accounts: &CreatePoolWithLiquidityAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = create_pool_with_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CREATE_POOL_WITH_LIQUIDITY_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 8usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+> {
+    pub from: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub pool_sol_reserves: &'me AccountInfo<'a2>,
+    pub lp_mint: &'me AccountInfo<'a3>,
+    pub mint_lp_tokens_to: &'me AccountInfo<'a4>,
+    pub flash_account: &'me AccountInfo<'a5>,
+    pub token_program: &'me AccountInfo<'a6>,
+    pub system_program: &'me AccountInfo<'a7>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityKeys {
+    pub from: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub flash_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&AddLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>> for AddLiquidityKeys {
+    fn from(accounts: &AddLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            from: *accounts.from.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
+            flash_account: *accounts.flash_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AddLiquidityKeys) -> Self {
+        [
+            AccountMeta::new(keys.from, true),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.mint_lp_tokens_to, false),
+            AccountMeta::new(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.from.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.mint_lp_tokens_to.clone(),
+            accounts.flash_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityIxArgs {
+    pub amount: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityIxData<'me>(pub &'me AddLiquidityIxArgs);
+pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
+impl<'me> From<&'me AddLiquidityIxArgs> for AddLiquidityIxData<'me> {
+    fn from(args: &'me AddLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AddLiquidityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn add_liquidity_ix<K: Into<AddLiquidityKeys>, A: Into<AddLiquidityIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AddLiquidityKeys = accounts.into();
+    let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AddLiquidityIxArgs = args.into();
+    let data: AddLiquidityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_invoke<'a, A: Into<AddLiquidityIxArgs>>(
+    accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = add_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_liquidity_invoke_signed<'a, A: Into<AddLiquidityIxArgs>>(
+    accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN: usize = 13usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityV2Accounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+> {
+    pub from: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub pool_sol_reserves: &'me AccountInfo<'a2>,
+    pub lp_mint: &'me AccountInfo<'a3>,
+    pub mint_lp_tokens_to: &'me AccountInfo<'a4>,
+    pub flash_account: &'me AccountInfo<'a5>,
+    pub token_program: &'me AccountInfo<'a6>,
+    pub system_program: &'me AccountInfo<'a7>,
+    pub pool_pause_account: &'me AccountInfo<'a8>,
+    pub protocol_pause_account: &'me AccountInfo<'a9>,
+    pub pool_config_account: &'me AccountInfo<'a10>,
+    pub allowed_lp_account: &'me AccountInfo<'a11>,
+    pub withdraw_queue_account: &'me AccountInfo<'a12>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityV2Keys {
+    pub from: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub flash_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub allowed_lp_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me> From<&AddLiquidityV2Accounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for AddLiquidityV2Keys
+{
+    fn from(
+        accounts: &AddLiquidityV2Accounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            from: *accounts.from.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
+            flash_account: *accounts.flash_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            allowed_lp_account: *accounts.allowed_lp_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+        }
+    }
+}
+impl From<&AddLiquidityV2Keys> for [AccountMeta; ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AddLiquidityV2Keys) -> Self {
+        [
+            AccountMeta::new(keys.from, true),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.mint_lp_tokens_to, false),
+            AccountMeta::new(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new_readonly(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.allowed_lp_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+        ]
+    }
+}
+impl<'a> From<&AddLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &AddLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
+        [
+            accounts.from.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.mint_lp_tokens_to.clone(),
+            accounts.flash_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.allowed_lp_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityV2IxArgs {
+    pub amount: u64,
+    pub min_lp_tokens_out: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityV2IxData<'me>(pub &'me AddLiquidityV2IxArgs);
+pub const ADD_LIQUIDITY_V2_IX_DISCM: [u8; 8] = [126, 118, 210, 37, 80, 190, 19, 105];
+impl<'me> From<&'me AddLiquidityV2IxArgs> for AddLiquidityV2IxData<'me> {
+    fn from(args: &'me AddLiquidityV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AddLiquidityV2IxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ADD_LIQUIDITY_V2_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn add_liquidity_v2_ix<K: Into<AddLiquidityV2Keys>, A: Into<AddLiquidityV2IxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AddLiquidityV2Keys = accounts.into();
+    let metas: [AccountMeta; ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AddLiquidityV2IxArgs = args.into();
+    let data: AddLiquidityV2IxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_v2_invoke<'a, A: Into<AddLiquidityV2IxArgs>>(
+    accounts: &AddLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = add_liquidity_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_liquidity_v2_invoke_signed<'a, A: Into<AddLiquidityV2IxArgs>>(
+    accounts: &AddLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_liquidity_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN: usize = 23usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityStakeAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+    'a17: 'me,
+    'a18: 'me,
+    'a19: 'me,
+    'a20: 'me,
+    'a21: 'me,
+    'a22: 'me,
+> {
+    pub depositor: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub mint_lp_tokens_to: &'me AccountInfo<'a5>,
+    pub stake_account_record_account: &'me AccountInfo<'a6>,
+    pub stake_deposit_fee_account: &'me AccountInfo<'a7>,
+    pub fee_account: &'me AccountInfo<'a8>,
+    pub validator_fee_account: &'me AccountInfo<'a9>,
+    pub flash_account: &'me AccountInfo<'a10>,
+    pub pool_pause_account: &'me AccountInfo<'a11>,
+    pub protocol_pause_account: &'me AccountInfo<'a12>,
+    pub pool_config_account: &'me AccountInfo<'a13>,
+    pub allowed_lp_account: &'me AccountInfo<'a14>,
+    pub withdraw_queue_account: &'me AccountInfo<'a15>,
+    pub protocol_fee_account: &'me AccountInfo<'a16>,
+    pub protocol_fee_destination: &'me AccountInfo<'a17>,
+    pub clock: &'me AccountInfo<'a18>,
+    pub stake_history: &'me AccountInfo<'a19>,
+    pub stake_program: &'me AccountInfo<'a20>,
+    pub token_program: &'me AccountInfo<'a21>,
+    pub system_program: &'me AccountInfo<'a22>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityStakeKeys {
+    pub depositor: Pubkey,
+    pub stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub stake_deposit_fee_account: Pubkey,
+    pub fee_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub flash_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub allowed_lp_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me>
+    From<
+        &AddLiquidityStakeAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for AddLiquidityStakeKeys
+{
+    fn from(
+        accounts: &AddLiquidityStakeAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
+        Self {
+            depositor: *accounts.depositor.key,
+            stake_account: *accounts.stake_account.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            stake_deposit_fee_account: *accounts.stake_deposit_fee_account.key,
+            fee_account: *accounts.fee_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            flash_account: *accounts.flash_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            allowed_lp_account: *accounts.allowed_lp_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&AddLiquidityStakeKeys> for [AccountMeta; ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AddLiquidityStakeKeys) -> Self {
+        [
+            AccountMeta::new(keys.depositor, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.mint_lp_tokens_to, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.stake_deposit_fee_account, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.validator_fee_account, false),
+            AccountMeta::new_readonly(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.allowed_lp_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a>
+    From<
+        &AddLiquidityStakeAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &AddLiquidityStakeAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
+        [
+            accounts.depositor.clone(),
+            accounts.stake_account.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.mint_lp_tokens_to.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.stake_deposit_fee_account.clone(),
+            accounts.fee_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.flash_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.allowed_lp_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityStakeIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityStakeIxData<'me>(pub &'me AddLiquidityStakeIxArgs);
+pub const ADD_LIQUIDITY_STAKE_IX_DISCM: [u8; 8] = [17, 147, 17, 109, 147, 254, 100, 213];
+impl<'me> From<&'me AddLiquidityStakeIxArgs> for AddLiquidityStakeIxData<'me> {
+    fn from(args: &'me AddLiquidityStakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AddLiquidityStakeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ADD_LIQUIDITY_STAKE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn add_liquidity_stake_ix<K: Into<AddLiquidityStakeKeys>, A: Into<AddLiquidityStakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AddLiquidityStakeKeys = accounts.into();
+    let metas: [AccountMeta; ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AddLiquidityStakeIxArgs = args.into();
+    let data: AddLiquidityStakeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_stake_invoke<'a, A: Into<AddLiquidityStakeIxArgs>>(
+    accounts: &AddLiquidityStakeAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+) -> ProgramResult {
+    let ix = add_liquidity_stake_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_liquidity_stake_invoke_signed<'a, A: Into<AddLiquidityStakeIxArgs>>(
+    accounts: &AddLiquidityStakeAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_liquidity_stake_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN: usize = 17usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityWsolAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+> {
+    pub from_authority: &'me AccountInfo<'a0>,
+    pub payer: &'me AccountInfo<'a1>,
+    pub from: &'me AccountInfo<'a2>,
+    pub wsol_staging_account: &'me AccountInfo<'a3>,
+    pub native_mint: &'me AccountInfo<'a4>,
+    pub pool_account: &'me AccountInfo<'a5>,
+    pub pool_sol_reserves: &'me AccountInfo<'a6>,
+    pub lp_mint: &'me AccountInfo<'a7>,
+    pub mint_lp_tokens_to: &'me AccountInfo<'a8>,
+    pub flash_account: &'me AccountInfo<'a9>,
+    pub pool_pause_account: &'me AccountInfo<'a10>,
+    pub protocol_pause_account: &'me AccountInfo<'a11>,
+    pub pool_config_account: &'me AccountInfo<'a12>,
+    pub allowed_lp_account: &'me AccountInfo<'a13>,
+    pub withdraw_queue_account: &'me AccountInfo<'a14>,
+    pub token_program: &'me AccountInfo<'a15>,
+    pub system_program: &'me AccountInfo<'a16>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityWsolKeys {
+    pub from_authority: Pubkey,
+    pub payer: Pubkey,
+    pub from: Pubkey,
+    pub wsol_staging_account: Pubkey,
+    pub native_mint: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub flash_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub allowed_lp_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me>
+    From<
+        &AddLiquidityWsolAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for AddLiquidityWsolKeys
+{
+    fn from(
+        accounts: &AddLiquidityWsolAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
+        Self {
+            from_authority: *accounts.from_authority.key,
+            payer: *accounts.payer.key,
+            from: *accounts.from.key,
+            wsol_staging_account: *accounts.wsol_staging_account.key,
+            native_mint: *accounts.native_mint.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
+            flash_account: *accounts.flash_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            allowed_lp_account: *accounts.allowed_lp_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&AddLiquidityWsolKeys> for [AccountMeta; ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AddLiquidityWsolKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.from_authority, true),
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new(keys.from, false),
+            AccountMeta::new(keys.wsol_staging_account, false),
+            AccountMeta::new_readonly(keys.native_mint, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.mint_lp_tokens_to, false),
+            AccountMeta::new_readonly(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new_readonly(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.allowed_lp_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a>
+    From<
+        &AddLiquidityWsolAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &AddLiquidityWsolAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
+        [
+            accounts.from_authority.clone(),
+            accounts.payer.clone(),
+            accounts.from.clone(),
+            accounts.wsol_staging_account.clone(),
+            accounts.native_mint.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.mint_lp_tokens_to.clone(),
+            accounts.flash_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.allowed_lp_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityWsolIxArgs {
+    pub amount: u64,
+    pub min_lp_tokens_out: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddLiquidityWsolIxData<'me>(pub &'me AddLiquidityWsolIxArgs);
+pub const ADD_LIQUIDITY_WSOL_IX_DISCM: [u8; 8] = [27, 35, 75, 112, 52, 76, 4, 17];
+impl<'me> From<&'me AddLiquidityWsolIxArgs> for AddLiquidityWsolIxData<'me> {
+    fn from(args: &'me AddLiquidityWsolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AddLiquidityWsolIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ADD_LIQUIDITY_WSOL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn add_liquidity_wsol_ix<K: Into<AddLiquidityWsolKeys>, A: Into<AddLiquidityWsolIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AddLiquidityWsolKeys = accounts.into();
+    let metas: [AccountMeta; ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AddLiquidityWsolIxArgs = args.into();
+    let data: AddLiquidityWsolIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_wsol_invoke<'a, A: Into<AddLiquidityWsolIxArgs>>(
+    accounts: &AddLiquidityWsolAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+) -> ProgramResult {
+    let ix = add_liquidity_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_liquidity_wsol_invoke_signed<'a, A: Into<AddLiquidityWsolIxArgs>>(
+    accounts: &AddLiquidityWsolAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_liquidity_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 9usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+> {
+    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a0>,
+    pub to: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub burn_lp_tokens_from: &'me AccountInfo<'a5>,
+    pub flash_account: &'me AccountInfo<'a6>,
+    pub token_program: &'me AccountInfo<'a7>,
+    pub system_program: &'me AccountInfo<'a8>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityKeys {
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub to: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub flash_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&RemoveLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for RemoveLiquidityKeys
+{
+    fn from(accounts: &RemoveLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
+            to: *accounts.to.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
+            flash_account: *accounts.flash_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveLiquidityKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
+            AccountMeta::new(keys.to, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.burn_lp_tokens_from, false),
+            AccountMeta::new(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.burn_lp_tokens_from_authority.clone(),
+            accounts.to.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.burn_lp_tokens_from.clone(),
+            accounts.flash_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityIxArgs {
+    pub amount_lp: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityIxData<'me>(pub &'me RemoveLiquidityIxArgs);
+pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
+impl<'me> From<&'me RemoveLiquidityIxArgs> for RemoveLiquidityIxData<'me> {
+    fn from(args: &'me RemoveLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RemoveLiquidityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn remove_liquidity_ix<K: Into<RemoveLiquidityKeys>, A: Into<RemoveLiquidityIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RemoveLiquidityKeys = accounts.into();
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveLiquidityIxArgs = args.into();
+    let data: RemoveLiquidityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_invoke<'a, A: Into<RemoveLiquidityIxArgs>>(
+    accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_liquidity_invoke_signed<'a, A: Into<RemoveLiquidityIxArgs>>(
+    accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_liquidity_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN: usize = 10usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityV2Accounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+> {
+    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a0>,
+    pub to: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub burn_lp_tokens_from: &'me AccountInfo<'a5>,
+    pub flash_account: &'me AccountInfo<'a6>,
+    pub token_program: &'me AccountInfo<'a7>,
+    pub system_program: &'me AccountInfo<'a8>,
+    pub withdraw_queue_account: &'me AccountInfo<'a9>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityV2Keys {
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub to: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub flash_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me> From<&RemoveLiquidityV2Accounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for RemoveLiquidityV2Keys
+{
+    fn from(
+        accounts: &RemoveLiquidityV2Accounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
+            to: *accounts.to.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
+            flash_account: *accounts.flash_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+        }
+    }
+}
+impl From<&RemoveLiquidityV2Keys> for [AccountMeta; REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveLiquidityV2Keys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
+            AccountMeta::new(keys.to, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.burn_lp_tokens_from, false),
+            AccountMeta::new(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+        ]
+    }
+}
+impl<'a> From<&RemoveLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &RemoveLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
+        [
+            accounts.burn_lp_tokens_from_authority.clone(),
+            accounts.to.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.burn_lp_tokens_from.clone(),
+            accounts.flash_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.withdraw_queue_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityV2IxArgs {
+    pub amount_lp: u64,
+    pub min_lamports_out: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityV2IxData<'me>(pub &'me RemoveLiquidityV2IxArgs);
+pub const REMOVE_LIQUIDITY_V2_IX_DISCM: [u8; 8] = [155, 227, 21, 224, 143, 194, 226, 238];
+impl<'me> From<&'me RemoveLiquidityV2IxArgs> for RemoveLiquidityV2IxData<'me> {
+    fn from(args: &'me RemoveLiquidityV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RemoveLiquidityV2IxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_LIQUIDITY_V2_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn remove_liquidity_v2_ix<K: Into<RemoveLiquidityV2Keys>, A: Into<RemoveLiquidityV2IxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RemoveLiquidityV2Keys = accounts.into();
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveLiquidityV2IxArgs = args.into();
+    let data: RemoveLiquidityV2IxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_v2_invoke<'a, A: Into<RemoveLiquidityV2IxArgs>>(
+    accounts: &RemoveLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_liquidity_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_liquidity_v2_invoke_signed<'a, A: Into<RemoveLiquidityV2IxArgs>>(
+    accounts: &RemoveLiquidityV2Accounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_liquidity_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_V2_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN: usize = 16usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityInKindAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+> {
+    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a0>,
+    pub new_stake_authority: &'me AccountInfo<'a1>,
+    pub stake_account: &'me AccountInfo<'a2>,
+    pub split_stake_account: &'me AccountInfo<'a3>,
+    pub pool_account: &'me AccountInfo<'a4>,
+    pub pool_sol_reserves: &'me AccountInfo<'a5>,
+    pub stake_account_record_account: &'me AccountInfo<'a6>,
+    pub lp_mint: &'me AccountInfo<'a7>,
+    pub burn_lp_tokens_from: &'me AccountInfo<'a8>,
+    pub flash_account: &'me AccountInfo<'a9>,
+    pub withdraw_queue_account: &'me AccountInfo<'a10>,
+    pub clock: &'me AccountInfo<'a11>,
+    pub stake_program: &'me AccountInfo<'a12>,
+    pub token_program: &'me AccountInfo<'a13>,
+    pub system_program: &'me AccountInfo<'a14>,
+    pub custodian: &'me AccountInfo<'a15>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityInKindKeys {
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub new_stake_authority: Pubkey,
+    pub stake_account: Pubkey,
+    pub split_stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub flash_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub custodian: Pubkey,
+}
+impl<'me>
+    From<
+        &RemoveLiquidityInKindAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for RemoveLiquidityInKindKeys
+{
+    fn from(
+        accounts: &RemoveLiquidityInKindAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
+            new_stake_authority: *accounts.new_stake_authority.key,
+            stake_account: *accounts.stake_account.key,
+            split_stake_account: *accounts.split_stake_account.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            lp_mint: *accounts.lp_mint.key,
+            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
+            flash_account: *accounts.flash_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            clock: *accounts.clock.key,
+            stake_program: *accounts.stake_program.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+            custodian: *accounts.custodian.key,
+        }
+    }
+}
+impl From<&RemoveLiquidityInKindKeys> for [AccountMeta; REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveLiquidityInKindKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
+            AccountMeta::new_readonly(keys.new_stake_authority, false),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.split_stake_account, true),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.burn_lp_tokens_from, false),
+            AccountMeta::new_readonly(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.custodian, false),
+        ]
+    }
+}
+impl<'a>
+    From<
+        &RemoveLiquidityInKindAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &RemoveLiquidityInKindAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
+        [
+            accounts.burn_lp_tokens_from_authority.clone(),
+            accounts.new_stake_authority.clone(),
+            accounts.stake_account.clone(),
+            accounts.split_stake_account.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.lp_mint.clone(),
+            accounts.burn_lp_tokens_from.clone(),
+            accounts.flash_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.clock.clone(),
+            accounts.stake_program.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+            accounts.custodian.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityInKindIxArgs {
+    pub lamports: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityInKindIxData<'me>(pub &'me RemoveLiquidityInKindIxArgs);
+pub const REMOVE_LIQUIDITY_IN_KIND_IX_DISCM: [u8; 8] = [92, 140, 47, 158, 168, 117, 58, 191];
+impl<'me> From<&'me RemoveLiquidityInKindIxArgs> for RemoveLiquidityInKindIxData<'me> {
+    fn from(args: &'me RemoveLiquidityInKindIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RemoveLiquidityInKindIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_LIQUIDITY_IN_KIND_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn remove_liquidity_in_kind_ix<
+    K: Into<RemoveLiquidityInKindKeys>,
+    A: Into<RemoveLiquidityInKindIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RemoveLiquidityInKindKeys = accounts.into();
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveLiquidityInKindIxArgs = args.into();
+    let data: RemoveLiquidityInKindIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_in_kind_invoke<'a, A: Into<RemoveLiquidityInKindIxArgs>>(
+    accounts: &RemoveLiquidityInKindAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_liquidity_in_kind_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_liquidity_in_kind_invoke_signed<'a, A: Into<RemoveLiquidityInKindIxArgs>>(
+    accounts: &RemoveLiquidityInKindAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_liquidity_in_kind_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IN_KIND_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN: usize = 10usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityWsolAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+> {
+    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a0>,
+    pub to: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub burn_lp_tokens_from: &'me AccountInfo<'a5>,
+    pub flash_account: &'me AccountInfo<'a6>,
+    pub withdraw_queue_account: &'me AccountInfo<'a7>,
+    pub token_program: &'me AccountInfo<'a8>,
+    pub system_program: &'me AccountInfo<'a9>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityWsolKeys {
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub to: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub flash_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&RemoveLiquidityWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for RemoveLiquidityWsolKeys
+{
+    fn from(
+        accounts: &RemoveLiquidityWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
+            to: *accounts.to.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
+            flash_account: *accounts.flash_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&RemoveLiquidityWsolKeys> for [AccountMeta; REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveLiquidityWsolKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
+            AccountMeta::new(keys.to, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.burn_lp_tokens_from, false),
+            AccountMeta::new_readonly(keys.flash_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&RemoveLiquidityWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &RemoveLiquidityWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
+        [
+            accounts.burn_lp_tokens_from_authority.clone(),
+            accounts.to.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.burn_lp_tokens_from.clone(),
+            accounts.flash_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityWsolIxArgs {
+    pub amount_lp: u64,
+    pub min_lamports_out: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveLiquidityWsolIxData<'me>(pub &'me RemoveLiquidityWsolIxArgs);
+pub const REMOVE_LIQUIDITY_WSOL_IX_DISCM: [u8; 8] = [164, 121, 50, 180, 10, 163, 22, 82];
+impl<'me> From<&'me RemoveLiquidityWsolIxArgs> for RemoveLiquidityWsolIxData<'me> {
+    fn from(args: &'me RemoveLiquidityWsolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RemoveLiquidityWsolIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_LIQUIDITY_WSOL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn remove_liquidity_wsol_ix<
+    K: Into<RemoveLiquidityWsolKeys>,
+    A: Into<RemoveLiquidityWsolIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RemoveLiquidityWsolKeys = accounts.into();
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveLiquidityWsolIxArgs = args.into();
+    let data: RemoveLiquidityWsolIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_wsol_invoke<'a, A: Into<RemoveLiquidityWsolIxArgs>>(
+    accounts: &RemoveLiquidityWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_liquidity_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_liquidity_wsol_invoke_signed<'a, A: Into<RemoveLiquidityWsolIxArgs>>(
+    accounts: &RemoveLiquidityWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_liquidity_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN: usize = 11usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RequestWithdrawalAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub burn_lp_tokens_from: &'me AccountInfo<'a5>,
+    pub flash_account: &'me AccountInfo<'a6>,
+    pub withdraw_queue_account: &'me AccountInfo<'a7>,
+    pub withdraw_ticket_account: &'me AccountInfo<'a8>,
+    pub token_program: &'me AccountInfo<'a9>,
+    pub system_program: &'me AccountInfo<'a10>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RequestWithdrawalKeys {
+    pub payer: Pubkey,
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub flash_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub withdraw_ticket_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&RequestWithdrawalAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for RequestWithdrawalKeys
+{
+    fn from(
+        accounts: &RequestWithdrawalAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
+            flash_account: *accounts.flash_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            withdraw_ticket_account: *accounts.withdraw_ticket_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&RequestWithdrawalKeys> for [AccountMeta; REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RequestWithdrawalKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.burn_lp_tokens_from, false),
+            AccountMeta::new_readonly(keys.flash_account, false),
+            AccountMeta::new(keys.withdraw_queue_account, false),
+            AccountMeta::new(keys.withdraw_ticket_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&RequestWithdrawalAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &RequestWithdrawalAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.burn_lp_tokens_from_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.burn_lp_tokens_from.clone(),
+            accounts.flash_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.withdraw_ticket_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequestWithdrawalIxArgs {
+    pub amount_lp: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RequestWithdrawalIxData<'me>(pub &'me RequestWithdrawalIxArgs);
+pub const REQUEST_WITHDRAWAL_IX_DISCM: [u8; 8] = [251, 85, 121, 205, 56, 201, 12, 177];
+impl<'me> From<&'me RequestWithdrawalIxArgs> for RequestWithdrawalIxData<'me> {
+    fn from(args: &'me RequestWithdrawalIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RequestWithdrawalIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REQUEST_WITHDRAWAL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn request_withdrawal_ix<K: Into<RequestWithdrawalKeys>, A: Into<RequestWithdrawalIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RequestWithdrawalKeys = accounts.into();
+    let metas: [AccountMeta; REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RequestWithdrawalIxArgs = args.into();
+    let data: RequestWithdrawalIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn request_withdrawal_invoke<'a, A: Into<RequestWithdrawalIxArgs>>(
+    accounts: &RequestWithdrawalAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = request_withdrawal_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn request_withdrawal_invoke_signed<'a, A: Into<RequestWithdrawalIxArgs>>(
+    accounts: &RequestWithdrawalAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = request_withdrawal_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REQUEST_WITHDRAWAL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN: usize = 7usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ClaimWithdrawTicketAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+> {
+    pub owner: &'me AccountInfo<'a0>,
+    pub to: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub withdraw_queue_account: &'me AccountInfo<'a4>,
+    pub withdraw_ticket_account: &'me AccountInfo<'a5>,
+    pub system_program: &'me AccountInfo<'a6>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ClaimWithdrawTicketKeys {
+    pub owner: Pubkey,
+    pub to: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub withdraw_ticket_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&ClaimWithdrawTicketAccounts<'me, '_, '_, '_, '_, '_, '_, '_>>
+    for ClaimWithdrawTicketKeys
+{
+    fn from(accounts: &ClaimWithdrawTicketAccounts<'me, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            owner: *accounts.owner.key,
+            to: *accounts.to.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            withdraw_ticket_account: *accounts.withdraw_ticket_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&ClaimWithdrawTicketKeys> for [AccountMeta; CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ClaimWithdrawTicketKeys) -> Self {
+        [
+            AccountMeta::new(keys.owner, true),
+            AccountMeta::new(keys.to, false),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.withdraw_queue_account, false),
+            AccountMeta::new(keys.withdraw_ticket_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&ClaimWithdrawTicketAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ClaimWithdrawTicketAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.owner.clone(),
+            accounts.to.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.withdraw_ticket_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClaimWithdrawTicketIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ClaimWithdrawTicketIxData<'me>(pub &'me ClaimWithdrawTicketIxArgs);
+pub const CLAIM_WITHDRAW_TICKET_IX_DISCM: [u8; 8] = [244, 86, 93, 56, 226, 222, 108, 99];
+impl<'me> From<&'me ClaimWithdrawTicketIxArgs> for ClaimWithdrawTicketIxData<'me> {
+    fn from(args: &'me ClaimWithdrawTicketIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ClaimWithdrawTicketIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&CLAIM_WITHDRAW_TICKET_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn claim_withdraw_ticket_ix<
+    K: Into<ClaimWithdrawTicketKeys>,
+    A: Into<ClaimWithdrawTicketIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ClaimWithdrawTicketKeys = accounts.into();
+    let metas: [AccountMeta; CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ClaimWithdrawTicketIxArgs = args.into();
+    let data: ClaimWithdrawTicketIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn claim_withdraw_ticket_invoke<'a, A: Into<ClaimWithdrawTicketIxArgs>>(
+    accounts: &ClaimWithdrawTicketAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = claim_withdraw_ticket_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn claim_withdraw_ticket_invoke_signed<'a, A: Into<ClaimWithdrawTicketIxArgs>>(
+    accounts: &ClaimWithdrawTicketAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = claim_withdraw_ticket_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CLAIM_WITHDRAW_TICKET_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 8usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub fee_account: &'me AccountInfo<'a2>,
+    pub system_program: &'me AccountInfo<'a3>,
+    pub rent: &'me AccountInfo<'a4>,
+    pub fee_delay_account: &'me AccountInfo<'a5>,
+    pub clock: &'me AccountInfo<'a6>,
+    pub pending_fee_account: &'me AccountInfo<'a7>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_account: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+    pub fee_delay_account: Pubkey,
+    pub clock: Pubkey,
+    pub pending_fee_account: Pubkey,
+}
+impl<'me> From<&SetFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>> for SetFeeKeys {
+    fn from(accounts: &SetFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            fee_account: *accounts.fee_account.key,
+            system_program: *accounts.system_program.key,
+            rent: *accounts.rent.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
+            clock: *accounts.clock.key,
+            pending_fee_account: *accounts.pending_fee_account.key,
+        }
+    }
+}
+impl From<&SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetFeeKeys) -> Self {
+        [
+            AccountMeta::new(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.rent, false),
+            AccountMeta::new_readonly(keys.fee_delay_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new(keys.pending_fee_account, false),
+        ]
+    }
+}
+impl<'a> From<&SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.fee_account.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+            accounts.fee_delay_account.clone(),
+            accounts.clock.clone(),
+            accounts.pending_fee_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeIxArgs {
+    pub fee: Fee,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeIxData<'me>(pub &'me SetFeeIxArgs);
+pub const SET_FEE_IX_DISCM: [u8; 8] = [18, 154, 24, 18, 237, 214, 19, 80];
+impl<'me> From<&'me SetFeeIxArgs> for SetFeeIxData<'me> {
+    fn from(args: &'me SetFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetFeeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_FEE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_fee_ix<K: Into<SetFeeKeys>, A: Into<SetFeeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetFeeKeys = accounts.into();
+    let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetFeeIxArgs = args.into();
+    let data: SetFeeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_fee_invoke<'a, A: Into<SetFeeIxArgs>>(
+    accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_fee_invoke_signed<'a, A: Into<SetFeeIxArgs>>(
+    accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_FEE_DELAY_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeDelayAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub fee_delay_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeDelayKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_delay_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetFeeDelayAccounts<'me, '_, '_, '_, '_, '_>> for SetFeeDelayKeys {
+    fn from(accounts: &SetFeeDelayAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetFeeDelayKeys> for [AccountMeta; SET_FEE_DELAY_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetFeeDelayKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.fee_delay_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetFeeDelayAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_FEE_DELAY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetFeeDelayAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.fee_delay_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeDelayIxArgs {
+    pub min_delay_seconds: u64,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetFeeDelayIxData<'me>(pub &'me SetFeeDelayIxArgs);
+pub const SET_FEE_DELAY_IX_DISCM: [u8; 8] = [84, 3, 218, 229, 13, 13, 216, 122];
+impl<'me> From<&'me SetFeeDelayIxArgs> for SetFeeDelayIxData<'me> {
+    fn from(args: &'me SetFeeDelayIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetFeeDelayIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_FEE_DELAY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_fee_delay_ix<K: Into<SetFeeDelayKeys>, A: Into<SetFeeDelayIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetFeeDelayKeys = accounts.into();
+    let metas: [AccountMeta; SET_FEE_DELAY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetFeeDelayIxArgs = args.into();
+    let data: SetFeeDelayIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_fee_delay_invoke<'a, A: Into<SetFeeDelayIxArgs>>(
+    accounts: &SetFeeDelayAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_fee_delay_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_FEE_DELAY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_fee_delay_invoke_signed<'a, A: Into<SetFeeDelayIxArgs>>(
+    accounts: &SetFeeDelayAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_fee_delay_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_FEE_DELAY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const APPLY_PENDING_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub fee_account: &'me AccountInfo<'a2>,
+    pub pending_fee_account: &'me AccountInfo<'a3>,
+    pub clock: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingFeeKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_account: Pubkey,
+    pub pending_fee_account: Pubkey,
+    pub clock: Pubkey,
+}
+impl<'me> From<&ApplyPendingFeeAccounts<'me, '_, '_, '_, '_, '_>> for ApplyPendingFeeKeys {
+    fn from(accounts: &ApplyPendingFeeAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            fee_account: *accounts.fee_account.key,
+            pending_fee_account: *accounts.pending_fee_account.key,
+            clock: *accounts.clock.key,
+        }
+    }
+}
+impl From<&ApplyPendingFeeKeys> for [AccountMeta; APPLY_PENDING_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ApplyPendingFeeKeys) -> Self {
+        [
+            AccountMeta::new(keys.fee_authority, false),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new(keys.pending_fee_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+        ]
+    }
+}
+impl<'a> From<&ApplyPendingFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; APPLY_PENDING_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ApplyPendingFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.fee_account.clone(),
+            accounts.pending_fee_account.clone(),
+            accounts.clock.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApplyPendingFeeIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ApplyPendingFeeIxData<'me>(pub &'me ApplyPendingFeeIxArgs);
+pub const APPLY_PENDING_FEE_IX_DISCM: [u8; 8] = [219, 52, 207, 255, 26, 252, 19, 230];
+impl<'me> From<&'me ApplyPendingFeeIxArgs> for ApplyPendingFeeIxData<'me> {
+    fn from(args: &'me ApplyPendingFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ApplyPendingFeeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&APPLY_PENDING_FEE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn apply_pending_fee_ix<K: Into<ApplyPendingFeeKeys>, A: Into<ApplyPendingFeeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ApplyPendingFeeKeys = accounts.into();
+    let metas: [AccountMeta; APPLY_PENDING_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ApplyPendingFeeIxArgs = args.into();
+    let data: ApplyPendingFeeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn apply_pending_fee_invoke<'a, A: Into<ApplyPendingFeeIxArgs>>(
+    accounts: &ApplyPendingFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = apply_pending_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn apply_pending_fee_invoke_signed<'a, A: Into<ApplyPendingFeeIxArgs>>(
+    accounts: &ApplyPendingFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = apply_pending_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeFeeAuthorityAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub new_fee_authority: &'me AccountInfo<'a2>,
+    pub fee_authority_nominee_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeFeeAuthorityKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub new_fee_authority: Pubkey,
+    pub fee_authority_nominee_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&ProposeFeeAuthorityAccounts<'me, '_, '_, '_, '_, '_>> for ProposeFeeAuthorityKeys {
+    fn from(accounts: &ProposeFeeAuthorityAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            new_fee_authority: *accounts.new_fee_authority.key,
+            fee_authority_nominee_account: *accounts.fee_authority_nominee_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&ProposeFeeAuthorityKeys> for [AccountMeta; PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ProposeFeeAuthorityKeys) -> Self {
+        [
+            AccountMeta::new(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.new_fee_authority, false),
+            AccountMeta::new(keys.fee_authority_nominee_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&ProposeFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ProposeFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.new_fee_authority.clone(),
+            accounts.fee_authority_nominee_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposeFeeAuthorityIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ProposeFeeAuthorityIxData<'me>(pub &'me ProposeFeeAuthorityIxArgs);
+pub const PROPOSE_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [227, 159, 241, 145, 209, 220, 201, 222];
+impl<'me> From<&'me ProposeFeeAuthorityIxArgs> for ProposeFeeAuthorityIxData<'me> {
+    fn from(args: &'me ProposeFeeAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ProposeFeeAuthorityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&PROPOSE_FEE_AUTHORITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn propose_fee_authority_ix<
+    K: Into<ProposeFeeAuthorityKeys>,
+    A: Into<ProposeFeeAuthorityIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ProposeFeeAuthorityKeys = accounts.into();
+    let metas: [AccountMeta; PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ProposeFeeAuthorityIxArgs = args.into();
+    let data: ProposeFeeAuthorityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn propose_fee_authority_invoke<'a, A: Into<ProposeFeeAuthorityIxArgs>>(
+    accounts: &ProposeFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = propose_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn propose_fee_authority_invoke_signed<'a, A: Into<ProposeFeeAuthorityIxArgs>>(
+    accounts: &ProposeFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = propose_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; PROPOSE_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptFeeAuthorityAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me> {
+    pub new_fee_authority: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub fee_authority_nominee_account: &'me AccountInfo<'a3>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptFeeAuthorityKeys {
+    pub new_fee_authority: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_authority_nominee_account: Pubkey,
+}
+impl<'me> From<&AcceptFeeAuthorityAccounts<'me, '_, '_, '_, '_>> for AcceptFeeAuthorityKeys {
+    fn from(accounts: &AcceptFeeAuthorityAccounts<'me, '_, '_, '_, '_>) -> Self {
+        Self {
+            new_fee_authority: *accounts.new_fee_authority.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            fee_authority_nominee_account: *accounts.fee_authority_nominee_account.key,
+        }
+    }
+}
+impl From<&AcceptFeeAuthorityKeys> for [AccountMeta; ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AcceptFeeAuthorityKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.new_fee_authority, true),
+            AccountMeta::new(keys.fee_authority, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.fee_authority_nominee_account, false),
+        ]
+    }
+}
+impl<'a> From<&AcceptFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &AcceptFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.new_fee_authority.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.fee_authority_nominee_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcceptFeeAuthorityIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptFeeAuthorityIxData<'me>(pub &'me AcceptFeeAuthorityIxArgs);
+pub const ACCEPT_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [109, 14, 135, 142, 170, 250, 189, 240];
+impl<'me> From<&'me AcceptFeeAuthorityIxArgs> for AcceptFeeAuthorityIxData<'me> {
+    fn from(args: &'me AcceptFeeAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AcceptFeeAuthorityIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ACCEPT_FEE_AUTHORITY_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn accept_fee_authority_ix<
+    K: Into<AcceptFeeAuthorityKeys>,
+    A: Into<AcceptFeeAuthorityIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AcceptFeeAuthorityKeys = accounts.into();
+    let metas: [AccountMeta; ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AcceptFeeAuthorityIxArgs = args.into();
+    let data: AcceptFeeAuthorityIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn accept_fee_authority_invoke<'a, A: Into<AcceptFeeAuthorityIxArgs>>(
+    accounts: &AcceptFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = accept_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn accept_fee_authority_invoke_signed<'a, A: Into<AcceptFeeAuthorityIxArgs>>(
+    accounts: &AcceptFeeAuthorityAccounts<'_, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = accept_fee_authority_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ACCEPT_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN: usize = 3usize;
+#[derive(Copy, Clone, Debug)]
+pub struct CancelFeeAuthorityProposalAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub fee_authority_nominee_account: &'me AccountInfo<'a2>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CancelFeeAuthorityProposalKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_authority_nominee_account: Pubkey,
+}
+impl<'me> From<&CancelFeeAuthorityProposalAccounts<'me, '_, '_, '_>>
+    for CancelFeeAuthorityProposalKeys
+{
+    fn from(accounts: &CancelFeeAuthorityProposalAccounts<'me, '_, '_, '_>) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            fee_authority_nominee_account: *accounts.fee_authority_nominee_account.key,
+        }
+    }
+}
+impl From<&CancelFeeAuthorityProposalKeys>
+    for [AccountMeta; CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &CancelFeeAuthorityProposalKeys) -> Self {
+        [
+            AccountMeta::new(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.fee_authority_nominee_account, false),
+        ]
+    }
+}
+impl<'a> From<&CancelFeeAuthorityProposalAccounts<'_, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &CancelFeeAuthorityProposalAccounts<'_, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.fee_authority_nominee_account.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelFeeAuthorityProposalIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct CancelFeeAuthorityProposalIxData<'me>(pub &'me CancelFeeAuthorityProposalIxArgs);
+pub const CANCEL_FEE_AUTHORITY_PROPOSAL_IX_DISCM: [u8; 8] = [171, 106, 68, 81, 97, 69, 60, 4];
+impl<'me> From<&'me CancelFeeAuthorityProposalIxArgs> for CancelFeeAuthorityProposalIxData<'me> {
+    fn from(args: &'me CancelFeeAuthorityProposalIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for CancelFeeAuthorityProposalIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&CANCEL_FEE_AUTHORITY_PROPOSAL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn cancel_fee_authority_proposal_ix<
+    K: Into<CancelFeeAuthorityProposalKeys>,
+    A: Into<CancelFeeAuthorityProposalIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: CancelFeeAuthorityProposalKeys = accounts.into();
+    let metas: [AccountMeta; CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: CancelFeeAuthorityProposalIxArgs = args.into();
+    let data: CancelFeeAuthorityProposalIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn cancel_fee_authority_proposal_invoke<'a, A: Into<CancelFeeAuthorityProposalIxArgs>>(
+    accounts: &CancelFeeAuthorityProposalAccounts<'_, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = cancel_fee_authority_proposal_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn cancel_fee_authority_proposal_invoke_signed<
+    'a,
+    A: Into<CancelFeeAuthorityProposalIxArgs>,
+>(
+    accounts: &CancelFeeAuthorityProposalAccounts<'_, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = cancel_fee_authority_proposal_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CANCEL_FEE_AUTHORITY_PROPOSAL_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN: usize = 6usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPauseGuardianAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me, 'a5: 'me>
+{
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub new_guardian: &'me AccountInfo<'a3>,
+    pub pool_pause_account: &'me AccountInfo<'a4>,
+    pub system_program: &'me AccountInfo<'a5>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPauseGuardianKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub new_guardian: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetPauseGuardianAccounts<'me, '_, '_, '_, '_, '_, '_>> for SetPauseGuardianKeys {
+    fn from(accounts: &SetPauseGuardianAccounts<'me, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            new_guardian: *accounts.new_guardian.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetPauseGuardianKeys> for [AccountMeta; SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetPauseGuardianKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.new_guardian, false),
+            AccountMeta::new(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetPauseGuardianAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetPauseGuardianAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.new_guardian.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPauseGuardianIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPauseGuardianIxData<'me>(pub &'me SetPauseGuardianIxArgs);
+pub const SET_PAUSE_GUARDIAN_IX_DISCM: [u8; 8] = [192, 82, 193, 216, 26, 22, 10, 117];
+impl<'me> From<&'me SetPauseGuardianIxArgs> for SetPauseGuardianIxData<'me> {
+    fn from(args: &'me SetPauseGuardianIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetPauseGuardianIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_PAUSE_GUARDIAN_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_pause_guardian_ix<K: Into<SetPauseGuardianKeys>, A: Into<SetPauseGuardianIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetPauseGuardianKeys = accounts.into();
+    let metas: [AccountMeta; SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetPauseGuardianIxArgs = args.into();
+    let data: SetPauseGuardianIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pause_guardian_invoke<'a, A: Into<SetPauseGuardianIxArgs>>(
+    accounts: &SetPauseGuardianAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_pause_guardian_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_pause_guardian_invoke_signed<'a, A: Into<SetPauseGuardianIxArgs>>(
+    accounts: &SetPauseGuardianAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_pause_guardian_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_PAUSE_GUARDIAN_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_POOL_PAUSE_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolPauseAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_pause_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolPauseKeys {
+    pub payer: Pubkey,
+    pub authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetPoolPauseAccounts<'me, '_, '_, '_, '_, '_>> for SetPoolPauseKeys {
+    fn from(accounts: &SetPoolPauseAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            authority: *accounts.authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetPoolPauseKeys> for [AccountMeta; SET_POOL_PAUSE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetPoolPauseKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetPoolPauseAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_POOL_PAUSE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetPoolPauseAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPoolPauseIxArgs {
+    pub paused: PausedInstructions,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolPauseIxData<'me>(pub &'me SetPoolPauseIxArgs);
+pub const SET_POOL_PAUSE_IX_DISCM: [u8; 8] = [52, 171, 212, 208, 28, 209, 90, 15];
+impl<'me> From<&'me SetPoolPauseIxArgs> for SetPoolPauseIxData<'me> {
+    fn from(args: &'me SetPoolPauseIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetPoolPauseIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_POOL_PAUSE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_pool_pause_ix<K: Into<SetPoolPauseKeys>, A: Into<SetPoolPauseIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetPoolPauseKeys = accounts.into();
+    let metas: [AccountMeta; SET_POOL_PAUSE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetPoolPauseIxArgs = args.into();
+    let data: SetPoolPauseIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pool_pause_invoke<'a, A: Into<SetPoolPauseIxArgs>>(
+    accounts: &SetPoolPauseAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_pool_pause_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_POOL_PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_pool_pause_invoke_signed<'a, A: Into<SetPoolPauseIxArgs>>(
+    accounts: &SetPoolPauseAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_pool_pause_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_POOL_PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_POOL_CONFIG_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolConfigAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_config_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolConfigKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetPoolConfigAccounts<'me, '_, '_, '_, '_, '_>> for SetPoolConfigKeys {
+    fn from(accounts: &SetPoolConfigAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetPoolConfigKeys> for [AccountMeta; SET_POOL_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetPoolConfigKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetPoolConfigAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_POOL_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetPoolConfigAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPoolConfigIxArgs {
+    pub max_unstake_lamports: Option<u64>,
+    pub max_epoch_incoming_stake_lamports: Option<u64>,
+    pub max_pool_owned_lamports: Option<u64>,
+    pub lp_allowlist_enabled: bool,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetPoolConfigIxData<'me>(pub &'me SetPoolConfigIxArgs);
+pub const SET_POOL_CONFIG_IX_DISCM: [u8; 8] = [216, 87, 65, 125, 113, 110, 185, 120];
+impl<'me> From<&'me SetPoolConfigIxArgs> for SetPoolConfigIxData<'me> {
+    fn from(args: &'me SetPoolConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetPoolConfigIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_POOL_CONFIG_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_pool_config_ix<K: Into<SetPoolConfigKeys>, A: Into<SetPoolConfigIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetPoolConfigKeys = accounts.into();
+    let metas: [AccountMeta; SET_POOL_CONFIG_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetPoolConfigIxArgs = args.into();
+    let data: SetPoolConfigIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_pool_config_invoke<'a, A: Into<SetPoolConfigIxArgs>>(
+    accounts: &SetPoolConfigAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_pool_config_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_POOL_CONFIG_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_pool_config_invoke_signed<'a, A: Into<SetPoolConfigIxArgs>>(
+    accounts: &SetPoolConfigAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_pool_config_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_POOL_CONFIG_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const ADD_ALLOWED_LP_IX_ACCOUNTS_LEN: usize = 6usize;
+#[derive(Copy, Clone, Debug)]
+pub struct AddAllowedLpAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me, 'a5: 'me> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub lp: &'me AccountInfo<'a3>,
+    pub allowed_lp_account: &'me AccountInfo<'a4>,
+    pub system_program: &'me AccountInfo<'a5>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AddAllowedLpKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub lp: Pubkey,
+    pub allowed_lp_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&AddAllowedLpAccounts<'me, '_, '_, '_, '_, '_, '_>> for AddAllowedLpKeys {
+    fn from(accounts: &AddAllowedLpAccounts<'me, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            lp: *accounts.lp.key,
+            allowed_lp_account: *accounts.allowed_lp_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&AddAllowedLpKeys> for [AccountMeta; ADD_ALLOWED_LP_IX_ACCOUNTS_LEN] {
+    fn from(keys: &AddAllowedLpKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.lp, false),
+            AccountMeta::new(keys.allowed_lp_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&AddAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; ADD_ALLOWED_LP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &AddAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.lp.clone(),
+            accounts.allowed_lp_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddAllowedLpIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct AddAllowedLpIxData<'me>(pub &'me AddAllowedLpIxArgs);
+pub const ADD_ALLOWED_LP_IX_DISCM: [u8; 8] = [130, 51, 85, 126, 186, 95, 121, 255];
+impl<'me> From<&'me AddAllowedLpIxArgs> for AddAllowedLpIxData<'me> {
+    fn from(args: &'me AddAllowedLpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for AddAllowedLpIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&ADD_ALLOWED_LP_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn add_allowed_lp_ix<K: Into<AddAllowedLpKeys>, A: Into<AddAllowedLpIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: AddAllowedLpKeys = accounts.into();
+    let metas: [AccountMeta; ADD_ALLOWED_LP_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: AddAllowedLpIxArgs = args.into();
+    let data: AddAllowedLpIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_allowed_lp_invoke<'a, A: Into<AddAllowedLpIxArgs>>(
+    accounts: &AddAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = add_allowed_lp_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_ALLOWED_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_allowed_lp_invoke_signed<'a, A: Into<AddAllowedLpIxArgs>>(
+    accounts: &AddAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_allowed_lp_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; ADD_ALLOWED_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveAllowedLpAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub lp: &'me AccountInfo<'a2>,
+    pub allowed_lp_account: &'me AccountInfo<'a3>,
+    pub refund_rent_to: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveAllowedLpKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub lp: Pubkey,
+    pub allowed_lp_account: Pubkey,
+    pub refund_rent_to: Pubkey,
+}
+impl<'me> From<&RemoveAllowedLpAccounts<'me, '_, '_, '_, '_, '_>> for RemoveAllowedLpKeys {
+    fn from(accounts: &RemoveAllowedLpAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            lp: *accounts.lp.key,
+            allowed_lp_account: *accounts.allowed_lp_account.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+        }
+    }
+}
+impl From<&RemoveAllowedLpKeys> for [AccountMeta; REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveAllowedLpKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.lp, false),
+            AccountMeta::new(keys.allowed_lp_account, false),
+            AccountMeta::new(keys.refund_rent_to, false),
+        ]
+    }
+}
+impl<'a> From<&RemoveAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &RemoveAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.lp.clone(),
+            accounts.allowed_lp_account.clone(),
+            accounts.refund_rent_to.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveAllowedLpIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveAllowedLpIxData<'me>(pub &'me RemoveAllowedLpIxArgs);
+pub const REMOVE_ALLOWED_LP_IX_DISCM: [u8; 8] = [230, 218, 119, 83, 216, 201, 82, 165];
+impl<'me> From<&'me RemoveAllowedLpIxArgs> for RemoveAllowedLpIxData<'me> {
+    fn from(args: &'me RemoveAllowedLpIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for RemoveAllowedLpIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&REMOVE_ALLOWED_LP_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn remove_allowed_lp_ix<K: Into<RemoveAllowedLpKeys>, A: Into<RemoveAllowedLpIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: RemoveAllowedLpKeys = accounts.into();
+    let metas: [AccountMeta; REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveAllowedLpIxArgs = args.into();
+    let data: RemoveAllowedLpIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_allowed_lp_invoke<'a, A: Into<RemoveAllowedLpIxArgs>>(
+    accounts: &RemoveAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_allowed_lp_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_allowed_lp_invoke_signed<'a, A: Into<RemoveAllowedLpIxArgs>>(
+    accounts: &RemoveAllowedLpAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_allowed_lp_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_ALLOWED_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 9usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub metadata: &'me AccountInfo<'a5>,
+    pub metadata_program: &'me AccountInfo<'a6>,
+    pub system_program: &'me AccountInfo<'a7>,
+    pub rent: &'me AccountInfo<'a8>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub metadata_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+impl<'me> From<&SetLpTokenMetadataAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for SetLpTokenMetadataKeys
+{
+    fn from(
+        accounts: &SetLpTokenMetadataAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            metadata: *accounts.metadata.key,
+            metadata_program: *accounts.metadata_program.key,
+            system_program: *accounts.system_program.key,
+            rent: *accounts.rent.key,
+        }
+    }
+}
+impl From<&SetLpTokenMetadataKeys> for [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetLpTokenMetadataKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.lp_mint, false),
+            AccountMeta::new(keys.metadata, false),
+            AccountMeta::new_readonly(keys.metadata_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.rent, false),
+        ]
+    }
+}
+impl<'a> From<&SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.metadata.clone(),
+            accounts.metadata_program.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetLpTokenMetadataIxArgs {
+    pub data: DataV2LpToken,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetLpTokenMetadataIxData<'me>(pub &'me SetLpTokenMetadataIxArgs);
+pub const SET_LP_TOKEN_METADATA_IX_DISCM: [u8; 8] = [71, 73, 56, 155, 202, 142, 100, 150];
+impl<'me> From<&'me SetLpTokenMetadataIxArgs> for SetLpTokenMetadataIxData<'me> {
+    fn from(args: &'me SetLpTokenMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetLpTokenMetadataIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_LP_TOKEN_METADATA_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_lp_token_metadata_ix<
+    K: Into<SetLpTokenMetadataKeys>,
+    A: Into<SetLpTokenMetadataIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetLpTokenMetadataKeys = accounts.into();
+    let metas: [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetLpTokenMetadataIxArgs = args.into();
+    let data: SetLpTokenMetadataIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_lp_token_metadata_invoke<'a, A: Into<SetLpTokenMetadataIxArgs>>(
+    accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_lp_token_metadata_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_lp_token_metadata_invoke_signed<'a, A: Into<SetLpTokenMetadataIxArgs>>(
+    accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_lp_token_metadata_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const CLOSE_POOL_IX_ACCOUNTS_LEN: usize = 19usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ClosePoolAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+    'a17: 'me,
+    'a18: 'me,
+> {
+    pub fee_authority: &'me AccountInfo<'a0>,
+    pub destination: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub lp_mint: &'me AccountInfo<'a4>,
+    pub locked_lp_token_account: &'me AccountInfo<'a5>,
+    pub fee_account: &'me AccountInfo<'a6>,
+    pub flash_loan_fee_account: &'me AccountInfo<'a7>,
+    pub fee_delay_account: &'me AccountInfo<'a8>,
+    pub pending_fee_account: &'me AccountInfo<'a9>,
+    pub fee_authority_nominee_account: &'me AccountInfo<'a10>,
+    pub pool_config_account: &'me AccountInfo<'a11>,
+    pub pool_pause_account: &'me AccountInfo<'a12>,
+    pub stake_deposit_fee_account: &'me AccountInfo<'a13>,
+    pub lockup_fee_account: &'me AccountInfo<'a14>,
+    pub pending_lockup_fee_account: &'me AccountInfo<'a15>,
+    pub withdraw_queue_account: &'me AccountInfo<'a16>,
+    pub token_program: &'me AccountInfo<'a17>,
+    pub system_program: &'me AccountInfo<'a18>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ClosePoolKeys {
+    pub fee_authority: Pubkey,
+    pub destination: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub locked_lp_token_account: Pubkey,
+    pub fee_account: Pubkey,
+    pub flash_loan_fee_account: Pubkey,
+    pub fee_delay_account: Pubkey,
+    pub pending_fee_account: Pubkey,
+    pub fee_authority_nominee_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub stake_deposit_fee_account: Pubkey,
+    pub lockup_fee_account: Pubkey,
+    pub pending_lockup_fee_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me>
+    From<
+        &ClosePoolAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for ClosePoolKeys
+{
+    fn from(
+        accounts: &ClosePoolAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
+        Self {
+            fee_authority: *accounts.fee_authority.key,
+            destination: *accounts.destination.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lp_mint: *accounts.lp_mint.key,
+            locked_lp_token_account: *accounts.locked_lp_token_account.key,
+            fee_account: *accounts.fee_account.key,
+            flash_loan_fee_account: *accounts.flash_loan_fee_account.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
+            pending_fee_account: *accounts.pending_fee_account.key,
+            fee_authority_nominee_account: *accounts.fee_authority_nominee_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            stake_deposit_fee_account: *accounts.stake_deposit_fee_account.key,
+            lockup_fee_account: *accounts.lockup_fee_account.key,
+            pending_lockup_fee_account: *accounts.pending_lockup_fee_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
+            token_program: *accounts.token_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&ClosePoolKeys> for [AccountMeta; CLOSE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ClosePoolKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new(keys.destination, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lp_mint, false),
+            AccountMeta::new(keys.locked_lp_token_account, false),
+            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new(keys.flash_loan_fee_account, false),
+            AccountMeta::new(keys.fee_delay_account, false),
+            AccountMeta::new(keys.pending_fee_account, false),
+            AccountMeta::new(keys.fee_authority_nominee_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new(keys.pool_pause_account, false),
+            AccountMeta::new(keys.stake_deposit_fee_account, false),
+            AccountMeta::new(keys.lockup_fee_account, false),
+            AccountMeta::new(keys.pending_lockup_fee_account, false),
+            AccountMeta::new(keys.withdraw_queue_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a>
+    From<
+        &ClosePoolAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; CLOSE_POOL_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &ClosePoolAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
+        [
+            accounts.fee_authority.clone(),
+            accounts.destination.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.lp_mint.clone(),
+            accounts.locked_lp_token_account.clone(),
+            accounts.fee_account.clone(),
+            accounts.flash_loan_fee_account.clone(),
+            accounts.fee_delay_account.clone(),
+            accounts.pending_fee_account.clone(),
+            accounts.fee_authority_nominee_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.stake_deposit_fee_account.clone(),
+            accounts.lockup_fee_account.clone(),
+            accounts.pending_lockup_fee_account.clone(),
+            accounts.withdraw_queue_account.clone(),
+            accounts.token_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClosePoolIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ClosePoolIxData<'me>(pub &'me ClosePoolIxArgs);
+pub const CLOSE_POOL_IX_DISCM: [u8; 8] = [140, 189, 209, 23, 239, 62, 239, 11];
+impl<'me> From<&'me ClosePoolIxArgs> for ClosePoolIxData<'me> {
+    fn from(args: &'me ClosePoolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ClosePoolIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_POOL_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn close_pool_ix<K: Into<ClosePoolKeys>, A: Into<ClosePoolIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ClosePoolKeys = accounts.into();
+    let metas: [AccountMeta; CLOSE_POOL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ClosePoolIxArgs = args.into();
+    let data: ClosePoolIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn close_pool_invoke<'a, A: Into<ClosePoolIxArgs>>(
+    accounts: &ClosePoolAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+) -> ProgramResult {
+    let ix = close_pool_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CLOSE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn close_pool_invoke_signed<'a, A: Into<ClosePoolIxArgs>>(
+    accounts: &ClosePoolAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = close_pool_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; CLOSE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5usize;
+#[derive(Copy, Clone, Debug)]
+pub struct DeactivateStakeAccountAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub stake_account: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub pool_sol_reserves: &'me AccountInfo<'a2>,
+    pub clock: &'me AccountInfo<'a3>,
+    pub stake_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeactivateStakeAccountKeys {
+    pub stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+}
+impl<'me> From<&DeactivateStakeAccountAccounts<'me, '_, '_, '_, '_, '_>>
+    for DeactivateStakeAccountKeys
+{
+    fn from(accounts: &DeactivateStakeAccountAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            stake_account: *accounts.stake_account.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            clock: *accounts.clock.key,
+            stake_program: *accounts.stake_program.key,
+        }
+    }
+}
+impl From<&DeactivateStakeAccountKeys> for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: &DeactivateStakeAccountKeys) -> Self {
+        [
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+        ]
+    }
+}
+impl<'a> From<&DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.stake_account.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.clock.clone(),
+            accounts.stake_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeactivateStakeAccountIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct DeactivateStakeAccountIxData<'me>(pub &'me DeactivateStakeAccountIxArgs);
+pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [217, 64, 76, 16, 216, 77, 123, 226];
+impl<'me> From<&'me DeactivateStakeAccountIxArgs> for DeactivateStakeAccountIxData<'me> {
+    fn from(args: &'me DeactivateStakeAccountIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for DeactivateStakeAccountIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn deactivate_stake_account_ix<
+    K: Into<DeactivateStakeAccountKeys>,
+    A: Into<DeactivateStakeAccountIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: DeactivateStakeAccountKeys = accounts.into();
+    let metas: [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: DeactivateStakeAccountIxArgs = args.into();
+    let data: DeactivateStakeAccountIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deactivate_stake_account_invoke<'a, A: Into<DeactivateStakeAccountIxArgs>>(
+    accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = deactivate_stake_account_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn deactivate_stake_account_invoke_signed<'a, A: Into<DeactivateStakeAccountIxArgs>>(
+    accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deactivate_stake_account_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7usize;
+#[derive(Copy, Clone, Debug)]
+pub struct ReclaimStakeAccountAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+> {
+    pub stake_account: &'me AccountInfo<'a0>,
+    pub pool_account: &'me AccountInfo<'a1>,
+    pub pool_sol_reserves: &'me AccountInfo<'a2>,
+    pub stake_account_record_account: &'me AccountInfo<'a3>,
+    pub clock: &'me AccountInfo<'a4>,
+    pub stake_history: &'me AccountInfo<'a5>,
+    pub stake_program: &'me AccountInfo<'a6>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ReclaimStakeAccountKeys {
+    pub stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
+}
+impl<'me> From<&ReclaimStakeAccountAccounts<'me, '_, '_, '_, '_, '_, '_, '_>>
+    for ReclaimStakeAccountKeys
+{
+    fn from(accounts: &ReclaimStakeAccountAccounts<'me, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            stake_account: *accounts.stake_account.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
+        }
+    }
+}
+impl From<&ReclaimStakeAccountKeys> for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ReclaimStakeAccountKeys) -> Self {
+        [
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+        ]
+    }
+}
+impl<'a> From<&ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.stake_account.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReclaimStakeAccountIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct ReclaimStakeAccountIxData<'me>(pub &'me ReclaimStakeAccountIxArgs);
+pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [47, 127, 90, 221, 10, 160, 183, 117];
+impl<'me> From<&'me ReclaimStakeAccountIxArgs> for ReclaimStakeAccountIxData<'me> {
+    fn from(args: &'me ReclaimStakeAccountIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for ReclaimStakeAccountIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn reclaim_stake_account_ix<
+    K: Into<ReclaimStakeAccountKeys>,
+    A: Into<ReclaimStakeAccountIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: ReclaimStakeAccountKeys = accounts.into();
+    let metas: [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ReclaimStakeAccountIxArgs = args.into();
+    let data: ReclaimStakeAccountIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn reclaim_stake_account_invoke<'a, A: Into<ReclaimStakeAccountIxArgs>>(
+    accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = reclaim_stake_account_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn reclaim_stake_account_invoke_signed<'a, A: Into<ReclaimStakeAccountIxArgs>>(
+    accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = reclaim_stake_account_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const UNSTAKE_IX_ACCOUNTS_LEN: usize = 12usize;
+#[derive(Copy, Clone, Debug)]
+pub struct UnstakeAccounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -197,129 +4915,312 @@ pub struct CreatePoolAccounts<
     'a6: 'me,
     'a7: 'me,
     'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
 > {
-    pub payer: &'me AccountInfo<'a0>,
-    pub fee_authority: &'me AccountInfo<'a1>,
-    pub pool_account: &'me AccountInfo<'a2>,
-    pub pool_sol_reserves: &'me AccountInfo<'a3>,
-    pub fee_account: &'me AccountInfo<'a4>,
-    pub lp_mint: &'me AccountInfo<'a5>,
-    pub token_program: &'me AccountInfo<'a6>,
-    pub system_program: &'me AccountInfo<'a7>,
-    pub rent: &'me AccountInfo<'a8>,
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub destination: &'me AccountInfo<'a2>,
+    pub pool_account: &'me AccountInfo<'a3>,
+    pub pool_sol_reserves: &'me AccountInfo<'a4>,
+    pub fee_account: &'me AccountInfo<'a5>,
+    pub stake_account_record_account: &'me AccountInfo<'a6>,
+    pub protocol_fee_account: &'me AccountInfo<'a7>,
+    pub protocol_fee_destination: &'me AccountInfo<'a8>,
+    pub clock: &'me AccountInfo<'a9>,
+    pub stake_program: &'me AccountInfo<'a10>,
+    pub system_program: &'me AccountInfo<'a11>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct CreatePoolKeys {
-    pub payer: Pubkey,
-    pub fee_authority: Pubkey,
+pub struct UnstakeKeys {
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
     pub pool_account: Pubkey,
     pub pool_sol_reserves: Pubkey,
     pub fee_account: Pubkey,
-    pub lp_mint: Pubkey,
-    pub token_program: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
     pub system_program: Pubkey,
-    pub rent: Pubkey,
 }
-impl<'me> From<&CreatePoolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>> for CreatePoolKeys {
-    fn from(accounts: &CreatePoolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+impl<'me> From<&UnstakeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for UnstakeKeys
+{
+    fn from(
+        accounts: &UnstakeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
+        Self {
+            unstaker: *accounts.unstaker.key,
+            stake_account: *accounts.stake_account.key,
+            destination: *accounts.destination.key,
+            pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            fee_account: *accounts.fee_account.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_program: *accounts.stake_program.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&UnstakeKeys> for [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakeKeys) -> Self {
+        [
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.destination, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN]
+{
+    fn from(
+        accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
+        [
+            accounts.unstaker.clone(),
+            accounts.stake_account.clone(),
+            accounts.destination.clone(),
+            accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.fee_account.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_program.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnstakeIxArgs {}
+#[derive(Copy, Clone, Debug)]
+pub struct UnstakeIxData<'me>(pub &'me UnstakeIxArgs);
+pub const UNSTAKE_IX_DISCM: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
+impl<'me> From<&'me UnstakeIxArgs> for UnstakeIxData<'me> {
+    fn from(args: &'me UnstakeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for UnstakeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&UNSTAKE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn unstake_ix<K: Into<UnstakeKeys>, A: Into<UnstakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: UnstakeKeys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakeIxArgs = args.into();
+    let data: UnstakeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn unstake_invoke<'a, A: Into<UnstakeIxArgs>>(
+    accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = unstake_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn unstake_invoke_signed<'a, A: Into<UnstakeIxArgs>>(
+    accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = unstake_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const UNSTAKE_WSOL_IX_ACCOUNTS_LEN: usize = 13usize;
+#[derive(Copy, Clone, Debug)]
+pub struct UnstakeWsolAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+> {
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub destination: &'me AccountInfo<'a2>,
+    pub pool_account: &'me AccountInfo<'a3>,
+    pub pool_sol_reserves: &'me AccountInfo<'a4>,
+    pub fee_account: &'me AccountInfo<'a5>,
+    pub stake_account_record_account: &'me AccountInfo<'a6>,
+    pub protocol_fee_account: &'me AccountInfo<'a7>,
+    pub protocol_fee_destination: &'me AccountInfo<'a8>,
+    pub clock: &'me AccountInfo<'a9>,
+    pub stake_program: &'me AccountInfo<'a10>,
+    pub system_program: &'me AccountInfo<'a11>,
+    pub token_program: &'me AccountInfo<'a12>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UnstakeWsolKeys {
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+}
+impl<'me> From<&UnstakeWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for UnstakeWsolKeys
+{
+    fn from(
+        accounts: &UnstakeWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+    ) -> Self {
         Self {
-            payer: *accounts.payer.key,
-            fee_authority: *accounts.fee_authority.key,
+            unstaker: *accounts.unstaker.key,
+            stake_account: *accounts.stake_account.key,
+            destination: *accounts.destination.key,
             pool_account: *accounts.pool_account.key,
             pool_sol_reserves: *accounts.pool_sol_reserves.key,
             fee_account: *accounts.fee_account.key,
-            lp_mint: *accounts.lp_mint.key,
-            token_program: *accounts.token_program.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_program: *accounts.stake_program.key,
             system_program: *accounts.system_program.key,
-            rent: *accounts.rent.key,
+            token_program: *accounts.token_program.key,
         }
     }
 }
-impl From<&CreatePoolKeys> for [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] {
-    fn from(keys: &CreatePoolKeys) -> Self {
+impl From<&UnstakeWsolKeys> for [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakeWsolKeys) -> Self {
         [
-            AccountMeta::new(keys.payer, true),
-            AccountMeta::new_readonly(keys.fee_authority, true),
-            AccountMeta::new(keys.pool_account, true),
-            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
-            AccountMeta::new(keys.fee_account, false),
-            AccountMeta::new(keys.lp_mint, true),
-            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.destination, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
             AccountMeta::new_readonly(keys.system_program, false),
-            AccountMeta::new_readonly(keys.rent, false),
+            AccountMeta::new_readonly(keys.token_program, false),
         ]
     }
 }
-impl<'a> From<&CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN]
+impl<'a> From<&UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(
+        accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+    ) -> Self {
         [
-            accounts.payer.clone(),
-            accounts.fee_authority.clone(),
+            accounts.unstaker.clone(),
+            accounts.stake_account.clone(),
+            accounts.destination.clone(),
             accounts.pool_account.clone(),
             accounts.pool_sol_reserves.clone(),
             accounts.fee_account.clone(),
-            accounts.lp_mint.clone(),
-            accounts.token_program.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_program.clone(),
             accounts.system_program.clone(),
-            accounts.rent.clone(),
+            accounts.token_program.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct CreatePoolIxArgs {
-    pub fee: Fee,
-}
+pub struct UnstakeWsolIxArgs {}
 #[derive(Copy, Clone, Debug)]
-pub struct CreatePoolIxData<'me>(pub &'me CreatePoolIxArgs);
-pub const CREATE_POOL_IX_DISCM: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
-impl<'me> From<&'me CreatePoolIxArgs> for CreatePoolIxData<'me> {
-    fn from(args: &'me CreatePoolIxArgs) -> Self {
+pub struct UnstakeWsolIxData<'me>(pub &'me UnstakeWsolIxArgs);
+pub const UNSTAKE_WSOL_IX_DISCM: [u8; 8] = [125, 93, 190, 135, 89, 174, 142, 149];
+impl<'me> From<&'me UnstakeWsolIxArgs> for UnstakeWsolIxData<'me> {
+    fn from(args: &'me UnstakeWsolIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for CreatePoolIxData<'_> {
+impl BorshSerialize for UnstakeWsolIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&CREATE_POOL_IX_DISCM)?;
+        writer.write_all(&UNSTAKE_WSOL_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn create_pool_ix<K: Into<CreatePoolKeys>, A: Into<CreatePoolIxArgs>>(
+pub fn unstake_wsol_ix<K: Into<UnstakeWsolKeys>, A: Into<UnstakeWsolIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: CreatePoolKeys = accounts.into();
-    let metas: [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: CreatePoolIxArgs = args.into();
-    let data: CreatePoolIxData = (&args_full).into();
+    let keys: UnstakeWsolKeys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakeWsolIxArgs = args.into();
+    let data: UnstakeWsolIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn create_pool_invoke<'a, A: Into<CreatePoolIxArgs>>(
-    accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_wsol_invoke<'a, A: Into<UnstakeWsolIxArgs>>(
+    accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = create_pool_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn create_pool_invoke_signed<'a, A: Into<CreatePoolIxArgs>>(
-    accounts: &CreatePoolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_wsol_invoke_signed<'a, A: Into<UnstakeWsolIxArgs>>(
+    accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = create_pool_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_wsol_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 8usize;
+pub const UNSTAKE_V2_IX_ACCOUNTS_LEN: usize = 20usize;
 #[derive(Copy, Clone, Debug)]
-pub struct AddLiquidityAccounts<
+pub struct UnstakeV2Accounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -329,124 +5230,338 @@ pub struct AddLiquidityAccounts<
     'a5: 'me,
     'a6: 'me,
     'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+    'a17: 'me,
+    'a18: 'me,
+    'a19: 'me,
 > {
-    pub from: &'me AccountInfo<'a0>,
-    pub pool_account: &'me AccountInfo<'a1>,
-    pub pool_sol_reserves: &'me AccountInfo<'a2>,
-    pub lp_mint: &'me AccountInfo<'a3>,
-    pub mint_lp_tokens_to: &'me AccountInfo<'a4>,
-    pub flash_account: &'me AccountInfo<'a5>,
-    pub token_program: &'me AccountInfo<'a6>,
-    pub system_program: &'me AccountInfo<'a7>,
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub destination: &'me AccountInfo<'a2>,
+    pub pool_account: &'me AccountInfo<'a3>,
+    pub pool_sol_reserves: &'me AccountInfo<'a4>,
+    pub fee_account: &'me AccountInfo<'a5>,
+    pub validator_fee_account: &'me AccountInfo<'a6>,
+    pub stake_account_record_account: &'me AccountInfo<'a7>,
+    pub protocol_fee_account: &'me AccountInfo<'a8>,
+    pub protocol_fee_destination: &'me AccountInfo<'a9>,
+    pub clock: &'me AccountInfo<'a10>,
+    pub stake_history: &'me AccountInfo<'a11>,
+    pub stake_program: &'me AccountInfo<'a12>,
+    pub system_program: &'me AccountInfo<'a13>,
+    pub custodian: &'me AccountInfo<'a14>,
+    pub lockup_fee_account: &'me AccountInfo<'a15>,
+    pub pool_pause_account: &'me AccountInfo<'a16>,
+    pub protocol_pause_account: &'me AccountInfo<'a17>,
+    pub pool_config_account: &'me AccountInfo<'a18>,
+    pub withdraw_queue_account: &'me AccountInfo<'a19>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct AddLiquidityKeys {
-    pub from: Pubkey,
+pub struct UnstakeV2Keys {
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
     pub pool_account: Pubkey,
     pub pool_sol_reserves: Pubkey,
-    pub lp_mint: Pubkey,
-    pub mint_lp_tokens_to: Pubkey,
-    pub flash_account: Pubkey,
-    pub token_program: Pubkey,
+    pub fee_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
     pub system_program: Pubkey,
-}
-impl<'me> From<&AddLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>> for AddLiquidityKeys {
-    fn from(accounts: &AddLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+    pub custodian: Pubkey,
+    pub lockup_fee_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me>
+    From<
+        &UnstakeV2Accounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for UnstakeV2Keys
+{
+    fn from(
+        accounts: &UnstakeV2Accounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
         Self {
-            from: *accounts.from.key,
+            unstaker: *accounts.unstaker.key,
+            stake_account: *accounts.stake_account.key,
+            destination: *accounts.destination.key,
             pool_account: *accounts.pool_account.key,
             pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            lp_mint: *accounts.lp_mint.key,
-            mint_lp_tokens_to: *accounts.mint_lp_tokens_to.key,
-            flash_account: *accounts.flash_account.key,
-            token_program: *accounts.token_program.key,
+            fee_account: *accounts.fee_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
             system_program: *accounts.system_program.key,
+            custodian: *accounts.custodian.key,
+            lockup_fee_account: *accounts.lockup_fee_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
         }
     }
 }
-impl From<&AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
-    fn from(keys: &AddLiquidityKeys) -> Self {
+impl From<&UnstakeV2Keys> for [AccountMeta; UNSTAKE_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakeV2Keys) -> Self {
         [
-            AccountMeta::new(keys.from, true),
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.destination, false),
             AccountMeta::new(keys.pool_account, false),
             AccountMeta::new(keys.pool_sol_reserves, false),
-            AccountMeta::new(keys.lp_mint, false),
-            AccountMeta::new(keys.mint_lp_tokens_to, false),
-            AccountMeta::new(keys.flash_account, false),
-            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.validator_fee_account, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
             AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.custodian, false),
+            AccountMeta::new_readonly(keys.lockup_fee_account, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
         ]
     }
 }
-impl<'a> From<&AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]
+impl<'a>
+    From<
+        &UnstakeV2Accounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; UNSTAKE_V2_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(
+        accounts: &UnstakeV2Accounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
         [
-            accounts.from.clone(),
+            accounts.unstaker.clone(),
+            accounts.stake_account.clone(),
+            accounts.destination.clone(),
             accounts.pool_account.clone(),
             accounts.pool_sol_reserves.clone(),
-            accounts.lp_mint.clone(),
-            accounts.mint_lp_tokens_to.clone(),
-            accounts.flash_account.clone(),
-            accounts.token_program.clone(),
+            accounts.fee_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
             accounts.system_program.clone(),
+            accounts.custodian.clone(),
+            accounts.lockup_fee_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.withdraw_queue_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AddLiquidityIxArgs {
-    pub amount: u64,
+pub struct UnstakeV2IxArgs {
+    pub min_lamports_out: u64,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct AddLiquidityIxData<'me>(pub &'me AddLiquidityIxArgs);
-pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
-impl<'me> From<&'me AddLiquidityIxArgs> for AddLiquidityIxData<'me> {
-    fn from(args: &'me AddLiquidityIxArgs) -> Self {
+pub struct UnstakeV2IxData<'me>(pub &'me UnstakeV2IxArgs);
+pub const UNSTAKE_V2_IX_DISCM: [u8; 8] = [88, 53, 113, 37, 217, 40, 248, 41];
+impl<'me> From<&'me UnstakeV2IxArgs> for UnstakeV2IxData<'me> {
+    fn from(args: &'me UnstakeV2IxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for AddLiquidityIxData<'_> {
+impl BorshSerialize for UnstakeV2IxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
+        writer.write_all(&UNSTAKE_V2_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn add_liquidity_ix<K: Into<AddLiquidityKeys>, A: Into<AddLiquidityIxArgs>>(
+pub fn unstake_v2_ix<K: Into<UnstakeV2Keys>, A: Into<UnstakeV2IxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: AddLiquidityKeys = accounts.into();
-    let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: AddLiquidityIxArgs = args.into();
-    let data: AddLiquidityIxData = (&args_full).into();
+    let keys: UnstakeV2Keys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_V2_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakeV2IxArgs = args.into();
+    let data: UnstakeV2IxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn add_liquidity_invoke<'a, A: Into<AddLiquidityIxArgs>>(
-    accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_v2_invoke<'a, A: Into<UnstakeV2IxArgs>>(
+    accounts: &UnstakeV2Accounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
 ) -> ProgramResult {
-    let ix = add_liquidity_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_V2_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn add_liquidity_invoke_signed<'a, A: Into<AddLiquidityIxArgs>>(
-    accounts: &AddLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_v2_invoke_signed<'a, A: Into<UnstakeV2IxArgs>>(
+    accounts: &UnstakeV2Accounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = add_liquidity_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_V2_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 9usize;
+pub const UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN: usize = 21usize;
 #[derive(Copy, Clone, Debug)]
-pub struct RemoveLiquidityAccounts<
+pub struct UnstakeWsolV2Accounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -457,323 +5572,889 @@ pub struct RemoveLiquidityAccounts<
     'a6: 'me,
     'a7: 'me,
     'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+    'a17: 'me,
+    'a18: 'me,
+    'a19: 'me,
+    'a20: 'me,
 > {
-    pub burn_lp_tokens_from_authority: &'me AccountInfo<'a0>,
-    pub to: &'me AccountInfo<'a1>,
-    pub pool_account: &'me AccountInfo<'a2>,
-    pub pool_sol_reserves: &'me AccountInfo<'a3>,
-    pub lp_mint: &'me AccountInfo<'a4>,
-    pub burn_lp_tokens_from: &'me AccountInfo<'a5>,
-    pub flash_account: &'me AccountInfo<'a6>,
-    pub token_program: &'me AccountInfo<'a7>,
-    pub system_program: &'me AccountInfo<'a8>,
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub destination: &'me AccountInfo<'a2>,
+    pub pool_account: &'me AccountInfo<'a3>,
+    pub pool_sol_reserves: &'me AccountInfo<'a4>,
+    pub fee_account: &'me AccountInfo<'a5>,
+    pub validator_fee_account: &'me AccountInfo<'a6>,
+    pub stake_account_record_account: &'me AccountInfo<'a7>,
+    pub protocol_fee_account: &'me AccountInfo<'a8>,
+    pub protocol_fee_destination: &'me AccountInfo<'a9>,
+    pub clock: &'me AccountInfo<'a10>,
+    pub stake_history: &'me AccountInfo<'a11>,
+    pub stake_program: &'me AccountInfo<'a12>,
+    pub system_program: &'me AccountInfo<'a13>,
+    pub token_program: &'me AccountInfo<'a14>,
+    pub custodian: &'me AccountInfo<'a15>,
+    pub lockup_fee_account: &'me AccountInfo<'a16>,
+    pub pool_pause_account: &'me AccountInfo<'a17>,
+    pub protocol_pause_account: &'me AccountInfo<'a18>,
+    pub pool_config_account: &'me AccountInfo<'a19>,
+    pub withdraw_queue_account: &'me AccountInfo<'a20>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct RemoveLiquidityKeys {
-    pub burn_lp_tokens_from_authority: Pubkey,
-    pub to: Pubkey,
+pub struct UnstakeWsolV2Keys {
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
     pub pool_account: Pubkey,
     pub pool_sol_reserves: Pubkey,
-    pub lp_mint: Pubkey,
-    pub burn_lp_tokens_from: Pubkey,
-    pub flash_account: Pubkey,
-    pub token_program: Pubkey,
+    pub fee_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
     pub system_program: Pubkey,
-}
-impl<'me> From<&RemoveLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
-    for RemoveLiquidityKeys
+    pub token_program: Pubkey,
+    pub custodian: Pubkey,
+    pub lockup_fee_account: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me>
+    From<
+        &UnstakeWsolV2Accounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for UnstakeWsolV2Keys
 {
-    fn from(accounts: &RemoveLiquidityAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+    fn from(
+        accounts: &UnstakeWsolV2Accounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
         Self {
-            burn_lp_tokens_from_authority: *accounts.burn_lp_tokens_from_authority.key,
-            to: *accounts.to.key,
+            unstaker: *accounts.unstaker.key,
+            stake_account: *accounts.stake_account.key,
+            destination: *accounts.destination.key,
             pool_account: *accounts.pool_account.key,
             pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            lp_mint: *accounts.lp_mint.key,
-            burn_lp_tokens_from: *accounts.burn_lp_tokens_from.key,
-            flash_account: *accounts.flash_account.key,
-            token_program: *accounts.token_program.key,
+            fee_account: *accounts.fee_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
             system_program: *accounts.system_program.key,
+            token_program: *accounts.token_program.key,
+            custodian: *accounts.custodian.key,
+            lockup_fee_account: *accounts.lockup_fee_account.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
         }
     }
 }
-impl From<&RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
-    fn from(keys: &RemoveLiquidityKeys) -> Self {
+impl From<&UnstakeWsolV2Keys> for [AccountMeta; UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakeWsolV2Keys) -> Self {
         [
-            AccountMeta::new_readonly(keys.burn_lp_tokens_from_authority, true),
-            AccountMeta::new(keys.to, false),
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.destination, false),
             AccountMeta::new(keys.pool_account, false),
             AccountMeta::new(keys.pool_sol_reserves, false),
-            AccountMeta::new(keys.lp_mint, false),
-            AccountMeta::new(keys.burn_lp_tokens_from, false),
-            AccountMeta::new(keys.flash_account, false),
-            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.validator_fee_account, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
             AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.custodian, false),
+            AccountMeta::new_readonly(keys.lockup_fee_account, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
         ]
     }
 }
-impl<'a> From<&RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]
+impl<'a>
+    From<
+        &UnstakeWsolV2Accounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(
+        accounts: &UnstakeWsolV2Accounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
         [
-            accounts.burn_lp_tokens_from_authority.clone(),
-            accounts.to.clone(),
+            accounts.unstaker.clone(),
+            accounts.stake_account.clone(),
+            accounts.destination.clone(),
             accounts.pool_account.clone(),
             accounts.pool_sol_reserves.clone(),
-            accounts.lp_mint.clone(),
-            accounts.burn_lp_tokens_from.clone(),
-            accounts.flash_account.clone(),
-            accounts.token_program.clone(),
+            accounts.fee_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
             accounts.system_program.clone(),
+            accounts.token_program.clone(),
+            accounts.custodian.clone(),
+            accounts.lockup_fee_account.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.withdraw_queue_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct RemoveLiquidityIxArgs {
-    pub amount_lp: u64,
+pub struct UnstakeWsolV2IxArgs {
+    pub min_lamports_out: u64,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct RemoveLiquidityIxData<'me>(pub &'me RemoveLiquidityIxArgs);
-pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
-impl<'me> From<&'me RemoveLiquidityIxArgs> for RemoveLiquidityIxData<'me> {
-    fn from(args: &'me RemoveLiquidityIxArgs) -> Self {
+pub struct UnstakeWsolV2IxData<'me>(pub &'me UnstakeWsolV2IxArgs);
+pub const UNSTAKE_WSOL_V2_IX_DISCM: [u8; 8] = [205, 173, 209, 126, 106, 125, 77, 27];
+impl<'me> From<&'me UnstakeWsolV2IxArgs> for UnstakeWsolV2IxData<'me> {
+    fn from(args: &'me UnstakeWsolV2IxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for RemoveLiquidityIxData<'_> {
+impl BorshSerialize for UnstakeWsolV2IxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
+        writer.write_all(&UNSTAKE_WSOL_V2_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn remove_liquidity_ix<K: Into<RemoveLiquidityKeys>, A: Into<RemoveLiquidityIxArgs>>(
+pub fn unstake_wsol_v2_ix<K: Into<UnstakeWsolV2Keys>, A: Into<UnstakeWsolV2IxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: RemoveLiquidityKeys = accounts.into();
-    let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: RemoveLiquidityIxArgs = args.into();
-    let data: RemoveLiquidityIxData = (&args_full).into();
+    let keys: UnstakeWsolV2Keys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakeWsolV2IxArgs = args.into();
+    let data: UnstakeWsolV2IxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn remove_liquidity_invoke<'a, A: Into<RemoveLiquidityIxArgs>>(
-    accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_wsol_v2_invoke<'a, A: Into<UnstakeWsolV2IxArgs>>(
+    accounts: &UnstakeWsolV2Accounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
 ) -> ProgramResult {
-    let ix = remove_liquidity_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_wsol_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn remove_liquidity_invoke_signed<'a, A: Into<RemoveLiquidityIxArgs>>(
-    accounts: &RemoveLiquidityAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_wsol_v2_invoke_signed<'a, A: Into<UnstakeWsolV2IxArgs>>(
+    accounts: &UnstakeWsolV2Accounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = remove_liquidity_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_wsol_v2_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_V2_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
+pub const UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN: usize = 19usize;
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
-    pub fee_authority: &'me AccountInfo<'a0>,
-    pub pool_account: &'me AccountInfo<'a1>,
-    pub fee_account: &'me AccountInfo<'a2>,
-    pub system_program: &'me AccountInfo<'a3>,
-    pub rent: &'me AccountInfo<'a4>,
+pub struct UnstakePartialAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+    'a15: 'me,
+    'a16: 'me,
+    'a17: 'me,
+    'a18: 'me,
+> {
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub stake_account: &'me AccountInfo<'a1>,
+    pub split_stake_account: &'me AccountInfo<'a2>,
+    pub destination: &'me AccountInfo<'a3>,
+    pub pool_account: &'me AccountInfo<'a4>,
+    pub pool_sol_reserves: &'me AccountInfo<'a5>,
+    pub fee_account: &'me AccountInfo<'a6>,
+    pub validator_fee_account: &'me AccountInfo<'a7>,
+    pub stake_account_record_account: &'me AccountInfo<'a8>,
+    pub protocol_fee_account: &'me AccountInfo<'a9>,
+    pub protocol_fee_destination: &'me AccountInfo<'a10>,
+    pub clock: &'me AccountInfo<'a11>,
+    pub stake_history: &'me AccountInfo<'a12>,
+    pub stake_program: &'me AccountInfo<'a13>,
+    pub system_program: &'me AccountInfo<'a14>,
+    pub pool_pause_account: &'me AccountInfo<'a15>,
+    pub protocol_pause_account: &'me AccountInfo<'a16>,
+    pub pool_config_account: &'me AccountInfo<'a17>,
+    pub withdraw_queue_account: &'me AccountInfo<'a18>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeKeys {
-    pub fee_authority: Pubkey,
+pub struct UnstakePartialKeys {
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub split_stake_account: Pubkey,
+    pub destination: Pubkey,
     pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
     pub fee_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
     pub system_program: Pubkey,
-    pub rent: Pubkey,
-}
-impl<'me> From<&SetFeeAccounts<'me, '_, '_, '_, '_, '_>> for SetFeeKeys {
-    fn from(accounts: &SetFeeAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me>
+    From<
+        &UnstakePartialAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    > for UnstakePartialKeys
+{
+    fn from(
+        accounts: &UnstakePartialAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
         Self {
-            fee_authority: *accounts.fee_authority.key,
+            unstaker: *accounts.unstaker.key,
+            stake_account: *accounts.stake_account.key,
+            split_stake_account: *accounts.split_stake_account.key,
+            destination: *accounts.destination.key,
             pool_account: *accounts.pool_account.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
             fee_account: *accounts.fee_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            stake_account_record_account: *accounts.stake_account_record_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
             system_program: *accounts.system_program.key,
-            rent: *accounts.rent.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
         }
     }
 }
-impl From<&SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
-    fn from(keys: &SetFeeKeys) -> Self {
+impl From<&UnstakePartialKeys> for [AccountMeta; UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakePartialKeys) -> Self {
         [
-            AccountMeta::new_readonly(keys.fee_authority, true),
-            AccountMeta::new_readonly(keys.pool_account, false),
-            AccountMeta::new(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.split_stake_account, false),
+            AccountMeta::new(keys.destination, false),
+            AccountMeta::new(keys.pool_account, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.validator_fee_account, false),
+            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
             AccountMeta::new_readonly(keys.system_program, false),
-            AccountMeta::new_readonly(keys.rent, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
         ]
     }
 }
-impl<'a> From<&SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN]
+impl<'a>
+    From<
+        &UnstakePartialAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    > for [AccountInfo<'a>; UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(
+        accounts: &UnstakePartialAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
         [
-            accounts.fee_authority.clone(),
+            accounts.unstaker.clone(),
+            accounts.stake_account.clone(),
+            accounts.split_stake_account.clone(),
+            accounts.destination.clone(),
             accounts.pool_account.clone(),
+            accounts.pool_sol_reserves.clone(),
             accounts.fee_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.stake_account_record_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
             accounts.system_program.clone(),
-            accounts.rent.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.withdraw_queue_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetFeeIxArgs {
-    pub fee: Fee,
+pub struct UnstakePartialIxArgs {
+    pub lamports: u64,
+    pub min_lamports_out: u64,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeIxData<'me>(pub &'me SetFeeIxArgs);
-pub const SET_FEE_IX_DISCM: [u8; 8] = [18, 154, 24, 18, 237, 214, 19, 80];
-impl<'me> From<&'me SetFeeIxArgs> for SetFeeIxData<'me> {
-    fn from(args: &'me SetFeeIxArgs) -> Self {
+pub struct UnstakePartialIxData<'me>(pub &'me UnstakePartialIxArgs);
+pub const UNSTAKE_PARTIAL_IX_DISCM: [u8; 8] = [91, 27, 226, 57, 112, 31, 78, 184];
+impl<'me> From<&'me UnstakePartialIxArgs> for UnstakePartialIxData<'me> {
+    fn from(args: &'me UnstakePartialIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for SetFeeIxData<'_> {
+impl BorshSerialize for UnstakePartialIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&SET_FEE_IX_DISCM)?;
+        writer.write_all(&UNSTAKE_PARTIAL_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn set_fee_ix<K: Into<SetFeeKeys>, A: Into<SetFeeIxArgs>>(
+pub fn unstake_partial_ix<K: Into<UnstakePartialKeys>, A: Into<UnstakePartialIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: SetFeeKeys = accounts.into();
-    let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: SetFeeIxArgs = args.into();
-    let data: SetFeeIxData = (&args_full).into();
+    let keys: UnstakePartialKeys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakePartialIxArgs = args.into();
+    let data: UnstakePartialIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn set_fee_invoke<'a, A: Into<SetFeeIxArgs>>(
-    accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_partial_invoke<'a, A: Into<UnstakePartialIxArgs>>(
+    accounts: &UnstakePartialAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
 ) -> ProgramResult {
-    let ix = set_fee_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_partial_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn set_fee_invoke_signed<'a, A: Into<SetFeeIxArgs>>(
-    accounts: &SetFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+pub fn unstake_partial_invoke_signed<'a, A: Into<UnstakePartialIxArgs>>(
+    accounts: &UnstakePartialAccounts<
+        '_,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+        'a,
+    >,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = set_fee_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_partial_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_PARTIAL_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3usize;
+pub const UNSTAKE_MULTI_IX_ACCOUNTS_LEN: usize = 15usize;
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeAuthorityAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me> {
-    pub fee_authority: &'me AccountInfo<'a0>,
-    pub pool_account: &'me AccountInfo<'a1>,
-    pub new_fee_authority: &'me AccountInfo<'a2>,
+pub struct UnstakeMultiAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
+    'a9: 'me,
+    'a10: 'me,
+    'a11: 'me,
+    'a12: 'me,
+    'a13: 'me,
+    'a14: 'me,
+> {
+    pub unstaker: &'me AccountInfo<'a0>,
+    pub destination: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub pool_sol_reserves: &'me AccountInfo<'a3>,
+    pub fee_account: &'me AccountInfo<'a4>,
+    pub protocol_fee_account: &'me AccountInfo<'a5>,
+    pub protocol_fee_destination: &'me AccountInfo<'a6>,
+    pub clock: &'me AccountInfo<'a7>,
+    pub stake_history: &'me AccountInfo<'a8>,
+    pub stake_program: &'me AccountInfo<'a9>,
+    pub system_program: &'me AccountInfo<'a10>,
+    pub pool_pause_account: &'me AccountInfo<'a11>,
+    pub protocol_pause_account: &'me AccountInfo<'a12>,
+    pub pool_config_account: &'me AccountInfo<'a13>,
+    pub withdraw_queue_account: &'me AccountInfo<'a14>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeAuthorityKeys {
-    pub fee_authority: Pubkey,
+pub struct UnstakeMultiKeys {
+    pub unstaker: Pubkey,
+    pub destination: Pubkey,
     pub pool_account: Pubkey,
-    pub new_fee_authority: Pubkey,
-}
-impl<'me> From<&SetFeeAuthorityAccounts<'me, '_, '_, '_>> for SetFeeAuthorityKeys {
-    fn from(accounts: &SetFeeAuthorityAccounts<'me, '_, '_, '_>) -> Self {
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
+    pub system_program: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
+    pub pool_config_account: Pubkey,
+    pub withdraw_queue_account: Pubkey,
+}
+impl<'me>
+    From<&UnstakeMultiAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for UnstakeMultiKeys
+{
+    fn from(
+        accounts: &UnstakeMultiAccounts<
+            'me,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+            '_,
+        >,
+    ) -> Self {
         Self {
-            fee_authority: *accounts.fee_authority.key,
+            unstaker: *accounts.unstaker.key,
+            destination: *accounts.destination.key,
             pool_account: *accounts.pool_account.key,
-            new_fee_authority: *accounts.new_fee_authority.key,
+            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            fee_account: *accounts.fee_account.key,
+            protocol_fee_account: *accounts.protocol_fee_account.key,
+            protocol_fee_destination: *accounts.protocol_fee_destination.key,
+            clock: *accounts.clock.key,
+            stake_history: *accounts.stake_history.key,
+            stake_program: *accounts.stake_program.key,
+            system_program: *accounts.system_program.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
+            pool_config_account: *accounts.pool_config_account.key,
+            withdraw_queue_account: *accounts.withdraw_queue_account.key,
         }
     }
 }
-impl From<&SetFeeAuthorityKeys> for [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
-    fn from(keys: &SetFeeAuthorityKeys) -> Self {
+impl From<&UnstakeMultiKeys> for [AccountMeta; UNSTAKE_MULTI_IX_ACCOUNTS_LEN] {
+    fn from(keys: &UnstakeMultiKeys) -> Self {
         [
-            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.unstaker, true),
+            AccountMeta::new(keys.destination, false),
             AccountMeta::new(keys.pool_account, false),
-            AccountMeta::new_readonly(keys.new_fee_authority, false),
+            AccountMeta::new(keys.pool_sol_reserves, false),
+            AccountMeta::new_readonly(keys.fee_account, false),
+            AccountMeta::new_readonly(keys.protocol_fee_account, false),
+            AccountMeta::new(keys.protocol_fee_destination, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new_readonly(keys.stake_history, false),
+            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
+            AccountMeta::new(keys.pool_config_account, false),
+            AccountMeta::new_readonly(keys.withdraw_queue_account, false),
         ]
     }
 }
-impl<'a> From<&SetFeeAuthorityAccounts<'_, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
+impl<'a> From<&UnstakeMultiAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; UNSTAKE_MULTI_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &SetFeeAuthorityAccounts<'_, 'a, 'a, 'a>) -> Self {
+    fn from(
+        accounts: &UnstakeMultiAccounts<
+            '_,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+            'a,
+        >,
+    ) -> Self {
         [
-            accounts.fee_authority.clone(),
+            accounts.unstaker.clone(),
+            accounts.destination.clone(),
             accounts.pool_account.clone(),
-            accounts.new_fee_authority.clone(),
+            accounts.pool_sol_reserves.clone(),
+            accounts.fee_account.clone(),
+            accounts.protocol_fee_account.clone(),
+            accounts.protocol_fee_destination.clone(),
+            accounts.clock.clone(),
+            accounts.stake_history.clone(),
+            accounts.stake_program.clone(),
+            accounts.system_program.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
+            accounts.pool_config_account.clone(),
+            accounts.withdraw_queue_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetFeeAuthorityIxArgs {}
+pub struct UnstakeMultiIxArgs {
+    pub min_lamports_out: u64,
+}
 #[derive(Copy, Clone, Debug)]
-pub struct SetFeeAuthorityIxData<'me>(pub &'me SetFeeAuthorityIxArgs);
-pub const SET_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [31, 1, 50, 87, 237, 101, 97, 132];
-impl<'me> From<&'me SetFeeAuthorityIxArgs> for SetFeeAuthorityIxData<'me> {
-    fn from(args: &'me SetFeeAuthorityIxArgs) -> Self {
+pub struct UnstakeMultiIxData<'me>(pub &'me UnstakeMultiIxArgs);
+pub const UNSTAKE_MULTI_IX_DISCM: [u8; 8] = [114, 146, 180, 141, 132, 129, 238, 229];
+impl<'me> From<&'me UnstakeMultiIxArgs> for UnstakeMultiIxData<'me> {
+    fn from(args: &'me UnstakeMultiIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for SetFeeAuthorityIxData<'_> {
+impl BorshSerialize for UnstakeMultiIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM)?;
+        writer.write_all(&UNSTAKE_MULTI_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn set_fee_authority_ix<K: Into<SetFeeAuthorityKeys>, A: Into<SetFeeAuthorityIxArgs>>(
+pub fn unstake_multi_ix<K: Into<UnstakeMultiKeys>, A: Into<UnstakeMultiIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: SetFeeAuthorityKeys = accounts.into();
-    let metas: [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: SetFeeAuthorityIxArgs = args.into();
-    let data: SetFeeAuthorityIxData = (&args_full).into();
+    let keys: UnstakeMultiKeys = accounts.into();
+    let metas: [AccountMeta; UNSTAKE_MULTI_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: UnstakeMultiIxArgs = args.into();
+    let data: UnstakeMultiIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn set_fee_authority_invoke<'a, A: Into<SetFeeAuthorityIxArgs>>(
-    accounts: &SetFeeAuthorityAccounts<'_, 'a, 'a, 'a>,
+pub fn unstake_multi_invoke<'a, A: Into<UnstakeMultiIxArgs>>(
+    accounts: &UnstakeMultiAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = set_fee_authority_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_multi_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_MULTI_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn set_fee_authority_invoke_signed<'a, A: Into<SetFeeAuthorityIxArgs>>(
-    accounts: &SetFeeAuthorityAccounts<'_, 'a, 'a, 'a>,
+pub fn unstake_multi_invoke_signed<'a, A: Into<UnstakeMultiIxArgs>>(
+    accounts: &UnstakeMultiAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = set_fee_authority_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = unstake_multi_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; UNSTAKE_MULTI_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN: usize = 9usize;
+pub const SET_LOCKUP_FEE_IX_ACCOUNTS_LEN: usize = 8usize;
 #[derive(Copy, Clone, Debug)]
-pub struct SetLpTokenMetadataAccounts<
+pub struct SetLockupFeeAccounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -783,242 +6464,334 @@ pub struct SetLpTokenMetadataAccounts<
     'a5: 'me,
     'a6: 'me,
     'a7: 'me,
-    'a8: 'me,
 > {
     pub payer: &'me AccountInfo<'a0>,
     pub fee_authority: &'me AccountInfo<'a1>,
     pub pool_account: &'me AccountInfo<'a2>,
-    pub pool_sol_reserves: &'me AccountInfo<'a3>,
-    pub lp_mint: &'me AccountInfo<'a4>,
-    pub metadata: &'me AccountInfo<'a5>,
-    pub metadata_program: &'me AccountInfo<'a6>,
-    pub system_program: &'me AccountInfo<'a7>,
-    pub rent: &'me AccountInfo<'a8>,
+    pub lockup_fee_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+    pub fee_delay_account: &'me AccountInfo<'a5>,
+    pub clock: &'me AccountInfo<'a6>,
+    pub pending_lockup_fee_account: &'me AccountInfo<'a7>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetLpTokenMetadataKeys {
+pub struct SetLockupFeeKeys {
     pub payer: Pubkey,
     pub fee_authority: Pubkey,
     pub pool_account: Pubkey,
-    pub pool_sol_reserves: Pubkey,
-    pub lp_mint: Pubkey,
-    pub metadata: Pubkey,
-    pub metadata_program: Pubkey,
+    pub lockup_fee_account: Pubkey,
     pub system_program: Pubkey,
-    pub rent: Pubkey,
+    pub fee_delay_account: Pubkey,
+    pub clock: Pubkey,
+    pub pending_lockup_fee_account: Pubkey,
 }
-impl<'me> From<&SetLpTokenMetadataAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
-    for SetLpTokenMetadataKeys
-{
-    fn from(
-        accounts: &SetLpTokenMetadataAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
-    ) -> Self {
+impl<'me> From<&SetLockupFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>> for SetLockupFeeKeys {
+    fn from(accounts: &SetLockupFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
         Self {
             payer: *accounts.payer.key,
             fee_authority: *accounts.fee_authority.key,
             pool_account: *accounts.pool_account.key,
-            pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            lp_mint: *accounts.lp_mint.key,
-            metadata: *accounts.metadata.key,
-            metadata_program: *accounts.metadata_program.key,
+            lockup_fee_account: *accounts.lockup_fee_account.key,
             system_program: *accounts.system_program.key,
-            rent: *accounts.rent.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
+            clock: *accounts.clock.key,
+            pending_lockup_fee_account: *accounts.pending_lockup_fee_account.key,
         }
     }
 }
-impl From<&SetLpTokenMetadataKeys> for [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] {
-    fn from(keys: &SetLpTokenMetadataKeys) -> Self {
+impl From<&SetLockupFeeKeys> for [AccountMeta; SET_LOCKUP_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetLockupFeeKeys) -> Self {
         [
             AccountMeta::new(keys.payer, true),
             AccountMeta::new_readonly(keys.fee_authority, true),
             AccountMeta::new_readonly(keys.pool_account, false),
-            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
-            AccountMeta::new_readonly(keys.lp_mint, false),
-            AccountMeta::new(keys.metadata, false),
-            AccountMeta::new_readonly(keys.metadata_program, false),
+            AccountMeta::new(keys.lockup_fee_account, false),
             AccountMeta::new_readonly(keys.system_program, false),
-            AccountMeta::new_readonly(keys.rent, false),
+            AccountMeta::new_readonly(keys.fee_delay_account, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new(keys.pending_lockup_fee_account, false),
         ]
     }
 }
-impl<'a> From<&SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN]
+impl<'a> From<&SetLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_LOCKUP_FEE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(accounts: &SetLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
         [
             accounts.payer.clone(),
             accounts.fee_authority.clone(),
             accounts.pool_account.clone(),
-            accounts.pool_sol_reserves.clone(),
-            accounts.lp_mint.clone(),
-            accounts.metadata.clone(),
-            accounts.metadata_program.clone(),
+            accounts.lockup_fee_account.clone(),
             accounts.system_program.clone(),
-            accounts.rent.clone(),
+            accounts.fee_delay_account.clone(),
+            accounts.clock.clone(),
+            accounts.pending_lockup_fee_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SetLpTokenMetadataIxArgs {
-    pub data: DataV2LpToken,
+pub struct SetLockupFeeIxArgs {
+    pub lockup_fee: LockupFee,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct SetLpTokenMetadataIxData<'me>(pub &'me SetLpTokenMetadataIxArgs);
-pub const SET_LP_TOKEN_METADATA_IX_DISCM: [u8; 8] = [71, 73, 56, 155, 202, 142, 100, 150];
-impl<'me> From<&'me SetLpTokenMetadataIxArgs> for SetLpTokenMetadataIxData<'me> {
-    fn from(args: &'me SetLpTokenMetadataIxArgs) -> Self {
+pub struct SetLockupFeeIxData<'me>(pub &'me SetLockupFeeIxArgs);
+pub const SET_LOCKUP_FEE_IX_DISCM: [u8; 8] = [194, 112, 75, 167, 247, 144, 189, 224];
+impl<'me> From<&'me SetLockupFeeIxArgs> for SetLockupFeeIxData<'me> {
+    fn from(args: &'me SetLockupFeeIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for SetLpTokenMetadataIxData<'_> {
+impl BorshSerialize for SetLockupFeeIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&SET_LP_TOKEN_METADATA_IX_DISCM)?;
+        writer.write_all(&SET_LOCKUP_FEE_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn set_lp_token_metadata_ix<
-    K: Into<SetLpTokenMetadataKeys>,
-    A: Into<SetLpTokenMetadataIxArgs>,
->(
+pub fn set_lockup_fee_ix<K: Into<SetLockupFeeKeys>, A: Into<SetLockupFeeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: SetLpTokenMetadataKeys = accounts.into();
-    let metas: [AccountMeta; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: SetLpTokenMetadataIxArgs = args.into();
-    let data: SetLpTokenMetadataIxData = (&args_full).into();
+    let keys: SetLockupFeeKeys = accounts.into();
+    let metas: [AccountMeta; SET_LOCKUP_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetLockupFeeIxArgs = args.into();
+    let data: SetLockupFeeIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn set_lp_token_metadata_invoke<'a, A: Into<SetLpTokenMetadataIxArgs>>(
-    accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn set_lockup_fee_invoke<'a, A: Into<SetLockupFeeIxArgs>>(
+    accounts: &SetLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = set_lp_token_metadata_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = set_lockup_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_LOCKUP_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn set_lp_token_metadata_invoke_signed<'a, A: Into<SetLpTokenMetadataIxArgs>>(
-    accounts: &SetLpTokenMetadataAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn set_lockup_fee_invoke_signed<'a, A: Into<SetLockupFeeIxArgs>>(
+    accounts: &SetLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = set_lp_token_metadata_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; SET_LP_TOKEN_METADATA_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = set_lockup_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_LOCKUP_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5usize;
+pub const APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
 #[derive(Copy, Clone, Debug)]
-pub struct DeactivateStakeAccountAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
-    pub stake_account: &'me AccountInfo<'a0>,
+pub struct ApplyPendingLockupFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub fee_authority: &'me AccountInfo<'a0>,
     pub pool_account: &'me AccountInfo<'a1>,
-    pub pool_sol_reserves: &'me AccountInfo<'a2>,
-    pub clock: &'me AccountInfo<'a3>,
-    pub stake_program: &'me AccountInfo<'a4>,
+    pub lockup_fee_account: &'me AccountInfo<'a2>,
+    pub pending_lockup_fee_account: &'me AccountInfo<'a3>,
+    pub clock: &'me AccountInfo<'a4>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct DeactivateStakeAccountKeys {
-    pub stake_account: Pubkey,
+pub struct ApplyPendingLockupFeeKeys {
+    pub fee_authority: Pubkey,
     pub pool_account: Pubkey,
-    pub pool_sol_reserves: Pubkey,
+    pub lockup_fee_account: Pubkey,
+    pub pending_lockup_fee_account: Pubkey,
     pub clock: Pubkey,
-    pub stake_program: Pubkey,
 }
-impl<'me> From<&DeactivateStakeAccountAccounts<'me, '_, '_, '_, '_, '_>>
-    for DeactivateStakeAccountKeys
+impl<'me> From<&ApplyPendingLockupFeeAccounts<'me, '_, '_, '_, '_, '_>>
+    for ApplyPendingLockupFeeKeys
 {
-    fn from(accounts: &DeactivateStakeAccountAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+    fn from(accounts: &ApplyPendingLockupFeeAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
         Self {
-            stake_account: *accounts.stake_account.key,
+            fee_authority: *accounts.fee_authority.key,
             pool_account: *accounts.pool_account.key,
-            pool_sol_reserves: *accounts.pool_sol_reserves.key,
+            lockup_fee_account: *accounts.lockup_fee_account.key,
+            pending_lockup_fee_account: *accounts.pending_lockup_fee_account.key,
             clock: *accounts.clock.key,
-            stake_program: *accounts.stake_program.key,
         }
     }
 }
-impl From<&DeactivateStakeAccountKeys> for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
-    fn from(keys: &DeactivateStakeAccountKeys) -> Self {
+impl From<&ApplyPendingLockupFeeKeys> for [AccountMeta; APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &ApplyPendingLockupFeeKeys) -> Self {
         [
-            AccountMeta::new(keys.stake_account, false),
+            AccountMeta::new(keys.fee_authority, false),
             AccountMeta::new_readonly(keys.pool_account, false),
-            AccountMeta::new_readonly(keys.pool_sol_reserves, false),
+            AccountMeta::new(keys.lockup_fee_account, false),
+            AccountMeta::new(keys.pending_lockup_fee_account, false),
             AccountMeta::new_readonly(keys.clock, false),
-            AccountMeta::new_readonly(keys.stake_program, false),
         ]
     }
 }
-impl<'a> From<&DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
+impl<'a> From<&ApplyPendingLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(accounts: &ApplyPendingLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
         [
-            accounts.stake_account.clone(),
+            accounts.fee_authority.clone(),
             accounts.pool_account.clone(),
-            accounts.pool_sol_reserves.clone(),
+            accounts.lockup_fee_account.clone(),
+            accounts.pending_lockup_fee_account.clone(),
             accounts.clock.clone(),
-            accounts.stake_program.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct DeactivateStakeAccountIxArgs {}
+pub struct ApplyPendingLockupFeeIxArgs {}
 #[derive(Copy, Clone, Debug)]
-pub struct DeactivateStakeAccountIxData<'me>(pub &'me DeactivateStakeAccountIxArgs);
-pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [217, 64, 76, 16, 216, 77, 123, 226];
-impl<'me> From<&'me DeactivateStakeAccountIxArgs> for DeactivateStakeAccountIxData<'me> {
-    fn from(args: &'me DeactivateStakeAccountIxArgs) -> Self {
+pub struct ApplyPendingLockupFeeIxData<'me>(pub &'me ApplyPendingLockupFeeIxArgs);
+pub const APPLY_PENDING_LOCKUP_FEE_IX_DISCM: [u8; 8] = [13, 123, 225, 218, 52, 166, 15, 131];
+impl<'me> From<&'me ApplyPendingLockupFeeIxArgs> for ApplyPendingLockupFeeIxData<'me> {
+    fn from(args: &'me ApplyPendingLockupFeeIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for DeactivateStakeAccountIxData<'_> {
+impl BorshSerialize for ApplyPendingLockupFeeIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)?;
+        writer.write_all(&APPLY_PENDING_LOCKUP_FEE_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn deactivate_stake_account_ix<
-    K: Into<DeactivateStakeAccountKeys>,
-    A: Into<DeactivateStakeAccountIxArgs>,
+pub fn apply_pending_lockup_fee_ix<
+    K: Into<ApplyPendingLockupFeeKeys>,
+    A: Into<ApplyPendingLockupFeeIxArgs>,
 >(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: DeactivateStakeAccountKeys = accounts.into();
-    let metas: [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: DeactivateStakeAccountIxArgs = args.into();
-    let data: DeactivateStakeAccountIxData = (&args_full).into();
+    let keys: ApplyPendingLockupFeeKeys = accounts.into();
+    let metas: [AccountMeta; APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ApplyPendingLockupFeeIxArgs = args.into();
+    let data: ApplyPendingLockupFeeIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn deactivate_stake_account_invoke<'a, A: Into<DeactivateStakeAccountIxArgs>>(
-    accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+pub fn apply_pending_lockup_fee_invoke<'a, A: Into<ApplyPendingLockupFeeIxArgs>>(
+    accounts: &ApplyPendingLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = deactivate_stake_account_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = apply_pending_lockup_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn deactivate_stake_account_invoke_signed<'a, A: Into<DeactivateStakeAccountIxArgs>>(
-    accounts: &DeactivateStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+pub fn apply_pending_lockup_fee_invoke_signed<'a, A: Into<ApplyPendingLockupFeeIxArgs>>(
+    accounts: &ApplyPendingLockupFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = deactivate_stake_account_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = apply_pending_lockup_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_LOCKUP_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7usize;
+pub const SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
 #[derive(Copy, Clone, Debug)]
-pub struct ReclaimStakeAccountAccounts<
+pub struct SetStakeDepositFeeAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me> {
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub stake_deposit_fee_account: &'me AccountInfo<'a3>,
+    pub system_program: &'me AccountInfo<'a4>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetStakeDepositFeeKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub stake_deposit_fee_account: Pubkey,
+    pub system_program: Pubkey,
+}
+impl<'me> From<&SetStakeDepositFeeAccounts<'me, '_, '_, '_, '_, '_>> for SetStakeDepositFeeKeys {
+    fn from(accounts: &SetStakeDepositFeeAccounts<'me, '_, '_, '_, '_, '_>) -> Self {
+        Self {
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
+            pool_account: *accounts.pool_account.key,
+            stake_deposit_fee_account: *accounts.stake_deposit_fee_account.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<&SetStakeDepositFeeKeys> for [AccountMeta; SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetStakeDepositFeeKeys) -> Self {
+        [
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new(keys.stake_deposit_fee_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+        ]
+    }
+}
+impl<'a> From<&SetStakeDepositFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: &SetStakeDepositFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>) -> Self {
+        [
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
+            accounts.pool_account.clone(),
+            accounts.stake_deposit_fee_account.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetStakeDepositFeeIxArgs {
+    pub stake_deposit_fee: StakeDepositFee,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetStakeDepositFeeIxData<'me>(pub &'me SetStakeDepositFeeIxArgs);
+pub const SET_STAKE_DEPOSIT_FEE_IX_DISCM: [u8; 8] = [167, 246, 246, 91, 72, 126, 156, 138];
+impl<'me> From<&'me SetStakeDepositFeeIxArgs> for SetStakeDepositFeeIxData<'me> {
+    fn from(args: &'me SetStakeDepositFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl BorshSerialize for SetStakeDepositFeeIxData<'_> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&SET_STAKE_DEPOSIT_FEE_IX_DISCM)?;
+        self.0.serialize(writer)
+    }
+}
+pub fn set_stake_deposit_fee_ix<
+    K: Into<SetStakeDepositFeeKeys>,
+    A: Into<SetStakeDepositFeeIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    let keys: SetStakeDepositFeeKeys = accounts.into();
+    let metas: [AccountMeta; SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetStakeDepositFeeIxArgs = args.into();
+    let data: SetStakeDepositFeeIxData = (&args_full).into();
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_stake_deposit_fee_invoke<'a, A: Into<SetStakeDepositFeeIxArgs>>(
+    accounts: &SetStakeDepositFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_stake_deposit_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_stake_deposit_fee_invoke_signed<'a, A: Into<SetStakeDepositFeeIxArgs>>(
+    accounts: &SetStakeDepositFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_stake_deposit_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_STAKE_DEPOSIT_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub const SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN: usize = 9usize;
+#[derive(Copy, Clone, Debug)]
+pub struct SetValidatorFeeAccounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -1027,122 +6800,133 @@ pub struct ReclaimStakeAccountAccounts<
     'a4: 'me,
     'a5: 'me,
     'a6: 'me,
+    'a7: 'me,
+    'a8: 'me,
 > {
-    pub stake_account: &'me AccountInfo<'a0>,
-    pub pool_account: &'me AccountInfo<'a1>,
-    pub pool_sol_reserves: &'me AccountInfo<'a2>,
-    pub stake_account_record_account: &'me AccountInfo<'a3>,
-    pub clock: &'me AccountInfo<'a4>,
-    pub stake_history: &'me AccountInfo<'a5>,
-    pub stake_program: &'me AccountInfo<'a6>,
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub vote_account: &'me AccountInfo<'a3>,
+    pub validator_fee_account: &'me AccountInfo<'a4>,
+    pub system_program: &'me AccountInfo<'a5>,
+    pub fee_delay_account: &'me AccountInfo<'a6>,
+    pub clock: &'me AccountInfo<'a7>,
+    pub pending_validator_fee_account: &'me AccountInfo<'a8>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct ReclaimStakeAccountKeys {
-    pub stake_account: Pubkey,
+pub struct SetValidatorFeeKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
     pub pool_account: Pubkey,
-    pub pool_sol_reserves: Pubkey,
-    pub stake_account_record_account: Pubkey,
+    pub vote_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub system_program: Pubkey,
+    pub fee_delay_account: Pubkey,
     pub clock: Pubkey,
-    pub stake_history: Pubkey,
-    pub stake_program: Pubkey,
+    pub pending_validator_fee_account: Pubkey,
 }
-impl<'me> From<&ReclaimStakeAccountAccounts<'me, '_, '_, '_, '_, '_, '_, '_>>
-    for ReclaimStakeAccountKeys
+impl<'me> From<&SetValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for SetValidatorFeeKeys
 {
-    fn from(accounts: &ReclaimStakeAccountAccounts<'me, '_, '_, '_, '_, '_, '_, '_>) -> Self {
+    fn from(accounts: &SetValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
         Self {
-            stake_account: *accounts.stake_account.key,
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
             pool_account: *accounts.pool_account.key,
-            pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            stake_account_record_account: *accounts.stake_account_record_account.key,
+            vote_account: *accounts.vote_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            system_program: *accounts.system_program.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
             clock: *accounts.clock.key,
-            stake_history: *accounts.stake_history.key,
-            stake_program: *accounts.stake_program.key,
+            pending_validator_fee_account: *accounts.pending_validator_fee_account.key,
         }
     }
 }
-impl From<&ReclaimStakeAccountKeys> for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
-    fn from(keys: &ReclaimStakeAccountKeys) -> Self {
+impl From<&SetValidatorFeeKeys> for [AccountMeta; SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &SetValidatorFeeKeys) -> Self {
         [
-            AccountMeta::new(keys.stake_account, false),
-            AccountMeta::new(keys.pool_account, false),
-            AccountMeta::new(keys.pool_sol_reserves, false),
-            AccountMeta::new(keys.stake_account_record_account, false),
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.vote_account, false),
+            AccountMeta::new(keys.validator_fee_account, false),
+            AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.fee_delay_account, false),
             AccountMeta::new_readonly(keys.clock, false),
-            AccountMeta::new_readonly(keys.stake_history, false),
-            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new(keys.pending_validator_fee_account, false),
         ]
     }
 }
-impl<'a> From<&ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
+impl<'a> From<&SetValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(accounts: &SetValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
         [
-            accounts.stake_account.clone(),
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
             accounts.pool_account.clone(),
-            accounts.pool_sol_reserves.clone(),
-            accounts.stake_account_record_account.clone(),
+            accounts.vote_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.system_program.clone(),
+            accounts.fee_delay_account.clone(),
             accounts.clock.clone(),
-            accounts.stake_history.clone(),
-            accounts.stake_program.clone(),
+            accounts.pending_validator_fee_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ReclaimStakeAccountIxArgs {}
+pub struct SetValidatorFeeIxArgs {
+    pub validator_fee: ValidatorFee,
+}
 #[derive(Copy, Clone, Debug)]
-pub struct ReclaimStakeAccountIxData<'me>(pub &'me ReclaimStakeAccountIxArgs);
-pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [47, 127, 90, 221, 10, 160, 183, 117];
-impl<'me> From<&'me ReclaimStakeAccountIxArgs> for ReclaimStakeAccountIxData<'me> {
-    fn from(args: &'me ReclaimStakeAccountIxArgs) -> Self {
+pub struct SetValidatorFeeIxData<'me>(pub &'me SetValidatorFeeIxArgs);
+pub const SET_VALIDATOR_FEE_IX_DISCM: [u8; 8] = [54, 176, 0, 62, 252, 22, 13, 88];
+impl<'me> From<&'me SetValidatorFeeIxArgs> for SetValidatorFeeIxData<'me> {
+    fn from(args: &'me SetValidatorFeeIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for ReclaimStakeAccountIxData<'_> {
+impl BorshSerialize for SetValidatorFeeIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)?;
+        writer.write_all(&SET_VALIDATOR_FEE_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn reclaim_stake_account_ix<
-    K: Into<ReclaimStakeAccountKeys>,
-    A: Into<ReclaimStakeAccountIxArgs>,
->(
+pub fn set_validator_fee_ix<K: Into<SetValidatorFeeKeys>, A: Into<SetValidatorFeeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: ReclaimStakeAccountKeys = accounts.into();
-    let metas: [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: ReclaimStakeAccountIxArgs = args.into();
-    let data: ReclaimStakeAccountIxData = (&args_full).into();
+    let keys: SetValidatorFeeKeys = accounts.into();
+    let metas: [AccountMeta; SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: SetValidatorFeeIxArgs = args.into();
+    let data: SetValidatorFeeIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn reclaim_stake_account_invoke<'a, A: Into<ReclaimStakeAccountIxArgs>>(
-    accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn set_validator_fee_invoke<'a, A: Into<SetValidatorFeeIxArgs>>(
+    accounts: &SetValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = reclaim_stake_account_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = set_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn reclaim_stake_account_invoke_signed<'a, A: Into<ReclaimStakeAccountIxArgs>>(
-    accounts: &ReclaimStakeAccountAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn set_validator_fee_invoke_signed<'a, A: Into<SetValidatorFeeIxArgs>>(
+    accounts: &SetValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = reclaim_stake_account_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = set_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; SET_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const UNSTAKE_IX_ACCOUNTS_LEN: usize = 12usize;
+pub const REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN: usize = 10usize;
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeAccounts<
+pub struct RemoveValidatorFeeAccounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -1154,150 +6938,141 @@ pub struct UnstakeAccounts<
     'a7: 'me,
     'a8: 'me,
     'a9: 'me,
-    'a10: 'me,
-    'a11: 'me,
 > {
-    pub unstaker: &'me AccountInfo<'a0>,
-    pub stake_account: &'me AccountInfo<'a1>,
-    pub destination: &'me AccountInfo<'a2>,
-    pub pool_account: &'me AccountInfo<'a3>,
-    pub pool_sol_reserves: &'me AccountInfo<'a4>,
-    pub fee_account: &'me AccountInfo<'a5>,
-    pub stake_account_record_account: &'me AccountInfo<'a6>,
-    pub protocol_fee_account: &'me AccountInfo<'a7>,
-    pub protocol_fee_destination: &'me AccountInfo<'a8>,
-    pub clock: &'me AccountInfo<'a9>,
-    pub stake_program: &'me AccountInfo<'a10>,
-    pub system_program: &'me AccountInfo<'a11>,
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub vote_account: &'me AccountInfo<'a3>,
+    pub validator_fee_account: &'me AccountInfo<'a4>,
+    pub refund_rent_to: &'me AccountInfo<'a5>,
+    pub fee_delay_account: &'me AccountInfo<'a6>,
+    pub system_program: &'me AccountInfo<'a7>,
+    pub clock: &'me AccountInfo<'a8>,
+    pub pending_validator_fee_account: &'me AccountInfo<'a9>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeKeys {
-    pub unstaker: Pubkey,
-    pub stake_account: Pubkey,
-    pub destination: Pubkey,
+pub struct RemoveValidatorFeeKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
     pub pool_account: Pubkey,
-    pub pool_sol_reserves: Pubkey,
-    pub fee_account: Pubkey,
-    pub stake_account_record_account: Pubkey,
-    pub protocol_fee_account: Pubkey,
-    pub protocol_fee_destination: Pubkey,
-    pub clock: Pubkey,
-    pub stake_program: Pubkey,
+    pub vote_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub refund_rent_to: Pubkey,
+    pub fee_delay_account: Pubkey,
     pub system_program: Pubkey,
+    pub clock: Pubkey,
+    pub pending_validator_fee_account: Pubkey,
 }
-impl<'me> From<&UnstakeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
-    for UnstakeKeys
+impl<'me> From<&RemoveValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for RemoveValidatorFeeKeys
 {
     fn from(
-        accounts: &UnstakeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+        accounts: &RemoveValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
     ) -> Self {
         Self {
-            unstaker: *accounts.unstaker.key,
-            stake_account: *accounts.stake_account.key,
-            destination: *accounts.destination.key,
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
             pool_account: *accounts.pool_account.key,
-            pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            fee_account: *accounts.fee_account.key,
-            stake_account_record_account: *accounts.stake_account_record_account.key,
-            protocol_fee_account: *accounts.protocol_fee_account.key,
-            protocol_fee_destination: *accounts.protocol_fee_destination.key,
-            clock: *accounts.clock.key,
-            stake_program: *accounts.stake_program.key,
+            vote_account: *accounts.vote_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            refund_rent_to: *accounts.refund_rent_to.key,
+            fee_delay_account: *accounts.fee_delay_account.key,
             system_program: *accounts.system_program.key,
+            clock: *accounts.clock.key,
+            pending_validator_fee_account: *accounts.pending_validator_fee_account.key,
         }
     }
 }
-impl From<&UnstakeKeys> for [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] {
-    fn from(keys: &UnstakeKeys) -> Self {
+impl From<&RemoveValidatorFeeKeys> for [AccountMeta; REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: &RemoveValidatorFeeKeys) -> Self {
         [
-            AccountMeta::new_readonly(keys.unstaker, true),
-            AccountMeta::new(keys.stake_account, false),
-            AccountMeta::new(keys.destination, false),
-            AccountMeta::new(keys.pool_account, false),
-            AccountMeta::new(keys.pool_sol_reserves, false),
-            AccountMeta::new_readonly(keys.fee_account, false),
-            AccountMeta::new(keys.stake_account_record_account, false),
-            AccountMeta::new_readonly(keys.protocol_fee_account, false),
-            AccountMeta::new(keys.protocol_fee_destination, false),
-            AccountMeta::new_readonly(keys.clock, false),
-            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new_readonly(keys.fee_authority, true),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.vote_account, false),
+            AccountMeta::new(keys.validator_fee_account, false),
+            AccountMeta::new(keys.refund_rent_to, false),
+            AccountMeta::new_readonly(keys.fee_delay_account, false),
             AccountMeta::new_readonly(keys.system_program, false),
+            AccountMeta::new_readonly(keys.clock, false),
+            AccountMeta::new(keys.pending_validator_fee_account, false),
         ]
     }
 }
-impl<'a> From<&UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN]
+impl<'a> From<&RemoveValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN]
 {
     fn from(
-        accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+        accounts: &RemoveValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     ) -> Self {
         [
-            accounts.unstaker.clone(),
-            accounts.stake_account.clone(),
-            accounts.destination.clone(),
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
             accounts.pool_account.clone(),
-            accounts.pool_sol_reserves.clone(),
-            accounts.fee_account.clone(),
-            accounts.stake_account_record_account.clone(),
-            accounts.protocol_fee_account.clone(),
-            accounts.protocol_fee_destination.clone(),
-            accounts.clock.clone(),
-            accounts.stake_program.clone(),
+            accounts.vote_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.refund_rent_to.clone(),
+            accounts.fee_delay_account.clone(),
             accounts.system_program.clone(),
+            accounts.clock.clone(),
+            accounts.pending_validator_fee_account.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UnstakeIxArgs {}
+pub struct RemoveValidatorFeeIxArgs {}
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeIxData<'me>(pub &'me UnstakeIxArgs);
-pub const UNSTAKE_IX_DISCM: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
-impl<'me> From<&'me UnstakeIxArgs> for UnstakeIxData<'me> {
-    fn from(args: &'me UnstakeIxArgs) -> Self {
+pub struct RemoveValidatorFeeIxData<'me>(pub &'me RemoveValidatorFeeIxArgs);
+pub const REMOVE_VALIDATOR_FEE_IX_DISCM: [u8; 8] = [63, 135, 225, 91, 149, 217, 240, 222];
+impl<'me> From<&'me RemoveValidatorFeeIxArgs> for RemoveValidatorFeeIxData<'me> {
+    fn from(args: &'me RemoveValidatorFeeIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for UnstakeIxData<'_> {
+impl BorshSerialize for RemoveValidatorFeeIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&UNSTAKE_IX_DISCM)?;
+        writer.write_all(&REMOVE_VALIDATOR_FEE_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn unstake_ix<K: Into<UnstakeKeys>, A: Into<UnstakeIxArgs>>(
+pub fn remove_validator_fee_ix<
+    K: Into<RemoveValidatorFeeKeys>,
+    A: Into<RemoveValidatorFeeIxArgs>,
+>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: UnstakeKeys = accounts.into();
-    let metas: [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: UnstakeIxArgs = args.into();
-    let data: UnstakeIxData = (&args_full).into();
+    let keys: RemoveValidatorFeeKeys = accounts.into();
+    let metas: [AccountMeta; REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: RemoveValidatorFeeIxArgs = args.into();
+    let data: RemoveValidatorFeeIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn unstake_invoke<'a, A: Into<UnstakeIxArgs>>(
-    accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn remove_validator_fee_invoke<'a, A: Into<RemoveValidatorFeeIxArgs>>(
+    accounts: &RemoveValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = unstake_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = remove_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn unstake_invoke_signed<'a, A: Into<UnstakeIxArgs>>(
-    accounts: &UnstakeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn remove_validator_fee_invoke_signed<'a, A: Into<RemoveValidatorFeeIxArgs>>(
+    accounts: &RemoveValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = unstake_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = remove_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; REMOVE_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const UNSTAKE_WSOL_IX_ACCOUNTS_LEN: usize = 13usize;
+pub const APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN: usize = 8usize;
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeWsolAccounts<
+pub struct ApplyPendingValidatorFeeAccounts<
     'me,
     'a0: 'me,
     'a1: 'me,
@@ -1307,153 +7082,130 @@ pub struct UnstakeWsolAccounts<
     'a5: 'me,
     'a6: 'me,
     'a7: 'me,
-    'a8: 'me,
-    'a9: 'me,
-    'a10: 'me,
-    'a11: 'me,
-    'a12: 'me,
 > {
-    pub unstaker: &'me AccountInfo<'a0>,
-    pub stake_account: &'me AccountInfo<'a1>,
-    pub destination: &'me AccountInfo<'a2>,
-    pub pool_account: &'me AccountInfo<'a3>,
-    pub pool_sol_reserves: &'me AccountInfo<'a4>,
-    pub fee_account: &'me AccountInfo<'a5>,
-    pub stake_account_record_account: &'me AccountInfo<'a6>,
-    pub protocol_fee_account: &'me AccountInfo<'a7>,
-    pub protocol_fee_destination: &'me AccountInfo<'a8>,
-    pub clock: &'me AccountInfo<'a9>,
-    pub stake_program: &'me AccountInfo<'a10>,
-    pub system_program: &'me AccountInfo<'a11>,
-    pub token_program: &'me AccountInfo<'a12>,
+    pub payer: &'me AccountInfo<'a0>,
+    pub fee_authority: &'me AccountInfo<'a1>,
+    pub pool_account: &'me AccountInfo<'a2>,
+    pub vote_account: &'me AccountInfo<'a3>,
+    pub validator_fee_account: &'me AccountInfo<'a4>,
+    pub pending_validator_fee_account: &'me AccountInfo<'a5>,
+    pub system_program: &'me AccountInfo<'a6>,
+    pub clock: &'me AccountInfo<'a7>,
 }
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeWsolKeys {
-    pub unstaker: Pubkey,
-    pub stake_account: Pubkey,
-    pub destination: Pubkey,
+pub struct ApplyPendingValidatorFeeKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
     pub pool_account: Pubkey,
-    pub pool_sol_reserves: Pubkey,
-    pub fee_account: Pubkey,
-    pub stake_account_record_account: Pubkey,
-    pub protocol_fee_account: Pubkey,
-    pub protocol_fee_destination: Pubkey,
-    pub clock: Pubkey,
-    pub stake_program: Pubkey,
+    pub vote_account: Pubkey,
+    pub validator_fee_account: Pubkey,
+    pub pending_validator_fee_account: Pubkey,
     pub system_program: Pubkey,
-    pub token_program: Pubkey,
+    pub clock: Pubkey,
 }
-impl<'me> From<&UnstakeWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>>
-    for UnstakeWsolKeys
+impl<'me> From<&ApplyPendingValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>>
+    for ApplyPendingValidatorFeeKeys
 {
     fn from(
-        accounts: &UnstakeWsolAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+        accounts: &ApplyPendingValidatorFeeAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>,
     ) -> Self {
         Self {
-            unstaker: *accounts.unstaker.key,
-            stake_account: *accounts.stake_account.key,
-            destination: *accounts.destination.key,
+            payer: *accounts.payer.key,
+            fee_authority: *accounts.fee_authority.key,
             pool_account: *accounts.pool_account.key,
-            pool_sol_reserves: *accounts.pool_sol_reserves.key,
-            fee_account: *accounts.fee_account.key,
-            stake_account_record_account: *accounts.stake_account_record_account.key,
-            protocol_fee_account: *accounts.protocol_fee_account.key,
-            protocol_fee_destination: *accounts.protocol_fee_destination.key,
-            clock: *accounts.clock.key,
-            stake_program: *accounts.stake_program.key,
+            vote_account: *accounts.vote_account.key,
+            validator_fee_account: *accounts.validator_fee_account.key,
+            pending_validator_fee_account: *accounts.pending_validator_fee_account.key,
             system_program: *accounts.system_program.key,
-            token_program: *accounts.token_program.key,
+            clock: *accounts.clock.key,
         }
     }
 }
-impl From<&UnstakeWsolKeys> for [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
-    fn from(keys: &UnstakeWsolKeys) -> Self {
+impl From<&ApplyPendingValidatorFeeKeys>
+    for [AccountMeta; APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN]
+{
+    fn from(keys: &ApplyPendingValidatorFeeKeys) -> Self {
         [
-            AccountMeta::new_readonly(keys.unstaker, true),
-            AccountMeta::new(keys.stake_account, false),
-            AccountMeta::new(keys.destination, false),
-            AccountMeta::new(keys.pool_account, false),
-            AccountMeta::new(keys.pool_sol_reserves, false),
-            AccountMeta::new_readonly(keys.fee_account, false),
-            AccountMeta::new(keys.stake_account_record_account, false),
-            AccountMeta::new_readonly(keys.protocol_fee_account, false),
-            AccountMeta::new(keys.protocol_fee_destination, false),
-            AccountMeta::new_readonly(keys.clock, false),
-            AccountMeta::new_readonly(keys.stake_program, false),
+            AccountMeta::new(keys.payer, true),
+            AccountMeta::new(keys.fee_authority, false),
+            AccountMeta::new_readonly(keys.pool_account, false),
+            AccountMeta::new_readonly(keys.vote_account, false),
+            AccountMeta::new(keys.validator_fee_account, false),
+            AccountMeta::new(keys.pending_validator_fee_account, false),
             AccountMeta::new_readonly(keys.system_program, false),
-            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(keys.clock, false),
         ]
     }
 }
-impl<'a> From<&UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
-    for [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]
+impl<'a> From<&ApplyPendingValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
+    for [AccountInfo<'a>; APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN]
 {
     fn from(
-        accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+        accounts: &ApplyPendingValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     ) -> Self {
         [
-            accounts.unstaker.clone(),
-            accounts.stake_account.clone(),
-            accounts.destination.clone(),
+            accounts.payer.clone(),
+            accounts.fee_authority.clone(),
             accounts.pool_account.clone(),
-            accounts.pool_sol_reserves.clone(),
-            accounts.fee_account.clone(),
-            accounts.stake_account_record_account.clone(),
-            accounts.protocol_fee_account.clone(),
-            accounts.protocol_fee_destination.clone(),
-            accounts.clock.clone(),
-            accounts.stake_program.clone(),
+            accounts.vote_account.clone(),
+            accounts.validator_fee_account.clone(),
+            accounts.pending_validator_fee_account.clone(),
             accounts.system_program.clone(),
-            accounts.token_program.clone(),
+            accounts.clock.clone(),
         ]
     }
 }
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UnstakeWsolIxArgs {}
+pub struct ApplyPendingValidatorFeeIxArgs {}
 #[derive(Copy, Clone, Debug)]
-pub struct UnstakeWsolIxData<'me>(pub &'me UnstakeWsolIxArgs);
-pub const UNSTAKE_WSOL_IX_DISCM: [u8; 8] = [125, 93, 190, 135, 89, 174, 142, 149];
-impl<'me> From<&'me UnstakeWsolIxArgs> for UnstakeWsolIxData<'me> {
-    fn from(args: &'me UnstakeWsolIxArgs) -> Self {
+pub struct ApplyPendingValidatorFeeIxData<'me>(pub &'me ApplyPendingValidatorFeeIxArgs);
+pub const APPLY_PENDING_VALIDATOR_FEE_IX_DISCM: [u8; 8] = [145, 55, 65, 37, 61, 209, 66, 7];
+impl<'me> From<&'me ApplyPendingValidatorFeeIxArgs> for ApplyPendingValidatorFeeIxData<'me> {
+    fn from(args: &'me ApplyPendingValidatorFeeIxArgs) -> Self {
         Self(args)
     }
 }
-impl BorshSerialize for UnstakeWsolIxData<'_> {
+impl BorshSerialize for ApplyPendingValidatorFeeIxData<'_> {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        writer.write_all(&UNSTAKE_WSOL_IX_DISCM)?;
+        writer.write_all(&APPLY_PENDING_VALIDATOR_FEE_IX_DISCM)?;
         self.0.serialize(writer)
     }
 }
-pub fn unstake_wsol_ix<K: Into<UnstakeWsolKeys>, A: Into<UnstakeWsolIxArgs>>(
+pub fn apply_pending_validator_fee_ix<
+    K: Into<ApplyPendingValidatorFeeKeys>,
+    A: Into<ApplyPendingValidatorFeeIxArgs>,
+>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
-    let keys: UnstakeWsolKeys = accounts.into();
-    let metas: [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = (&keys).into();
-    let args_full: UnstakeWsolIxArgs = args.into();
-    let data: UnstakeWsolIxData = (&args_full).into();
+    let keys: ApplyPendingValidatorFeeKeys = accounts.into();
+    let metas: [AccountMeta; APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN] = (&keys).into();
+    let args_full: ApplyPendingValidatorFeeIxArgs = args.into();
+    let data: ApplyPendingValidatorFeeIxData = (&args_full).into();
     Ok(Instruction {
         program_id: crate::ID,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
-pub fn unstake_wsol_invoke<'a, A: Into<UnstakeWsolIxArgs>>(
-    accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn apply_pending_validator_fee_invoke<'a, A: Into<ApplyPendingValidatorFeeIxArgs>>(
+    accounts: &ApplyPendingValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
-    let ix = unstake_wsol_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = apply_pending_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN] =
+        accounts.into();
     invoke(&ix, &account_info)
 }
-pub fn unstake_wsol_invoke_signed<'a, A: Into<UnstakeWsolIxArgs>>(
-    accounts: &UnstakeWsolAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
+pub fn apply_pending_validator_fee_invoke_signed<'a, A: Into<ApplyPendingValidatorFeeIxArgs>>(
+    accounts: &ApplyPendingValidatorFeeAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = unstake_wsol_ix(accounts, args)?;
-    let account_info: [AccountInfo<'a>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+    let ix = apply_pending_validator_fee_ix(accounts, args)?;
+    let account_info: [AccountInfo<'a>; APPLY_PENDING_VALIDATOR_FEE_IX_ACCOUNTS_LEN] =
+        accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
 pub const SET_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN: usize = 5usize;
@@ -1558,15 +7310,27 @@ pub fn set_flash_loan_fee_invoke_signed<'a, A: Into<SetFlashLoanFeeIxArgs>>(
     let account_info: [AccountInfo<'a>; SET_FLASH_LOAN_FEE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub const TAKE_FLASH_LOAN_IX_ACCOUNTS_LEN: usize = 6usize;
+pub const TAKE_FLASH_LOAN_IX_ACCOUNTS_LEN: usize = 8usize;
 #[derive(Copy, Clone, Debug)]
-pub struct TakeFlashLoanAccounts<'me, 'a0: 'me, 'a1: 'me, 'a2: 'me, 'a3: 'me, 'a4: 'me, 'a5: 'me> {
+pub struct TakeFlashLoanAccounts<
+    'me,
+    'a0: 'me,
+    'a1: 'me,
+    'a2: 'me,
+    'a3: 'me,
+    'a4: 'me,
+    'a5: 'me,
+    'a6: 'me,
+    'a7: 'me,
+> {
     pub receiver: &'me AccountInfo<'a0>,
     pub pool_account: &'me AccountInfo<'a1>,
     pub pool_sol_reserves: &'me AccountInfo<'a2>,
     pub flash_account: &'me AccountInfo<'a3>,
     pub system_program: &'me AccountInfo<'a4>,
     pub instructions: &'me AccountInfo<'a5>,
+    pub pool_pause_account: &'me AccountInfo<'a6>,
+    pub protocol_pause_account: &'me AccountInfo<'a7>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct TakeFlashLoanKeys {
@@ -1576,9 +7340,11 @@ pub struct TakeFlashLoanKeys {
     pub flash_account: Pubkey,
     pub system_program: Pubkey,
     pub instructions: Pubkey,
+    pub pool_pause_account: Pubkey,
+    pub protocol_pause_account: Pubkey,
 }
-impl<'me> From<&TakeFlashLoanAccounts<'me, '_, '_, '_, '_, '_, '_>> for TakeFlashLoanKeys {
-    fn from(accounts: &TakeFlashLoanAccounts<'me, '_, '_, '_, '_, '_, '_>) -> Self {
+impl<'me> From<&TakeFlashLoanAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>> for TakeFlashLoanKeys {
+    fn from(accounts: &TakeFlashLoanAccounts<'me, '_, '_, '_, '_, '_, '_, '_, '_>) -> Self {
         Self {
             receiver: *accounts.receiver.key,
             pool_account: *accounts.pool_account.key,
@@ -1586,6 +7352,8 @@ impl<'me> From<&TakeFlashLoanAccounts<'me, '_, '_, '_, '_, '_, '_>> for TakeFlas
             flash_account: *accounts.flash_account.key,
             system_program: *accounts.system_program.key,
             instructions: *accounts.instructions.key,
+            pool_pause_account: *accounts.pool_pause_account.key,
+            protocol_pause_account: *accounts.protocol_pause_account.key,
         }
     }
 }
@@ -1598,13 +7366,15 @@ impl From<&TakeFlashLoanKeys> for [AccountMeta; TAKE_FLASH_LOAN_IX_ACCOUNTS_LEN]
             AccountMeta::new(keys.flash_account, false),
             AccountMeta::new_readonly(keys.system_program, false),
             AccountMeta::new_readonly(keys.instructions, false),
+            AccountMeta::new_readonly(keys.pool_pause_account, false),
+            AccountMeta::new_readonly(keys.protocol_pause_account, false),
         ]
     }
 }
-impl<'a> From<&TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>>
+impl<'a> From<&TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>>
     for [AccountInfo<'a>; TAKE_FLASH_LOAN_IX_ACCOUNTS_LEN]
 {
-    fn from(accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
+    fn from(accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>) -> Self {
         [
             accounts.receiver.clone(),
             accounts.pool_account.clone(),
@@ -1612,6 +7382,8 @@ impl<'a> From<&TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>>
             accounts.flash_account.clone(),
             accounts.system_program.clone(),
             accounts.instructions.clone(),
+            accounts.pool_pause_account.clone(),
+            accounts.protocol_pause_account.clone(),
         ]
     }
 }
@@ -1649,7 +7421,7 @@ pub fn take_flash_loan_ix<K: Into<TakeFlashLoanKeys>, A: Into<TakeFlashLoanIxArg
     })
 }
 pub fn take_flash_loan_invoke<'a, A: Into<TakeFlashLoanIxArgs>>(
-    accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
 ) -> ProgramResult {
     let ix = take_flash_loan_ix(accounts, args)?;
@@ -1657,7 +7429,7 @@ pub fn take_flash_loan_invoke<'a, A: Into<TakeFlashLoanIxArgs>>(
     invoke(&ix, &account_info)
 }
 pub fn take_flash_loan_invoke_signed<'a, A: Into<TakeFlashLoanIxArgs>>(
-    accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a>,
+    accounts: &TakeFlashLoanAccounts<'_, 'a, 'a, 'a, 'a, 'a, 'a, 'a, 'a>,
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
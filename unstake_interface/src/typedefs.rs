@@ -6,7 +6,7 @@ pub struct DataV2LpToken {
     pub symbol: String,
     pub uri: String,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rational {
     pub num: u64,
@@ -18,9 +18,50 @@ pub struct LiquidityLinearParams {
     pub max_liq_remaining: Rational,
     pub zero_liq_remaining: Rational,
 }
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PiecewiseLinearPoint {
+    pub utilization: Rational,
+    pub fee_ratio: Rational,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PiecewiseLinearParams {
+    pub points: [PiecewiseLinearPoint; 5],
+    pub points_len: u8,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochProgressParams {
+    pub base_ratio: Rational,
+    pub epoch_end_multiplier: Rational,
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegationStateParams {
+    pub active_ratio: Rational,
+    pub deactivating_ratio: Rational,
+    pub inactive_ratio: Rational,
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeeEnum {
     Flat { ratio: Rational },
     LiquidityLinear { params: LiquidityLinearParams },
+    PiecewiseLinear { params: PiecewiseLinearParams },
+    EpochProgress { params: EpochProgressParams },
+    DelegationState { params: DelegationStateParams },
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidatorFeeEnum {
+    Override { fee: FeeEnum },
+    Deny,
+}
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PausedInstructions {
+    pub unstake: bool,
+    pub take_flash_loan: bool,
+    pub add_liquidity: bool,
 }